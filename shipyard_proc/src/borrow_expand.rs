@@ -47,7 +47,21 @@ pub(crate) fn expand_borrow(
             });
 
             let field = field_name
-                .into_iter()
+                .clone()
+                .zip(field_type.clone())
+                .zip(field_is_default.clone())
+                .map(|((field_name, field_type), field_is_default)| {
+                    if field_is_default {
+                        quote!(
+                            #field_name: core::default::Default::default()
+                        )
+                    } else {
+                        quote!(
+                            #field_name: <#field_type as ::shipyard::borrow::Borrow>::borrow(all_storages, all_borrow.clone(), last_run, current)?
+                        )
+                    }
+                });
+            let field_in_system = field_name
                 .zip(field_type)
                 .zip(field_is_default)
                 .map(|((field_name, field_type), field_is_default)| {
@@ -57,7 +71,7 @@ pub(crate) fn expand_borrow(
                         )
                     } else {
                         quote!(
-                            #field_name: <#field_type as ::shipyard::borrow::Borrow>::borrow(all_storages, all_borrow.clone(), last_run, current)?
+                            #field_name: <#field_type as ::shipyard::borrow::Borrow>::borrow_in_system(all_storages, all_borrow.clone(), last_run, current, locals)?
                         )
                     }
                 });
@@ -71,6 +85,12 @@ pub(crate) fn expand_borrow(
                             #(#field),*
                         })
                     }
+
+                    fn borrow_in_system<'__a>(all_storages: & '__a ::shipyard::all_storages::AllStorages, all_borrow: Option<::shipyard::advanced::atomic_refcell::SharedBorrow<'__a>>, last_run: Option<::shipyard::advanced::tracking::TrackingTimestamp>, current: ::shipyard::advanced::tracking::TrackingTimestamp, locals: & '__a ::shipyard::scheduler::SystemLocals) -> core::result::Result<Self::View<'__a>, ::shipyard::error::GetStorage> {
+                        Ok(#name {
+                            #(#field_in_system),*
+                        })
+                    }
                 }
             ))
         }
@@ -82,6 +102,13 @@ pub(crate) fn expand_borrow(
                     let field_type = &field.ty;
                     quote!(<#field_type as ::shipyard::borrow::Borrow>::borrow(all_storages, all_borrow.clone(), last_run, current)?)
                 });
+            let borrow_in_system = fields
+                .unnamed
+                .iter()
+                .map(|field| {
+                    let field_type = &field.ty;
+                    quote!(<#field_type as ::shipyard::borrow::Borrow>::borrow_in_system(all_storages, all_borrow.clone(), last_run, current, locals)?)
+                });
 
             Ok(quote!(
                 impl #impl_generics ::shipyard::borrow::Borrow for #name #ty_generics #where_clause {
@@ -90,6 +117,10 @@ pub(crate) fn expand_borrow(
                     fn borrow<'__a>(all_storages: & '__a ::shipyard::all_storages::AllStorages, all_borrow: Option<::shipyard::advanced::atomic_refcell::SharedBorrow<'__a>>, last_run: Option<::shipyard::advanced::tracking::TrackingTimestamp>, current: ::shipyard::advanced::tracking::TrackingTimestamp) -> core::result::Result<Self::View<'__a>, ::shipyard::error::GetStorage> {
                         Ok(#name(#(#borrow),*))
                     }
+
+                    fn borrow_in_system<'__a>(all_storages: & '__a ::shipyard::all_storages::AllStorages, all_borrow: Option<::shipyard::advanced::atomic_refcell::SharedBorrow<'__a>>, last_run: Option<::shipyard::advanced::tracking::TrackingTimestamp>, current: ::shipyard::advanced::tracking::TrackingTimestamp, locals: & '__a ::shipyard::scheduler::SystemLocals) -> core::result::Result<Self::View<'__a>, ::shipyard::error::GetStorage> {
+                        Ok(#name(#(#borrow_in_system),*))
+                    }
                 }
             ))
         }
@@ -50,7 +50,18 @@ pub(crate) fn expand_borrow_info(
                     }
                 },
             );
-            let field_tracking =
+            let field_tracking = field_type.clone().zip(field_is_default.clone()).map(
+                |(field_type, field_is_default)| {
+                    if field_is_default {
+                        quote!(();)
+                    } else {
+                        quote!(
+                            <#field_type>::enable_tracking(enable_tracking_fn);
+                        )
+                    }
+                },
+            );
+            let field_locals =
                 field_type
                     .zip(field_is_default)
                     .map(|(field_type, field_is_default)| {
@@ -58,7 +69,7 @@ pub(crate) fn expand_borrow_info(
                             quote!(();)
                         } else {
                             quote!(
-                                <#field_type>::enable_tracking(enable_tracking_fn);
+                                <#field_type>::init_locals(locals);
                             )
                         }
                     });
@@ -73,12 +84,16 @@ pub(crate) fn expand_borrow_info(
                     ) {
                         #(#field_tracking)*
                     }
+                    fn init_locals(locals: &mut ::shipyard::scheduler::SystemLocals) {
+                        #(#field_locals)*
+                    }
                 }
             ))
         }
         syn::Fields::Unnamed(fields) => {
             let field_type = fields.unnamed.iter().map(|field| &field.ty);
             let field_type_clone = field_type.clone();
+            let field_type_locals = field_type.clone();
 
             Ok(quote!(
                 unsafe impl #impl_generics ::shipyard::borrow::BorrowInfo for #name #ty_generics #where_clause {
@@ -90,6 +105,9 @@ pub(crate) fn expand_borrow_info(
                     ) {
                         #(<#field_type>::enable_tracking(enable_tracking_fn);)*
                     }
+                    fn init_locals(locals: &mut ::shipyard::scheduler::SystemLocals) {
+                        #(<#field_type_locals>::init_locals(locals);)*
+                    }
                 }
             ))
         }
@@ -0,0 +1,136 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{parse_quote, Error, Result};
+
+pub(crate) fn expand_bundle(
+    name: syn::Ident,
+    generics: syn::Generics,
+    data: syn::Data,
+) -> Result<TokenStream> {
+    let fields = match data {
+        syn::Data::Struct(data_struct) => data_struct.fields,
+        _ => {
+            return Err(Error::new(
+                Span::call_site(),
+                "Bundle can only be implemented on structs",
+            ))
+        }
+    };
+
+    let field_types = fields.iter().map(|field| &field.ty).collect::<Vec<_>>();
+    let bindings = (0..fields.len())
+        .map(|index| format_ident!("__field{}", index))
+        .collect::<Vec<_>>();
+
+    let (pattern, construct) = match &fields {
+        syn::Fields::Named(_) => {
+            let idents = fields.iter().map(|field| &field.ident).collect::<Vec<_>>();
+
+            (
+                quote!(#name { #(#idents: #bindings),* }),
+                quote!(#name { #(#idents: #bindings?),* }),
+            )
+        }
+        syn::Fields::Unnamed(_) => (
+            quote!(#name ( #(#bindings),* )),
+            quote!(#name ( #(#bindings?),* )),
+        ),
+        syn::Fields::Unit => (quote!(#name), quote!(#name)),
+    };
+
+    let add_generics = with_field_bounds(
+        &generics,
+        &field_types,
+        quote!(::shipyard::sparse_set::TupleAddComponent),
+    );
+    let (impl_generics, ty_generics, add_where_clause) = add_generics.split_for_impl();
+
+    let mut remove_generics = generics.clone();
+    let remove_where_clause = remove_generics.make_where_clause();
+    for field_type in &field_types {
+        remove_where_clause.predicates.push(parse_quote!(
+            #field_type: ::shipyard::sparse_set::TupleRemove<Out = Option<#field_type>>
+        ));
+    }
+    let remove_where_clause = &remove_generics.where_clause;
+
+    let delete_generics = with_field_bounds(
+        &generics,
+        &field_types,
+        quote!(::shipyard::sparse_set::TupleDelete),
+    );
+    let delete_where_clause = &delete_generics.where_clause;
+
+    Ok(quote!(
+        impl #impl_generics ::shipyard::sparse_set::TupleAddComponent for #name #ty_generics #add_where_clause {
+            #[inline]
+            #[track_caller]
+            #[allow(unused_variables)]
+            fn add_component(
+                self,
+                all_storages: &mut ::shipyard::all_storages::AllStorages,
+                entity: ::shipyard::EntityId,
+                current: ::shipyard::advanced::tracking::TrackingTimestamp,
+            ) {
+                let #pattern = self;
+
+                #(::shipyard::sparse_set::TupleAddComponent::add_component(#bindings, all_storages, entity, current);)*
+            }
+        }
+
+        impl #impl_generics ::shipyard::sparse_set::TupleRemove for #name #ty_generics #remove_where_clause {
+            type Out = Option<Self>;
+
+            #[allow(unused_variables)]
+            fn remove(
+                all_storages: &mut ::shipyard::all_storages::AllStorages,
+                entity: ::shipyard::EntityId,
+            ) -> Self::Out {
+                // a partial match leaves the entity untouched
+                if !<Self as ::shipyard::sparse_set::TupleRemove>::contains(all_storages, entity) {
+                    return None;
+                }
+
+                #(let #bindings = <#field_types as ::shipyard::sparse_set::TupleRemove>::remove(all_storages, entity);)*
+
+                Some(#construct)
+            }
+
+            #[allow(unused_variables)]
+            fn contains(
+                all_storages: &mut ::shipyard::all_storages::AllStorages,
+                entity: ::shipyard::EntityId,
+            ) -> bool {
+                true #(&& <#field_types as ::shipyard::sparse_set::TupleRemove>::contains(all_storages, entity))*
+            }
+        }
+
+        impl #impl_generics ::shipyard::sparse_set::TupleDelete for #name #ty_generics #delete_where_clause {
+            #[allow(unused_variables)]
+            fn delete(
+                all_storages: &mut ::shipyard::all_storages::AllStorages,
+                entity: ::shipyard::EntityId,
+            ) -> bool {
+                false #(| <#field_types as ::shipyard::sparse_set::TupleDelete>::delete(all_storages, entity))*
+            }
+        }
+    ))
+}
+
+/// Returns `generics` with `bound` added to all field types.
+fn with_field_bounds(
+    generics: &syn::Generics,
+    field_types: &[&syn::Type],
+    bound: TokenStream,
+) -> syn::Generics {
+    let mut generics = generics.clone();
+    let where_clause = generics.make_where_clause();
+
+    for field_type in field_types {
+        where_clause
+            .predicates
+            .push(parse_quote!(#field_type: #bound));
+    }
+
+    generics
+}
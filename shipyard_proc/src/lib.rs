@@ -2,16 +2,20 @@ extern crate proc_macro;
 
 mod borrow_expand;
 mod borrow_info_expand;
+mod bundle_expand;
 mod component_expand;
 mod into_iter_expand;
 mod label_expand;
+mod map_entities_expand;
 mod world_borrow_expand;
 
 use borrow_expand::expand_borrow;
 use borrow_info_expand::expand_borrow_info;
+use bundle_expand::expand_bundle;
 use component_expand::{expand_component, expand_unique};
 use into_iter_expand::expand_into_iter;
 use label_expand::expand_label;
+use map_entities_expand::expand_map_entities;
 use world_borrow_expand::expand_world_borrow;
 
 /// Multiple instances can be stored in the `World`.
@@ -94,6 +98,23 @@ pub fn borrow_info(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
         .into()
 }
 
+/// Group of components that can be added, removed and deleted as one.
+///
+/// Fields have to be components or other bundles.\
+/// Removing a bundle returns `None` and leaves the entity untouched if any of its components is missing.
+#[proc_macro_derive(Bundle)]
+pub fn bundle(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = syn::parse_macro_input!(item as syn::DeriveInput);
+
+    let name = input.ident;
+    let generics = input.generics;
+    let data = input.data;
+
+    expand_bundle(name, generics, data)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
 /// Flags a system or workload.
 ///
 /// Requires `Hash`, `Debug`, `PartialEq`, `Clone`
@@ -122,3 +143,19 @@ pub fn into_iter(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
         .unwrap_or_else(|err| err.to_compile_error())
         .into()
 }
+
+/// Updates the `EntityId`s stored in all fields.
+///
+/// Fields can be skipped with `#[shipyard(map_entities_skip)]`.
+#[proc_macro_derive(MapEntities, attributes(shipyard))]
+pub fn map_entities(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = syn::parse_macro_input!(item as syn::DeriveInput);
+
+    let name = input.ident;
+    let generics = input.generics;
+    let data = input.data;
+
+    expand_map_entities(name, generics, data)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
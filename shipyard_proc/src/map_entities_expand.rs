@@ -0,0 +1,110 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{parse_quote, spanned::Spanned, Error, Result};
+
+pub(crate) fn expand_map_entities(
+    name: syn::Ident,
+    mut generics: syn::Generics,
+    data: syn::Data,
+) -> Result<TokenStream> {
+    for param in generics.type_params_mut() {
+        param.bounds.push(parse_quote!(::shipyard::MapEntities));
+    }
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let arms = match data {
+        syn::Data::Struct(data_struct) => {
+            let arm = expand_fields(quote!(Self), &data_struct.fields)?;
+
+            vec![arm]
+        }
+        syn::Data::Enum(data_enum) => data_enum
+            .variants
+            .iter()
+            .map(|variant| {
+                let variant_name = &variant.ident;
+
+                expand_fields(quote!(Self::#variant_name), &variant.fields)
+            })
+            .collect::<Result<Vec<_>>>()?,
+        syn::Data::Union(_) => {
+            return Err(Error::new(
+                Span::call_site(),
+                "MapEntities cannot be implemented on unions",
+            ))
+        }
+    };
+
+    Ok(quote!(
+        impl #impl_generics ::shipyard::MapEntities for #name #ty_generics #where_clause {
+            #[allow(unused_variables)]
+            fn map_entities(&mut self, entity_map: &::shipyard::EntityMap) {
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+    ))
+}
+
+/// Returns a match arm mapping all fields not marked with `map_entities_skip`.
+fn expand_fields(path: TokenStream, fields: &syn::Fields) -> Result<TokenStream> {
+    let mut patterns = Vec::new();
+    let mut bindings = Vec::new();
+
+    for (index, field) in fields.iter().enumerate() {
+        let binding = format_ident!("__field{}", index);
+        let skip = is_skipped(field)?;
+
+        let pattern = match (&field.ident, skip) {
+            (Some(ident), true) => quote!(#ident: _),
+            (Some(ident), false) => quote!(#ident: #binding),
+            (None, true) => quote!(_),
+            (None, false) => quote!(#binding),
+        };
+
+        patterns.push(pattern);
+
+        if !skip {
+            bindings.push(binding);
+        }
+    }
+
+    let pattern = match fields {
+        syn::Fields::Named(_) => quote!(#path { #(#patterns),* }),
+        syn::Fields::Unnamed(_) => quote!(#path ( #(#patterns),* )),
+        syn::Fields::Unit => quote!(#path),
+    };
+
+    Ok(quote!(
+        #pattern => {
+            #(::shipyard::MapEntities::map_entities(#bindings, entity_map);)*
+        }
+    ))
+}
+
+fn is_skipped(field: &syn::Field) -> Result<bool> {
+    let mut skip = false;
+
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("shipyard"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("map_entities_skip") {
+                skip = true;
+
+                Ok(())
+            } else {
+                Err(Error::new(
+                    meta.path.span(),
+                    "Unknown attribute. Possible attribute: map_entities_skip",
+                ))
+            }
+        })?;
+    }
+
+    Ok(skip)
+}
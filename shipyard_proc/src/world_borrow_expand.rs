@@ -50,7 +50,21 @@ pub(crate) fn expand_world_borrow(
             });
 
             let field = field_name
-                .into_iter()
+                .clone()
+                .zip(field_type.clone())
+                .zip(field_is_default.clone())
+                .map(|((field_name, field_type), field_is_default)| {
+                    if field_is_default {
+                        quote!(
+                            #field_name: core::default::Default::default()
+                        )
+                    } else {
+                        quote!(
+                            #field_name: <#field_type as ::shipyard::borrow::WorldBorrow>::world_borrow(world, last_run, current)?
+                        )
+                    }
+                });
+            let field_in_system = field_name
                 .zip(field_type)
                 .zip(field_is_default)
                 .map(|((field_name, field_type), field_is_default)| {
@@ -60,7 +74,7 @@ pub(crate) fn expand_world_borrow(
                         )
                     } else {
                         quote!(
-                            #field_name: <#field_type as ::shipyard::borrow::WorldBorrow>::world_borrow(world, last_run, current)?
+                            #field_name: <#field_type as ::shipyard::borrow::WorldBorrow>::world_borrow_in_system(world, last_run, current, locals)?
                         )
                     }
                 });
@@ -74,6 +88,12 @@ pub(crate) fn expand_world_borrow(
                             #(#field),*
                         })
                     }
+
+                    fn world_borrow_in_system<'__w>(world: & '__w ::shipyard::World, last_run: Option<::shipyard::advanced::tracking::TrackingTimestamp>, current: ::shipyard::advanced::tracking::TrackingTimestamp, locals: & '__w ::shipyard::scheduler::SystemLocals) -> core::result::Result<Self::WorldView<'__w>, ::shipyard::error::GetStorage> {
+                        Ok(#name {
+                            #(#field_in_system),*
+                        })
+                    }
                 }
             ))
         }
@@ -82,6 +102,10 @@ pub(crate) fn expand_world_borrow(
                 let field_type = &field.ty;
                 quote!(<#field_type as ::shipyard::borrow::WorldBorrow>::world_borrow(world, last_run, current)?)
             });
+            let world_borrow_in_system = fields.unnamed.iter().map(|field| {
+                let field_type = &field.ty;
+                quote!(<#field_type as ::shipyard::borrow::WorldBorrow>::world_borrow_in_system(world, last_run, current, locals)?)
+            });
 
             Ok(quote!(
                 impl #impl_generics ::shipyard::borrow::WorldBorrow for #name #ty_generics #where_clause {
@@ -90,6 +114,10 @@ pub(crate) fn expand_world_borrow(
                     fn world_borrow<'__w>(world: & '__w ::shipyard::World, last_run: Option<::shipyard::advanced::tracking::TrackingTimestamp>, current: ::shipyard::advanced::tracking::TrackingTimestamp) -> core::result::Result<Self::WorldView<'__w>, ::shipyard::error::GetStorage> {
                         Ok(#name(#(#world_borrow),*))
                     }
+
+                    fn world_borrow_in_system<'__w>(world: & '__w ::shipyard::World, last_run: Option<::shipyard::advanced::tracking::TrackingTimestamp>, current: ::shipyard::advanced::tracking::TrackingTimestamp, locals: & '__w ::shipyard::scheduler::SystemLocals) -> core::result::Result<Self::WorldView<'__w>, ::shipyard::error::GetStorage> {
+                        Ok(#name(#(#world_borrow_in_system),*))
+                    }
                 }
             ))
         }
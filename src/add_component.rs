@@ -27,6 +27,11 @@ pub trait AddComponent<T> {
     fn add_component_unchecked(&mut self, entity: EntityId, component: T)
     where
         Self: Sized;
+    /// Disables the components just added to a disabled entity.
+    #[doc(hidden)]
+    #[inline]
+    #[allow(unused_variables)]
+    fn disable_added(&mut self, entity: EntityId) {}
 }
 
 impl AddComponent<()> for () {
@@ -40,6 +45,10 @@ impl<T: Component, TRACK> AddComponent<T> for ViewMut<'_, T, TRACK> {
     fn add_component_unchecked(&mut self, entity: EntityId, component: T) {
         let _ = self.sparse_set.insert(entity, component, self.current);
     }
+    #[inline]
+    fn disable_added(&mut self, entity: EntityId) {
+        self.sparse_set.private_disable(entity);
+    }
 }
 
 impl<T: Component, TRACK> AddComponent<T> for &mut ViewMut<'_, T, TRACK> {
@@ -48,6 +57,10 @@ impl<T: Component, TRACK> AddComponent<T> for &mut ViewMut<'_, T, TRACK> {
     fn add_component_unchecked(&mut self, entity: EntityId, component: T) {
         let _ = self.sparse_set.insert(entity, component, self.current);
     }
+    #[inline]
+    fn disable_added(&mut self, entity: EntityId) {
+        self.sparse_set.private_disable(entity);
+    }
 }
 
 impl<T: Component, TRACK> AddComponent<Option<T>> for ViewMut<'_, T, TRACK> {
@@ -58,6 +71,10 @@ impl<T: Component, TRACK> AddComponent<Option<T>> for ViewMut<'_, T, TRACK> {
             let _ = self.sparse_set.insert(entity, component, self.current);
         }
     }
+    #[inline]
+    fn disable_added(&mut self, entity: EntityId) {
+        self.sparse_set.private_disable(entity);
+    }
 }
 
 impl<T: Component, TRACK> AddComponent<Option<T>> for &mut ViewMut<'_, T, TRACK> {
@@ -68,6 +85,10 @@ impl<T: Component, TRACK> AddComponent<Option<T>> for &mut ViewMut<'_, T, TRACK>
             let _ = self.sparse_set.insert(entity, component, self.current);
         }
     }
+    #[inline]
+    fn disable_added(&mut self, entity: EntityId) {
+        self.sparse_set.private_disable(entity);
+    }
 }
 
 macro_rules! impl_add_component {
@@ -80,6 +101,12 @@ macro_rules! impl_add_component {
                     let _ = self.$index.add_component_unchecked(entity, component.$index);
                 )+
             }
+            #[inline]
+            fn disable_added(&mut self, entity: EntityId) {
+                $(
+                    self.$index.disable_added(entity);
+                )+
+            }
         }
     }
 }
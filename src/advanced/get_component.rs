@@ -6,6 +6,7 @@ use crate::borrow::{NonSend, NonSendSync, NonSync};
 use crate::component::Component;
 use crate::entity_id::EntityId;
 use crate::error;
+use crate::index::IndexSync;
 use crate::sparse_set::SparseSet;
 use crate::tracking::TrackingTimestamp;
 use core::any::type_name;
@@ -53,6 +54,7 @@ pub struct RefMut<'a, T> {
     inner: T,
     flag: Option<&'a mut TrackingTimestamp>,
     current: TrackingTimestamp,
+    index_sync: Option<IndexSync>,
     all_borrow: Option<SharedBorrow<'a>>,
     borrow: ExclusiveBorrow<'a>,
 }
@@ -67,6 +69,7 @@ impl<'a, T> RefMut<'a, T> {
             inner: f(orig.inner),
             flag: orig.flag,
             current: orig.current,
+            index_sync: orig.index_sync,
             all_borrow: orig.all_borrow,
             borrow: orig.borrow,
         }
@@ -142,12 +145,12 @@ impl<T: Component + Send + Sync> GetComponent for &'_ T {
         let (sparse_set, borrow) = unsafe { ARef::destructure(view) };
 
         Ok(Ref {
-            inner: sparse_set
-                .private_get(entity)
-                .ok_or_else(|| error::MissingComponent {
+            inner: sparse_set.private_get_enabled(entity).ok_or_else(|| {
+                error::MissingComponent {
                     id: entity,
                     name: type_name::<T>(),
-                })?,
+                }
+            })?,
             all_borrow,
             borrow,
         })
@@ -170,12 +173,12 @@ impl<T: Component + Sync> GetComponent for NonSend<&'_ T> {
         let (sparse_set, borrow) = unsafe { ARef::destructure(view) };
 
         Ok(Ref {
-            inner: sparse_set
-                .private_get(entity)
-                .ok_or_else(|| error::MissingComponent {
+            inner: sparse_set.private_get_enabled(entity).ok_or_else(|| {
+                error::MissingComponent {
                     id: entity,
                     name: type_name::<T>(),
-                })?,
+                }
+            })?,
             all_borrow,
             borrow,
         })
@@ -198,12 +201,12 @@ impl<T: Component + Send> GetComponent for NonSync<&'_ T> {
         let (sparse_set, borrow) = unsafe { ARef::destructure(view) };
 
         Ok(Ref {
-            inner: sparse_set
-                .private_get(entity)
-                .ok_or_else(|| error::MissingComponent {
+            inner: sparse_set.private_get_enabled(entity).ok_or_else(|| {
+                error::MissingComponent {
                     id: entity,
                     name: type_name::<T>(),
-                })?,
+                }
+            })?,
             all_borrow,
             borrow,
         })
@@ -227,12 +230,12 @@ impl<T: Component> GetComponent for NonSendSync<&'_ T> {
         let (sparse_set, borrow) = unsafe { ARef::destructure(view) };
 
         Ok(Ref {
-            inner: sparse_set
-                .private_get(entity)
-                .ok_or_else(|| error::MissingComponent {
+            inner: sparse_set.private_get_enabled(entity).ok_or_else(|| {
+                error::MissingComponent {
                     id: entity,
                     name: type_name::<T>(),
-                })?,
+                }
+            })?,
             all_borrow,
             borrow,
         })
@@ -253,8 +256,10 @@ impl<T: Component + Send + Sync> GetComponent for &'_ mut T {
 
         let (sparse_set, borrow) = unsafe { ARefMut::destructure(view) };
 
+        let (sparse_set, index_sync) = IndexSync::new(sparse_set);
+
         let index = sparse_set
-            .index_of(entity)
+            .enabled_index_of(entity)
             .ok_or_else(|| error::MissingComponent {
                 id: entity,
                 name: type_name::<T>(),
@@ -272,6 +277,7 @@ impl<T: Component + Send + Sync> GetComponent for &'_ mut T {
             flag: is_tracking_modification
                 .then(|| unsafe { modification_data.get_unchecked_mut(index) }),
             current,
+            index_sync,
             all_borrow,
             borrow,
         })
@@ -294,25 +300,28 @@ impl<T: Component + Sync> GetComponent for NonSend<&'_ mut T> {
 
         let (sparse_set, borrow) = unsafe { ARefMut::destructure(view) };
 
+        let (sparse_set, index_sync) = IndexSync::new(&mut sparse_set.0);
+
         let index = sparse_set
-            .index_of(entity)
+            .enabled_index_of(entity)
             .ok_or_else(|| error::MissingComponent {
                 id: entity,
                 name: type_name::<T>(),
             })?;
 
-        let NonSend(SparseSet {
+        let SparseSet {
             data,
             modification_data,
             is_tracking_modification,
             ..
-        }) = sparse_set;
+        } = sparse_set;
 
         Ok(RefMut {
             inner: unsafe { data.get_unchecked_mut(index) },
             flag: is_tracking_modification
                 .then(|| unsafe { modification_data.get_unchecked_mut(index) }),
             current,
+            index_sync,
             all_borrow,
             borrow,
         })
@@ -335,25 +344,28 @@ impl<T: Component + Send> GetComponent for NonSync<&'_ mut T> {
 
         let (sparse_set, borrow) = unsafe { ARefMut::destructure(view) };
 
+        let (sparse_set, index_sync) = IndexSync::new(&mut sparse_set.0);
+
         let index = sparse_set
-            .index_of(entity)
+            .enabled_index_of(entity)
             .ok_or_else(|| error::MissingComponent {
                 id: entity,
                 name: type_name::<T>(),
             })?;
 
-        let NonSync(SparseSet {
+        let SparseSet {
             data,
             modification_data,
             is_tracking_modification,
             ..
-        }) = sparse_set;
+        } = sparse_set;
 
         Ok(RefMut {
             inner: unsafe { data.get_unchecked_mut(index) },
             flag: is_tracking_modification
                 .then(|| unsafe { modification_data.get_unchecked_mut(index) }),
             current,
+            index_sync,
             all_borrow,
             borrow,
         })
@@ -376,25 +388,28 @@ impl<T: Component> GetComponent for NonSendSync<&'_ mut T> {
 
         let (sparse_set, borrow) = unsafe { ARefMut::destructure(view) };
 
+        let (sparse_set, index_sync) = IndexSync::new(&mut sparse_set.0);
+
         let index = sparse_set
-            .index_of(entity)
+            .enabled_index_of(entity)
             .ok_or_else(|| error::MissingComponent {
                 id: entity,
                 name: type_name::<T>(),
             })?;
 
-        let NonSendSync(SparseSet {
+        let SparseSet {
             data,
             modification_data,
             is_tracking_modification,
             ..
-        }) = sparse_set;
+        } = sparse_set;
 
         Ok(RefMut {
             inner: unsafe { data.get_unchecked_mut(index) },
             flag: is_tracking_modification
                 .then(|| unsafe { modification_data.get_unchecked_mut(index) }),
             current,
+            index_sync,
             all_borrow,
             borrow,
         })
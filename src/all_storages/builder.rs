@@ -4,12 +4,14 @@ use crate::all_storages::{AllStorages, LockPresent, ThreadIdPresent};
 use crate::atomic_refcell::AtomicRefCell;
 use crate::entities::Entities;
 use crate::public_transport::{RwLock, ShipyardRwLock};
+use crate::registry::TypeRegistry;
 #[cfg(feature = "std")]
 use crate::std_thread_id_generator;
 use crate::storage::{SBox, StorageId};
 use crate::ShipHashMap;
 use alloc::boxed::Box;
 use alloc::sync::Arc;
+use alloc::vec::Vec;
 use core::marker::PhantomData;
 use core::sync::atomic::AtomicU64;
 
@@ -75,7 +77,9 @@ impl AllStoragesBuilder<LockPresent, ThreadIdPresent> {
     pub(crate) fn build(self, counter: Arc<AtomicU64>) -> AtomicRefCell<AllStorages> {
         let mut storages = ShipHashMap::new();
 
-        storages.insert(StorageId::of::<Entities>(), SBox::new(Entities::new()));
+        let entities = Entities::new();
+        let deletion_queues = entities.deletion_queues.clone();
+        storages.insert(StorageId::of::<Entities>(), SBox::new(entities));
 
         let storages = if let Some(custom_lock) = self.custom_lock {
             RwLock::new_custom(custom_lock, storages)
@@ -103,13 +107,28 @@ impl AllStoragesBuilder<LockPresent, ThreadIdPresent> {
                     main_thread_id,
                     thread_id_generator: thread_id_generator.clone(),
                     counter,
+                    workload_runs: AtomicU64::new(0),
+                    registry: TypeRegistry::default(),
+                    groups: Vec::new(),
+                    deletion_queues,
+                    #[cfg(feature = "parallel")]
+                    fixed_chunk_size: None,
                 },
                 thread_id_generator,
             )
         }
         #[cfg(not(feature = "thread_local"))]
         {
-            AtomicRefCell::new(AllStorages { storages, counter })
+            AtomicRefCell::new(AllStorages {
+                storages,
+                counter,
+                workload_runs: AtomicU64::new(0),
+                registry: TypeRegistry::default(),
+                groups: Vec::new(),
+                deletion_queues,
+                #[cfg(feature = "parallel")]
+                fixed_chunk_size: None,
+            })
         }
     }
 }
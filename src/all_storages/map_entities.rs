@@ -0,0 +1,89 @@
+use crate::all_storages::AllStorages;
+#[cfg(feature = "thread_local")]
+use crate::borrow::{NonSend, NonSendSync, NonSync};
+use crate::component::Component;
+use crate::map_entities::MapEntities;
+use crate::sparse_set::SparseSet;
+use crate::storage::StorageId;
+
+pub trait TupleMapEntities {
+    fn register_map_entities(all_storages: &mut AllStorages);
+}
+
+impl TupleMapEntities for () {
+    fn register_map_entities(_all_storages: &mut AllStorages) {}
+}
+
+impl<T: Component + MapEntities + Send + Sync> TupleMapEntities for SparseSet<T> {
+    fn register_map_entities(all_storages: &mut AllStorages) {
+        all_storages
+            .exclusive_storage_or_insert_mut(StorageId::of::<SparseSet<T>>(), SparseSet::<T>::new)
+            .register_map_entities();
+    }
+}
+
+#[cfg(feature = "thread_local")]
+impl<T: Component + MapEntities + Send + Sync> TupleMapEntities for NonSend<SparseSet<T>> {
+    fn register_map_entities(all_storages: &mut AllStorages) {
+        all_storages
+            .exclusive_storage_or_insert_mut(StorageId::of::<NonSend<SparseSet<T>>>(), || {
+                NonSend(SparseSet::<T>::new())
+            })
+            .register_map_entities();
+    }
+}
+
+#[cfg(feature = "thread_local")]
+impl<T: Component + MapEntities + Send + Sync> TupleMapEntities for NonSync<SparseSet<T>> {
+    fn register_map_entities(all_storages: &mut AllStorages) {
+        all_storages
+            .exclusive_storage_or_insert_mut(StorageId::of::<NonSync<SparseSet<T>>>(), || {
+                NonSync(SparseSet::<T>::new())
+            })
+            .register_map_entities();
+    }
+}
+
+#[cfg(feature = "thread_local")]
+impl<T: Component + MapEntities + Send + Sync> TupleMapEntities for NonSendSync<SparseSet<T>> {
+    fn register_map_entities(all_storages: &mut AllStorages) {
+        all_storages
+            .exclusive_storage_or_insert_mut(StorageId::of::<NonSendSync<SparseSet<T>>>(), || {
+                NonSendSync(SparseSet::<T>::new())
+            })
+            .register_map_entities();
+    }
+}
+
+macro_rules! impl_map_entities {
+    ($(($storage: ident, $index: tt))+) => {
+        impl<$($storage: TupleMapEntities),+> TupleMapEntities for ($($storage,)+) {
+            #[track_caller]
+            fn register_map_entities(all_storages: &mut AllStorages) {
+                $(
+                    $storage::register_map_entities(all_storages);
+                )+
+            }
+        }
+    }
+}
+
+macro_rules! map_entities {
+    ($(($storage: ident, $index: tt))+; ($storage1: ident, $index1: tt) $(($queue_type: ident, $queue_index: tt))*) => {
+        impl_map_entities![$(($storage, $index))*];
+        map_entities![$(($storage, $index))* ($storage1, $index1); $(($queue_type, $queue_index))*];
+    };
+    ($(($storage: ident, $index: tt))+;) => {
+        impl_map_entities![$(($storage, $index))*];
+    }
+}
+
+#[cfg(not(feature = "extended_tuple"))]
+map_entities![(StorageA, 0); (StorageB, 1) (StorageC, 2) (StorageD, 3) (StorageE, 4) (StorageF, 5) (StorageG, 6) (StorageH, 7) (StorageI, 8) (StorageJ, 9)];
+#[cfg(feature = "extended_tuple")]
+map_entities![
+    (StorageA, 0); (StorageB, 1) (StorageC, 2) (StorageD, 3) (StorageE, 4) (StorageF, 5) (StorageG, 6) (StorageH, 7) (StorageI, 8) (StorageJ, 9)
+    (StorageK, 10) (StorageL, 11) (StorageM, 12) (StorageN, 13) (StorageO, 14) (StorageP, 15) (StorageQ, 16) (StorageR, 17) (StorageS, 18) (StorageT, 19)
+    (StorageU, 20) (StorageV, 21) (StorageW, 22) (StorageX, 23) (StorageY, 24) (StorageZ, 25) (StorageAA, 26) (StorageBB, 27) (StorageCC, 28) (StorageDD, 29)
+    (StorageEE, 30) (StorageFF, 31)
+];
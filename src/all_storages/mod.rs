@@ -2,14 +2,18 @@ mod builder;
 mod clone;
 mod custom_storage;
 mod delete_any;
+mod map_entities;
 mod retain;
+mod snapshot;
 
 pub use custom_storage::CustomStorageAccess;
 pub use delete_any::{CustomDeleteAny, TupleDeleteAny};
 pub use retain::TupleRetainStorage;
+pub use snapshot::Snapshot;
 
 pub(crate) use builder::AllStoragesBuilder;
 pub(crate) use clone::TupleClone;
+pub(crate) use map_entities::TupleMapEntities;
 
 use crate::atomic_refcell::{ARef, ARefMut, AtomicRefCell};
 use crate::borrow::Borrow;
@@ -20,13 +24,22 @@ use crate::entities::Entities;
 use crate::entity_id::EntityId;
 use crate::get_component::GetComponent;
 use crate::get_unique::GetUnique;
+use crate::group::Group;
+use crate::hierarchy::Hierarchy;
 use crate::iter::{ShiperatorCaptain, ShiperatorSailor};
 use crate::iter_component::{into_iter, IntoIterRef, IterComponent};
+use crate::map_entities::EntityMap;
 use crate::memory_usage::AllStoragesMemoryUsage;
+use crate::prefab::{Prefab, PrefabComponents, Prefabs};
 use crate::public_transport::RwLock;
 use crate::r#mut::Mut;
+use crate::registry::{ComponentRegistrar, TypeRegistry, UniqueRegistrar};
+use crate::relation::DeletionQueues;
 use crate::reserve::BulkEntityIter;
-use crate::sparse_set::{BulkAddEntity, SparseSet, TupleAddComponent, TupleDelete, TupleRemove};
+use crate::sparse_set::{
+    BulkAddEntity, DynamicComponentInfo, DynamicSparseSet, SparseSet, TupleAddComponent,
+    TupleDelete, TupleRemove,
+};
 #[cfg(feature = "thread_local")]
 use crate::std_thread_id_generator;
 use crate::storage::{SBox, Storage, StorageId};
@@ -35,8 +48,10 @@ use crate::tracking::{TrackingTimestamp, TupleTrack};
 use crate::unique::UniqueStorage;
 use crate::views::EntitiesViewMut;
 use crate::{error, ShipHashMap};
+use alloc::borrow::Cow;
 use alloc::boxed::Box;
 use alloc::sync::Arc;
+use alloc::vec::Vec;
 use core::any::type_name;
 use core::sync::atomic::AtomicU64;
 use hashbrown::hash_map::Entry;
@@ -65,6 +80,15 @@ pub struct AllStorages {
     #[cfg(feature = "thread_local")]
     thread_id_generator: Arc<dyn Fn() -> u64 + Send + Sync>,
     counter: Arc<AtomicU64>,
+    /// Number of workloads that ran to completion.
+    pub(crate) workload_runs: AtomicU64,
+    pub(crate) registry: TypeRegistry,
+    pub(crate) groups: Vec<Group>,
+    /// Shared with `Entities` to notify relation storages of deletions
+    pub(crate) deletion_queues: Arc<DeletionQueues>,
+    /// Chunk size of `par_iter` in a deterministic `World`.
+    #[cfg(feature = "parallel")]
+    pub(crate) fixed_chunk_size: Option<usize>,
 }
 
 #[cfg(not(feature = "thread_local"))]
@@ -77,7 +101,9 @@ impl AllStorages {
     pub(crate) fn new(counter: Arc<AtomicU64>) -> Self {
         let mut storages = ShipHashMap::new();
 
-        storages.insert(StorageId::of::<Entities>(), SBox::new(Entities::new()));
+        let entities = Entities::new();
+        let deletion_queues = entities.deletion_queues.clone();
+        storages.insert(StorageId::of::<Entities>(), SBox::new(entities));
 
         AllStorages {
             storages: RwLock::new_std(storages),
@@ -86,6 +112,12 @@ impl AllStorages {
             #[cfg(feature = "thread_local")]
             thread_id_generator: Arc::new(std_thread_id_generator),
             counter,
+            workload_runs: AtomicU64::new(0),
+            registry: TypeRegistry::default(),
+            groups: Vec::new(),
+            deletion_queues,
+            #[cfg(feature = "parallel")]
+            fixed_chunk_size: None,
         }
     }
     /// Adds a new unique storage, unique storages store exactly one `T` at any time.  
@@ -261,7 +293,7 @@ impl AllStorages {
         // no need to lock here since we have a unique access
         let mut entities = self.entities_mut().unwrap();
 
-        if entities.delete_unchecked(entity) {
+        if entities.delete_before_strip(entity) {
             drop(entities);
 
             self.strip(entity);
@@ -295,9 +327,22 @@ impl AllStorages {
     pub fn strip(&mut self, entity: EntityId) {
         let current = self.get_current();
 
+        let mut dependents = Vec::new();
+        for storage in self.storages.get_mut().values_mut() {
+            unsafe { &mut *storage.0 }
+                .get_mut()
+                .dependents(entity, &mut dependents);
+        }
+
+        self.ungroup_entity(entity);
         for storage in self.storages.get_mut().values_mut() {
             unsafe { &mut *storage.0 }.get_mut().delete(entity, current);
         }
+        self.group_entities(&[entity]);
+
+        for dependent in dependents {
+            self.delete_entity(dependent);
+        }
     }
 
     /// Deletes all components of multiple entities without deleting them.
@@ -443,11 +488,13 @@ impl AllStorages {
     pub fn retain_storage_by_id(&mut self, entity: EntityId, excluded_storage: &[StorageId]) {
         let current = self.get_current();
 
+        self.ungroup_entity(entity);
         for (storage_id, storage) in self.storages.get_mut().iter_mut() {
             if !excluded_storage.contains(storage_id) {
                 unsafe { &mut *storage.0 }.get_mut().delete(entity, current);
             }
         }
+        self.group_entities(&[entity]);
     }
     /// Deletes all entities and components in the `World`.
     ///
@@ -578,7 +625,9 @@ impl AllStorages {
         let current = self.get_current();
 
         let entity = self.exclusive_storage_mut::<Entities>().unwrap().generate();
+        self.pack_groups();
         component.add_component(self, entity, current);
+        self.group_entities(&[entity]);
 
         entity
     }
@@ -603,7 +652,24 @@ impl AllStorages {
     /// ```
     #[inline]
     pub fn bulk_add_entity<T: BulkAddEntity>(&mut self, source: T) -> BulkEntityIter<'_> {
-        source.bulk_add_entity(self)
+        if self.groups.is_empty() {
+            return source.bulk_add_entity(self);
+        }
+
+        self.pack_groups();
+        let new_entities_count = source.bulk_add_entity(self).slice.len();
+
+        let entities = self.exclusive_storage_mut::<Entities>().unwrap();
+        let new_entities = entities.data[entities.data.len() - new_entities_count..].to_vec();
+        self.group_entities(&new_entities);
+
+        let entities = self.exclusive_storage_mut::<Entities>().unwrap();
+        let slice = &entities.data[entities.data.len() - new_entities_count..];
+
+        BulkEntityIter {
+            iter: slice.iter().copied(),
+            slice,
+        }
     }
     /// Adds components to an existing entity.  
     /// If the entity already owned a component it will be replaced.  
@@ -644,7 +710,15 @@ impl AllStorages {
             .unwrap()
             .is_alive(entity)
         {
+            self.pack_groups();
             component.add_component(self, entity, current);
+
+            if self.is_entity_disabled(entity) {
+                for storage in self.storages.get_mut().values_mut() {
+                    unsafe { &mut *storage.0 }.get_mut().disable(entity);
+                }
+            }
+            self.group_entities(&[entity]);
         } else {
             panic!("{:?}", error::AddComponent::EntityIsNotAlive);
         }
@@ -672,7 +746,9 @@ impl AllStorages {
     /// ```
     #[inline]
     pub fn delete_component<C: TupleDelete>(&mut self, entity: EntityId) {
+        self.ungroup_entity(entity);
         C::delete(self, entity);
+        self.group_entities(&[entity]);
     }
     /// Removes components from an entity.  
     /// `C` must always be a tuple, even for a single component.
@@ -698,7 +774,11 @@ impl AllStorages {
     /// ```
     #[inline]
     pub fn remove<C: TupleRemove>(&mut self, entity: EntityId) -> C::Out {
-        C::remove(self, entity)
+        self.ungroup_entity(entity);
+        let component = C::remove(self, entity);
+        self.group_entities(&[entity]);
+
+        component
     }
     #[doc = "Borrows the requested storage(s), if it doesn't exist it'll get created.  
 You can use a tuple to get multiple storages at once.
@@ -905,6 +985,10 @@ You can use:
         let system_span = tracing::info_span!("system", name = ?type_name::<S>());
         #[cfg(feature = "tracing")]
         let _system_span = system_span.enter();
+        #[cfg(feature = "parallel")]
+        let _fixed_chunks = self
+            .fixed_chunk_size
+            .map(crate::iter::parallel::FixedChunksScope::enter);
 
         system
             .run((data,), self)
@@ -1029,6 +1113,10 @@ let i = all_storages.run(sys1);
         let system_span = tracing::info_span!("system", name = ?type_name::<S>());
         #[cfg(feature = "tracing")]
         let _system_span = system_span.enter();
+        #[cfg(feature = "parallel")]
+        let _fixed_chunks = self
+            .fixed_chunk_size
+            .map(crate::iter::parallel::FixedChunksScope::enter);
 
         system
             .run((), self)
@@ -1500,6 +1588,72 @@ for (i, j) in &mut iter {
             .is_alive(entity)
     }
 
+    /// Disables `entity`, its components are kept but skipped by iterators, [`Get`] and [`Contains`].  
+    /// Components added to a disabled entity are disabled too.  
+    /// Returns `true` if `entity` was alive and enabled.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Component, IntoIter, View, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// let world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// let entity = all_storages.add_entity((U32(0),));
+    /// all_storages.add_entity((U32(1),));
+    ///
+    /// assert!(all_storages.disable_entity(entity));
+    /// assert_eq!(all_storages.borrow::<View<U32>>().unwrap().iter().count(), 1);
+    /// ```
+    ///
+    /// [`Get`]: crate::Get
+    /// [`Contains`]: crate::Contains
+    pub fn disable_entity(&mut self, entity: EntityId) -> bool {
+        let mut entities = self.entities_mut().unwrap();
+
+        if entities.disable_entity(entity, ()) {
+            drop(entities);
+
+            self.ungroup_entity(entity);
+            for storage in self.storages.get_mut().values_mut() {
+                unsafe { &mut *storage.0 }.get_mut().disable(entity);
+            }
+            self.group_entities(&[entity]);
+
+            true
+        } else {
+            false
+        }
+    }
+    /// Enables a disabled entity and all its components.  
+    /// Returns `true` if `entity` was alive and disabled.
+    pub fn enable_entity(&mut self, entity: EntityId) -> bool {
+        let mut entities = self.entities_mut().unwrap();
+
+        if entities.enable_entity(entity, ()) {
+            drop(entities);
+
+            self.pack_groups();
+            for storage in self.storages.get_mut().values_mut() {
+                unsafe { &mut *storage.0 }.get_mut().enable(entity);
+            }
+            self.group_entities(&[entity]);
+
+            true
+        } else {
+            false
+        }
+    }
+    /// Returns true if entity matches a living disabled entity.
+    pub fn is_entity_disabled(&mut self, entity: EntityId) -> bool {
+        self.exclusive_storage_mut::<Entities>()
+            .unwrap()
+            .is_disabled(entity)
+    }
+
     /// Moves an entity from a `World` to another.
     ///
     /// ### Panics
@@ -1561,6 +1715,41 @@ for (i, j) in &mut iter {
         }
     }
 
+    /// Moves an entity from a `World` to another, it gets a new [`EntityId`] in `other`.
+    ///
+    /// Returns the new [`EntityId`].\
+    /// Components with a registered [`MapEntities`](crate::MapEntities) function have their [`EntityId`]s updated,
+    /// see [`AllStorages::move_entities`].
+    ///
+    /// ### Panics
+    ///
+    /// - `entity` is not alive
+    ///
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Component, World};
+    ///
+    /// #[derive(Component, Debug, PartialEq, Eq)]
+    /// struct USIZE(usize);
+    ///
+    /// let world1 = World::new();
+    /// let world2 = World::new();
+    ///
+    /// let mut all_storages1 = world1.borrow::<AllStoragesViewMut>().unwrap();
+    /// let mut all_storages2 = world2.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// let entity = all_storages1.add_entity(USIZE(1));
+    ///
+    /// let new_entity = all_storages1.move_entity_mapped(&mut all_storages2, entity);
+    ///
+    /// assert!(!all_storages1.is_entity_alive(entity));
+    /// assert_eq!(all_storages2.get::<&USIZE>(new_entity).as_deref(), Ok(&&USIZE(1)));
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn move_entity_mapped(&mut self, other: &mut AllStorages, entity: EntityId) -> EntityId {
+        self.move_entities(other, &[entity]).map(entity)
+    }
+
     /// Moves all components from an entity to another in another `World`.
     ///
     /// ### Panics
@@ -1626,12 +1815,388 @@ for (i, j) in &mut iter {
         }
     }
 
+    /// Moves entities from a `World` to another, they get a new [`EntityId`] in `other`.
+    ///
+    /// Returns the [`EntityMap`] from the old to the new ids.\
+    /// It is then applied to the moved components with a registered [`MapEntities`](crate::MapEntities) function.
+    ///
+    /// ### Panics
+    ///
+    /// - one of the `entities` is not alive, no entity is moved in this case
+    ///
+    /// ```
+    /// use shipyard::{sparse_set::SparseSet, AllStoragesViewMut, Component, EntityId, MapEntities, World};
+    ///
+    /// #[derive(Component, MapEntities)]
+    /// struct Target(EntityId);
+    ///
+    /// let world1 = World::new();
+    /// let world2 = World::new();
+    ///
+    /// let mut all_storages1 = world1.borrow::<AllStoragesViewMut>().unwrap();
+    /// let mut all_storages2 = world2.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// all_storages2.add_entity(());
+    /// let entity0 = all_storages1.add_entity(());
+    /// let entity1 = all_storages1.add_entity(Target(entity0));
+    ///
+    /// all_storages2.register_map_entities::<SparseSet<Target>>();
+    /// let entity_map = all_storages1.move_entities(&mut all_storages2, &[entity0, entity1]);
+    ///
+    /// assert_eq!(
+    ///     all_storages2.get::<&Target>(entity_map.map(entity1)).unwrap().0,
+    ///     entity_map.map(entity0)
+    /// );
+    /// ```
+    #[track_caller]
+    pub fn move_entities(&mut self, other: &mut AllStorages, entities: &[EntityId]) -> EntityMap {
+        let current = self.get_current();
+        let other_current = other.get_current();
+        let mut entity_map = EntityMap::new();
+
+        {
+            let self_entities = self.exclusive_storage_mut::<Entities>().unwrap();
+
+            for &entity in entities {
+                if !self_entities.is_alive(entity) {
+                    panic!(
+                        "Entity {:?} has to be alive to move it to another World.",
+                        entity
+                    );
+                };
+            }
+        }
+
+        for &entity in entities {
+            if !self
+                .exclusive_storage_mut::<Entities>()
+                .unwrap()
+                .delete_unchecked(entity)
+            {
+                // `entity` is present more than once in `entities`
+                continue;
+            }
+
+            let new_entity = other
+                .exclusive_storage_mut::<Entities>()
+                .unwrap()
+                .generate();
+
+            entity_map.insert(entity, new_entity);
+        }
+
+        for storage in self.storages.get_mut().values_mut() {
+            let storage = unsafe { &mut *storage.0 }.get_mut();
+
+            for (from, to) in entity_map.iter() {
+                storage.move_component_from(other, from, to, current, other_current);
+            }
+        }
+
+        other.map_entities(&entity_map);
+
+        entity_map
+    }
+
+    /// Spawns the entities that have a component without being alive, like after a deserialization.
+    #[cfg(feature = "serde1")]
+    pub(crate) fn spawn_stored_entities(&mut self) {
+        let mut stored = Vec::new();
+        for storage in self.storages.get_mut().values_mut() {
+            if let Some(dense) = unsafe { &mut *storage.0 }.get_mut().dense() {
+                stored.extend_from_slice(dense);
+            }
+        }
+
+        let entities = self.exclusive_storage_mut::<Entities>().unwrap();
+        for entity in stored {
+            if !entities.is_alive(entity) {
+                entities.spawn(entity);
+            }
+        }
+    }
+
     /// Registers the function to clone these components.
     #[inline]
     pub fn register_clone<T: TupleClone>(&mut self) {
         T::register_clone(self);
     }
 
+    /// Creates a prefab named `name`, replacing any prefab with the same name.\
+    /// Returns the template entity, it is disabled and holds a copy of `components`.
+    ///
+    /// See the [`prefab`](crate::prefab) module for an example.
+    pub fn add_prefab<C: PrefabComponents>(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+        components: C,
+    ) -> EntityId {
+        let name = name.into();
+
+        C::register_clone(self);
+
+        let template = self.add_entity(());
+        self.disable_entity(template);
+        self.add_component(template, components);
+
+        let prefabs =
+            self.exclusive_storage_or_insert_mut(StorageId::of::<Prefabs>(), Prefabs::default);
+        prefabs.names.insert(template, name.clone());
+        let old_prefab = prefabs.prefabs.insert(
+            name,
+            Prefab {
+                template,
+                children: Vec::new(),
+            },
+        );
+
+        if let Some(old_prefab) = old_prefab {
+            prefabs.names.remove(&old_prefab.template);
+            self.delete_entity(old_prefab.template);
+        }
+
+        template
+    }
+
+    /// Adds the prefab `child` to the prefab `parent`.\
+    /// Each instance of `parent` will have an instance of `child` attached to it in the default [`Hierarchy`].
+    ///
+    /// ### Panics
+    ///
+    /// - `parent` or `child` is not a prefab.
+    /// - `child` is `parent` or one of its ancestors.
+    #[track_caller]
+    pub fn add_prefab_child(&mut self, parent: &str, child: &str) {
+        let prefabs =
+            self.exclusive_storage_or_insert_mut(StorageId::of::<Prefabs>(), Prefabs::default);
+
+        let child_name = match prefabs.prefabs.get_key_value(child) {
+            Some((child_name, _)) => child_name.clone(),
+            None => panic!("Prefab {:?} does not exist.", child),
+        };
+
+        if prefabs.is_descendant(child, parent) {
+            panic!(
+                "Cannot add prefab {:?} to {:?}, it would create a cycle.",
+                child, parent
+            );
+        }
+
+        match prefabs.prefabs.get_mut(parent) {
+            Some(prefab) => prefab.children.push(child_name),
+            None => panic!("Prefab {:?} does not exist.", parent),
+        }
+    }
+
+    /// Returns the template entity of the prefab `name`.
+    pub fn prefab(&mut self, name: &str) -> Option<EntityId> {
+        self.exclusive_storage_or_insert_mut(StorageId::of::<Prefabs>(), Prefabs::default)
+            .prefabs
+            .get(name)
+            .map(|prefab| prefab.template)
+    }
+
+    /// Creates `count` instances of the prefab `name` and returns them.
+    ///
+    /// ### Panics
+    ///
+    /// - `name` or one of its children is not a prefab.
+    #[track_caller]
+    #[allow(clippy::manual_repeat_n, reason = "Too recent version")]
+    pub fn instantiate(&mut self, name: &str, count: usize) -> Vec<EntityId> {
+        self.instantiate_with(name, core::iter::repeat(()).take(count))
+    }
+
+    /// Creates an instance of the prefab `name` for each item of `overrides` and returns them.\
+    /// The components of each item are used instead of the prefab's, all components are tracked as inserted.
+    ///
+    /// ### Panics
+    ///
+    /// - `name` or one of its children is not a prefab.
+    #[track_caller]
+    pub fn instantiate_with<O: TupleAddComponent, I: IntoIterator<Item = O>>(
+        &mut self,
+        name: &str,
+        overrides: I,
+    ) -> Vec<EntityId> {
+        self.instantiate_prefab(name, overrides.into_iter().collect())
+    }
+
+    /// Adds `overrides` to new entities then fills them with the prefab's components they don't have.\
+    /// This way all components of an instance are tracked as inserted.
+    #[track_caller]
+    #[allow(clippy::manual_repeat_n, reason = "Too recent version")]
+    fn instantiate_prefab<O: TupleAddComponent>(
+        &mut self,
+        name: &str,
+        overrides: Vec<O>,
+    ) -> Vec<EntityId> {
+        let Prefab { template, children } = match self
+            .exclusive_storage_or_insert_mut(StorageId::of::<Prefabs>(), Prefabs::default)
+            .prefabs
+            .get(name)
+        {
+            Some(prefab) => prefab.clone(),
+            None => panic!("Prefab {:?} does not exist.", name),
+        };
+
+        let count = overrides.len();
+        let current = self.get_current();
+        let instances = self
+            .bulk_add_entity(core::iter::repeat(()).take(count))
+            .collect::<Vec<_>>();
+
+        for (&instance, components) in instances.iter().zip(overrides) {
+            components.add_component(self, instance, current);
+        }
+
+        for storage in self.storages.get_mut().values_mut() {
+            unsafe { &mut *storage.0 }
+                .get_mut()
+                .duplicate_component(template, &instances, current);
+        }
+        self.group_entities(&instances);
+
+        for child in children {
+            let child_instances =
+                self.instantiate_prefab(&child, core::iter::repeat(()).take(count).collect());
+            let hierarchy = self.exclusive_storage_or_insert_mut(
+                StorageId::of::<Hierarchy>(),
+                Hierarchy::<()>::default,
+            );
+
+            for (&child, &parent) in child_instances.iter().zip(&instances) {
+                hierarchy.attach(child, parent);
+            }
+        }
+
+        instances
+    }
+
+    /// Registers `T` in the [`TypeRegistry`] under `name` and creates its storage.\
+    /// Hooks are added with the returned [`ComponentRegistrar`].
+    ///
+    /// ### Panics
+    ///
+    /// - `name` is already used by another type.
+    #[track_caller]
+    pub fn register_component<T: Component + Send + Sync>(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+    ) -> ComponentRegistrar<'_, T> {
+        ComponentRegistrar::new(self, name.into())
+    }
+
+    /// Registers the unique `T` in the [`TypeRegistry`] under `name`.\
+    /// Hooks are added with the returned [`UniqueRegistrar`].
+    ///
+    /// ### Panics
+    ///
+    /// - `name` is already used by another type.
+    #[track_caller]
+    pub fn register_unique<T: Unique + Send + Sync>(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+    ) -> UniqueRegistrar<'_, T> {
+        UniqueRegistrar::new(self, name.into())
+    }
+
+    /// Returns the types registered with [`AllStorages::register_component`] and [`AllStorages::register_unique`].
+    #[inline]
+    pub fn registry(&self) -> &TypeRegistry {
+        &self.registry
+    }
+
+    /// Creates the storage described by `info` if it doesn't already exist.\
+    /// Returns the [`StorageId`] of the storage.
+    ///
+    /// ### Panics
+    ///
+    /// - A storage that isn't a [`DynamicSparseSet`] or has a different layout already uses this id.
+    #[track_caller]
+    pub fn add_dynamic_storage(&mut self, info: DynamicComponentInfo) -> StorageId {
+        let storage_id = info.storage_id();
+        let layout = info.layout();
+
+        let storage = unsafe {
+            &mut *self
+                .storages
+                .get_mut()
+                .entry(storage_id)
+                .or_insert_with(|| SBox::new(DynamicSparseSet::new(info)))
+                .0
+        }
+        .get_mut();
+
+        match storage.any().downcast_ref::<DynamicSparseSet>() {
+            Some(storage) if storage.info().layout() == layout => storage_id,
+            _ => panic!(
+                "{:?} is already used by {} with a different layout.",
+                storage_id,
+                storage.name()
+            ),
+        }
+    }
+
+    /// Borrows the [`DynamicSparseSet`] identified by `storage_id`.
+    pub fn dynamic_storage(
+        &self,
+        storage_id: StorageId,
+    ) -> Result<ARef<'_, &'_ DynamicSparseSet>, error::GetStorage> {
+        let storage = self.custom_storage_by_id(storage_id)?;
+
+        if storage.any().is::<DynamicSparseSet>() {
+            Ok(ARef::map(storage, |storage| {
+                storage.any().downcast_ref().unwrap()
+            }))
+        } else {
+            Err(error::GetStorage::MissingStorage {
+                name: Some(type_name::<DynamicSparseSet>()),
+                id: storage_id,
+            })
+        }
+    }
+
+    /// Mutably borrows the [`DynamicSparseSet`] identified by `storage_id`.
+    #[allow(clippy::mut_from_ref, reason = "Interior mutability")]
+    pub fn dynamic_storage_mut(
+        &self,
+        storage_id: StorageId,
+    ) -> Result<ARefMut<'_, &'_ mut DynamicSparseSet>, error::GetStorage> {
+        let storage = self.custom_storage_mut_by_id(storage_id)?;
+
+        if storage.any().is::<DynamicSparseSet>() {
+            Ok(ARefMut::map(storage, |storage| {
+                storage.any_mut().downcast_mut().unwrap()
+            }))
+        } else {
+            Err(error::GetStorage::MissingStorage {
+                name: Some(type_name::<DynamicSparseSet>()),
+                id: storage_id,
+            })
+        }
+    }
+
+    /// Registers the function to update the [`EntityId`]s stored in these components.
+    ///
+    /// The storage type is used, like for [`AllStorages::register_clone`].
+    #[inline]
+    pub fn register_map_entities<T: TupleMapEntities>(&mut self) {
+        T::register_map_entities(self);
+    }
+
+    /// Updates the [`EntityId`]s stored in the components of all entities `entity_map` maps to.
+    ///
+    /// Only components with a registered [`MapEntities`](crate::MapEntities) function are updated.
+    #[inline]
+    pub fn map_entities(&mut self, entity_map: &EntityMap) {
+        for storage in self.storages.get_mut().values_mut() {
+            unsafe { &mut *storage.0 }
+                .get_mut()
+                .map_entities(entity_map);
+        }
+    }
+
     /// Clones all storages with a registered clone function from this `AllStorages` to `other`.
     ///
     /// Tracking is not cloned. Components will count as inserted in `other`.
@@ -1702,9 +2267,118 @@ for (i, j) in &mut iter {
                 other_storages.insert(*storage_id, storage);
             }
         }
+
+        // the cloned Entities has to notify other's relation storages
+        other
+            .exclusive_storage_mut::<Entities>()
+            .unwrap()
+            .deletion_queues = other.deletion_queues.clone();
+    }
+
+    /// Copies [`Entities`] and all storages with a registered clone function, tracking information included.
+    ///
+    /// `!Send` and `!Sync` storages are not part of the snapshot.
+    #[track_caller]
+    pub fn snapshot(&self) -> Snapshot {
+        let mut storages = ShipHashMap::default();
+
+        for (storage_id, storage) in self.storages.read().iter() {
+            let storage = unsafe { &*storage.0 };
+
+            #[cfg(feature = "thread_local")]
+            {
+                if !storage.is_send() || !storage.is_sync() {
+                    continue;
+                }
+            }
+
+            if let Some(storage_builder) = Storage::try_snapshot(&*storage.borrow().unwrap()) {
+                #[cfg(not(feature = "thread_local"))]
+                let storage = storage_builder.build();
+                // SAFE only `Send` and `Sync` storages are part of snapshots
+                #[cfg(feature = "thread_local")]
+                let storage =
+                    unsafe { storage_builder.build(self.thread_id_generator.clone(), true, true) };
+
+                storages.insert(*storage_id, storage);
+            }
+        }
+
+        Snapshot { storages }
+    }
+
+    /// Rolls back [`Entities`] and all storages in `snapshot` to the state they were in when the snapshot was created.
+    ///
+    /// Storages that are not part of the snapshot keep their components,
+    /// except for entities that are no longer alive after the rollback.
+    ///
+    /// The tracking counter keeps moving forward, the rollback is tracked like any other change:
+    /// - components the snapshot brings back are flagged inserted
+    /// - components present before and after the rollback are flagged modified, uniques included
+    /// - components missing from the snapshot are flagged deleted
+    #[track_caller]
+    pub fn restore(&mut self, snapshot: &Snapshot) {
+        let current = self.get_current();
+        let alive_before: Vec<EntityId> = self.entities().unwrap().iter().collect();
+
+        let storages = self.storages.get_mut();
+
+        for (storage_id, snapshot_storage) in &snapshot.storages {
+            let snapshot_storage = unsafe { &*snapshot_storage.0 }.borrow().unwrap();
+
+            if let Some(storage) = storages.get_mut(storage_id) {
+                unsafe { &mut *storage.0 }
+                    .get_mut()
+                    .restore_snapshot(&*snapshot_storage, current);
+            } else if let Some(storage_builder) = snapshot_storage.try_snapshot() {
+                #[cfg(not(feature = "thread_local"))]
+                let storage = storage_builder.build();
+                // SAFE only `Send` and `Sync` storages are part of snapshots
+                #[cfg(feature = "thread_local")]
+                let storage =
+                    unsafe { storage_builder.build(self.thread_id_generator.clone(), true, true) };
+
+                // the components are all new to this world
+                let new_storage = unsafe { &mut *storage.0 }.get_mut();
+                new_storage.clear(current);
+                new_storage.restore_snapshot(&*snapshot_storage, current);
+
+                storages.insert(*storage_id, storage);
+            }
+        }
+
+        // entities created after the snapshot can't keep their components in the other storages
+        // or a reused `EntityId` would inherit them
+        let dead: Vec<EntityId> = {
+            let entities = self.entities().unwrap();
+
+            alive_before
+                .into_iter()
+                .filter(|&entity| !entities.is_alive(entity))
+                .collect()
+        };
+
+        if dead.is_empty() {
+            return;
+        }
+
+        for &entity in &dead {
+            self.ungroup_entity(entity);
+        }
+        for (storage_id, storage) in self.storages.get_mut().iter_mut() {
+            if snapshot.storages.contains_key(storage_id) {
+                continue;
+            }
+
+            let storage = unsafe { &mut *storage.0 }.get_mut();
+            for &entity in &dead {
+                storage.delete(entity, current);
+            }
+        }
+        self.group_entities(&dead);
     }
 
-    /// Clones `entity` from this `AllStorages` to `other_all_storages` alongside all its with a registered clone function.
+    /// Clones `entity` from this `AllStorages` to `other_all_storages` alongside all its components with a registered clone function.
     #[track_caller]
     pub fn clone_entity_to(&self, other_all_storages: &mut AllStorages, entity: EntityId) {
         let other_current = other_all_storages.get_current();
@@ -1733,6 +2407,27 @@ for (i, j) in &mut iter {
         }
     }
 
+    /// Clones `entity` from this `AllStorages` to `other_all_storages` alongside all its components with a registered clone function.\
+    /// The clone gets a new [`EntityId`] in `other_all_storages`.
+    ///
+    /// Returns the [`EntityId`] of the clone.\
+    /// Components with a registered [`MapEntities`](crate::MapEntities) function have their [`EntityId`]s updated,
+    /// see [`AllStorages::clone_entities_to`].
+    ///
+    /// ### Panics
+    ///
+    /// - `entity` is not alive
+    #[inline]
+    #[track_caller]
+    pub fn clone_entity_to_mapped(
+        &self,
+        other_all_storages: &mut AllStorages,
+        entity: EntityId,
+    ) -> EntityId {
+        self.clone_entities_to(other_all_storages, &[entity])
+            .map(entity)
+    }
+
     /// Clones all components of `from` entity with a registered clone function from
     /// this `AllStorages` to `other_all_storages`'s `to` entity.
     #[track_caller]
@@ -1769,6 +2464,63 @@ for (i, j) in &mut iter {
                 .clone_component_to(other_all_storages, from, to, other_current);
         }
     }
+
+    /// Clones `entities` from this `AllStorages` to `other_all_storages` alongside all their components with a registered clone function.\
+    /// The clones get a new [`EntityId`] in `other_all_storages`.
+    ///
+    /// Returns the [`EntityMap`] from the original to the cloned ids.\
+    /// It is then applied to the cloned components with a registered [`MapEntities`](crate::MapEntities) function.
+    ///
+    /// ### Panics
+    ///
+    /// - one of the `entities` is not alive, no entity is cloned in this case
+    #[track_caller]
+    pub fn clone_entities_to(
+        &self,
+        other_all_storages: &mut AllStorages,
+        entities: &[EntityId],
+    ) -> EntityMap {
+        let other_current = other_all_storages.get_current();
+        let mut entity_map = EntityMap::new();
+
+        {
+            let self_entities = self.entities().unwrap();
+
+            for &entity in entities {
+                if !self_entities.is_alive(entity) {
+                    panic!(
+                        "Entity {:?} has to be alive to clone it to another World.",
+                        entity
+                    );
+                };
+            }
+
+            for &entity in entities {
+                if entity_map.get(entity).is_some() {
+                    continue;
+                }
+
+                let new_entity = other_all_storages
+                    .exclusive_storage_mut::<Entities>()
+                    .unwrap()
+                    .generate();
+
+                entity_map.insert(entity, new_entity);
+            }
+        }
+
+        for storage in self.storages.read().values() {
+            let storage = unsafe { &*storage.0 }.borrow().unwrap();
+
+            for (from, to) in entity_map.iter() {
+                storage.clone_component_to(other_all_storages, from, to, other_current);
+            }
+        }
+
+        other_all_storages.map_entities(&entity_map);
+
+        entity_map
+    }
 }
 
 impl core::fmt::Debug for AllStorages {
@@ -0,0 +1,23 @@
+use crate::storage::{SBox, StorageId};
+use crate::ShipHashMap;
+
+/// Copy of a [`World`](crate::World)'s state, created with [`World::snapshot`](crate::World::snapshot).
+///
+/// Contains [`Entities`](crate::advanced::Entities) and all storages with a registered clone function,
+/// tracking information included.
+pub struct Snapshot {
+    pub(crate) storages: ShipHashMap<StorageId, SBox>,
+}
+
+impl Snapshot {
+    /// Returns the number of storages in this snapshot.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.storages.len()
+    }
+    /// Returns `true` if the snapshot doesn't contain any storage.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.storages.is_empty()
+    }
+}
@@ -0,0 +1,78 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use core::ptr::null_mut;
+use core::sync::atomic::{AtomicPtr, Ordering};
+
+struct Node<T> {
+    value: T,
+    next: *mut Node<T>,
+}
+
+/// Lock-free stack where values can be pushed with a shared reference.
+///
+/// Values can only be taken out with an exclusive reference.
+// There is no ABA problem since nodes are only ever removed with an exclusive access.
+pub(crate) struct AtomicStack<T> {
+    head: AtomicPtr<Node<T>>,
+    _phantom: PhantomData<T>,
+}
+
+// SAFE values can't be accessed with a shared reference, they can only be moved in
+unsafe impl<T: Send> Sync for AtomicStack<T> {}
+
+impl<T> AtomicStack<T> {
+    pub(crate) fn new() -> AtomicStack<T> {
+        AtomicStack {
+            head: AtomicPtr::new(null_mut()),
+            _phantom: PhantomData,
+        }
+    }
+
+    pub(crate) fn push(&self, value: T) {
+        let node = Box::into_raw(Box::new(Node {
+            value,
+            next: null_mut(),
+        }));
+
+        let mut head = self.head.load(Ordering::Relaxed);
+        loop {
+            // SAFE node is not shared until the exchange succeeds
+            unsafe { (*node).next = head };
+
+            match self
+                .head
+                .compare_exchange_weak(head, node, Ordering::Release, Ordering::Relaxed)
+            {
+                Ok(_) => break,
+                Err(new_head) => head = new_head,
+            }
+        }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.head.load(Ordering::Acquire).is_null()
+    }
+
+    /// Removes all values from the stack, in the order they were pushed.
+    pub(crate) fn take(&mut self) -> Vec<T> {
+        let mut head = core::mem::replace(self.head.get_mut(), null_mut());
+
+        let mut values = Vec::new();
+        while !head.is_null() {
+            // SAFE head was created by Box::into_raw and is only reachable from this list
+            let node = unsafe { Box::from_raw(head) };
+            head = node.next;
+            values.push(node.value);
+        }
+
+        values.reverse();
+        values
+    }
+}
+
+impl<T> Drop for AtomicStack<T> {
+    fn drop(&mut self) {
+        self.take();
+    }
+}
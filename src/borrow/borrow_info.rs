@@ -6,18 +6,23 @@ use super::non_send_sync::NonSendSync;
 use super::non_sync::NonSync;
 use super::Mutability;
 use crate::all_storages::{AllStorages, CustomStorageAccess};
+use crate::commands::CommandQueue;
 use crate::component::{Component, Unique};
 use crate::entities::Entities;
 use crate::error;
-use crate::scheduler::TypeInfo;
+use crate::events::Events;
+use crate::hierarchy::Hierarchy;
+use crate::relation::{Relation, RelationStorage};
+use crate::scheduler::{SystemLocals, TypeInfo};
 use crate::sparse_set::SparseSet;
 use crate::storage::StorageId;
 use crate::system::Nothing;
 use crate::tracking::Tracking;
 use crate::unique::UniqueStorage;
 use crate::views::{
-    AllStoragesView, AllStoragesViewMut, EntitiesView, EntitiesViewMut, UniqueView, UniqueViewMut,
-    View, ViewMut,
+    AllStoragesView, AllStoragesViewMut, Commands, EntitiesView, EntitiesViewMut, EventReader,
+    EventWriter, HierarchyView, HierarchyViewMut, RelationView, RelationViewMut, UniqueView,
+    UniqueViewMut, View, ViewMut,
 };
 use alloc::vec::Vec;
 use core::any::type_name;
@@ -67,6 +72,11 @@ pub unsafe trait BorrowInfo {
     fn enable_tracking(
         enable_tracking_fn: &mut Vec<fn(&AllStorages) -> Result<(), error::GetStorage>>,
     );
+    /// Inserts the initial state of the [`Local`](crate::views::Local) views in `locals`.
+    ///
+    /// Only views containing a `Local` have to implement this function.
+    #[inline]
+    fn init_locals(_locals: &mut SystemLocals) {}
 }
 
 // this is needed for downstream crates to impl IntoWorkloadSystem
@@ -134,6 +144,102 @@ unsafe impl<'a> BorrowInfo for EntitiesViewMut<'a> {
     fn enable_tracking(_: &mut Vec<fn(&AllStorages) -> Result<(), error::GetStorage>>) {}
 }
 
+unsafe impl<'a> BorrowInfo for Commands<'a> {
+    fn borrow_info(info: &mut Vec<TypeInfo>) {
+        info.push(TypeInfo {
+            name: type_name::<Entities>().into(),
+            mutability: Mutability::Shared,
+            storage_id: StorageId::of::<Entities>(),
+            thread_safe: true,
+        });
+        info.push(TypeInfo {
+            name: type_name::<CommandQueue>().into(),
+            mutability: Mutability::Shared,
+            storage_id: StorageId::of::<CommandQueue>(),
+            thread_safe: true,
+        });
+    }
+    fn enable_tracking(_: &mut Vec<fn(&AllStorages) -> Result<(), error::GetStorage>>) {}
+}
+
+unsafe impl<'a, H: 'static> BorrowInfo for HierarchyView<'a, H> {
+    fn borrow_info(info: &mut Vec<TypeInfo>) {
+        info.push(TypeInfo {
+            name: type_name::<Hierarchy<H>>().into(),
+            mutability: Mutability::Shared,
+            storage_id: StorageId::of::<Hierarchy<H>>(),
+            thread_safe: true,
+        });
+    }
+    fn enable_tracking(_: &mut Vec<fn(&AllStorages) -> Result<(), error::GetStorage>>) {}
+}
+
+unsafe impl<'a, H: 'static> BorrowInfo for HierarchyViewMut<'a, H> {
+    fn borrow_info(info: &mut Vec<TypeInfo>) {
+        info.push(TypeInfo {
+            name: type_name::<Entities>().into(),
+            mutability: Mutability::Shared,
+            storage_id: StorageId::of::<Entities>(),
+            thread_safe: true,
+        });
+        info.push(TypeInfo {
+            name: type_name::<Hierarchy<H>>().into(),
+            mutability: Mutability::Exclusive,
+            storage_id: StorageId::of::<Hierarchy<H>>(),
+            thread_safe: true,
+        });
+    }
+    fn enable_tracking(_: &mut Vec<fn(&AllStorages) -> Result<(), error::GetStorage>>) {}
+}
+
+unsafe impl<'a, R: Send + Sync + Relation> BorrowInfo for RelationView<'a, R> {
+    fn borrow_info(info: &mut Vec<TypeInfo>) {
+        info.push(TypeInfo {
+            name: type_name::<RelationStorage<R>>().into(),
+            mutability: Mutability::Shared,
+            storage_id: StorageId::of::<RelationStorage<R>>(),
+            thread_safe: true,
+        });
+    }
+    fn enable_tracking(_: &mut Vec<fn(&AllStorages) -> Result<(), error::GetStorage>>) {}
+}
+
+unsafe impl<'a, R: Send + Sync + Relation> BorrowInfo for RelationViewMut<'a, R> {
+    fn borrow_info(info: &mut Vec<TypeInfo>) {
+        info.push(TypeInfo {
+            name: type_name::<RelationStorage<R>>().into(),
+            mutability: Mutability::Exclusive,
+            storage_id: StorageId::of::<RelationStorage<R>>(),
+            thread_safe: true,
+        });
+    }
+    fn enable_tracking(_: &mut Vec<fn(&AllStorages) -> Result<(), error::GetStorage>>) {}
+}
+
+unsafe impl<'a, T: Send + Sync + 'static> BorrowInfo for EventWriter<'a, T> {
+    fn borrow_info(info: &mut Vec<TypeInfo>) {
+        info.push(TypeInfo {
+            name: type_name::<Events<T>>().into(),
+            mutability: Mutability::Exclusive,
+            storage_id: StorageId::of::<Events<T>>(),
+            thread_safe: true,
+        });
+    }
+    fn enable_tracking(_: &mut Vec<fn(&AllStorages) -> Result<(), error::GetStorage>>) {}
+}
+
+unsafe impl<'a, T: Send + Sync + 'static> BorrowInfo for EventReader<'a, T> {
+    fn borrow_info(info: &mut Vec<TypeInfo>) {
+        info.push(TypeInfo {
+            name: type_name::<Events<T>>().into(),
+            mutability: Mutability::Shared,
+            storage_id: StorageId::of::<Events<T>>(),
+            thread_safe: true,
+        });
+    }
+    fn enable_tracking(_: &mut Vec<fn(&AllStorages) -> Result<(), error::GetStorage>>) {}
+}
+
 unsafe impl<'a, T: Send + Sync + Component, Track> BorrowInfo for View<'a, T, Track>
 where
     Track: Tracking,
@@ -457,6 +563,9 @@ unsafe impl<T: BorrowInfo> BorrowInfo for Option<T> {
     ) {
         T::enable_tracking(enable_tracking);
     }
+    fn init_locals(locals: &mut SystemLocals) {
+        T::init_locals(locals);
+    }
 }
 
 macro_rules! impl_borrow_info {
@@ -472,6 +581,11 @@ macro_rules! impl_borrow_info {
                     $type::enable_tracking(enable_tracking_fn);
                 )+
             }
+            fn init_locals(locals: &mut SystemLocals) {
+                $(
+                    $type::init_locals(locals);
+                )+
+            }
         }
     }
 }
@@ -18,16 +18,27 @@ pub use world_borrow::WorldBorrow;
 
 use crate::all_storages::{AllStorages, CustomStorageAccess};
 use crate::atomic_refcell::{ARef, ARefMut, SharedBorrow};
+use crate::commands::CommandQueue;
 use crate::component::{Component, Unique};
 use crate::error;
+use crate::events::Events;
+use crate::hierarchy::Hierarchy;
+use crate::index::IndexSync;
+use crate::relation::{Relation, RelationStorage};
+use crate::scheduler::SystemLocals;
 use crate::sparse_set::SparseSet;
 #[cfg(feature = "thread_local")]
 use crate::storage::StorageId;
 use crate::system::Nothing;
 use crate::tracking::{Tracking, TrackingTimestamp};
 use crate::unique::UniqueStorage;
-use crate::views::{EntitiesView, EntitiesViewMut, UniqueView, UniqueViewMut, View, ViewMut};
+use crate::views::{
+    Commands, EntitiesView, EntitiesViewMut, EventReader, EventWriter, HierarchyView,
+    HierarchyViewMut, RelationView, RelationViewMut, UniqueView, UniqueViewMut, View, ViewMut,
+};
+use alloc::vec::Vec;
 use core::marker::PhantomData;
+use core::sync::atomic::Ordering;
 
 /// Describes if a storage is borrowed exclusively or not.  
 /// It is used to display workloads' borrowing information.
@@ -89,6 +100,21 @@ pub trait Borrow {
         last_run: Option<TrackingTimestamp>,
         current: TrackingTimestamp,
     ) -> Result<Self::View<'a>, error::GetStorage>;
+
+    /// Borrows the view for a system running in a workload.\
+    /// `locals` holds the state of the system's [`Local`](crate::views::Local) views.
+    ///
+    /// Defaults to [`Borrow::borrow`].
+    #[inline]
+    fn borrow_in_system<'a>(
+        all_storages: &'a AllStorages,
+        all_borrow: Option<SharedBorrow<'a>>,
+        last_run: Option<TrackingTimestamp>,
+        current: TrackingTimestamp,
+        _locals: &'a SystemLocals,
+    ) -> Result<Self::View<'a>, error::GetStorage> {
+        Self::borrow(all_storages, all_borrow, last_run, current)
+    }
 }
 
 // this is needed for downstream crate to impl System
@@ -166,6 +192,189 @@ impl Borrow for EntitiesViewMut<'_> {
     }
 }
 
+impl Borrow for Commands<'_> {
+    type View<'a> = Commands<'a>;
+
+    #[inline]
+    fn borrow<'a>(
+        all_storages: &'a AllStorages,
+        all_borrow: Option<SharedBorrow<'a>>,
+        _last_run: Option<TrackingTimestamp>,
+        _current: TrackingTimestamp,
+    ) -> Result<Self::View<'a>, error::GetStorage> {
+        let queue = all_storages.custom_storage_or_insert(CommandQueue::new)?;
+        let entities = all_storages.entities()?;
+
+        let (queue, queue_borrow) = unsafe { ARef::destructure(queue) };
+        let (entities, entities_borrow) = unsafe { ARef::destructure(entities) };
+
+        Ok(Commands {
+            entities,
+            queue,
+            commands: Vec::new(),
+            _entities_borrow: entities_borrow,
+            _queue_borrow: queue_borrow,
+            _all_borrow: all_borrow,
+        })
+    }
+}
+
+impl<H: 'static> Borrow for HierarchyView<'_, H> {
+    type View<'a> = HierarchyView<'a, H>;
+
+    #[inline]
+    fn borrow<'a>(
+        all_storages: &'a AllStorages,
+        all_borrow: Option<SharedBorrow<'a>>,
+        _last_run: Option<TrackingTimestamp>,
+        _current: TrackingTimestamp,
+    ) -> Result<Self::View<'a>, error::GetStorage> {
+        let view = all_storages.custom_storage_or_insert(Hierarchy::<H>::default)?;
+
+        let (hierarchy, borrow) = unsafe { ARef::destructure(view) };
+
+        Ok(HierarchyView {
+            hierarchy,
+            borrow: Some(borrow),
+            all_borrow,
+        })
+    }
+}
+
+impl<H: 'static> Borrow for HierarchyViewMut<'_, H> {
+    type View<'a> = HierarchyViewMut<'a, H>;
+
+    #[inline]
+    fn borrow<'a>(
+        all_storages: &'a AllStorages,
+        all_borrow: Option<SharedBorrow<'a>>,
+        _last_run: Option<TrackingTimestamp>,
+        _current: TrackingTimestamp,
+    ) -> Result<Self::View<'a>, error::GetStorage> {
+        let view = all_storages.custom_storage_or_insert_mut(Hierarchy::<H>::default)?;
+        let entities = all_storages.entities()?;
+
+        let (hierarchy, borrow) = unsafe { ARefMut::destructure(view) };
+        let (entities, entities_borrow) = unsafe { ARef::destructure(entities) };
+
+        Ok(HierarchyViewMut {
+            hierarchy,
+            entities,
+            _borrow: Some(borrow),
+            _entities_borrow: entities_borrow,
+            _all_borrow: all_borrow,
+        })
+    }
+}
+
+impl<R: Send + Sync + Relation> Borrow for RelationView<'_, R> {
+    type View<'a> = RelationView<'a, R>;
+
+    #[inline]
+    fn borrow<'a>(
+        all_storages: &'a AllStorages,
+        all_borrow: Option<SharedBorrow<'a>>,
+        _last_run: Option<TrackingTimestamp>,
+        _current: TrackingTimestamp,
+    ) -> Result<Self::View<'a>, error::GetStorage> {
+        let view = all_storages.custom_storage_or_insert(|| {
+            RelationStorage::<R>::with_deletions(all_storages.deletion_queues.clone())
+        })?;
+
+        let (relations, borrow) = unsafe { ARef::destructure(view) };
+
+        Ok(RelationView {
+            relations,
+            pending_deletions: relations.pending_deletions(),
+            borrow: Some(borrow),
+            all_borrow,
+        })
+    }
+}
+
+impl<R: Send + Sync + Relation> Borrow for RelationViewMut<'_, R> {
+    type View<'a> = RelationViewMut<'a, R>;
+
+    #[inline]
+    fn borrow<'a>(
+        all_storages: &'a AllStorages,
+        all_borrow: Option<SharedBorrow<'a>>,
+        _last_run: Option<TrackingTimestamp>,
+        _current: TrackingTimestamp,
+    ) -> Result<Self::View<'a>, error::GetStorage> {
+        let view = all_storages.custom_storage_or_insert_mut(|| {
+            RelationStorage::<R>::with_deletions(all_storages.deletion_queues.clone())
+        })?;
+
+        let (relations, borrow) = unsafe { ARefMut::destructure(view) };
+        relations.apply_deletions();
+
+        Ok(RelationViewMut {
+            relations,
+            _borrow: Some(borrow),
+            _all_borrow: all_borrow,
+        })
+    }
+}
+
+impl<T: Send + Sync + 'static> Borrow for EventWriter<'_, T> {
+    type View<'a> = EventWriter<'a, T>;
+
+    #[inline]
+    fn borrow<'a>(
+        all_storages: &'a AllStorages,
+        all_borrow: Option<SharedBorrow<'a>>,
+        _last_run: Option<TrackingTimestamp>,
+        current: TrackingTimestamp,
+    ) -> Result<Self::View<'a>, error::GetStorage> {
+        let view = all_storages.custom_storage_or_insert_mut(Events::<T>::default)?;
+
+        let (events, borrow) = unsafe { ARefMut::destructure(view) };
+
+        let workload_runs = all_storages.workload_runs.load(Ordering::Relaxed);
+        events.maintain(workload_runs);
+
+        Ok(EventWriter {
+            events,
+            current,
+            workload_runs,
+            _borrow: Some(borrow),
+            _all_borrow: all_borrow,
+        })
+    }
+}
+
+impl<T: Send + Sync + 'static> Borrow for EventReader<'_, T> {
+    type View<'a> = EventReader<'a, T>;
+
+    #[inline]
+    fn borrow<'a>(
+        all_storages: &'a AllStorages,
+        all_borrow: Option<SharedBorrow<'a>>,
+        last_run: Option<TrackingTimestamp>,
+        current: TrackingTimestamp,
+    ) -> Result<Self::View<'a>, error::GetStorage> {
+        let view = all_storages.custom_storage_or_insert(Events::<T>::default)?;
+
+        let (events, borrow) = unsafe { ARef::destructure(view) };
+
+        let workload_runs = all_storages.workload_runs.load(Ordering::Relaxed);
+
+        if let Some(last_run) = last_run {
+            events.move_cursor(last_run.get(), current.get(), workload_runs);
+        }
+
+        Ok(EventReader {
+            events,
+            last_run,
+            current,
+            workload_runs,
+            borrow: Some(borrow),
+            all_borrow,
+        })
+    }
+}
+
 impl<T: Send + Sync + Component, Track> Borrow for View<'_, T, Track>
 where
     Track: Tracking,
@@ -287,12 +496,15 @@ where
 
         sparse_set.check_tracking::<Track>()?;
 
+        let (sparse_set, index_sync) = IndexSync::new(sparse_set);
+
         Ok(ViewMut {
             last_insertion: last_run.unwrap_or(sparse_set.last_insert),
             last_modification: last_run.unwrap_or(sparse_set.last_modified),
             last_removal_or_deletion: last_run.unwrap_or(TrackingTimestamp::origin()),
             current,
             sparse_set,
+            index_sync,
             borrow,
             all_borrow,
             phantom: PhantomData,
@@ -321,12 +533,15 @@ where
 
         sparse_set.check_tracking::<Track>()?;
 
+        let (sparse_set, index_sync) = IndexSync::new(sparse_set);
+
         Ok(NonSend(ViewMut {
             last_insertion: last_run.unwrap_or(sparse_set.last_insert),
             last_modification: last_run.unwrap_or(sparse_set.last_modified),
             last_removal_or_deletion: last_run.unwrap_or(TrackingTimestamp::origin()),
             current,
             sparse_set,
+            index_sync,
             borrow: borrow,
             all_borrow: all_borrow,
             phantom: PhantomData,
@@ -355,12 +570,15 @@ where
 
         sparse_set.check_tracking::<Track>()?;
 
+        let (sparse_set, index_sync) = IndexSync::new(sparse_set);
+
         Ok(NonSync(ViewMut {
             last_insertion: last_run.unwrap_or(sparse_set.last_insert),
             last_modification: last_run.unwrap_or(sparse_set.last_modified),
             last_removal_or_deletion: last_run.unwrap_or(TrackingTimestamp::origin()),
             current,
             sparse_set,
+            index_sync,
             borrow: borrow,
             all_borrow: all_borrow,
             phantom: PhantomData,
@@ -389,12 +607,15 @@ where
 
         sparse_set.check_tracking::<Track>()?;
 
+        let (sparse_set, index_sync) = IndexSync::new(sparse_set);
+
         Ok(NonSendSync(ViewMut {
             last_insertion: last_run.unwrap_or(sparse_set.last_insert),
             last_modification: last_run.unwrap_or(sparse_set.last_modified),
             last_removal_or_deletion: last_run.unwrap_or(TrackingTimestamp::origin()),
             current,
             sparse_set,
+            index_sync,
             borrow: borrow,
             all_borrow: all_borrow,
             phantom: PhantomData,
@@ -656,6 +877,17 @@ impl<T: Borrow> Borrow for Option<T> {
     ) -> Result<Self::View<'a>, error::GetStorage> {
         Ok(T::borrow(all_storages, all_borrow, last_run, current).ok())
     }
+
+    #[inline]
+    fn borrow_in_system<'a>(
+        all_storages: &'a AllStorages,
+        all_borrow: Option<SharedBorrow<'a>>,
+        last_run: Option<TrackingTimestamp>,
+        current: TrackingTimestamp,
+        locals: &'a SystemLocals,
+    ) -> Result<Self::View<'a>, error::GetStorage> {
+        Ok(T::borrow_in_system(all_storages, all_borrow, last_run, current, locals).ok())
+    }
 }
 
 macro_rules! impl_borrow {
@@ -672,6 +904,17 @@ macro_rules! impl_borrow {
             ) -> Result<Self::View<'a>, error::GetStorage> {
                 Ok(($($type::borrow(all_storages, all_borrow.clone(), last_run, current)?,)+))
             }
+
+            #[inline]
+            fn borrow_in_system<'a>(
+                all_storages: &'a AllStorages,
+                all_borrow: Option<SharedBorrow<'a>>,
+                last_run: Option<TrackingTimestamp>,
+                current: TrackingTimestamp,
+                locals: &'a SystemLocals,
+            ) -> Result<Self::View<'a>, error::GetStorage> {
+                Ok(($($type::borrow_in_system(all_storages, all_borrow.clone(), last_run, current, locals)?,)+))
+            }
         }
     }
 }
@@ -1,6 +1,7 @@
 use crate::atomic_refcell::ARef;
 use crate::borrow::Borrow;
 use crate::error;
+use crate::scheduler::SystemLocals;
 use crate::tracking::TrackingTimestamp;
 use crate::views::{AllStoragesView, AllStoragesViewMut};
 use crate::world::World;
@@ -16,6 +17,20 @@ pub trait WorldBorrow {
         last_run: Option<TrackingTimestamp>,
         current: TrackingTimestamp,
     ) -> Result<Self::WorldView<'_>, error::GetStorage>;
+
+    /// Borrows the view for a system running in a workload.\
+    /// `locals` holds the state of the system's [`Local`](crate::views::Local) views.
+    ///
+    /// Defaults to [`WorldBorrow::world_borrow`].
+    #[inline]
+    fn world_borrow_in_system<'a>(
+        world: &'a World,
+        last_run: Option<TrackingTimestamp>,
+        current: TrackingTimestamp,
+        _locals: &'a SystemLocals,
+    ) -> Result<Self::WorldView<'a>, error::GetStorage> {
+        Self::world_borrow(world, last_run, current)
+    }
 }
 
 impl<T: Borrow> WorldBorrow for T {
@@ -37,6 +52,24 @@ impl<T: Borrow> WorldBorrow for T {
 
         T::borrow(all_storages, Some(all_borrow), last_run, current)
     }
+
+    fn world_borrow_in_system<'a>(
+        world: &'a World,
+        last_run: Option<TrackingTimestamp>,
+        current: TrackingTimestamp,
+        locals: &'a SystemLocals,
+    ) -> Result<Self::WorldView<'a>, error::GetStorage> {
+        let (all_storages, all_borrow) = unsafe {
+            ARef::destructure(
+                world
+                    .all_storages
+                    .borrow()
+                    .map_err(error::GetStorage::AllStoragesBorrow)?,
+            )
+        };
+
+        T::borrow_in_system(all_storages, Some(all_borrow), last_run, current, locals)
+    }
 }
 
 impl WorldBorrow for AllStoragesView<'_> {
@@ -0,0 +1,91 @@
+use crate::all_storages::{AllStorages, CustomStorageAccess};
+use crate::atomic_stack::AtomicStack;
+use crate::storage::Storage;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+/// A deferred operation on [`AllStorages`].
+pub(crate) type Command = Box<dyn FnOnce(&mut AllStorages) + Send>;
+
+/// Storage holding the commands recorded by all [`Commands`](crate::views::Commands) views.
+///
+/// Views only push to the queue, which only requires a shared borrow.\
+/// The queue can only be emptied with an exclusive access.
+// Each view pushes all its commands at once when dropped.
+pub(crate) struct CommandQueue {
+    stack: AtomicStack<Vec<Command>>,
+}
+
+impl CommandQueue {
+    pub(crate) fn new() -> CommandQueue {
+        CommandQueue {
+            stack: AtomicStack::new(),
+        }
+    }
+
+    pub(crate) fn push(&self, commands: Vec<Command>) {
+        self.stack.push(commands);
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.stack.is_empty()
+    }
+
+    /// Removes all commands from the queue, in the order they were pushed.
+    pub(crate) fn take(&mut self) -> Vec<Command> {
+        self.stack.take().into_iter().flatten().collect()
+    }
+}
+
+impl Storage for CommandQueue {
+    fn is_empty(&self) -> bool {
+        CommandQueue::is_empty(self)
+    }
+}
+
+impl AllStorages {
+    /// Returns `true` if a [`Commands`](crate::views::Commands) view recorded commands that were not applied yet.
+    pub(crate) fn has_pending_commands(&self) -> bool {
+        self.custom_storage::<CommandQueue>()
+            .map(|queue| !queue.is_empty())
+            .unwrap_or(false)
+    }
+    /// Applies all commands recorded by [`Commands`](crate::views::Commands) views.
+    ///
+    /// This is done automatically at the end of each workload and at each [`Workload::with_barrier`].
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Commands, Component, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// let world = World::new();
+    ///
+    /// let entity = world.run(|mut commands: Commands| commands.add_entity((U32(0),)));
+    ///
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    /// assert!(!all_storages.is_entity_alive(entity));
+    ///
+    /// all_storages.apply_commands();
+    /// assert!(all_storages.is_entity_alive(entity));
+    /// ```
+    ///
+    /// [`Workload::with_barrier`]: crate::Workload::with_barrier()
+    pub fn apply_commands(&mut self) {
+        self.exclusive_storage_mut::<crate::entities::Entities>()
+            .unwrap()
+            .materialize_reserved();
+
+        let commands = match self.exclusive_storage_mut::<CommandQueue>() {
+            Ok(queue) => queue.take(),
+            Err(_) => return,
+        };
+
+        for command in commands {
+            (command)(self);
+        }
+    }
+}
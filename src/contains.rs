@@ -1,5 +1,6 @@
 use crate::component::Component;
 use crate::entity_id::EntityId;
+use crate::include_disabled::IncludeDisabled;
 use crate::sparse_set::SparseSet;
 use crate::tracking::Tracking;
 use crate::views::{View, ViewMut};
@@ -12,19 +13,43 @@ pub trait Contains {
 
 impl<'a: 'b, 'b, T: Component, Track: Tracking> Contains for &'b View<'a, T, Track> {
     fn contains(&self, entity: EntityId) -> bool {
-        SparseSet::contains(self, entity)
+        self.enabled_index_of(entity).is_some()
     }
 }
 
 impl<'a: 'b, 'b, T: Component, Track: Tracking> Contains for &'b ViewMut<'a, T, Track> {
     fn contains(&self, entity: EntityId) -> bool {
-        SparseSet::contains(&**self, entity)
+        self.enabled_index_of(entity).is_some()
     }
 }
 
 impl<'a: 'b, 'b, T: Component, Track: Tracking> Contains for &'b mut ViewMut<'a, T, Track> {
     fn contains(&self, entity: EntityId) -> bool {
-        SparseSet::contains(&**self, entity)
+        self.enabled_index_of(entity).is_some()
+    }
+}
+
+impl<'a: 'b, 'b, T: Component, Track: Tracking> Contains
+    for IncludeDisabled<&'b View<'a, T, Track>>
+{
+    fn contains(&self, entity: EntityId) -> bool {
+        SparseSet::contains(self.0, entity)
+    }
+}
+
+impl<'a: 'b, 'b, T: Component, Track: Tracking> Contains
+    for IncludeDisabled<&'b ViewMut<'a, T, Track>>
+{
+    fn contains(&self, entity: EntityId) -> bool {
+        SparseSet::contains(self.0, entity)
+    }
+}
+
+impl<'a: 'b, 'b, T: Component, Track: Tracking> Contains
+    for IncludeDisabled<&'b mut ViewMut<'a, T, Track>>
+{
+    fn contains(&self, entity: EntityId) -> bool {
+        SparseSet::contains(self.0, entity)
     }
 }
 
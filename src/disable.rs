@@ -0,0 +1,110 @@
+use crate::component::Component;
+use crate::entity_id::EntityId;
+use crate::views::ViewMut;
+
+/// Disables and enables components of an entity.
+pub trait Disable {
+    /// Hides `entity`'s component from iterators, [`Get`] and [`Contains`] without removing it.
+    /// Multiple storages can be disabled at the same time using a tuple.
+    /// This function does not mark `entity` as disabled, components added later will not be disabled.
+    /// Use [`Entities::disable_entity`] if you're unsure.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, Contains, Disable, ViewMut, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// #[derive(Component)]
+    /// struct USIZE(usize);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity = world.add_entity((USIZE(0), U32(1)));
+    ///
+    /// let (mut usizes, mut u32s) = world.borrow::<(ViewMut<USIZE>, ViewMut<U32>)>().unwrap();
+    ///
+    /// (&mut usizes, &mut u32s).disable_unchecked(entity);
+    /// assert!(!(&usizes, &u32s).contains(entity));
+    /// ```
+    ///
+    /// [`Get`]: crate::Get
+    /// [`Contains`]: crate::Contains
+    /// [`Entities::disable_entity`]: crate::entities::Entities::disable_entity()
+    fn disable_unchecked(&mut self, entity: EntityId);
+    /// Makes `entity`'s component visible to iterators, [`Get`] and [`Contains`] again.
+    /// Multiple storages can be enabled at the same time using a tuple.
+    ///
+    /// [`Get`]: crate::Get
+    /// [`Contains`]: crate::Contains
+    fn enable_unchecked(&mut self, entity: EntityId);
+}
+
+impl Disable for () {
+    #[inline]
+    fn disable_unchecked(&mut self, _: EntityId) {}
+    #[inline]
+    fn enable_unchecked(&mut self, _: EntityId) {}
+}
+
+impl<T: Component, TRACK> Disable for ViewMut<'_, T, TRACK> {
+    #[inline]
+    fn disable_unchecked(&mut self, entity: EntityId) {
+        self.private_disable(entity);
+    }
+    #[inline]
+    fn enable_unchecked(&mut self, entity: EntityId) {
+        self.private_enable(entity);
+    }
+}
+
+impl<T: Component, TRACK> Disable for &mut ViewMut<'_, T, TRACK> {
+    #[inline]
+    fn disable_unchecked(&mut self, entity: EntityId) {
+        self.private_disable(entity);
+    }
+    #[inline]
+    fn enable_unchecked(&mut self, entity: EntityId) {
+        self.private_enable(entity);
+    }
+}
+
+macro_rules! impl_disable {
+    ($(($storage: ident, $index: tt))+) => {
+        impl<$($storage: Disable),+> Disable for ($($storage,)+) {
+            #[inline]
+            fn disable_unchecked(&mut self, entity: EntityId) {
+                $(
+                    self.$index.disable_unchecked(entity);
+                )+
+            }
+            #[inline]
+            fn enable_unchecked(&mut self, entity: EntityId) {
+                $(
+                    self.$index.enable_unchecked(entity);
+                )+
+            }
+        }
+    }
+}
+
+macro_rules! disable {
+    ($(($storage: ident, $index: tt))+; ($storage1: ident, $index1: tt) $(($queue_type: ident, $queue_index: tt))*) => {
+        impl_disable![$(($storage, $index))*];
+        disable![$(($storage, $index))* ($storage1, $index1); $(($queue_type, $queue_index))*];
+    };
+    ($(($storage: ident, $index: tt))+;) => {
+        impl_disable![$(($storage, $index))*];
+    }
+}
+
+#[cfg(not(feature = "extended_tuple"))]
+disable![(ViewA, 0); (ViewB, 1) (ViewC, 2) (ViewD, 3) (ViewE, 4) (ViewF, 5) (ViewG, 6) (ViewH, 7) (ViewI, 8) (ViewJ, 9)];
+#[cfg(feature = "extended_tuple")]
+disable![
+    (ViewA, 0); (ViewB, 1) (ViewC, 2) (ViewD, 3) (ViewE, 4) (ViewF, 5) (ViewG, 6) (ViewH, 7) (ViewI, 8) (ViewJ, 9)
+    (ViewK, 10) (ViewL, 11) (ViewM, 12) (ViewN, 13) (ViewO, 14) (ViewP, 15) (ViewQ, 16) (ViewR, 17) (ViewS, 18) (ViewT, 19)
+    (ViewU, 20) (ViewV, 21) (ViewW, 22) (ViewX, 23) (ViewY, 24) (ViewZ, 25) (ViewAA, 26) (ViewBB, 27) (ViewCC, 28) (ViewDD, 29)
+    (ViewEE, 30) (ViewFF, 31)
+];
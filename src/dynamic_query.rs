@@ -0,0 +1,443 @@
+//! Queries built at runtime from [`StorageId`]s.
+//!
+//! Views and their tuples are known at compile time, tools and scripts can use [`DynamicQuery`] instead.\
+//! Each storage added to the query is a term, terms are indexed in the order they were added.
+//!
+//! ### Example
+//!
+//! ```
+//! use shipyard::advanced::StorageId;
+//! use shipyard::dynamic_query::DynamicQuery;
+//! use shipyard::sparse_set::SparseSet;
+//! use shipyard::{AllStoragesViewMut, Component, World};
+//!
+//! #[derive(Component)]
+//! struct Position(f32);
+//! #[derive(Component)]
+//! struct Velocity(f32);
+//! #[derive(Component)]
+//! struct Frozen;
+//!
+//! let mut world = World::new();
+//!
+//! let entity = world.add_entity((Position(0.0), Velocity(1.0)));
+//! world.add_entity((Position(0.0), Velocity(1.0), Frozen));
+//!
+//! let all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+//!
+//! let mut view = DynamicQuery::new()
+//!     .write(StorageId::of::<SparseSet<Position>>())
+//!     .read(StorageId::of::<SparseSet<Velocity>>())
+//!     .without(StorageId::of::<SparseSet<Frozen>>())
+//!     .borrow(&all_storages)
+//!     .unwrap();
+//!
+//! for mut item in view.iter() {
+//!     let velocity = item.get::<Velocity>(1).unwrap().0;
+//!     item.get_mut::<Position>(0).unwrap().0 += velocity;
+//!
+//!     assert_eq!(item.entity(), entity);
+//! }
+//! ```
+
+use crate::all_storages::{AllStorages, CustomStorageAccess};
+use crate::atomic_refcell::{ARef, ARefMut};
+use crate::component::Component;
+use crate::entities::{Entities, EntitiesIter};
+use crate::entity_id::EntityId;
+use crate::error;
+use crate::sparse_set::{RawComponentAccess, SparseSet};
+use crate::storage::{Storage, StorageId};
+use crate::tracking::TrackingTimestamp;
+use alloc::vec::Vec;
+use core::any::type_name;
+use core::ops::Range;
+
+/// How a [`DynamicQuery`] term accesses its storage.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Access {
+    /// The component is required and read, like `&T`.
+    Read,
+    /// The component is required and can be modified, like `&mut T`.
+    Write,
+    /// The component is read if present, like `Option<&T>`.
+    Optional,
+    /// The component can be modified if present, like `Option<&mut T>`.
+    OptionalMut,
+    /// The entity must not have this component, like `Not<&T>`.
+    Without,
+    /// The entity must have at least one component of the group, like `Or<(&T, &U)>`.
+    Or,
+}
+
+/// A query built at runtime from [`StorageId`]s.
+///
+/// Use [`DynamicQuery::borrow`] to borrow the storages and iterate the matching entities.
+#[derive(Clone, Debug, Default)]
+pub struct DynamicQuery {
+    terms: Vec<(StorageId, Access)>,
+    or_groups: Vec<Range<usize>>,
+}
+
+impl DynamicQuery {
+    /// Creates a query without terms, it matches all entities.
+    #[inline]
+    pub fn new() -> DynamicQuery {
+        DynamicQuery::default()
+    }
+    /// Requires a component of `storage_id` and reads it.
+    #[inline]
+    pub fn read(mut self, storage_id: StorageId) -> DynamicQuery {
+        self.terms.push((storage_id, Access::Read));
+        self
+    }
+    /// Requires a component of `storage_id` and borrows it mutably.
+    #[inline]
+    pub fn write(mut self, storage_id: StorageId) -> DynamicQuery {
+        self.terms.push((storage_id, Access::Write));
+        self
+    }
+    /// Reads the component of `storage_id` when present.
+    #[inline]
+    pub fn optional(mut self, storage_id: StorageId) -> DynamicQuery {
+        self.terms.push((storage_id, Access::Optional));
+        self
+    }
+    /// Borrows the component of `storage_id` mutably when present.
+    #[inline]
+    pub fn optional_mut(mut self, storage_id: StorageId) -> DynamicQuery {
+        self.terms.push((storage_id, Access::OptionalMut));
+        self
+    }
+    /// Excludes entities with a component of `storage_id`.
+    #[inline]
+    pub fn without(mut self, storage_id: StorageId) -> DynamicQuery {
+        self.terms.push((storage_id, Access::Without));
+        self
+    }
+    /// Requires a component of at least one of `storage_ids` and reads the present ones.\
+    /// Adds one term per storage.
+    pub fn or<I: IntoIterator<Item = StorageId>>(mut self, storage_ids: I) -> DynamicQuery {
+        let start = self.terms.len();
+
+        self.terms.extend(
+            storage_ids
+                .into_iter()
+                .map(|storage_id| (storage_id, Access::Or)),
+        );
+
+        self.or_groups.push(start..self.terms.len());
+        self
+    }
+    /// Returns the terms of this query.
+    #[inline]
+    pub fn terms(&self) -> &[(StorageId, Access)] {
+        &self.terms
+    }
+    /// Borrows all storages of the query.
+    ///
+    /// A missing storage doesn't make the borrow fail, the query won't match any entity if the storage was required.
+    ///
+    /// ### Borrows
+    ///
+    /// - Storages with [`Access::Write`] or [`Access::OptionalMut`] (exclusive)
+    /// - Other storages (shared)
+    /// - Entities (shared) if the query doesn't have any required term
+    ///
+    /// ### Errors
+    ///
+    /// - Storage borrow failed.
+    /// - Entities borrow failed.
+    pub fn borrow<'a>(
+        &self,
+        all_storages: &'a AllStorages,
+    ) -> Result<DynamicQueryView<'a>, error::GetStorage> {
+        let mut storages = Vec::with_capacity(self.terms.len());
+        let mut is_empty = false;
+
+        for &(storage_id, access) in &self.terms {
+            let storage = match access {
+                Access::Write | Access::OptionalMut => {
+                    match all_storages.custom_storage_mut_by_id(storage_id) {
+                        Ok(mut storage) => {
+                            let access = storage.raw_component_access();
+
+                            Some(TermStorage::Exclusive {
+                                _borrow: storage,
+                                access,
+                            })
+                        }
+                        Err(error::GetStorage::MissingStorage { .. }) => None,
+                        Err(err) => return Err(err),
+                    }
+                }
+                _ => match all_storages.custom_storage_by_id(storage_id) {
+                    Ok(storage) => Some(TermStorage::Shared(storage)),
+                    Err(error::GetStorage::MissingStorage { .. }) => None,
+                    Err(err) => return Err(err),
+                },
+            };
+
+            if storage.is_none() && matches!(access, Access::Read | Access::Write) {
+                is_empty = true;
+            }
+
+            storages.push(storage);
+        }
+
+        // Iterates the smallest required storage, all entities if there is none
+        let driver = self
+            .terms
+            .iter()
+            .zip(&storages)
+            .enumerate()
+            .filter(|(_, ((_, access), _))| matches!(access, Access::Read | Access::Write))
+            .filter_map(|(index, (_, storage))| Some((index, storage.as_ref()?.dense()?)))
+            .min_by_key(|(_, dense)| dense.len())
+            .map(|(index, _)| index);
+
+        let entities = if driver.is_none() && !is_empty {
+            Some(all_storages.entities()?)
+        } else {
+            None
+        };
+
+        Ok(DynamicQueryView {
+            query: self.clone(),
+            storages,
+            entities,
+            driver,
+            is_empty,
+            current: all_storages.get_current(),
+        })
+    }
+}
+
+enum TermStorage<'a> {
+    Shared(ARef<'a, &'a dyn Storage>),
+    /// `access` points into the storage `_borrow` keeps borrowed.\
+    /// The storage is never borrowed again, items can hand out references to different components.
+    Exclusive {
+        _borrow: ARefMut<'a, &'a mut (dyn Storage + 'static)>,
+        access: Option<RawComponentAccess>,
+    },
+}
+
+impl TermStorage<'_> {
+    #[inline]
+    fn dense(&self) -> Option<&[EntityId]> {
+        match self {
+            TermStorage::Shared(storage) => storage.dense(),
+            TermStorage::Exclusive { access, .. } => access.as_ref().map(RawComponentAccess::dense),
+        }
+    }
+    #[inline]
+    fn contains(&self, entity: EntityId) -> bool {
+        match self {
+            TermStorage::Shared(storage) => match storage.sparse_array() {
+                Some(sparse_array) => sparse_array.contains(entity),
+                None => storage.component_ptr(entity).is_some(),
+            },
+            TermStorage::Exclusive { access, .. } => access
+                .as_ref()
+                .is_some_and(|access| access.contains(entity)),
+        }
+    }
+    #[inline]
+    fn component_ptr(&self, entity: EntityId) -> Option<*const u8> {
+        match self {
+            TermStorage::Shared(storage) => storage.component_ptr(entity),
+            TermStorage::Exclusive { access, .. } => access.as_ref()?.component_ptr(entity),
+        }
+    }
+}
+
+/// Borrowed storages of a [`DynamicQuery`].
+pub struct DynamicQueryView<'a> {
+    query: DynamicQuery,
+    storages: Vec<Option<TermStorage<'a>>>,
+    entities: Option<ARef<'a, &'a Entities>>,
+    driver: Option<usize>,
+    is_empty: bool,
+    current: TrackingTimestamp,
+}
+
+impl<'a> DynamicQueryView<'a> {
+    /// Returns the query this view was borrowed from.
+    #[inline]
+    pub fn query(&self) -> &DynamicQuery {
+        &self.query
+    }
+    /// Returns `true` if `entity` matches the query.
+    pub fn matches(&self, entity: EntityId) -> bool {
+        let has_terms =
+            self.query
+                .terms
+                .iter()
+                .zip(&self.storages)
+                .all(|((_, access), storage)| {
+                    let contains = storage
+                        .as_ref()
+                        .is_some_and(|storage| storage.contains(entity));
+
+                    match access {
+                        Access::Read | Access::Write => contains,
+                        Access::Without => !contains,
+                        Access::Optional | Access::OptionalMut | Access::Or => true,
+                    }
+                });
+
+        has_terms
+            && self.query.or_groups.iter().all(|group| {
+                self.storages[group.clone()]
+                    .iter()
+                    .flatten()
+                    .any(|storage| storage.contains(entity))
+            })
+    }
+    /// Returns the item of `entity` if it matches the query.
+    pub fn get(&mut self, entity: EntityId) -> Option<DynamicQueryItem<'_, 'a>> {
+        if self.matches(entity) {
+            Some(DynamicQueryItem { view: self, entity })
+        } else {
+            None
+        }
+    }
+    /// Returns an iterator over all entities matching the query.
+    pub fn iter(&mut self) -> DynamicQueryIter<'_, 'a> {
+        let view = &*self;
+
+        let entities = if view.is_empty {
+            EntitySource::Empty
+        } else if let Some(driver) = view.driver {
+            let dense = view.storages[driver]
+                .as_ref()
+                .and_then(TermStorage::dense)
+                .unwrap_or_default();
+
+            EntitySource::Dense(dense.iter())
+        } else if let Some(entities) = &view.entities {
+            EntitySource::Entities(entities.iter())
+        } else {
+            EntitySource::Empty
+        };
+
+        DynamicQueryIter { view, entities }
+    }
+}
+
+enum EntitySource<'v> {
+    Dense(core::slice::Iter<'v, EntityId>),
+    Entities(EntitiesIter<'v>),
+    Empty,
+}
+
+/// Iterator over the entities matching a [`DynamicQuery`].
+pub struct DynamicQueryIter<'v, 'a> {
+    view: &'v DynamicQueryView<'a>,
+    entities: EntitySource<'v>,
+}
+
+impl<'v, 'a> Iterator for DynamicQueryIter<'v, 'a> {
+    type Item = DynamicQueryItem<'v, 'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let entity = match &mut self.entities {
+                EntitySource::Dense(iter) => *iter.next()?,
+                EntitySource::Entities(iter) => iter.next()?,
+                EntitySource::Empty => return None,
+            };
+
+            if self.view.matches(entity) {
+                return Some(DynamicQueryItem {
+                    view: self.view,
+                    entity,
+                });
+            }
+        }
+    }
+}
+
+/// An entity matching a [`DynamicQuery`] and access to its components.
+pub struct DynamicQueryItem<'v, 'a> {
+    view: &'v DynamicQueryView<'a>,
+    entity: EntityId,
+}
+
+impl DynamicQueryItem<'_, '_> {
+    /// Returns the entity.
+    #[inline]
+    pub fn entity(&self) -> EntityId {
+        self.entity
+    }
+    /// Returns a type-erased pointer to the component of `term`.\
+    /// Returns `None` if the entity doesn't have this component or the storage doesn't support type-erased access.
+    ///
+    /// ### Panics
+    ///
+    /// - `term` is out of bounds.
+    #[track_caller]
+    pub fn ptr(&self, term: usize) -> Option<*const u8> {
+        self.storage(term)?.component_ptr(self.entity)
+    }
+    /// Returns a type-erased mutable pointer to the component of `term` and flags it as modified.\
+    /// Returns `None` if the entity doesn't have this component or the storage doesn't support type-erased access.
+    ///
+    /// ### Panics
+    ///
+    /// - `term` is out of bounds.
+    /// - `term` doesn't have [`Access::Write`] or [`Access::OptionalMut`].
+    #[track_caller]
+    pub fn ptr_mut(&mut self, term: usize) -> Option<*mut u8> {
+        match self.storage(term)? {
+            // SAFE the storage is exclusively borrowed and each entity is yielded at most once
+            TermStorage::Exclusive { access, .. } => unsafe {
+                access
+                    .as_ref()?
+                    .component_ptr_mut(self.entity, self.view.current)
+            },
+            TermStorage::Shared(_) => panic!("Term {} is not borrowed mutably.", term),
+        }
+    }
+    /// Returns the component of `term`.
+    ///
+    /// ### Panics
+    ///
+    /// - `term` is out of bounds.
+    /// - `term`'s storage isn't a `SparseSet<T>`.
+    #[track_caller]
+    pub fn get<T: Component>(&self, term: usize) -> Option<&T> {
+        self.check_type::<T>(term);
+
+        self.ptr(term).map(|ptr| unsafe { &*ptr.cast::<T>() })
+    }
+    /// Returns the component of `term` mutably and flags it as modified.
+    ///
+    /// ### Panics
+    ///
+    /// - `term` is out of bounds.
+    /// - `term` doesn't have [`Access::Write`] or [`Access::OptionalMut`].
+    /// - `term`'s storage isn't a `SparseSet<T>`.
+    #[track_caller]
+    pub fn get_mut<T: Component>(&mut self, term: usize) -> Option<&mut T> {
+        self.check_type::<T>(term);
+
+        self.ptr_mut(term)
+            .map(|ptr| unsafe { &mut *ptr.cast::<T>() })
+    }
+
+    #[track_caller]
+    fn storage(&self, term: usize) -> Option<&TermStorage<'_>> {
+        self.view.storages[term].as_ref()
+    }
+    #[track_caller]
+    fn check_type<T: Component>(&self, term: usize) {
+        assert!(
+            self.view.query.terms[term].0 == StorageId::of::<SparseSet<T>>(),
+            "Term {} is not a {}.",
+            term,
+            type_name::<SparseSet<T>>()
+        );
+    }
+}
@@ -5,17 +5,22 @@ pub use iterator::EntitiesIter;
 use crate::add_component::AddComponent;
 use crate::add_distinct_component::AddDistinctComponent;
 use crate::add_entity::AddEntity;
+use crate::disable::Disable;
 use crate::entity_id::EntityId;
 use crate::error;
 use crate::memory_usage::StorageMemoryUsage;
+use crate::relation::DeletionQueues;
 use crate::reserve::{BulkEntityIter, BulkReserve};
 use crate::storage::{SBoxBuilder, Storage};
 use crate::tracking::TrackingTimestamp;
+use crate::ShipHashSet;
 use alloc::boxed::Box;
+use alloc::sync::Arc;
 use alloc::vec::Vec;
 use core::any::type_name;
 use core::iter::repeat_with;
 use core::mem::size_of;
+use core::sync::atomic::{AtomicU64, Ordering};
 
 /// Holds the handles to all entities: living, removed and dead.
 ///
@@ -35,10 +40,15 @@ use core::mem::size_of;
 // Removed entities form a linked list inside the vector, using their index part to point to the next.
 // Removed entities are added to one end and removed from the other.
 // Dead entities are simply never added to the linked list.
+// Reserved entities are handed out without exclusive access, they always get a new index
+// and are pushed to data the next time Entities is borrowed exclusively.
 pub struct Entities {
     pub(crate) data: Vec<EntityId>,
     list: Option<(usize, usize)>,
+    reserved: AtomicU64,
+    disabled: ShipHashSet<EntityId>,
     on_deletion: Option<Box<dyn FnMut(EntityId) + Send + Sync>>,
+    pub(crate) deletion_queues: Arc<DeletionQueues>,
 }
 
 impl Entities {
@@ -47,7 +57,10 @@ impl Entities {
         Entities {
             data: Vec::new(),
             list: None,
+            reserved: AtomicU64::new(0),
+            disabled: ShipHashSet::new(),
             on_deletion: None,
+            deletion_queues: Arc::default(),
         }
     }
     /// Returns `true` if `entity` matches a living entity.
@@ -91,6 +104,10 @@ impl Entities {
     ) {
         if self.is_alive(entity) {
             storages.add_component_unchecked(entity, component);
+
+            if self.is_disabled(entity) {
+                storages.disable_added(entity);
+            }
         } else {
             panic!("{:?}", error::AddComponent::EntityIsNotAlive);
         }
@@ -135,7 +152,26 @@ impl Entities {
             panic!("{:?}", error::AddComponent::EntityIsNotAlive);
         }
     }
+    /// Reserves a new [`EntityId`] without borrowing `Entities` exclusively.
+    ///
+    /// The entity will only be alive after the next exclusive access to `Entities`.
+    pub(crate) fn reserve(&self) -> EntityId {
+        let index = self.data.len() as u64 + self.reserved.fetch_add(1, Ordering::Relaxed);
+
+        EntityId::new(index)
+    }
+    /// Makes all reserved entities alive.
+    pub(crate) fn materialize_reserved(&mut self) {
+        let reserved = core::mem::take(self.reserved.get_mut());
+
+        if reserved > 0 {
+            let len = self.data.len() as u64;
+            self.data.extend((len..len + reserved).map(EntityId::new));
+        }
+    }
     pub(crate) fn generate(&mut self) -> EntityId {
+        self.materialize_reserved();
+
         if let Some((new, ref mut old)) = self.list {
             let old_index = *old;
 
@@ -158,6 +194,7 @@ impl Entities {
             entity_id
         }
     }
+    /// Reserved entities have to be materialized before calling this function.
     pub(crate) fn bulk_generate(&mut self, count: usize) -> &[EntityId] {
         self.data
             .extend((self.data.len() as u64..(self.data.len() + count) as u64).map(EntityId::new));
@@ -167,6 +204,19 @@ impl Entities {
     /// Deletes an entity, returns true if the entity was alive.  
     /// If the entity has components, they will not be deleted and still be accessible using this id.
     pub fn delete_unchecked(&mut self, entity_id: EntityId) -> bool {
+        if self.delete_before_strip(entity_id) {
+            self.deletion_queues.push(entity_id);
+
+            true
+        } else {
+            false
+        }
+    }
+    /// Same as [`delete_unchecked`](Entities::delete_unchecked) but relation storages are not notified.\
+    /// The caller has to strip `entity_id` from all storages.
+    pub(crate) fn delete_before_strip(&mut self, entity_id: EntityId) -> bool {
+        self.materialize_reserved();
+
         if self.is_alive(entity_id) {
             // SAFE we checked for OOB
             if unsafe {
@@ -198,6 +248,8 @@ impl Entities {
                 }
             }
 
+            self.disabled.remove(&entity_id);
+
             if let Some(on_deletion) = &mut self.on_deletion {
                 (on_deletion)(entity_id)
             }
@@ -207,6 +259,58 @@ impl Entities {
             false
         }
     }
+    /// Returns `true` if `entity` is alive and disabled.
+    #[inline]
+    pub fn is_disabled(&self, entity: EntityId) -> bool {
+        self.disabled.contains(&entity)
+    }
+    /// Disables `entity`, its components in `storages` are kept but skipped by iterators, [`Get`] and [`Contains`].  
+    /// Multiple storages can be passed using a tuple, storages not passed keep `entity`'s components enabled.  
+    /// Returns `true` if `entity` was alive and enabled.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, EntitiesViewMut, Get, ViewMut, World};
+    ///
+    /// #[derive(Component, Debug, PartialEq, Eq)]
+    /// struct U32(u32);
+    ///
+    /// let world = World::new();
+    ///
+    /// let (mut entities, mut u32s) = world.borrow::<(EntitiesViewMut, ViewMut<U32>)>().unwrap();
+    ///
+    /// let entity = entities.add_entity(&mut u32s, U32(0));
+    ///
+    /// assert!(entities.disable_entity(entity, &mut u32s));
+    /// assert!((&u32s).get(entity).is_err());
+    ///
+    /// assert!(entities.enable_entity(entity, &mut u32s));
+    /// assert_eq!((&u32s).get(entity), Ok(&U32(0)));
+    /// ```
+    ///
+    /// [`Get`]: crate::Get
+    /// [`Contains`]: crate::Contains
+    pub fn disable_entity<S: Disable>(&mut self, entity: EntityId, mut storages: S) -> bool {
+        if self.is_alive(entity) && self.disabled.insert(entity) {
+            storages.disable_unchecked(entity);
+
+            true
+        } else {
+            false
+        }
+    }
+    /// Enables `entity` and its components in `storages`.  
+    /// Multiple storages can be passed using a tuple.  
+    /// Returns `true` if `entity` was alive and disabled.
+    pub fn enable_entity<S: Disable>(&mut self, entity: EntityId, mut storages: S) -> bool {
+        if self.disabled.remove(&entity) {
+            storages.enable_unchecked(entity);
+
+            true
+        } else {
+            false
+        }
+    }
     /// Stores `component` in a new entity and returns its [`EntityId`].  
     /// Multiple components can be added at the same time using a tuple.
     ///
@@ -275,6 +379,7 @@ impl Entities {
         let mut iter = component.into_iter();
         let len = iter.size_hint().0;
 
+        self.materialize_reserved();
         let entities_len = self.data.len();
         let new_entities = self.bulk_generate(len);
 
@@ -302,6 +407,8 @@ impl Entities {
     /// Does nothing if an entity with a greater generation is already at this index.  
     /// Returns `true` if the entity is successfully spawned.
     pub fn spawn(&mut self, entity: EntityId) -> bool {
+        self.materialize_reserved();
+
         if let Some(&old_entity) = self.data.get(entity.index() as usize) {
             if self.is_alive(old_entity) {
                 if old_entity.gen() <= entity.gen() {
@@ -390,6 +497,9 @@ impl Entities {
 
 impl Storage for Entities {
     fn clear(&mut self, _current: TrackingTimestamp) {
+        self.materialize_reserved();
+        self.disabled.clear();
+
         if self.data.is_empty() {
             return;
         }
@@ -462,10 +572,27 @@ impl Storage for Entities {
         Some(SBoxBuilder::new(Entities {
             data: self.data.clone(),
             list: self.list,
+            reserved: AtomicU64::new(self.reserved.load(Ordering::Relaxed)),
+            disabled: self.disabled.clone(),
             on_deletion: None,
+            deletion_queues: Arc::default(),
         }))
     }
 
+    #[inline]
+    fn try_snapshot(&self) -> Option<SBoxBuilder> {
+        self.try_clone(TrackingTimestamp::origin())
+    }
+
+    fn restore_snapshot(&mut self, snapshot: &dyn Storage, _current: TrackingTimestamp) {
+        if let Some(snapshot) = snapshot.any().downcast_ref::<Entities>() {
+            self.data.clone_from(&snapshot.data);
+            self.list = snapshot.list;
+            *self.reserved.get_mut() = snapshot.reserved.load(Ordering::Relaxed);
+            self.disabled.clone_from(&snapshot.disabled);
+        }
+    }
+
     #[inline]
     fn clone_component_to(
         &self,
@@ -73,6 +73,11 @@ pub enum GetStorage {
         id: StorageId,
         tracking: &'static str,
     },
+    /// A [`Local`](crate::views::Local) view was borrowed outside of a workload.
+    MissingLocal {
+        /// Type name of the view.
+        name: &'static str,
+    },
     /// Error returned by a custom view.
     #[cfg(feature = "std")]
     Custom(Box<dyn Error + Send + Sync>),
@@ -133,6 +138,10 @@ impl PartialEq for GetStorage {
                     tracking: r_tracking,
                 },
             ) => l_name == r_name && l_id == r_id && l_tracking == r_tracking,
+            (
+                GetStorage::MissingLocal { name: l_name },
+                GetStorage::MissingLocal { name: r_name },
+            ) => l_name == r_name,
             _ => false,
         }
     }
@@ -189,6 +198,7 @@ impl Debug for GetStorage {
             } else {
                 f.write_fmt(format_args!("{} tracking is not enabled for {:?} storage.", tracking, id))
             }
+            GetStorage::MissingLocal { name } => f.write_fmt(format_args!("{} can only be borrowed by a system running in a workload.", name)),
             GetStorage::Custom(err) => {
                 f.write_fmt(format_args!("Storage borrow failed with a custom error, {:?}.", err))
             }
@@ -292,6 +302,16 @@ pub enum AddWorkload {
         id: StorageId,
         borrow: Borrow,
     },
+    /// Two systems borrow the same storage, at least one of them mutably, without any ordering constraint between them.\
+    /// Only returned by workloads built with [`Workload::deny_ambiguities`](crate::Workload::deny_ambiguities).
+    Ambiguity {
+        #[allow(missing_docs)]
+        system: Box<dyn Label>,
+        #[allow(missing_docs)]
+        other_system: Box<dyn Label>,
+        #[allow(missing_docs)]
+        type_info: TypeInfo,
+    },
 }
 
 // For some reason this trait can't be derived with Box<dyn Label>
@@ -322,6 +342,22 @@ impl PartialEq for AddWorkload {
                     borrow: r_borrow,
                 },
             ) => l_name == r_name && l_id == r_id && l_borrow == r_borrow,
+            (
+                AddWorkload::Ambiguity {
+                    system: l_system,
+                    other_system: l_other_system,
+                    type_info: l_type_info,
+                },
+                AddWorkload::Ambiguity {
+                    system: r_system,
+                    other_system: r_other_system,
+                    type_info: r_type_info,
+                },
+            ) => {
+                l_system == r_system
+                    && l_other_system == r_other_system
+                    && l_type_info == r_type_info
+            }
             _ => core::mem::discriminant(self) == core::mem::discriminant(other),
         }
     }
@@ -376,6 +412,14 @@ impl Debug for AddWorkload {
                     }
                 }
             }
+            AddWorkload::Ambiguity {
+                system,
+                other_system,
+                type_info,
+            } => f.write_fmt(format_args!(
+                "{:?} and {:?} both borrow {} and at least one of them mutably but they are not ordered. Add a before_all/after_all constraint or allow the ambiguity.",
+                system, other_system, type_info.name
+            )),
         }
     }
 }
@@ -386,6 +430,61 @@ impl Display for AddWorkload {
     }
 }
 
+/// Error returned when modifying a workload already added to the `World`.
+pub enum ModifyWorkload {
+    /// The `Scheduler` is already borrowed.
+    Borrow,
+    /// The workload does not exist.
+    MissingWorkload,
+    /// No system of the workload matches this label.
+    MissingSystem(Box<dyn Label>),
+    /// The system cannot be added to a workload.
+    InvalidSystem(InvalidSystem),
+    /// The modified workload is not valid, the workload was left unchanged.
+    Invalid(AddWorkload),
+}
+
+// For some reason this trait can't be derived with Box<dyn Label>
+impl PartialEq for ModifyWorkload {
+    fn eq(&self, other: &ModifyWorkload) -> bool {
+        match (self, other) {
+            (ModifyWorkload::MissingSystem(l0), ModifyWorkload::MissingSystem(r0)) => l0 == r0,
+            (ModifyWorkload::InvalidSystem(l0), ModifyWorkload::InvalidSystem(r0)) => l0 == r0,
+            (ModifyWorkload::Invalid(l0), ModifyWorkload::Invalid(r0)) => l0 == r0,
+            _ => core::mem::discriminant(self) == core::mem::discriminant(other),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for ModifyWorkload {}
+
+impl Debug for ModifyWorkload {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            ModifyWorkload::Borrow => {
+                f.write_str("Cannot mutably borrow the scheduler while it's already borrowed.")
+            }
+            ModifyWorkload::MissingWorkload => f.write_str("Workload is not present in the World."),
+            ModifyWorkload::MissingSystem(label) => f.write_fmt(format_args!(
+                "No system of the workload matches {:?}.",
+                label
+            )),
+            ModifyWorkload::InvalidSystem(err) => Debug::fmt(err, f),
+            ModifyWorkload::Invalid(err) => f.write_fmt(format_args!(
+                "The modified workload is invalid and was left unchanged: {:?}",
+                err
+            )),
+        }
+    }
+}
+
+impl Display for ModifyWorkload {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        Debug::fmt(self, f)
+    }
+}
+
 /// Trying to set the default workload to a non existent one will result in this error.
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub enum SetDefaultWorkload {
@@ -429,6 +528,12 @@ pub enum RunWorkload {
     Run((Box<dyn Label>, Run)),
     /// Workload is not present in the world.
     MissingWorkload,
+    /// `AllStorages` could not be borrowed exclusively to apply the recorded [`Commands`](crate::views::Commands).
+    AllStoragesBorrow(Borrow),
+    /// Errors returned by the systems using [`ErrorPolicy::Collect`](crate::scheduler::ErrorPolicy::Collect), as [`RunWorkload::Run`], in the order they failed.\
+    /// If another error stopped the workload, a system using [`ErrorPolicy::Abort`](crate::scheduler::ErrorPolicy::Abort)
+    /// or failing to apply commands for example, it is last.
+    Multiple(Vec<RunWorkload>),
 }
 
 impl RunWorkload {
@@ -463,6 +568,18 @@ impl Debug for RunWorkload {
             RunWorkload::Run((system_name, run)) => {
                 f.write_fmt(format_args!("{:?} failed: {:?}", system_name, run))
             }
+            RunWorkload::AllStoragesBorrow(_) => f.write_str(
+                "Cannot apply commands while AllStorages is borrowed. Commands are applied at the end of workloads and at barriers.",
+            ),
+            RunWorkload::Multiple(errors) => {
+                f.write_fmt(format_args!("{} errors:", errors.len()))?;
+
+                for error in errors {
+                    f.write_fmt(format_args!("\n{:?}", error))?;
+                }
+
+                Ok(())
+            }
         }
     }
 }
@@ -932,3 +1049,55 @@ impl<'de, D: serde::Deserializer<'de>> Display for Deserialize<'de, D> {
         Debug::fmt(self, f)
     }
 }
+
+/// Returned by [`TypeRegistration`]'s hooks.
+///
+/// [`TypeRegistration`]: crate::registry::TypeRegistration
+#[derive(PartialEq)]
+pub enum Registry {
+    /// The type was registered without this hook.
+    MissingHook {
+        /// Name the type was registered with.
+        name: Cow<'static, str>,
+        /// Name of the missing hook.
+        hook: &'static str,
+    },
+    #[allow(missing_docs)]
+    StorageBorrow(GetStorage),
+    #[allow(missing_docs)]
+    MissingComponent(MissingComponent),
+}
+
+impl From<GetStorage> for Registry {
+    fn from(get_storage: GetStorage) -> Registry {
+        Registry::StorageBorrow(get_storage)
+    }
+}
+
+impl From<MissingComponent> for Registry {
+    fn from(missing_component: MissingComponent) -> Registry {
+        Registry::MissingComponent(missing_component)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for Registry {}
+
+impl Debug for Registry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            Registry::MissingHook { name, hook } => f.write_fmt(format_args!(
+                "{} was registered without a {} hook.",
+                name, hook
+            )),
+            Registry::StorageBorrow(err) => f.write_fmt(format_args!("{:?}", err)),
+            Registry::MissingComponent(err) => f.write_fmt(format_args!("{:?}", err)),
+        }
+    }
+}
+
+impl Display for Registry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        Debug::fmt(self, f)
+    }
+}
@@ -0,0 +1,247 @@
+//! Typed event channels.
+//!
+//! Events are sent with [`EventWriter`] and read with [`EventReader`].\
+//! In workloads, each system reading events has its own cursor and sees every event exactly once.
+//! Outside of workloads, readers see all events that haven't expired.
+//!
+//! ### Example
+//!
+//! ```
+//! use shipyard::{EventReader, EventWriter, Workload, World};
+//!
+//! struct Explosion(u32);
+//!
+//! fn explode(mut explosions: EventWriter<Explosion>) {
+//!     explosions.send(Explosion(10));
+//! }
+//!
+//! fn play_sound(explosions: EventReader<Explosion>) {
+//!     assert_eq!(explosions.iter().count(), 1);
+//! }
+//!
+//! fn shake_camera(explosions: EventReader<Explosion>) {
+//!     assert_eq!(explosions.iter().map(|explosion| explosion.0).sum::<u32>(), 10);
+//! }
+//!
+//! let world = World::new();
+//!
+//! Workload::new("")
+//!     .with_system(explode)
+//!     .with_system(play_sound)
+//!     .with_system(shake_camera)
+//!     .add_to_world(&world)
+//!     .unwrap();
+//!
+//! world.run_default_workload().unwrap();
+//! world.run_default_workload().unwrap();
+//! ```
+//!
+//! [`EventReader`]: crate::views::EventReader
+//! [`EventWriter`]: crate::views::EventWriter
+
+use crate::memory_usage::StorageMemoryUsage;
+use crate::spin_lock::SpinLock;
+use crate::storage::Storage;
+use crate::tracking::TrackingTimestamp;
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+use core::any::type_name;
+use core::mem::size_of;
+
+/// Decides when events are dropped.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum EventExpiry {
+    /// Events are dropped once every reader has seen them.
+    ///
+    /// Readers are the systems that borrowed an [`EventReader`](crate::views::EventReader) in a workload.\
+    /// A reader that didn't run during the last [`stale_reader_runs`](Events::stale_reader_runs) workload runs is forgotten,
+    /// if it runs again it only sees the events that didn't expire in the meantime
+    /// and [`EventReader::missed_events`](crate::views::EventReader::missed_events) returns `true`.\
+    /// Without any reader, events are dropped after one workload run.
+    #[default]
+    AllReaders,
+    /// Events are dropped after this number of workload runs.\
+    /// With `Runs(1)` events are only visible during the workload run they were sent in.
+    Runs(u64),
+}
+
+pub(crate) struct Event<T> {
+    pub(crate) timestamp: TrackingTimestamp,
+    pub(crate) run: u64,
+    pub(crate) value: T,
+}
+
+/// Events storage.
+///
+/// Accessed with [`EventWriter`](crate::views::EventWriter) and [`EventReader`](crate::views::EventReader).
+pub struct Events<T: 'static> {
+    pub(crate) events: VecDeque<Event<T>>,
+    pub(crate) expiry: EventExpiry,
+    stale_reader_runs: u64,
+    /// Timestamp of the most recent event dropped
+    pub(crate) last_dropped: Option<TrackingTimestamp>,
+    /// Position of each reader, moved by the readers themselves when they are borrowed
+    cursors: SpinLock<Vec<Cursor>>,
+}
+
+struct Cursor {
+    /// Timestamp up to which the reader has seen events
+    timestamp: u64,
+    /// Number of workload runs when the reader last ran
+    run: u64,
+}
+
+impl<T: 'static> Default for Events<T> {
+    fn default() -> Self {
+        Events::new(EventExpiry::default())
+    }
+}
+
+impl<T: 'static> Events<T> {
+    /// Default number of workload runs after which a reader that didn't run is forgotten.
+    pub const DEFAULT_STALE_READER_RUNS: u64 = 64;
+
+    /// Creates an empty event storage.
+    pub fn new(expiry: EventExpiry) -> Events<T> {
+        Events {
+            events: VecDeque::new(),
+            expiry,
+            stale_reader_runs: Events::<T>::DEFAULT_STALE_READER_RUNS,
+            last_dropped: None,
+            cursors: SpinLock::default(),
+        }
+    }
+    /// Sets the number of workload runs after which a reader that didn't run is forgotten.\
+    /// Only used with [`EventExpiry::AllReaders`].
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::events::{EventExpiry, Events};
+    /// use shipyard::{advanced::StorageId, World};
+    ///
+    /// struct Hit;
+    ///
+    /// let world = World::new();
+    ///
+    /// world
+    ///     .add_custom_storage(
+    ///         StorageId::of::<Events<Hit>>(),
+    ///         Events::<Hit>::new(EventExpiry::AllReaders).with_stale_reader_runs(600),
+    ///     )
+    ///     .unwrap();
+    /// ```
+    pub fn with_stale_reader_runs(mut self, stale_reader_runs: u64) -> Events<T> {
+        self.stale_reader_runs = stale_reader_runs;
+
+        self
+    }
+    /// Returns the number of workload runs after which a reader that didn't run is forgotten.
+    #[inline]
+    pub fn stale_reader_runs(&self) -> u64 {
+        self.stale_reader_runs
+    }
+    /// Returns the number of events currently stored.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+    /// Returns `true` if no event is stored.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+    /// Returns the expiry policy.
+    #[inline]
+    pub fn expiry(&self) -> EventExpiry {
+        self.expiry
+    }
+    pub(crate) fn push(&mut self, value: T, timestamp: TrackingTimestamp, run: u64) {
+        self.events.push_back(Event {
+            timestamp,
+            run,
+            value,
+        });
+    }
+    /// Moves the cursor of the reader that last ran at `last_run`.
+    pub(crate) fn move_cursor(&self, last_run: u64, current: u64, workload_runs: u64) {
+        let mut cursors = self.cursors.lock();
+
+        if let Some(cursor) = cursors
+            .iter_mut()
+            .find(|cursor| cursor.timestamp == last_run)
+        {
+            cursor.timestamp = current;
+            cursor.run = workload_runs;
+        } else if !cursors.iter().any(|cursor| cursor.timestamp == current) {
+            // First run of this reader, or the reader was forgotten.
+            // Multiple readers in the same system share the same current timestamp.
+            cursors.push(Cursor {
+                timestamp: current,
+                run: workload_runs,
+            });
+        }
+    }
+    /// Forgets stale readers and drops expired events.
+    pub(crate) fn maintain(&mut self, workload_runs: u64) {
+        let stale_reader_runs = self.stale_reader_runs;
+        let cursors = self.cursors.get_mut();
+        cursors.retain(|cursor| workload_runs.saturating_sub(cursor.run) <= stale_reader_runs);
+
+        match self.expiry {
+            EventExpiry::AllReaders => {
+                if let Some(oldest_cursor) = cursors.iter().map(|cursor| cursor.timestamp).min() {
+                    self.drop_events(|event| event.timestamp.get() <= oldest_cursor);
+                } else {
+                    self.drop_events(|event| is_expired(event, 1, workload_runs));
+                }
+            }
+            EventExpiry::Runs(runs) => {
+                self.drop_events(|event| is_expired(event, runs, workload_runs));
+            }
+        }
+    }
+    /// Drops the events for which `is_expired` returns `true` and remembers the most recent one.
+    fn drop_events(&mut self, mut is_expired: impl FnMut(&Event<T>) -> bool) {
+        let last_dropped = &mut self.last_dropped;
+
+        self.events.retain(|event| {
+            if !is_expired(event) {
+                return true;
+            }
+
+            match *last_dropped {
+                Some(last) if !last.is_older_than(event.timestamp) => {}
+                _ => *last_dropped = Some(event.timestamp),
+            }
+
+            false
+        });
+    }
+}
+
+pub(crate) fn is_expired<T>(event: &Event<T>, runs: u64, workload_runs: u64) -> bool {
+    workload_runs - event.run >= runs
+}
+
+impl<T: 'static> Storage for Events<T> {
+    #[inline]
+    fn clear(&mut self, _current: TrackingTimestamp) {
+        self.drop_events(|_| true);
+    }
+    fn memory_usage(&self) -> Option<StorageMemoryUsage> {
+        Some(StorageMemoryUsage {
+            storage_name: type_name::<Self>().into(),
+            allocated_memory_bytes: self.events.capacity() * size_of::<Event<T>>()
+                + self.cursors.lock().capacity() * size_of::<Cursor>()
+                + size_of::<Self>(),
+            used_memory_bytes: self.events.len() * size_of::<Event<T>>()
+                + self.cursors.lock().len() * size_of::<Cursor>()
+                + size_of::<Self>(),
+            component_count: self.events.len(),
+        })
+    }
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+}
@@ -1,6 +1,7 @@
 use crate::component::Component;
 use crate::entity_id::EntityId;
 use crate::error;
+use crate::include_disabled::IncludeDisabled;
 use crate::r#mut::Mut;
 use crate::sparse_set::SparseSet;
 use crate::tracking::Tracking;
@@ -39,7 +40,7 @@ impl<'a, T: Component> Get for &'a SparseSet<T> {
     type Out = &'a T;
 
     fn get(self, entity: EntityId) -> Result<Self::Out, error::MissingComponent> {
-        self.private_get(entity)
+        self.private_get_enabled(entity)
             .ok_or_else(|| error::MissingComponent {
                 id: entity,
                 name: type_name::<T>(),
@@ -53,7 +54,7 @@ impl<'a, 'b, T: Component, Track: Tracking> Get for &'b View<'a, T, Track> {
     #[inline]
     fn get(self, entity: EntityId) -> Result<Self::Out, error::MissingComponent> {
         (**self)
-            .private_get(entity)
+            .private_get_enabled(entity)
             .ok_or_else(|| error::MissingComponent {
                 id: entity,
                 name: type_name::<T>(),
@@ -67,7 +68,7 @@ impl<'a, 'b, T: Component, Track: Tracking> Get for &'b ViewMut<'a, T, Track> {
     #[inline]
     fn get(self, entity: EntityId) -> Result<Self::Out, error::MissingComponent> {
         (**self)
-            .private_get(entity)
+            .private_get_enabled(entity)
             .ok_or_else(|| error::MissingComponent {
                 id: entity,
                 name: type_name::<T>(),
@@ -81,7 +82,7 @@ impl<'a, 'b, T: Component, Track: Tracking> Get for &'b mut ViewMut<'a, T, Track
     #[inline]
     fn get(self, entity: EntityId) -> Result<Self::Out, error::MissingComponent> {
         let index = self
-            .index_of(entity)
+            .enabled_index_of(entity)
             .ok_or_else(|| error::MissingComponent {
                 id: entity,
                 name: type_name::<T>(),
@@ -103,6 +104,63 @@ impl<'a, 'b, T: Component, Track: Tracking> Get for &'b mut ViewMut<'a, T, Track
     }
 }
 
+impl<'a, 'b, T: Component, Track: Tracking> Get for IncludeDisabled<&'b View<'a, T, Track>> {
+    type Out = &'b T;
+
+    #[inline]
+    fn get(self, entity: EntityId) -> Result<Self::Out, error::MissingComponent> {
+        (**self.0)
+            .private_get(entity)
+            .ok_or_else(|| error::MissingComponent {
+                id: entity,
+                name: type_name::<T>(),
+            })
+    }
+}
+
+impl<'a, 'b, T: Component, Track: Tracking> Get for IncludeDisabled<&'b ViewMut<'a, T, Track>> {
+    type Out = &'b T;
+
+    #[inline]
+    fn get(self, entity: EntityId) -> Result<Self::Out, error::MissingComponent> {
+        (**self.0)
+            .private_get(entity)
+            .ok_or_else(|| error::MissingComponent {
+                id: entity,
+                name: type_name::<T>(),
+            })
+    }
+}
+
+impl<'a, 'b, T: Component, Track: Tracking> Get for IncludeDisabled<&'b mut ViewMut<'a, T, Track>> {
+    type Out = Mut<'b, T>;
+
+    #[inline]
+    fn get(self, entity: EntityId) -> Result<Self::Out, error::MissingComponent> {
+        let view = self.0;
+        let index = view
+            .index_of(entity)
+            .ok_or_else(|| error::MissingComponent {
+                id: entity,
+                name: type_name::<T>(),
+            })?;
+
+        let SparseSet {
+            data,
+            modification_data,
+            is_tracking_modification,
+            ..
+        } = view.sparse_set;
+
+        Ok(Mut {
+            flag: is_tracking_modification
+                .then(|| unsafe { modification_data.get_unchecked_mut(index) }),
+            current: view.current,
+            data: unsafe { data.get_unchecked_mut(index) },
+        })
+    }
+}
+
 macro_rules! impl_get_component {
     ($(($type: ident, $index: tt))+) => {
         impl<$($type: Get),+> Get for ($($type,)+) {
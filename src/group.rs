@@ -0,0 +1,440 @@
+//! Groups keep the components of entities owning multiple components packed at the front of their storages.
+//!
+//! An owning group sorts the storages it owns so that the entities owning all of the group's components
+//! sit at the same index at the beginning of each storage.\
+//! Iterating all the owned storages is then a simple walk over slices, without any lookup.
+//!
+//! A partial group also observes storages it doesn't own. Its entities have to own a component
+//! in the observed storages too, but these storages are not sorted and are accessed with a lookup.
+//!
+//! Groups are kept packed by all [`World`] and [`AllStorages`] operations.\
+//! Adding or removing components using views only marks the group as unpacked,
+//! iteration then goes back to the regular algorithm until the next [`World`] or [`AllStorages`] operation packs it again.
+//!
+//! ### Example
+//!
+//! ```
+//! use shipyard::{Component, IntoIter, View, World};
+//!
+//! #[derive(Component)]
+//! struct Position(f32);
+//!
+//! #[derive(Component)]
+//! struct Velocity(f32);
+//!
+//! let mut world = World::new();
+//!
+//! world.add_group::<(Position, Velocity)>();
+//!
+//! world.add_entity((Position(0.0),));
+//! world.add_entity((Position(1.0), Velocity(2.0)));
+//!
+//! world.run(|positions: View<Position>, velocities: View<Velocity>| {
+//!     // iterates the first component of each storage, without checking the other entity
+//!     assert_eq!((&positions, &velocities).iter().count(), 1);
+//! });
+//! ```
+//!
+//! [`World`]: crate::World
+
+use crate::all_storages::AllStorages;
+use crate::component::Component;
+use crate::entity_id::EntityId;
+use crate::sparse_set::SparseSet;
+use crate::storage::{SBox, Storage, StorageId};
+use crate::ShipHashMap;
+use alloc::vec::Vec;
+
+/// Information about the group a storage is part of.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct GroupMember {
+    pub(crate) group: usize,
+    pub(crate) is_owned: bool,
+    /// Number of entities in the group.
+    pub(crate) len: usize,
+    pub(crate) owned_count: usize,
+    pub(crate) observed_count: usize,
+    pub(crate) state: GroupState,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum GroupState {
+    /// All entities of the group are at the front of the owned storages.
+    Packed,
+    /// Only this entity might be missing from the group or be in it when it shouldn't.
+    Pending(EntityId),
+    /// The group has to be rebuilt.
+    Unpacked,
+}
+
+impl GroupMember {
+    /// Records that `entity`'s component at `index` was added, removed, disabled or enabled.
+    #[inline]
+    pub(crate) fn pending(&mut self, entity: EntityId, index: usize) {
+        let is_outside = !self.is_owned || index >= self.len;
+
+        self.state = match self.state {
+            GroupState::Packed if is_outside => GroupState::Pending(entity),
+            GroupState::Pending(pending) if pending == entity && is_outside => {
+                GroupState::Pending(entity)
+            }
+            _ => GroupState::Unpacked,
+        };
+    }
+    /// Records that the storage was reordered.
+    #[inline]
+    pub(crate) fn unpack(&mut self) {
+        self.state = GroupState::Unpacked;
+    }
+}
+
+/// Trait used as bound for [`World::add_group`] and [`AllStorages::add_group`].
+///
+/// [`World::add_group`]: crate::World::add_group()
+pub trait TupleGroup {
+    /// Returns the storages of these components, creating them if needed.
+    fn storage_ids(all_storages: &mut AllStorages) -> Vec<StorageId>;
+}
+
+impl TupleGroup for () {
+    #[inline]
+    fn storage_ids(_: &mut AllStorages) -> Vec<StorageId> {
+        Vec::new()
+    }
+}
+
+impl<T: Component + Send + Sync> TupleGroup for T {
+    #[inline]
+    fn storage_ids(all_storages: &mut AllStorages) -> Vec<StorageId> {
+        all_storages
+            .exclusive_storage_or_insert_mut(StorageId::of::<SparseSet<T>>(), SparseSet::<T>::new);
+
+        alloc::vec![StorageId::of::<SparseSet<T>>()]
+    }
+}
+
+macro_rules! impl_tuple_group {
+    ($(($type: ident, $index: tt))+) => {
+        impl<$($type: TupleGroup),+> TupleGroup for ($($type,)+) {
+            #[inline]
+            fn storage_ids(all_storages: &mut AllStorages) -> Vec<StorageId> {
+                let mut storage_ids = Vec::new();
+
+                $(
+                    storage_ids.extend($type::storage_ids(all_storages));
+                )+
+
+                storage_ids
+            }
+        }
+    }
+}
+
+macro_rules! tuple_group {
+    ($(($type: ident, $index: tt))+; ($type1: ident, $index1: tt) $(($queue_type: ident, $queue_index: tt))*) => {
+        impl_tuple_group![$(($type, $index))*];
+        tuple_group![$(($type, $index))* ($type1, $index1); $(($queue_type, $queue_index))*];
+    };
+    ($(($type: ident, $index: tt))+;) => {
+        impl_tuple_group![$(($type, $index))*];
+    }
+}
+
+#[cfg(not(feature = "extended_tuple"))]
+tuple_group![(A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)];
+#[cfg(feature = "extended_tuple")]
+tuple_group![
+    (A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)
+    (K, 10) (L, 11) (M, 12) (N, 13) (O, 14) (P, 15) (Q, 16) (R, 17) (S, 18) (T, 19)
+    (U, 20) (V, 21) (W, 22) (X, 23) (Y, 24) (Z, 25) (AA, 26) (BB, 27) (CC, 28) (DD, 29)
+    (EE, 30) (FF, 31)
+];
+
+/// Storages owned and observed by a group.
+pub(crate) struct Group {
+    pub(crate) owned: Vec<StorageId>,
+    pub(crate) observed: Vec<StorageId>,
+}
+
+type Storages = ShipHashMap<StorageId, SBox>;
+
+fn storage(storages: &mut Storages, storage_id: StorageId) -> &mut dyn Storage {
+    unsafe { &mut *storages.get_mut(&storage_id).unwrap().0 }.get_mut()
+}
+
+fn member(storages: &mut Storages, storage_id: StorageId) -> &mut GroupMember {
+    storage(storages, storage_id)
+        .group_member_mut()
+        .and_then(Option::as_mut)
+        .unwrap()
+}
+
+/// Returns the index of `entity`'s component if it is not disabled.
+fn enabled_index_of(storage: &dyn Storage, entity: EntityId) -> Option<usize> {
+    let sparse_entity = storage.sparse_array()?.get(entity)?;
+
+    (sparse_entity.gen() == entity.gen() && sparse_entity.uindex() < storage.dense()?.len())
+        .then_some(sparse_entity.uindex())
+}
+
+impl Group {
+    fn members(&self) -> impl Iterator<Item = StorageId> + '_ {
+        self.owned.iter().chain(&self.observed).copied()
+    }
+    /// Returns the state of the group as a whole.
+    fn state(&self, storages: &mut Storages) -> GroupState {
+        self.members()
+            .fold(GroupState::Packed, |state, storage_id| {
+                match (state, member(storages, storage_id).state) {
+                    (state, GroupState::Packed) | (GroupState::Packed, state) => state,
+                    (GroupState::Pending(a), GroupState::Pending(b)) if a == b => state,
+                    _ => GroupState::Unpacked,
+                }
+            })
+    }
+    fn set_state(&self, storages: &mut Storages, state: GroupState) {
+        for storage_id in self.members() {
+            member(storages, storage_id).state = state;
+        }
+    }
+    fn len(&self, storages: &mut Storages) -> usize {
+        member(storages, self.owned[0]).len
+    }
+    fn set_len(&self, storages: &mut Storages, len: usize) {
+        for &storage_id in &self.owned {
+            member(storages, storage_id).len = len;
+        }
+    }
+    /// Returns `true` if `entity` is at the front of the owned storages.
+    fn contains(&self, storages: &mut Storages, entity: EntityId) -> bool {
+        let len = self.len(storages);
+
+        enabled_index_of(storage(storages, self.owned[0]), entity).is_some_and(|index| index < len)
+    }
+    /// Returns `true` if `entity` owns an enabled component in all storages of the group.
+    fn matches(&self, storages: &mut Storages, entity: EntityId) -> bool {
+        self.members()
+            .all(|storage_id| enabled_index_of(storage(storages, storage_id), entity).is_some())
+    }
+    /// Swaps `entity`'s components with the ones at `index` in all owned storages.
+    fn swap_with(&self, storages: &mut Storages, entity: EntityId, index: usize) {
+        for &storage_id in &self.owned {
+            let storage = storage(storages, storage_id);
+            let entity_index = enabled_index_of(storage, entity).unwrap();
+
+            storage.group_swap(entity_index, index);
+        }
+    }
+    /// Adds `entity` to the group or removes it based on the components it owns.
+    fn update(&self, storages: &mut Storages, entity: EntityId) {
+        let len = self.len(storages);
+
+        match (
+            self.contains(storages, entity),
+            self.matches(storages, entity),
+        ) {
+            (false, true) => {
+                self.swap_with(storages, entity, len);
+                self.set_len(storages, len + 1);
+            }
+            (true, false) => self.leave(storages, entity),
+            _ => {}
+        }
+    }
+    /// Moves `entity` out of the group.
+    fn leave(&self, storages: &mut Storages, entity: EntityId) {
+        let len = self.len(storages);
+
+        self.swap_with(storages, entity, len - 1);
+        self.set_len(storages, len - 1);
+    }
+    /// Sorts all owned storages from scratch.
+    fn pack(&self, storages: &mut Storages) {
+        self.set_len(storages, 0);
+
+        let mut index = 0;
+        while let Some(&entity) = storage(storages, self.owned[0])
+            .dense()
+            .and_then(|dense| dense.get(index))
+        {
+            if self.matches(storages, entity) {
+                let len = self.len(storages);
+
+                self.swap_with(storages, entity, len);
+                self.set_len(storages, len + 1);
+            }
+
+            index += 1;
+        }
+
+        self.set_state(storages, GroupState::Packed);
+    }
+    /// Packs the group if it isn't.
+    fn repair(&self, storages: &mut Storages) {
+        match self.state(storages) {
+            GroupState::Packed => {}
+            GroupState::Pending(entity) => {
+                self.update(storages, entity);
+                self.set_state(storages, GroupState::Packed);
+            }
+            GroupState::Unpacked => self.pack(storages),
+        }
+    }
+}
+
+impl AllStorages {
+    /// Creates an owning group over the storages of `Owned`.\
+    /// The components of the entities owning all of them will be kept at the front of each storage,
+    /// making iteration over all these storages as fast as iterating a single one.
+    ///
+    /// ### Panics
+    ///
+    /// - One of the storages is already part of a group.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Component, IntoIter, View, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// #[derive(Component)]
+    /// struct USIZE(usize);
+    ///
+    /// let world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// all_storages.add_group::<(U32, USIZE)>();
+    ///
+    /// all_storages.add_entity((U32(0), USIZE(1)));
+    ///
+    /// let (u32s, usizes) = all_storages.borrow::<(View<U32>, View<USIZE>)>().unwrap();
+    /// assert_eq!((&u32s, &usizes).iter().count(), 1);
+    /// ```
+    #[track_caller]
+    pub fn add_group<Owned: TupleGroup>(&mut self) {
+        self.add_partial_group::<Owned, ()>();
+    }
+    /// Creates a group owning the storages of `Owned` and observing the ones of `Observed`.\
+    /// Entities in the group own a component in all these storages but only the owned storages are sorted.
+    ///
+    /// ### Panics
+    ///
+    /// - `Owned` is empty.
+    /// - One of the storages is already part of a group.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Component, IntoIter, View, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// #[derive(Component)]
+    /// struct USIZE(usize);
+    ///
+    /// #[derive(Component)]
+    /// struct Name(&'static str);
+    ///
+    /// let world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// all_storages.add_partial_group::<(U32, USIZE), Name>();
+    ///
+    /// all_storages.add_entity((U32(0), USIZE(1), Name("Ferris")));
+    ///
+    /// let (u32s, usizes, names) = all_storages
+    ///     .borrow::<(View<U32>, View<USIZE>, View<Name>)>()
+    ///     .unwrap();
+    /// assert_eq!((&u32s, &usizes, &names).iter().count(), 1);
+    /// ```
+    #[track_caller]
+    pub fn add_partial_group<Owned: TupleGroup, Observed: TupleGroup>(&mut self) {
+        let owned = Owned::storage_ids(self);
+        let observed = Observed::storage_ids(self);
+
+        if owned.is_empty() {
+            panic!("A group has to own at least one storage.");
+        }
+
+        let group = Group { owned, observed };
+        let index = self.groups.len();
+        let storages = self.storages.get_mut();
+
+        for (i, storage_id) in group.members().enumerate() {
+            let storage = storage(storages, storage_id);
+            let name = storage.name();
+            let slot = storage.group_member_mut().unwrap();
+
+            if slot.is_some() {
+                panic!("Storage {} is already part of a group.", name);
+            }
+
+            *slot = Some(GroupMember {
+                group: index,
+                is_owned: i < group.owned.len(),
+                len: 0,
+                owned_count: group.owned.len(),
+                observed_count: group.observed.len(),
+                state: GroupState::Unpacked,
+            });
+        }
+
+        group.pack(storages);
+        self.groups.push(group);
+    }
+    /// Packs all groups, called before a structural change.
+    pub(crate) fn pack_groups(&mut self) {
+        let AllStorages {
+            storages, groups, ..
+        } = self;
+
+        for group in groups.iter() {
+            group.repair(storages.get_mut());
+        }
+    }
+    /// Packs all groups then moves `entity` out of all of them, called before removing some of its components.
+    pub(crate) fn ungroup_entity(&mut self, entity: EntityId) {
+        let AllStorages {
+            storages, groups, ..
+        } = self;
+
+        for group in groups.iter() {
+            let storages = storages.get_mut();
+
+            group.repair(storages);
+            if group.contains(storages, entity) {
+                group.leave(storages, entity);
+            }
+        }
+    }
+    /// Adds `entities` to the groups they are part of, called after a structural change.
+    ///
+    /// All groups have to be packed before the change.
+    pub(crate) fn group_entities(&mut self, entities: &[EntityId]) {
+        let AllStorages {
+            storages, groups, ..
+        } = self;
+
+        for group in groups.iter() {
+            let storages = storages.get_mut();
+
+            match group.state(storages) {
+                GroupState::Packed => {}
+                GroupState::Pending(entity) if entities.contains(&entity) => {}
+                _ => {
+                    group.pack(storages);
+                    continue;
+                }
+            }
+
+            for &entity in entities {
+                group.update(storages, entity);
+            }
+
+            group.set_state(storages, GroupState::Packed);
+        }
+    }
+}
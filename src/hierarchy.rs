@@ -0,0 +1,351 @@
+//! Parent/child relationship between entities.
+//!
+//! Each hierarchy is a storage, accessed with [`HierarchyView`] and [`HierarchyViewMut`].\
+//! Multiple independent hierarchies can exist, they are identified by a marker type.
+//!
+//! When an entity is deleted or stripped, it is removed from all hierarchies.
+//! Its children are either deleted recursively or orphaned based on the hierarchy's [`DeletionPolicy`].
+//!
+//! ### Example
+//!
+//! ```
+//! use shipyard::hierarchy::DeletionPolicy;
+//! use shipyard::{HierarchyViewMut, World};
+//!
+//! let mut world = World::new();
+//!
+//! let root = world.add_entity(());
+//! let child = world.add_entity(());
+//! let grandchild = world.add_entity(());
+//!
+//! world.run(|mut hierarchy: HierarchyViewMut| {
+//!     hierarchy.set_deletion_policy(DeletionPolicy::Cascade);
+//!
+//!     hierarchy.attach(child, root);
+//!     hierarchy.attach(grandchild, child);
+//!
+//!     assert_eq!(hierarchy.ancestors(grandchild).collect::<Vec<_>>(), [child, root]);
+//! });
+//!
+//! world.delete_entity(root);
+//!
+//! assert!(!world.is_entity_alive(child));
+//! assert!(!world.is_entity_alive(grandchild));
+//! ```
+//!
+//! [`HierarchyView`]: crate::views::HierarchyView
+//! [`HierarchyViewMut`]: crate::views::HierarchyViewMut
+
+use crate::entity_id::EntityId;
+use crate::memory_usage::StorageMemoryUsage;
+use crate::storage::Storage;
+use crate::tracking::TrackingTimestamp;
+use crate::ShipHashMap;
+use alloc::vec::Vec;
+use core::any::type_name;
+use core::marker::PhantomData;
+use core::mem::size_of;
+
+/// What happens to the children of an entity when it is deleted or stripped.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum DeletionPolicy {
+    /// Children become roots.
+    #[default]
+    Orphan,
+    /// Children are deleted, recursively.
+    Cascade,
+}
+
+#[derive(Clone, Copy)]
+struct Node {
+    parent: Option<EntityId>,
+    first_child: Option<EntityId>,
+    num_children: usize,
+    // Siblings form a circular linked list
+    prev: EntityId,
+    next: EntityId,
+}
+
+impl Node {
+    fn new(entity: EntityId) -> Node {
+        Node {
+            parent: None,
+            first_child: None,
+            num_children: 0,
+            prev: entity,
+            next: entity,
+        }
+    }
+}
+
+/// Parent/child storage.
+///
+/// `H` is a marker type, it makes it possible to have multiple independent hierarchies.
+pub struct Hierarchy<H: 'static = ()> {
+    nodes: ShipHashMap<EntityId, Node>,
+    deletion_policy: DeletionPolicy,
+    _phantom: PhantomData<fn() -> H>,
+}
+
+impl<H: 'static> Default for Hierarchy<H> {
+    fn default() -> Self {
+        Hierarchy::new(DeletionPolicy::default())
+    }
+}
+
+impl<H: 'static> Hierarchy<H> {
+    /// Creates an empty hierarchy with the given [`DeletionPolicy`].
+    pub fn new(deletion_policy: DeletionPolicy) -> Hierarchy<H> {
+        Hierarchy {
+            nodes: ShipHashMap::default(),
+            deletion_policy,
+            _phantom: PhantomData,
+        }
+    }
+    /// Returns the [`DeletionPolicy`] of this hierarchy.
+    #[inline]
+    pub fn deletion_policy(&self) -> DeletionPolicy {
+        self.deletion_policy
+    }
+    /// Sets what happens to the children of an entity when it is deleted or stripped.
+    #[inline]
+    pub fn set_deletion_policy(&mut self, deletion_policy: DeletionPolicy) {
+        self.deletion_policy = deletion_policy;
+    }
+    /// Attaches `child` to `parent`, as its last child.\
+    /// If `child` already had a parent, it is detached first.
+    ///
+    /// This method doesn't know which entities are alive, [`HierarchyViewMut::attach`] checks it.
+    ///
+    /// ### Panics
+    ///
+    /// - `child` is `parent` or one of its ancestors.
+    ///
+    /// [`HierarchyViewMut::attach`]: crate::views::HierarchyViewMut::attach()
+    #[track_caller]
+    pub fn attach(&mut self, child: EntityId, parent: EntityId) {
+        if child == parent || self.ancestors(parent).any(|ancestor| ancestor == child) {
+            panic!(
+                "Cannot attach {:?} to {:?}, it would create a cycle.",
+                child, parent
+            );
+        }
+
+        self.detach(child);
+
+        let parent_node = self
+            .nodes
+            .entry(parent)
+            .or_insert_with(|| Node::new(parent));
+        parent_node.num_children += 1;
+
+        let (prev, next) = if let Some(first_child) = parent_node.first_child {
+            let last_child = self.nodes[&first_child].prev;
+
+            self.node_mut(last_child).next = child;
+            self.node_mut(first_child).prev = child;
+
+            (last_child, first_child)
+        } else {
+            parent_node.first_child = Some(child);
+
+            (child, child)
+        };
+
+        let child_node = self.nodes.entry(child).or_insert_with(|| Node::new(child));
+        child_node.parent = Some(parent);
+        child_node.prev = prev;
+        child_node.next = next;
+    }
+    /// Removes `child` from its parent's children.\
+    /// Returns the former parent.
+    pub fn detach(&mut self, child: EntityId) -> Option<EntityId> {
+        let node = *self.nodes.get(&child)?;
+        let parent = node.parent?;
+
+        let parent_node = self.node_mut(parent);
+        parent_node.num_children -= 1;
+
+        if parent_node.num_children == 0 {
+            parent_node.first_child = None;
+        } else {
+            if parent_node.first_child == Some(child) {
+                parent_node.first_child = Some(node.next);
+            }
+
+            self.node_mut(node.prev).next = node.next;
+            self.node_mut(node.next).prev = node.prev;
+        }
+
+        let child_node = self.node_mut(child);
+        child_node.parent = None;
+        child_node.prev = child;
+        child_node.next = child;
+
+        self.remove_if_unused(parent);
+        self.remove_if_unused(child);
+
+        Some(parent)
+    }
+    /// Returns the parent of `entity`.
+    #[inline]
+    pub fn parent(&self, entity: EntityId) -> Option<EntityId> {
+        self.nodes.get(&entity).and_then(|node| node.parent)
+    }
+    /// Returns `true` if `entity` has a parent or children in this hierarchy.
+    #[inline]
+    pub fn contains(&self, entity: EntityId) -> bool {
+        self.nodes.contains_key(&entity)
+    }
+    /// Returns the number of children of `entity`.
+    #[inline]
+    pub fn num_children(&self, entity: EntityId) -> usize {
+        self.nodes.get(&entity).map_or(0, |node| node.num_children)
+    }
+    /// Returns an iterator over the direct children of `entity`, in attachment order.
+    pub fn children(&self, entity: EntityId) -> ChildrenIter<'_, H> {
+        let node = self.nodes.get(&entity);
+
+        ChildrenIter {
+            hierarchy: self,
+            current: node.and_then(|node| node.first_child),
+            remaining: node.map_or(0, |node| node.num_children),
+        }
+    }
+    /// Returns an iterator going from the parent of `entity` up to its root.
+    pub fn ancestors(&self, entity: EntityId) -> AncestorsIter<'_, H> {
+        AncestorsIter {
+            hierarchy: self,
+            current: self.parent(entity),
+        }
+    }
+    /// Returns a depth-first iterator over all the descendants of `entity`.
+    ///
+    /// A parent is always yielded before its children.
+    pub fn descendants(&self, entity: EntityId) -> DescendantsIter<'_, H> {
+        let mut stack: Vec<EntityId> = self.children(entity).collect();
+        stack.reverse();
+
+        DescendantsIter {
+            hierarchy: self,
+            stack,
+        }
+    }
+    /// Removes `entity` from the hierarchy, its children become roots.
+    fn remove_entity(&mut self, entity: EntityId) {
+        self.detach(entity);
+
+        let children: Vec<EntityId> = self.children(entity).collect();
+        for child in children {
+            self.detach(child);
+        }
+    }
+    fn remove_if_unused(&mut self, entity: EntityId) {
+        if let Some(node) = self.nodes.get(&entity) {
+            if node.parent.is_none() && node.num_children == 0 {
+                self.nodes.remove(&entity);
+            }
+        }
+    }
+    fn node_mut(&mut self, entity: EntityId) -> &mut Node {
+        self.nodes.get_mut(&entity).unwrap()
+    }
+}
+
+impl<H: 'static> Storage for Hierarchy<H> {
+    #[inline]
+    fn delete(&mut self, entity: EntityId, _current: TrackingTimestamp) {
+        self.remove_entity(entity);
+    }
+    #[inline]
+    fn dependents(&self, entity: EntityId, dependents: &mut Vec<EntityId>) {
+        if self.deletion_policy == DeletionPolicy::Cascade {
+            dependents.extend(self.children(entity));
+        }
+    }
+    #[inline]
+    fn clear(&mut self, _current: TrackingTimestamp) {
+        self.nodes.clear();
+    }
+    fn memory_usage(&self) -> Option<StorageMemoryUsage> {
+        Some(StorageMemoryUsage {
+            storage_name: type_name::<Self>().into(),
+            allocated_memory_bytes: self.nodes.capacity()
+                * (size_of::<EntityId>() + size_of::<Node>())
+                + size_of::<Self>(),
+            used_memory_bytes: self.nodes.len() * (size_of::<EntityId>() + size_of::<Node>())
+                + size_of::<Self>(),
+            component_count: self.nodes.len(),
+        })
+    }
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+}
+
+/// Iterator over the direct children of an entity.
+pub struct ChildrenIter<'a, H: 'static> {
+    hierarchy: &'a Hierarchy<H>,
+    current: Option<EntityId>,
+    remaining: usize,
+}
+
+impl<H: 'static> Iterator for ChildrenIter<'_, H> {
+    type Item = EntityId;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let current = self.current?;
+        self.remaining -= 1;
+        self.current = Some(self.hierarchy.nodes[&current].next);
+
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<H: 'static> ExactSizeIterator for ChildrenIter<'_, H> {}
+
+/// Iterator from the parent of an entity up to its root.
+pub struct AncestorsIter<'a, H: 'static> {
+    hierarchy: &'a Hierarchy<H>,
+    current: Option<EntityId>,
+}
+
+impl<H: 'static> Iterator for AncestorsIter<'_, H> {
+    type Item = EntityId;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.current?;
+        self.current = self.hierarchy.parent(current);
+
+        Some(current)
+    }
+}
+
+/// Depth-first iterator over all the descendants of an entity.
+pub struct DescendantsIter<'a, H: 'static> {
+    hierarchy: &'a Hierarchy<H>,
+    stack: Vec<EntityId>,
+}
+
+impl<H: 'static> Iterator for DescendantsIter<'_, H> {
+    type Item = EntityId;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.stack.pop()?;
+
+        let len = self.stack.len();
+        self.stack.extend(self.hierarchy.children(current));
+        self.stack[len..].reverse();
+
+        Some(current)
+    }
+}
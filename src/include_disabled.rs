@@ -0,0 +1,50 @@
+use crate::component::Component;
+use crate::views::{View, ViewMut};
+
+/// Includes the components of disabled entities.
+///
+/// Iterators, [`Get`](crate::Get) and [`Contains`](crate::Contains) skip disabled entities by default.
+///
+/// ### Example:
+///
+/// ```
+/// use shipyard::{Component, Get, IntoIter, View, World};
+///
+/// #[derive(Component, PartialEq, Eq, Debug)]
+/// struct A(u32);
+///
+/// let mut world = World::new();
+///
+/// world.add_entity((A(0),));
+/// let entity = world.add_entity((A(1),));
+/// world.disable_entity(entity);
+///
+/// let a = world.borrow::<View<A>>().unwrap();
+///
+/// assert_eq!(a.iter().count(), 1);
+/// assert!(a.get(entity).is_err());
+///
+/// assert_eq!(a.include_disabled().iter().count(), 2);
+/// assert_eq!(a.include_disabled().get(entity), Ok(&A(1)));
+/// ```
+#[derive(Copy, Clone)]
+pub struct IncludeDisabled<T>(pub(crate) T);
+
+impl<'v, T: Component, Track> View<'v, T, Track> {
+    /// Wraps this view to also access the components of disabled entities, see [`IncludeDisabled`].
+    pub fn include_disabled(&self) -> IncludeDisabled<&'_ View<'v, T, Track>> {
+        IncludeDisabled(self)
+    }
+}
+
+impl<'v, T: Component, Track> ViewMut<'v, T, Track> {
+    /// Wraps this view to also access the components of disabled entities, see [`IncludeDisabled`].
+    pub fn include_disabled(&self) -> IncludeDisabled<&'_ ViewMut<'v, T, Track>> {
+        IncludeDisabled(self)
+    }
+
+    /// Wraps this view to also modify the components of disabled entities, see [`IncludeDisabled`].
+    pub fn include_disabled_mut(&mut self) -> IncludeDisabled<&'_ mut ViewMut<'v, T, Track>> {
+        IncludeDisabled(self)
+    }
+}
@@ -0,0 +1,314 @@
+//! Indexes map a key computed from a component to the entities owning a component with this key.
+//!
+//! An index is added to a storage with [`World::add_index`] or [`AllStorages::add_index`]
+//! and is then accessed from the storage's views with [`SparseSet::component_index`].
+//!
+//! Insertions, removals and deletions update the index immediately.\
+//! Components modified in place, through a [`ViewMut`], [`World::get`] or [`World::iter`], are re-keyed
+//! when the borrow ends.\
+//! When the component tracks modification, only the components modified since the last re-key are looked at.
+//! Otherwise all the components of the storage are walked at the end of each exclusive borrow,
+//! add `#[track(Modification)]` to components indexed in storages modified often.
+//!
+//! Disabled entities are not part of the index, they are indexed again when enabled.
+//!
+//! ### Example
+//!
+//! ```
+//! use shipyard::{Component, IntoIter, View, ViewMut, World};
+//!
+//! #[derive(Component, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+//! struct GridCell(i32, i32);
+//!
+//! let mut world = World::new();
+//!
+//! world.add_index(|cell: &GridCell| *cell);
+//!
+//! let entity = world.add_entity((GridCell(0, 0),));
+//!
+//! world.run(|mut cells: ViewMut<GridCell>| {
+//!     for cell in (&mut cells).iter() {
+//!         cell.0 += 1;
+//!     }
+//! });
+//!
+//! world.run(|cells: View<GridCell>| {
+//!     let index = cells.component_index::<GridCell>().unwrap();
+//!
+//!     assert!(index.get(&GridCell(0, 0)).is_empty());
+//!     assert_eq!(index.get(&GridCell(1, 0)), [entity]);
+//! });
+//! ```
+//!
+//! [`World::add_index`]: crate::World::add_index
+//! [`World::get`]: crate::World::get
+//! [`World::iter`]: crate::World::iter
+//! [`AllStorages::add_index`]: crate::all_storages::AllStorages::add_index
+//! [`SparseSet::component_index`]: crate::sparse_set::SparseSet::component_index
+//! [`ViewMut`]: crate::ViewMut
+
+use crate::all_storages::AllStorages;
+use crate::component::Component;
+use crate::entity_id::EntityId;
+use crate::sparse_set::SparseSet;
+use crate::storage::StorageId;
+use crate::tracking::TrackingTimestamp;
+use crate::ShipHashMap;
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use core::any::Any;
+use core::borrow::Borrow;
+use core::ops::RangeBounds;
+use core::ptr::NonNull;
+
+/// Maps the key computed from each component of `T` to the entities owning a component with this key.
+pub struct ComponentIndex<T, K> {
+    key: fn(&T) -> K,
+    entities: BTreeMap<K, Vec<EntityId>>,
+    keys: ShipHashMap<EntityId, K>,
+    /// Most recent modification re-keyed.
+    last_sync: TrackingTimestamp,
+}
+
+impl<T, K: Ord + Clone> ComponentIndex<T, K> {
+    pub(crate) fn new(key: fn(&T) -> K) -> Self {
+        ComponentIndex {
+            key,
+            entities: BTreeMap::new(),
+            keys: ShipHashMap::default(),
+            last_sync: TrackingTimestamp::origin(),
+        }
+    }
+
+    /// Returns the entities owning a component with `key`.
+    #[inline]
+    pub fn get<Q: Ord + ?Sized>(&self, key: &Q) -> &[EntityId]
+    where
+        K: Borrow<Q>,
+    {
+        self.entities.get(key).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns the key of `entity`'s component.
+    #[inline]
+    pub fn key_of(&self, entity: EntityId) -> Option<&K> {
+        self.keys.get(&entity)
+    }
+
+    /// Iterates the keys in `range` in ascending order, with the entities owning a component with this key.
+    #[inline]
+    pub fn range<R: RangeBounds<K>>(
+        &self,
+        range: R,
+    ) -> impl Iterator<Item = (&K, &[EntityId])> + '_ {
+        self.entities
+            .range(range)
+            .map(|(key, entities)| (key, entities.as_slice()))
+    }
+
+    /// Iterates all keys in ascending order, with the entities owning a component with this key.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &[EntityId])> + '_ {
+        self.range(..)
+    }
+
+    /// Returns the number of distinct keys.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entities.len()
+    }
+
+    /// Returns `true` if no component is indexed.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entities.is_empty()
+    }
+
+    fn insert_key(&mut self, entity: EntityId, key: K) {
+        self.remove_entity(entity);
+
+        self.entities.entry(key.clone()).or_default().push(entity);
+        self.keys.insert(entity, key);
+    }
+
+    fn remove_entity(&mut self, entity: EntityId) {
+        if let Some(key) = self.keys.remove(&entity) {
+            if let Some(entities) = self.entities.get_mut(&key) {
+                if let Some(position) = entities.iter().position(|&other| other == entity) {
+                    entities.swap_remove(position);
+                }
+
+                if entities.is_empty() {
+                    self.entities.remove(&key);
+                }
+            }
+        }
+    }
+}
+
+impl AllStorages {
+    /// Indexes the components of `T` by the key returned by `key`.\
+    /// Replaces the previous index of this storage.
+    ///
+    /// See the [`index`](crate::index) module for more details.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Component, View, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Name(&'static str);
+    ///
+    /// let world = World::new();
+    ///
+    /// world.run(|mut all_storages: AllStoragesViewMut| {
+    ///     all_storages.add_index(|name: &Name| name.0);
+    ///
+    ///     let entity = all_storages.add_entity((Name("Alice"),));
+    ///
+    ///     let names = all_storages.borrow::<View<Name>>().unwrap();
+    ///     let index = names.component_index::<&str>().unwrap();
+    ///     assert_eq!(index.get("Alice"), [entity]);
+    /// });
+    /// ```
+    pub fn add_index<T: Component + Send + Sync, K: Ord + Clone + Send + Sync + 'static>(
+        &mut self,
+        key: fn(&T) -> K,
+    ) {
+        self.exclusive_storage_or_insert_mut(StorageId::of::<SparseSet<T>>(), SparseSet::<T>::new)
+            .add_index(key);
+    }
+}
+
+/// Type erased [`ComponentIndex`] stored inside [`SparseSet`].
+pub(crate) trait AnyComponentIndex<T>: Send + Sync {
+    fn insert(&mut self, entity: EntityId, component: &T);
+    fn remove(&mut self, entity: EntityId);
+    fn clear(&mut self);
+    /// Indexes all `data` from scratch.
+    fn rebuild(&mut self, dense: &[EntityId], data: &[T]);
+    /// Re-keys the components whose key changed.\
+    /// With `modification_data`, only the components modified since the last sync are looked at.
+    fn sync(
+        &mut self,
+        dense: &[EntityId],
+        data: &[T],
+        modification_data: Option<&[TrackingTimestamp]>,
+    );
+    fn as_any(&self) -> &dyn Any;
+}
+
+impl<T: 'static, K: Ord + Clone + Send + Sync + 'static> AnyComponentIndex<T>
+    for ComponentIndex<T, K>
+{
+    #[inline]
+    fn insert(&mut self, entity: EntityId, component: &T) {
+        let key = (self.key)(component);
+
+        self.insert_key(entity, key);
+    }
+
+    #[inline]
+    fn remove(&mut self, entity: EntityId) {
+        self.remove_entity(entity);
+    }
+
+    fn clear(&mut self) {
+        self.entities.clear();
+        self.keys.clear();
+    }
+
+    fn rebuild(&mut self, dense: &[EntityId], data: &[T]) {
+        self.clear();
+
+        for (&entity, component) in dense.iter().zip(data) {
+            self.insert(entity, component);
+        }
+    }
+
+    fn sync(
+        &mut self,
+        dense: &[EntityId],
+        data: &[T],
+        modification_data: Option<&[TrackingTimestamp]>,
+    ) {
+        let mut last_sync = self.last_sync;
+
+        for (i, (&entity, component)) in dense.iter().zip(data).enumerate() {
+            if let Some(modification_data) = modification_data {
+                let modification = modification_data[i];
+
+                if !self.last_sync.is_older_than(modification) {
+                    continue;
+                }
+
+                if last_sync.is_older_than(modification) {
+                    last_sync = modification;
+                }
+            }
+
+            let key = (self.key)(component);
+
+            if self.keys.get(&entity) != Some(&key) {
+                self.insert_key(entity, key);
+            }
+        }
+
+        self.last_sync = last_sync;
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Re-keys the index of a storage when dropped.
+///
+/// Held by the exclusive borrows of indexed storages, alongside their [`ExclusiveBorrow`].
+///
+/// [`ExclusiveBorrow`]: crate::atomic_refcell::ExclusiveBorrow
+pub struct IndexSync {
+    sparse_set: NonNull<()>,
+    sync: unsafe fn(NonNull<()>),
+}
+
+// IndexSync is only created from an exclusive borrow of the storage
+unsafe impl Send for IndexSync {}
+unsafe impl Sync for IndexSync {}
+
+impl IndexSync {
+    /// Returns a guard re-keying `sparse_set`'s index if it has one.\
+    /// The returned reference has to be used instead of `sparse_set` and must not outlive the guard.
+    #[inline]
+    pub(crate) fn new<T: Component>(
+        sparse_set: &mut SparseSet<T>,
+    ) -> (&mut SparseSet<T>, Option<IndexSync>) {
+        if sparse_set.index.is_none() {
+            return (sparse_set, None);
+        }
+
+        let ptr = NonNull::from(sparse_set);
+
+        (
+            // SAFE the reference is derived from the pointer so the guard can use it once the reference is dead
+            unsafe { &mut *ptr.as_ptr() },
+            Some(IndexSync {
+                sparse_set: ptr.cast(),
+                sync: sync_index::<T>,
+            }),
+        )
+    }
+}
+
+unsafe fn sync_index<T: Component>(sparse_set: NonNull<()>) {
+    (*sparse_set.cast::<SparseSet<T>>().as_ptr()).sync_index();
+}
+
+impl Drop for IndexSync {
+    #[inline]
+    fn drop(&mut self) {
+        // SAFE the storage is still exclusively borrowed, the borrow is released after this guard
+        unsafe { (self.sync)(self.sparse_set) }
+    }
+}
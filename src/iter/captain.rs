@@ -4,6 +4,7 @@ mod tracking;
 
 use crate::component::Component;
 use crate::entity_id::EntityId;
+use crate::group::GroupMember;
 use crate::iter::ShiperatorOutput;
 use crate::optional::Optional;
 use crate::r#mut::Mut;
@@ -31,6 +32,11 @@ pub trait ShiperatorCaptain: ShiperatorOutput {
     /// By default `into_shiperator` returns Shiperators that thinks they are captains.\
     /// This function is called on the ones that end up not being picked.
     fn unpick(&mut self);
+    /// Returns the group of the storage when it can be iterated as part of it.
+    #[inline]
+    fn group(&self) -> Option<GroupMember> {
+        None
+    }
 }
 
 impl<'tmp, T: Component> ShiperatorCaptain for FullRawWindow<'tmp, T> {
@@ -54,6 +60,11 @@ impl<'tmp, T: Component> ShiperatorCaptain for FullRawWindow<'tmp, T> {
 
     #[inline]
     fn unpick(&mut self) {}
+
+    #[inline]
+    fn group(&self) -> Option<GroupMember> {
+        self.group
+    }
 }
 
 macro_rules! impl_shiperator_captain_no_mut {
@@ -80,6 +91,11 @@ macro_rules! impl_shiperator_captain_no_mut {
 
                 #[inline]
                 fn unpick(&mut self) {}
+
+                #[inline]
+                fn group(&self) -> Option<GroupMember> {
+                    self.group
+                }
             }
         )+
     }
@@ -115,6 +131,11 @@ macro_rules! impl_shiperator_captain_mut {
 
                 #[inline]
                 fn unpick(&mut self) {}
+
+                #[inline]
+                fn group(&self) -> Option<GroupMember> {
+                    self.group
+                }
             }
         )+
     }
@@ -4,8 +4,10 @@ mod tracking;
 
 use crate::component::Component;
 use crate::entity_id::EntityId;
+use crate::group::{GroupMember, GroupState};
+use crate::include_disabled::IncludeDisabled;
 #[cfg(feature = "parallel")]
-use crate::iter::ParShiperator;
+use crate::iter::parallel::{fixed_chunk_size, ParShiperator};
 use crate::iter::{captain::ShiperatorCaptain, mixed::Mixed, Shiperator};
 use crate::optional::Optional;
 use crate::sparse_set::{FullRawWindow, FullRawWindowMut, RawEntityIdAccess};
@@ -92,7 +94,7 @@ where
     #[cfg(feature = "parallel")]
     #[inline]
     fn par_iter(self) -> ParShiperator<Self::Shiperator> {
-        ParShiperator(self.iter())
+        ParShiperator(self.iter(), fixed_chunk_size())
     }
 }
 
@@ -283,6 +285,96 @@ impl<'tmp, 'v: 'tmp, T: Component, Track: Tracking> IntoShiperator
     }
 }
 
+impl<'tmp, 'v: 'tmp, T: Component, Track: Tracking> IntoShiperator
+    for IncludeDisabled<&'tmp View<'v, T, Track>>
+{
+    type Shiperator = FullRawWindow<'tmp, T>;
+
+    #[inline]
+    fn into_shiperator(
+        self,
+        storage_ids: &mut ShipHashSet<StorageId>,
+    ) -> (Self::Shiperator, usize, RawEntityIdAccess) {
+        let len = self.0.len();
+        let (mut window, _, entities) = self.0.into_shiperator(storage_ids);
+        window.dense_len = len;
+        // disabled components are not part of groups
+        window.group = None;
+
+        (window, len, entities)
+    }
+
+    #[inline]
+    fn can_captain() -> bool {
+        true
+    }
+
+    #[inline]
+    fn can_sailor() -> bool {
+        true
+    }
+}
+
+impl<'tmp, 'v: 'tmp, T: Component, Track: Tracking> IntoShiperator
+    for IncludeDisabled<&'tmp ViewMut<'v, T, Track>>
+{
+    type Shiperator = FullRawWindow<'tmp, T>;
+
+    #[inline]
+    fn into_shiperator(
+        self,
+        storage_ids: &mut ShipHashSet<StorageId>,
+    ) -> (Self::Shiperator, usize, RawEntityIdAccess) {
+        let len = self.0.len();
+        let (mut window, _, entities) = self.0.into_shiperator(storage_ids);
+        window.dense_len = len;
+        // disabled components are not part of groups
+        window.group = None;
+
+        (window, len, entities)
+    }
+
+    #[inline]
+    fn can_captain() -> bool {
+        true
+    }
+
+    #[inline]
+    fn can_sailor() -> bool {
+        true
+    }
+}
+
+impl<'tmp, 'v: 'tmp, T: Component, Track> IntoShiperator
+    for IncludeDisabled<&'tmp mut ViewMut<'v, T, Track>>
+{
+    type Shiperator = FullRawWindowMut<'tmp, T, Track>;
+
+    #[inline]
+    fn into_shiperator(
+        self,
+        storage_ids: &mut ShipHashSet<StorageId>,
+    ) -> (Self::Shiperator, usize, RawEntityIdAccess) {
+        let len = self.0.len();
+        let (mut window, _, entities) = self.0.into_shiperator(storage_ids);
+        window.dense_len = len;
+        // disabled components are not part of groups
+        window.group = None;
+
+        (window, len, entities)
+    }
+
+    #[inline]
+    fn can_captain() -> bool {
+        true
+    }
+
+    #[inline]
+    fn can_sailor() -> bool {
+        true
+    }
+}
+
 impl<T: IntoShiperator> IntoShiperator for (T,) {
     type Shiperator = T::Shiperator;
 
@@ -302,6 +394,43 @@ impl<T: IntoShiperator> IntoShiperator for (T,) {
     }
 }
 
+/// Returns the mask of the storages owned by a packed group and the group's length.\
+/// All storages of the group have to be iterated.
+fn packed_group(members: &[Option<GroupMember>]) -> Option<(u32, usize)> {
+    members
+        .iter()
+        .flatten()
+        .filter(|member| member.is_owned)
+        .find_map(|owner| {
+            let mut mask = 0u32;
+            let mut observed_count = 0;
+
+            for (index, member) in members.iter().enumerate() {
+                let Some(member) = member.filter(|member| member.group == owner.group) else {
+                    continue;
+                };
+
+                if member.state != GroupState::Packed {
+                    return None;
+                }
+
+                if member.is_owned {
+                    if member.len != owner.len {
+                        return None;
+                    }
+
+                    mask |= 1 << index;
+                } else {
+                    observed_count += 1;
+                }
+            }
+
+            (mask.count_ones() as usize == owner.owned_count
+                && observed_count == owner.observed_count)
+                .then_some((mask, owner.len))
+        })
+}
+
 // It's not currently possible to have a final non repeating '+'
 // https://github.com/rust-lang/rust/issues/18700
 macro_rules! strip_plus {
@@ -399,6 +528,32 @@ macro_rules! impl_into_shiperator_tuple {
                     );
                 }
 
+                if let Some((mask, len)) = packed_group(&[$(shiperators.$index.0.group()),+]) {
+                    let first = mask.trailing_zeros();
+                    let mut entity_iter = RawEntityIdAccess::dangling();
+
+                    $(
+                        if first == $index {
+                            entity_iter = shiperators.$index.2;
+                        }
+                    )+
+
+                    $(
+                        if mask & (1 << $index) == 0 {
+                            shiperators.$index.0.unpick();
+                        }
+                    )+
+
+                    return (
+                        Mixed {
+                            shiperator: ($(shiperators.$index.0,)+),
+                            mask,
+                        },
+                        len,
+                        entity_iter,
+                    );
+                }
+
                 let mut mask = 0;
                 let mut len = 0;
                 let mut entity_iter = RawEntityIdAccess::dangling();
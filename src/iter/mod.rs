@@ -3,10 +3,11 @@ mod into_shiperator;
 mod mixed;
 mod output;
 #[cfg(feature = "parallel")]
-mod parallel;
+pub(crate) mod parallel;
 mod sailor;
 mod with_id;
 
+pub use crate::include_disabled::IncludeDisabled;
 pub use crate::iter_component::{IntoIterRef, IterComponent};
 pub use crate::not::Not;
 pub use crate::optional::Optional;
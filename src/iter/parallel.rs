@@ -1,7 +1,40 @@
 use crate::iter::{Shiperator, ShiperatorCaptain, ShiperatorSailor};
+use core::cell::Cell;
 
-#[allow(missing_docs)]
-pub struct ParShiperator<S>(pub(crate) Shiperator<S>);
+std::thread_local! {
+    /// Chunk size `par_iter` uses on this thread, set while a deterministic `World` runs systems.
+    static FIXED_CHUNK_SIZE: Cell<Option<usize>> = const { Cell::new(None) };
+}
+
+/// Makes `par_iter` use [`ParShiperator::fixed_chunks`] on this thread until dropped.
+pub(crate) struct FixedChunksScope {
+    previous: Option<usize>,
+}
+
+impl FixedChunksScope {
+    pub(crate) fn enter(chunk_size: usize) -> FixedChunksScope {
+        FixedChunksScope {
+            previous: FIXED_CHUNK_SIZE.with(|size| size.replace(Some(chunk_size.max(1)))),
+        }
+    }
+}
+
+impl Drop for FixedChunksScope {
+    fn drop(&mut self) {
+        FIXED_CHUNK_SIZE.with(|size| size.set(self.previous));
+    }
+}
+
+/// Returns the chunk size `par_iter` has to use on this thread, `None` if it can split freely.
+pub(crate) fn fixed_chunk_size() -> Option<usize> {
+    FIXED_CHUNK_SIZE.with(Cell::get)
+}
+
+/// Parallel iterator over components.
+///
+/// In a deterministic [`World`](crate::World), see [`World::set_deterministic`](crate::World::set_deterministic),
+/// it uses [`ParShiperator::fixed_chunks`] with the `World`'s [chunk size](crate::World::set_deterministic_chunk_size).
+pub struct ParShiperator<S>(pub(crate) Shiperator<S>, pub(crate) Option<usize>);
 
 impl<S: ShiperatorCaptain + ShiperatorSailor + Send + Clone>
     rayon::iter::plumbing::UnindexedProducer for Shiperator<S>
@@ -59,7 +92,10 @@ where
     where
         C: rayon::iter::plumbing::UnindexedConsumer<Self::Item>,
     {
-        rayon::iter::plumbing::bridge_unindexed(self.0, consumer)
+        match self.1 {
+            Some(chunk_size) => bridge_fixed_chunks(self.0, chunk_size, consumer),
+            None => rayon::iter::plumbing::bridge_unindexed(self.0, consumer),
+        }
     }
 
     #[inline]
@@ -71,3 +107,78 @@ where
         }
     }
 }
+
+impl<S: ShiperatorCaptain + ShiperatorSailor + Send + Clone> ParShiperator<S> {
+    /// Splits the iteration in chunks of at most `chunk_size` components.\
+    /// Chunk boundaries only depend on the iterated storages and `chunk_size`,
+    /// not on the number of threads or work stealing, and results are combined in iteration order.
+    ///
+    /// Use it when a reduction has to give bit-for-bit identical results across runs, like summing floats.\
+    /// Only rayon's order preserving adaptors, like `fold` followed by `reduce`, keep this guarantee.
+    ///
+    /// ### Example
+    /// ```
+    /// use rayon::prelude::ParallelIterator;
+    /// use shipyard::{Component, IntoIter, View, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Mass(f32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// world.bulk_add_entity((0..1000).map(|i| (Mass(i as f32 / 7.0),)));
+    ///
+    /// world.run(|masses: View<Mass>| {
+    ///     let total = masses
+    ///         .par_iter()
+    ///         .fixed_chunks(64)
+    ///         .fold(|| 0.0, |sum, mass| sum + mass.0)
+    ///         .reduce(|| 0.0, |left, right| left + right);
+    ///
+    ///     for _ in 0..10 {
+    ///         assert_eq!(
+    ///             masses
+    ///                 .par_iter()
+    ///                 .fixed_chunks(64)
+    ///                 .fold(|| 0.0, |sum, mass| sum + mass.0)
+    ///                 .reduce(|| 0.0, |left, right| left + right)
+    ///                 .to_bits(),
+    ///             total.to_bits()
+    ///         );
+    ///     }
+    /// });
+    /// ```
+    pub fn fixed_chunks(self, chunk_size: usize) -> ParShiperator<S> {
+        ParShiperator(self.0, Some(chunk_size.max(1)))
+    }
+}
+
+/// Like `bridge_unindexed` but only splits `shiperator` in halves until they fit in `chunk_size`.\
+/// Rayon's reducers combine the halves' results in iteration order.
+fn bridge_fixed_chunks<S, C>(shiperator: Shiperator<S>, chunk_size: usize, consumer: C) -> C::Result
+where
+    S: ShiperatorCaptain + ShiperatorSailor + Send + Clone,
+    C: rayon::iter::plumbing::UnindexedConsumer<S::Out>,
+{
+    use rayon::iter::plumbing::{Folder, Reducer, UnindexedProducer};
+
+    let max_len = shiperator.end - shiperator.start + shiperator.entities.follow_up_len();
+    if max_len <= chunk_size || consumer.full() {
+        return shiperator.fold_with(consumer.into_folder()).complete();
+    }
+
+    match shiperator.split() {
+        (left, Some(right)) => {
+            let left_consumer = consumer.split_off_left();
+            let reducer = consumer.to_reducer();
+
+            let (left, right) = rayon::join(
+                || bridge_fixed_chunks(left, chunk_size, left_consumer),
+                || bridge_fixed_chunks(right, chunk_size, consumer),
+            );
+
+            reducer.reduce(left, right)
+        }
+        (shiperator, None) => shiperator.fold_with(consumer.into_folder()).complete(),
+    }
+}
@@ -8,6 +8,7 @@ use crate::borrow::Borrow;
 #[cfg(feature = "thread_local")]
 use crate::borrow::{NonSend, NonSendSync, NonSync};
 use crate::component::Component;
+use crate::index::IndexSync;
 use crate::iter::{Mixed, ShiperatorCaptain};
 use crate::r#mut::Mut;
 use crate::sparse_set::{FullRawWindow, FullRawWindowMut, RawEntityIdAccess};
@@ -193,7 +194,7 @@ impl<T: Component> IterComponent for NonSendSync<&'_ T> {
 
 impl<T: Component + Send + Sync> IterComponent for &'_ mut T {
     type Shiperator<'a> = FullRawWindowMut<'a, T, T::Tracking>;
-    type Borrow<'a> = ExclusiveBorrow<'a>;
+    type Borrow<'a> = (Option<IndexSync>, ExclusiveBorrow<'a>);
 
     #[track_caller]
     fn into_shiperator<'a>(
@@ -232,7 +233,7 @@ impl<T: Component + Send + Sync> IterComponent for &'_ mut T {
 #[cfg(feature = "thread_local")]
 impl<T: Component + Sync> IterComponent for NonSend<&'_ mut T> {
     type Shiperator<'a> = FullRawWindowMut<'a, T, T::Tracking>;
-    type Borrow<'a> = ExclusiveBorrow<'a>;
+    type Borrow<'a> = (Option<IndexSync>, ExclusiveBorrow<'a>);
 
     fn into_shiperator<'a>(
         all_storages: &'a AllStorages,
@@ -270,7 +271,7 @@ impl<T: Component + Sync> IterComponent for NonSend<&'_ mut T> {
 #[cfg(feature = "thread_local")]
 impl<T: Component + Send> IterComponent for NonSync<&'_ mut T> {
     type Shiperator<'a> = FullRawWindowMut<'a, T, T::Tracking>;
-    type Borrow<'a> = ExclusiveBorrow<'a>;
+    type Borrow<'a> = (Option<IndexSync>, ExclusiveBorrow<'a>);
 
     fn into_shiperator<'a>(
         all_storages: &'a AllStorages,
@@ -308,7 +309,7 @@ impl<T: Component + Send> IterComponent for NonSync<&'_ mut T> {
 #[cfg(feature = "thread_local")]
 impl<T: Component> IterComponent for NonSendSync<&'_ mut T> {
     type Shiperator<'a> = FullRawWindowMut<'a, T, T::Tracking>;
-    type Borrow<'a> = ExclusiveBorrow<'a>;
+    type Borrow<'a> = (Option<IndexSync>, ExclusiveBorrow<'a>);
 
     fn into_shiperator<'a>(
         all_storages: &'a AllStorages,
@@ -345,7 +346,7 @@ impl<T: Component> IterComponent for NonSendSync<&'_ mut T> {
 
 impl<T: Component + Send + Sync> IterComponent for Mut<'_, T> {
     type Shiperator<'a> = FullRawWindowMut<'a, T, track::Modification>;
-    type Borrow<'a> = ExclusiveBorrow<'a>;
+    type Borrow<'a> = (Option<IndexSync>, ExclusiveBorrow<'a>);
 
     fn into_shiperator<'a>(
         all_storages: &'a AllStorages,
@@ -376,7 +377,7 @@ impl<T: Component + Send + Sync> IterComponent for Mut<'_, T> {
 #[cfg(feature = "thread_local")]
 impl<T: Component + Sync> IterComponent for NonSend<Mut<'_, T>> {
     type Shiperator<'a> = FullRawWindowMut<'a, T, track::Modification>;
-    type Borrow<'a> = ExclusiveBorrow<'a>;
+    type Borrow<'a> = (Option<IndexSync>, ExclusiveBorrow<'a>);
 
     fn into_shiperator<'a>(
         all_storages: &'a AllStorages,
@@ -411,7 +412,7 @@ impl<T: Component + Sync> IterComponent for NonSend<Mut<'_, T>> {
 #[cfg(feature = "thread_local")]
 impl<T: Component + Send> IterComponent for NonSync<Mut<'_, T>> {
     type Shiperator<'a> = FullRawWindowMut<'a, T, track::Modification>;
-    type Borrow<'a> = ExclusiveBorrow<'a>;
+    type Borrow<'a> = (Option<IndexSync>, ExclusiveBorrow<'a>);
 
     fn into_shiperator<'a>(
         all_storages: &'a AllStorages,
@@ -446,7 +447,7 @@ impl<T: Component + Send> IterComponent for NonSync<Mut<'_, T>> {
 #[cfg(feature = "thread_local")]
 impl<T: Component> IterComponent for NonSendSync<Mut<'_, T>> {
     type Shiperator<'a> = FullRawWindowMut<'a, T, track::Modification>;
-    type Borrow<'a> = ExclusiveBorrow<'a>;
+    type Borrow<'a> = (Option<IndexSync>, ExclusiveBorrow<'a>);
 
     fn into_shiperator<'a>(
         all_storages: &'a AllStorages,
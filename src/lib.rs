@@ -34,7 +34,7 @@
 #![warn(missing_docs)]
 #![no_std]
 
-#[cfg(feature = "std")]
+#[cfg(any(feature = "std", feature = "parallel"))]
 extern crate std;
 
 extern crate alloc;
@@ -49,32 +49,46 @@ mod add_entity;
 pub mod advanced;
 /// Contains all storages present in the [`World`].
 pub mod all_storages;
+mod atomic_stack;
 /// Allows access to helper types needed to implement [`Borrow`](borrow::Borrow).
 pub mod borrow;
+mod commands;
 mod component;
 mod contains;
 mod delete;
+mod disable;
+pub mod dynamic_query;
 mod entities;
 mod entity_id;
 pub mod error;
+pub mod events;
 mod get;
+pub mod group;
+pub mod hierarchy;
+mod include_disabled;
+pub mod index;
 /// Contains all items related to storage iteration.
 pub mod iter;
 /// Trait used as bound for [`World::iter`](crate::world::World::iter) and [`AllStorages::iter`](crate::all_storages::AllStorages::iter).
 mod iter_component;
+mod map_entities;
 /// Module describing internal memory usage.
 pub mod memory_usage;
 mod r#mut;
 mod not;
 mod optional;
 mod or;
+pub mod prefab;
 mod public_transport;
+pub mod registry;
+pub mod relation;
 mod remove;
 /// Stores systems from all workloads and their scheduling.
 pub mod scheduler;
 mod seal;
 /// Default component storage.
 pub mod sparse_set;
+mod spin_lock;
 mod storage;
 /// Module related to storage tracking, like insertion or modification.
 pub mod track;
@@ -91,20 +105,25 @@ pub use add_entity::AddEntity;
 pub use component::{Component, Unique};
 pub use contains::Contains;
 pub use delete::Delete;
+pub use disable::Disable;
 pub use entity_id::EntityId;
 pub use get::Get;
 #[doc(inline)]
 pub use iter::IntoIter;
+pub use map_entities::{EntityMap, MapEntities};
 pub use remove::Remove;
 #[doc(inline)]
 pub use scheduler::{IntoWorkload, Workload};
 #[cfg(feature = "proc")]
-pub use shipyard_proc::{Borrow, BorrowInfo, Component, IntoIter, Label, Unique, WorldBorrow};
+pub use shipyard_proc::{
+    Borrow, BorrowInfo, Bundle, Component, IntoIter, Label, MapEntities, Unique, WorldBorrow,
+};
 pub use unique::UniqueStorage;
 #[doc(inline)]
 pub use views::{
-    AllStoragesView, AllStoragesViewMut, EntitiesView, EntitiesViewMut, UniqueView, UniqueViewMut,
-    View, ViewMut,
+    AllStoragesView, AllStoragesViewMut, Commands, EntitiesView, EntitiesViewMut, EventReader,
+    EventWriter, HierarchyView, HierarchyViewMut, RelationView, RelationViewMut, UniqueView,
+    UniqueViewMut, View, ViewMut,
 };
 #[doc(inline)]
 pub use world::World;
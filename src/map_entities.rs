@@ -0,0 +1,122 @@
+use crate::entity_id::EntityId;
+use crate::ShipHashMap;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+/// Maps [`EntityId`]s from one [`World`](crate::World) to another.
+///
+/// Filled by operations that create entities in another `World`,
+/// like [`World::move_entities`](crate::World::move_entities) or [`World::clone_entities_to`](crate::World::clone_entities_to).
+#[derive(Default, Clone, Debug)]
+pub struct EntityMap {
+    map: ShipHashMap<EntityId, EntityId>,
+}
+
+impl EntityMap {
+    /// Creates an empty `EntityMap`.
+    #[inline]
+    pub fn new() -> EntityMap {
+        EntityMap::default()
+    }
+    /// Maps `from` to `to`.\
+    /// Returns the previous mapping of `from`.
+    #[inline]
+    pub fn insert(&mut self, from: EntityId, to: EntityId) -> Option<EntityId> {
+        self.map.insert(from, to)
+    }
+    /// Returns the entity `entity` is mapped to.
+    #[inline]
+    pub fn get(&self, entity: EntityId) -> Option<EntityId> {
+        self.map.get(&entity).copied()
+    }
+    /// Returns the entity `entity` is mapped to or `entity` if it isn't mapped.
+    #[inline]
+    pub fn map(&self, entity: EntityId) -> EntityId {
+        self.get(entity).unwrap_or(entity)
+    }
+    /// Returns the number of mapped entities.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+    /// Returns `true` if no entity is mapped.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+    /// Returns an iterator over all `(from, to)` pairs.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (EntityId, EntityId)> + '_ {
+        self.map.iter().map(|(from, to)| (*from, *to))
+    }
+}
+
+/// Updates the [`EntityId`]s stored in a type when entities are moved to another [`World`](crate::World).
+///
+/// Register components with [`World::register_map_entities`](crate::World::register_map_entities)
+/// to have them updated by operations filling an [`EntityMap`].
+///
+/// Can be derived, all fields are mapped unless marked with `#[shipyard(map_entities_skip)]`.
+///
+/// ### Example
+///
+/// ```
+/// use shipyard::{Component, EntityId, MapEntities};
+///
+/// #[derive(Component, MapEntities)]
+/// struct Target {
+///     entity: EntityId,
+///     #[shipyard(map_entities_skip)]
+///     distance: f32,
+/// }
+/// ```
+pub trait MapEntities {
+    /// Replaces all [`EntityId`]s with the entity they are mapped to.
+    fn map_entities(&mut self, entity_map: &EntityMap);
+}
+
+impl MapEntities for EntityId {
+    #[inline]
+    fn map_entities(&mut self, entity_map: &EntityMap) {
+        *self = entity_map.map(*self);
+    }
+}
+
+impl<T: MapEntities> MapEntities for Option<T> {
+    #[inline]
+    fn map_entities(&mut self, entity_map: &EntityMap) {
+        if let Some(value) = self {
+            value.map_entities(entity_map);
+        }
+    }
+}
+
+impl<T: MapEntities> MapEntities for Box<T> {
+    #[inline]
+    fn map_entities(&mut self, entity_map: &EntityMap) {
+        (**self).map_entities(entity_map);
+    }
+}
+
+impl<T: MapEntities> MapEntities for Vec<T> {
+    #[inline]
+    fn map_entities(&mut self, entity_map: &EntityMap) {
+        self.as_mut_slice().map_entities(entity_map);
+    }
+}
+
+impl<T: MapEntities> MapEntities for [T] {
+    #[inline]
+    fn map_entities(&mut self, entity_map: &EntityMap) {
+        for value in self {
+            value.map_entities(entity_map);
+        }
+    }
+}
+
+impl<T: MapEntities, const N: usize> MapEntities for [T; N] {
+    #[inline]
+    fn map_entities(&mut self, entity_map: &EntityMap) {
+        self.as_mut_slice().map_entities(entity_map);
+    }
+}
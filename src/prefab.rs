@@ -0,0 +1,161 @@
+//! Templates to spawn many entities with the same components.
+//!
+//! A prefab is a named, disabled entity whose components are cloned into each instance.\
+//! Its components are never visible to iterators, [`Get`](crate::Get) or [`Contains`](crate::Contains).
+//!
+//! Prefabs can have child prefabs, each instance then gets its own child instances
+//! attached to it in the default [`Hierarchy`](crate::hierarchy::Hierarchy).
+//!
+//! ### Example
+//!
+//! ```
+//! use shipyard::{Component, HierarchyView, IntoIter, View, World};
+//!
+//! #[derive(Component, Clone, Debug, PartialEq, Eq)]
+//! struct Health(u32);
+//!
+//! #[derive(Component, Clone, Debug, PartialEq, Eq)]
+//! struct Name(&'static str);
+//!
+//! let mut world = World::new();
+//!
+//! world.add_prefab("goblin", (Name("Goblin"), Health(10)));
+//! world.add_prefab("spear", (Name("Spear"),));
+//! world.add_prefab_child("goblin", "spear");
+//!
+//! let goblins = world.instantiate_with("goblin", [(Health(5),), (Health(20),)]);
+//!
+//! world.run(|healths: View<Health>, names: View<Name>, hierarchy: HierarchyView| {
+//!     assert_eq!(healths.iter().collect::<Vec<_>>(), [&Health(5), &Health(20)]);
+//!     assert_eq!(names.iter().count(), 4);
+//!
+//!     let spear = hierarchy.children(goblins[0]).next().unwrap();
+//!     assert_eq!(names[spear], Name("Spear"));
+//! });
+//! ```
+
+use crate::all_storages::{AllStorages, TupleClone};
+use crate::component::Component;
+use crate::entity_id::EntityId;
+use crate::memory_usage::StorageMemoryUsage;
+use crate::sparse_set::{SparseSet, TupleAddComponent};
+use crate::storage::Storage;
+use crate::tracking::TrackingTimestamp;
+use crate::ShipHashMap;
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+use core::any::type_name;
+use core::mem::size_of;
+
+/// Components that can be stored in a prefab.
+///
+/// Implemented for all cloneable components and tuples of them.
+pub trait PrefabComponents: TupleAddComponent {
+    /// Registers the function to clone these components.
+    fn register_clone(all_storages: &mut AllStorages);
+}
+
+impl PrefabComponents for () {
+    #[inline]
+    fn register_clone(_all_storages: &mut AllStorages) {}
+}
+
+impl<T: Component + Clone + Send + Sync> PrefabComponents for T {
+    #[inline]
+    fn register_clone(all_storages: &mut AllStorages) {
+        <SparseSet<T> as TupleClone>::register_clone(all_storages);
+    }
+}
+
+macro_rules! impl_prefab_components {
+    ($(($type: ident, $index: tt))+) => {
+        impl<$($type: PrefabComponents,)+> PrefabComponents for ($($type,)+) {
+            #[inline]
+            fn register_clone(all_storages: &mut AllStorages) {
+                $(
+                    $type::register_clone(all_storages);
+                )+
+            }
+        }
+    };
+}
+
+macro_rules! prefab_components {
+    ($(($type: ident, $index: tt))*;($type1: ident, $index1: tt) $(($queue_type: ident, $queue_index: tt))*) => {
+        impl_prefab_components![$(($type, $index))*];
+        prefab_components![$(($type, $index))* ($type1, $index1); $(($queue_type, $queue_index))*];
+    };
+    ($(($type: ident, $index: tt))*;) => {
+        impl_prefab_components![$(($type, $index))*];
+    }
+}
+
+#[cfg(not(feature = "extended_tuple"))]
+prefab_components![(A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)];
+#[cfg(feature = "extended_tuple")]
+prefab_components![
+    (A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)
+    (K, 10) (L, 11) (M, 12) (N, 13) (O, 14) (P, 15) (Q, 16) (R, 17) (S, 18) (T, 19)
+    (U, 20) (V, 21) (W, 22) (X, 23) (Y, 24) (Z, 25) (AA, 26) (BB, 27) (CC, 28) (DD, 29)
+    (EE, 30) (FF, 31)
+];
+
+#[derive(Clone)]
+pub(crate) struct Prefab {
+    pub(crate) template: EntityId,
+    pub(crate) children: Vec<Cow<'static, str>>,
+}
+
+/// Storage holding all prefabs of a `World`.
+#[derive(Default)]
+pub(crate) struct Prefabs {
+    pub(crate) prefabs: ShipHashMap<Cow<'static, str>, Prefab>,
+    pub(crate) names: ShipHashMap<EntityId, Cow<'static, str>>,
+}
+
+impl Prefabs {
+    /// Returns `true` if `name` is `prefab` or one of its descendants.
+    pub(crate) fn is_descendant(&self, prefab: &str, name: &str) -> bool {
+        prefab == name
+            || self.prefabs.get(prefab).is_some_and(|prefab| {
+                prefab
+                    .children
+                    .iter()
+                    .any(|child| self.is_descendant(child, name))
+            })
+    }
+}
+
+impl Storage for Prefabs {
+    #[inline]
+    fn delete(&mut self, entity: EntityId, _current: TrackingTimestamp) {
+        if let Some(name) = self.names.remove(&entity) {
+            self.prefabs.remove(&name);
+
+            for prefab in self.prefabs.values_mut() {
+                prefab.children.retain(|child| *child != name);
+            }
+        }
+    }
+    #[inline]
+    fn clear(&mut self, _current: TrackingTimestamp) {
+        self.prefabs.clear();
+        self.names.clear();
+    }
+    fn memory_usage(&self) -> Option<StorageMemoryUsage> {
+        Some(StorageMemoryUsage {
+            storage_name: type_name::<Self>().into(),
+            allocated_memory_bytes: self.prefabs.capacity()
+                * (size_of::<Cow<'static, str>>() + size_of::<Prefab>())
+                + size_of::<Self>(),
+            used_memory_bytes: self.prefabs.len()
+                * (size_of::<Cow<'static, str>>() + size_of::<Prefab>())
+                + size_of::<Self>(),
+            component_count: self.prefabs.len(),
+        })
+    }
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.prefabs.is_empty()
+    }
+}
@@ -0,0 +1,749 @@
+//! Runtime information about component and unique types.
+//!
+//! Each type is registered under a stable name with [`World::register_component`] or [`World::register_unique`].\
+//! Its [`TypeRegistration`] can then be looked up by [`StorageId`] or name to create its storage, clone, debug print,
+//! default construct or (de)serialize it without knowing the type at compile time.
+//!
+//! ### Example
+//!
+//! ```
+//! use shipyard::{AllStoragesViewMut, Component, World};
+//!
+//! #[derive(Component, Clone, Debug, Default)]
+//! struct Health(u32);
+//!
+//! let mut world = World::new();
+//!
+//! world.register_component::<Health>("Health").clone().debug().default();
+//!
+//! let entity = world.add_entity(());
+//!
+//! let all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+//! let registration = all_storages.registry().get_by_name("Health").unwrap();
+//!
+//! registration.add_default(&all_storages, entity).unwrap();
+//! assert_eq!(registration.debug(&all_storages, entity).unwrap(), "Health(0)");
+//! ```
+//!
+//! [`World::register_component`]: crate::World::register_component
+//! [`World::register_unique`]: crate::World::register_unique
+
+use crate::all_storages::AllStorages;
+use crate::component::{Component, Unique};
+use crate::entity_id::EntityId;
+use crate::error;
+use crate::get::Get;
+use crate::sparse_set::SparseSet;
+use crate::storage::StorageId;
+use crate::unique::UniqueStorage;
+use crate::views::{EntitiesView, UniqueView, View, ViewMut};
+use crate::ShipHashMap;
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::string::String;
+use core::any::type_name;
+use core::fmt::Debug;
+use core::marker::PhantomData;
+
+type CloneHook = fn(&AllStorages, EntityId, EntityId) -> Result<(), error::Registry>;
+type DebugHook = fn(&AllStorages, EntityId) -> Result<String, error::Registry>;
+type DefaultHook = fn(&AllStorages, EntityId) -> Result<(), error::Registry>;
+#[cfg(feature = "serde1")]
+type SerializeHook =
+    for<'a> fn(
+        &'a AllStorages,
+    )
+        -> Result<alloc::boxed::Box<dyn erased_serde::Serialize + 'a>, error::GetStorage>;
+#[cfg(feature = "serde1")]
+type DeserializeHook = for<'de> fn(
+    &AllStorages,
+    &mut dyn erased_serde::Deserializer<'de>,
+) -> Result<(), erased_serde::Error>;
+
+/// Name of the entry holding the alive entities in [`RegistrySerializer`]'s map, it can't be used to register a type.
+pub const ENTITIES_NAME: &str = "Entities";
+
+/// Registered types, indexed by [`StorageId`] and name.
+#[derive(Default)]
+pub struct TypeRegistry {
+    registrations: ShipHashMap<StorageId, TypeRegistration>,
+    names: ShipHashMap<Cow<'static, str>, StorageId>,
+}
+
+impl TypeRegistry {
+    /// Returns the registration of the storage identified by `storage_id`.
+    #[inline]
+    pub fn get(&self, storage_id: StorageId) -> Option<&TypeRegistration> {
+        self.registrations.get(&storage_id)
+    }
+    /// Returns the registration with this `name`.
+    #[inline]
+    pub fn get_by_name(&self, name: &str) -> Option<&TypeRegistration> {
+        self.names
+            .get(name)
+            .and_then(|storage_id| self.registrations.get(storage_id))
+    }
+    /// Returns an iterator over all registrations, in no particular order.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &TypeRegistration> {
+        self.registrations.values()
+    }
+    /// Returns the number of registered types.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.registrations.len()
+    }
+    /// Returns `true` if no type is registered.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.registrations.is_empty()
+    }
+    #[track_caller]
+    fn register(
+        &mut self,
+        storage_id: StorageId,
+        name: Cow<'static, str>,
+        type_name: &'static str,
+        insert_storage: fn(&AllStorages) -> Result<(), error::Registry>,
+        is_unique: bool,
+    ) -> &mut TypeRegistration {
+        assert!(
+            name != ENTITIES_NAME,
+            "{} cannot be registered as {:?}, the name is reserved for entities.",
+            type_name,
+            name
+        );
+
+        if let Some(other_id) = self.names.get(&name) {
+            if *other_id != storage_id {
+                panic!(
+                    "{} cannot be registered as {:?}, the name is already used by another type.",
+                    type_name, name
+                );
+            }
+        }
+
+        if let Some(old) = self.registrations.get(&storage_id) {
+            if old.name != name {
+                self.names.remove(&old.name);
+            }
+        }
+
+        self.names.insert(name.clone(), storage_id);
+
+        let registration =
+            self.registrations
+                .entry(storage_id)
+                .or_insert_with(|| TypeRegistration {
+                    name: name.clone(),
+                    storage_id,
+                    type_name,
+                    is_unique,
+                    insert_storage,
+                    clone: None,
+                    debug: None,
+                    default: None,
+                    #[cfg(feature = "serde1")]
+                    serialize: None,
+                    #[cfg(feature = "serde1")]
+                    deserialize: None,
+                });
+        registration.name = name;
+
+        registration
+    }
+}
+
+/// Type information and type-erased hooks of a registered component or unique.
+pub struct TypeRegistration {
+    name: Cow<'static, str>,
+    storage_id: StorageId,
+    type_name: &'static str,
+    is_unique: bool,
+    insert_storage: fn(&AllStorages) -> Result<(), error::Registry>,
+    clone: Option<CloneHook>,
+    debug: Option<DebugHook>,
+    default: Option<DefaultHook>,
+    #[cfg(feature = "serde1")]
+    serialize: Option<SerializeHook>,
+    #[cfg(feature = "serde1")]
+    deserialize: Option<DeserializeHook>,
+}
+
+impl TypeRegistration {
+    /// Returns the name this type was registered with.
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    /// Returns the [`StorageId`] of this type's storage.
+    #[inline]
+    pub fn storage_id(&self) -> StorageId {
+        self.storage_id
+    }
+    /// Returns the Rust type name, it is not guaranteed to be stable.
+    #[inline]
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+    /// Returns `true` if the type was registered as a unique.
+    #[inline]
+    pub fn is_unique(&self) -> bool {
+        self.is_unique
+    }
+    /// Returns `true` if this type has a serde hook.
+    #[cfg(feature = "serde1")]
+    #[inline]
+    pub fn is_serde(&self) -> bool {
+        self.serialize.is_some()
+    }
+    /// Creates the storage if it doesn't exist.\
+    /// Uniques require a default hook.
+    pub fn insert_storage(&self, all_storages: &AllStorages) -> Result<(), error::Registry> {
+        (self.insert_storage)(all_storages)
+    }
+    /// Clones the component of `from` and adds it to `to`.
+    ///
+    /// Uniques don't have a clone hook, [`UniqueRegistrar::clone`] only registers the storage's clone function.
+    ///
+    /// ### Panics
+    ///
+    /// - `to` is not alive.
+    #[track_caller]
+    pub fn clone_component(
+        &self,
+        all_storages: &AllStorages,
+        from: EntityId,
+        to: EntityId,
+    ) -> Result<(), error::Registry> {
+        let clone = self.clone.ok_or_else(|| self.missing_hook("clone"))?;
+
+        clone(all_storages, from, to)
+    }
+    /// Returns the `Debug` representation of `entity`'s component.\
+    /// `entity` is ignored for uniques.
+    pub fn debug(
+        &self,
+        all_storages: &AllStorages,
+        entity: EntityId,
+    ) -> Result<String, error::Registry> {
+        let debug = self.debug.ok_or_else(|| self.missing_hook("debug"))?;
+
+        debug(all_storages, entity)
+    }
+    /// Adds the default value of the component to `entity`.\
+    /// For uniques, `entity` is ignored and the unique is added.
+    ///
+    /// ### Panics
+    ///
+    /// - `entity` is not alive.
+    #[track_caller]
+    pub fn add_default(
+        &self,
+        all_storages: &AllStorages,
+        entity: EntityId,
+    ) -> Result<(), error::Registry> {
+        let default = self.default.ok_or_else(|| self.missing_hook("default"))?;
+
+        default(all_storages, entity)
+    }
+    /// Serializes this type's storage, in the same format as [`View`] or [`UniqueView`].
+    #[cfg(feature = "serde1")]
+    pub fn serialize<S: serde::Serializer>(
+        &self,
+        all_storages: &AllStorages,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        use serde::ser::Error;
+
+        let serialize = self
+            .serialize
+            .ok_or_else(|| S::Error::custom(self.missing_hook("serde")))?;
+        let storage = serialize(all_storages).map_err(S::Error::custom)?;
+
+        erased_serde::serialize(&*storage, serializer)
+    }
+    /// Deserializes this type's storage, in the same format as [`ViewMut`] or [`UniqueViewMut`](crate::UniqueViewMut).\
+    /// Uniques are added if they weren't present.
+    #[cfg(feature = "serde1")]
+    pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+        &self,
+        all_storages: &AllStorages,
+        deserializer: D,
+    ) -> Result<(), D::Error> {
+        use serde::de::Error;
+
+        let deserialize = self
+            .deserialize
+            .ok_or_else(|| D::Error::custom(self.missing_hook("serde")))?;
+
+        deserialize(
+            all_storages,
+            &mut <dyn erased_serde::Deserializer<'de>>::erase(deserializer),
+        )
+        .map_err(D::Error::custom)
+    }
+    fn missing_hook(&self, hook: &'static str) -> error::Registry {
+        error::Registry::MissingHook {
+            name: self.name.clone(),
+            hook,
+        }
+    }
+}
+
+/// Adds hooks to a registered component.
+///
+/// Returned by [`World::register_component`](crate::World::register_component).
+pub struct ComponentRegistrar<'a, T> {
+    all_storages: &'a mut AllStorages,
+    _phantom: PhantomData<fn() -> T>,
+}
+
+impl<'a, T: Component + Send + Sync> ComponentRegistrar<'a, T> {
+    #[track_caller]
+    pub(crate) fn new(
+        all_storages: &'a mut AllStorages,
+        name: Cow<'static, str>,
+    ) -> ComponentRegistrar<'a, T> {
+        all_storages.registry.register(
+            StorageId::of::<SparseSet<T>>(),
+            name,
+            type_name::<T>(),
+            insert_sparse_set::<T>,
+            false,
+        );
+
+        all_storages
+            .exclusive_storage_or_insert_mut(StorageId::of::<SparseSet<T>>(), SparseSet::<T>::new);
+
+        ComponentRegistrar {
+            all_storages,
+            _phantom: PhantomData,
+        }
+    }
+    fn registration(&mut self) -> &mut TypeRegistration {
+        self.all_storages
+            .registry
+            .registrations
+            .get_mut(&StorageId::of::<SparseSet<T>>())
+            .unwrap()
+    }
+}
+
+impl<'a, T: Component + Clone + Send + Sync> ComponentRegistrar<'a, T> {
+    /// Adds the clone hook and registers the clone function of the storage.
+    #[allow(clippy::should_implement_trait)]
+    pub fn clone(mut self) -> Self {
+        self.all_storages.register_clone::<SparseSet<T>>();
+        self.registration().clone = Some(clone_component::<T>);
+
+        self
+    }
+}
+
+impl<'a, T: Component + Debug + Send + Sync> ComponentRegistrar<'a, T> {
+    /// Adds the debug hook.
+    pub fn debug(mut self) -> Self {
+        self.registration().debug = Some(debug_component::<T>);
+
+        self
+    }
+}
+
+impl<'a, T: Component + Default + Send + Sync> ComponentRegistrar<'a, T> {
+    /// Adds the default hook.
+    #[allow(clippy::should_implement_trait)]
+    pub fn default(mut self) -> Self {
+        self.registration().default = Some(default_component::<T>);
+
+        self
+    }
+}
+
+#[cfg(feature = "serde1")]
+impl<'a, T: Component + serde::Serialize + serde::de::DeserializeOwned + Send + Sync>
+    ComponentRegistrar<'a, T>
+{
+    /// Adds the serialization and deserialization hooks.
+    pub fn serde(mut self) -> Self {
+        let registration = self.registration();
+        registration.serialize = Some(serialize_component::<T>);
+        registration.deserialize = Some(deserialize_component::<T>);
+
+        self
+    }
+}
+
+/// Adds hooks to a registered unique.
+///
+/// Returned by [`World::register_unique`](crate::World::register_unique).
+pub struct UniqueRegistrar<'a, T> {
+    all_storages: &'a mut AllStorages,
+    _phantom: PhantomData<fn() -> T>,
+}
+
+impl<'a, T: Unique + Send + Sync> UniqueRegistrar<'a, T> {
+    #[track_caller]
+    pub(crate) fn new(
+        all_storages: &'a mut AllStorages,
+        name: Cow<'static, str>,
+    ) -> UniqueRegistrar<'a, T> {
+        all_storages.registry.register(
+            StorageId::of::<UniqueStorage<T>>(),
+            name,
+            type_name::<T>(),
+            insert_unique::<T>,
+            true,
+        );
+
+        UniqueRegistrar {
+            all_storages,
+            _phantom: PhantomData,
+        }
+    }
+    fn registration(&mut self) -> &mut TypeRegistration {
+        self.all_storages
+            .registry
+            .registrations
+            .get_mut(&StorageId::of::<UniqueStorage<T>>())
+            .unwrap()
+    }
+}
+
+impl<'a, T: Unique + Clone + Send + Sync> UniqueRegistrar<'a, T> {
+    /// Registers the clone function of the storage.
+    ///
+    /// ### Panics
+    ///
+    /// - The unique is not present.
+    #[allow(clippy::should_implement_trait)]
+    #[track_caller]
+    pub fn clone(self) -> Self {
+        self.all_storages.register_clone::<UniqueStorage<T>>();
+
+        self
+    }
+}
+
+impl<'a, T: Unique + Debug + Send + Sync> UniqueRegistrar<'a, T> {
+    /// Adds the debug hook.
+    pub fn debug(mut self) -> Self {
+        self.registration().debug = Some(debug_unique::<T>);
+
+        self
+    }
+}
+
+impl<'a, T: Unique + Default + Send + Sync> UniqueRegistrar<'a, T> {
+    /// Adds the default hook, it is also used to create the storage.
+    #[allow(clippy::should_implement_trait)]
+    pub fn default(mut self) -> Self {
+        self.registration().default = Some(default_unique::<T>);
+
+        self
+    }
+}
+
+#[cfg(feature = "serde1")]
+impl<'a, T: Unique + serde::Serialize + serde::de::DeserializeOwned + Send + Sync>
+    UniqueRegistrar<'a, T>
+{
+    /// Adds the serialization and deserialization hooks.
+    pub fn serde(mut self) -> Self {
+        let registration = self.registration();
+        registration.serialize = Some(serialize_unique::<T>);
+        registration.deserialize = Some(deserialize_unique::<T>);
+
+        self
+    }
+}
+
+fn insert_sparse_set<T: Component + Send + Sync>(
+    all_storages: &AllStorages,
+) -> Result<(), error::Registry> {
+    all_storages.borrow::<View<'_, T>>()?;
+
+    Ok(())
+}
+
+fn insert_unique<T: Unique + Send + Sync>(
+    all_storages: &AllStorages,
+) -> Result<(), error::Registry> {
+    match all_storages.borrow::<UniqueView<'_, T>>() {
+        Ok(_) => Ok(()),
+        Err(error::GetStorage::MissingStorage { .. }) => {
+            let registration = all_storages
+                .registry
+                .get(StorageId::of::<UniqueStorage<T>>())
+                .unwrap();
+
+            registration.add_default(all_storages, EntityId::dead())
+        }
+        Err(err) => Err(err.into()),
+    }
+}
+
+#[track_caller]
+fn clone_component<T: Component + Clone + Send + Sync>(
+    all_storages: &AllStorages,
+    from: EntityId,
+    to: EntityId,
+) -> Result<(), error::Registry> {
+    let (entities, mut view) = all_storages.borrow::<(EntitiesView<'_>, ViewMut<'_, T>)>()?;
+    let component = (&view).get(from)?.clone();
+
+    entities.add_component(to, &mut view, component);
+
+    Ok(())
+}
+
+fn debug_component<T: Component + Debug + Send + Sync>(
+    all_storages: &AllStorages,
+    entity: EntityId,
+) -> Result<String, error::Registry> {
+    let view = all_storages.borrow::<View<'_, T>>()?;
+
+    Ok(format!("{:?}", view.get(entity)?))
+}
+
+#[track_caller]
+fn default_component<T: Component + Default + Send + Sync>(
+    all_storages: &AllStorages,
+    entity: EntityId,
+) -> Result<(), error::Registry> {
+    let (entities, mut view) = all_storages.borrow::<(EntitiesView<'_>, ViewMut<'_, T>)>()?;
+
+    entities.add_component(entity, &mut view, T::default());
+
+    Ok(())
+}
+
+#[cfg(feature = "serde1")]
+fn serialize_component<'a, T: Component + serde::Serialize + Send + Sync>(
+    all_storages: &'a AllStorages,
+) -> Result<alloc::boxed::Box<dyn erased_serde::Serialize + 'a>, error::GetStorage> {
+    Ok(alloc::boxed::Box::new(
+        all_storages.borrow::<View<'_, T>>()?,
+    ))
+}
+
+#[cfg(feature = "serde1")]
+fn deserialize_component<T: Component + serde::de::DeserializeOwned + Send + Sync>(
+    all_storages: &AllStorages,
+    deserializer: &mut dyn erased_serde::Deserializer<'_>,
+) -> Result<(), erased_serde::Error> {
+    use serde::de::Error;
+
+    let mut view = all_storages
+        .borrow::<ViewMut<'_, T>>()
+        .map_err(erased_serde::Error::custom)?;
+
+    serde::Deserialize::deserialize_in_place(deserializer, &mut view)
+}
+
+fn debug_unique<T: Unique + Debug + Send + Sync>(
+    all_storages: &AllStorages,
+    _entity: EntityId,
+) -> Result<String, error::Registry> {
+    let unique = all_storages.borrow::<UniqueView<'_, T>>()?;
+
+    Ok(format!("{:?}", *unique))
+}
+
+fn default_unique<T: Unique + Default + Send + Sync>(
+    all_storages: &AllStorages,
+    _entity: EntityId,
+) -> Result<(), error::Registry> {
+    all_storages.add_unique(T::default());
+
+    Ok(())
+}
+
+#[cfg(feature = "serde1")]
+fn serialize_unique<'a, T: Unique + serde::Serialize + Send + Sync>(
+    all_storages: &'a AllStorages,
+) -> Result<alloc::boxed::Box<dyn erased_serde::Serialize + 'a>, error::GetStorage> {
+    Ok(alloc::boxed::Box::new(
+        all_storages.borrow::<UniqueView<'_, T>>()?,
+    ))
+}
+
+#[cfg(feature = "serde1")]
+fn deserialize_unique<T: Unique + serde::de::DeserializeOwned + Send + Sync>(
+    all_storages: &AllStorages,
+    deserializer: &mut dyn erased_serde::Deserializer<'_>,
+) -> Result<(), erased_serde::Error> {
+    use serde::de::Error;
+
+    match all_storages.borrow::<crate::views::UniqueViewMut<'_, T>>() {
+        Ok(mut unique) => serde::Deserialize::deserialize_in_place(deserializer, &mut unique),
+        Err(error::GetStorage::MissingStorage { .. }) => {
+            all_storages.add_unique(erased_serde::deserialize::<T>(deserializer)?);
+
+            Ok(())
+        }
+        Err(err) => Err(erased_serde::Error::custom(err)),
+    }
+}
+
+/// Serializes all registered types with a serde hook as a map from their name to their storage.
+///
+/// The alive entities are serialized first, under [`ENTITIES_NAME`].\
+/// Use [`World::serialize_registered`](crate::World::serialize_registered) to borrow `AllStorages` and serialize in one step.
+#[cfg(feature = "serde1")]
+pub struct RegistrySerializer<'a> {
+    #[allow(missing_docs)]
+    pub all_storages: &'a AllStorages,
+}
+
+#[cfg(feature = "serde1")]
+impl<'a> serde::Serialize for RegistrySerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::{Error, SerializeMap};
+
+        struct Storage<'a> {
+            registration: &'a TypeRegistration,
+            all_storages: &'a AllStorages,
+        }
+
+        impl serde::Serialize for Storage<'_> {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                self.registration.serialize(self.all_storages, serializer)
+            }
+        }
+
+        let mut registrations = self
+            .all_storages
+            .registry
+            .iter()
+            .filter(|registration| registration.is_serde())
+            .filter(|registration| {
+                // Skip uniques that were never added
+                !registration.is_unique
+                    || self
+                        .all_storages
+                        .storages
+                        .read()
+                        .contains_key(&registration.storage_id)
+            })
+            .collect::<alloc::vec::Vec<_>>();
+        registrations.sort_unstable_by(|a, b| a.name.cmp(&b.name));
+
+        let entities = self
+            .all_storages
+            .borrow::<EntitiesView<'_>>()
+            .map_err(S::Error::custom)?;
+
+        let mut map = serializer.serialize_map(Some(registrations.len() + 1))?;
+        map.serialize_entry(ENTITIES_NAME, &entities)?;
+        for registration in registrations {
+            map.serialize_entry(
+                &*registration.name,
+                &Storage {
+                    registration,
+                    all_storages: self.all_storages,
+                },
+            )?;
+        }
+        map.end()
+    }
+}
+
+/// Deserializes a map created by [`RegistrySerializer`], looking up each type by name.
+///
+/// The entities under [`ENTITIES_NAME`] are spawned.\
+/// Use [`World::deserialize_registered`](crate::World::deserialize_registered) to borrow `AllStorages` and deserialize in one step.
+#[cfg(feature = "serde1")]
+pub struct RegistryDeserializer<'a> {
+    #[allow(missing_docs)]
+    pub all_storages: &'a AllStorages,
+}
+
+#[cfg(feature = "serde1")]
+impl<'a, 'de> serde::de::DeserializeSeed<'de> for RegistryDeserializer<'a> {
+    type Value = ();
+
+    fn deserialize<D>(self, deserializer: D) -> Result<(), D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct Storage<'a> {
+            registration: &'a TypeRegistration,
+            all_storages: &'a AllStorages,
+        }
+
+        impl<'a, 'de> serde::de::DeserializeSeed<'de> for Storage<'a> {
+            type Value = ();
+
+            fn deserialize<D>(self, deserializer: D) -> Result<(), D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                self.registration
+                    .deserialize(self.all_storages, deserializer)
+            }
+        }
+
+        struct MapVisitor<'a> {
+            all_storages: &'a AllStorages,
+        }
+
+        impl<'a, 'de> serde::de::Visitor<'de> for MapVisitor<'a> {
+            type Value = ();
+
+            fn expecting(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                formatter.write_str("a map of registered type names to storages")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<(), A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                use serde::de::Error;
+
+                // serde doesn't like to deserialize field names using &str
+                // when there are escape characters
+                while let Some(name) = map.next_key::<String>()? {
+                    if name == ENTITIES_NAME {
+                        let entity_ids = map.next_value::<alloc::vec::Vec<EntityId>>()?;
+                        let mut entities = self
+                            .all_storages
+                            .borrow::<crate::views::EntitiesViewMut<'_>>()
+                            .map_err(A::Error::custom)?;
+
+                        for entity in entity_ids {
+                            entities.spawn(entity);
+                        }
+
+                        continue;
+                    }
+
+                    let registration =
+                        self.all_storages
+                            .registry
+                            .get_by_name(&name)
+                            .ok_or_else(|| {
+                                A::Error::custom(format!("{:?} is not a registered type", name))
+                            })?;
+
+                    map.next_value_seed(Storage {
+                        registration,
+                        all_storages: self.all_storages,
+                    })?;
+                }
+
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_map(MapVisitor {
+            all_storages: self.all_storages,
+        })
+    }
+}
@@ -0,0 +1,351 @@
+//! Relationship pairs between entities.
+//!
+//! A relation links a source entity to a target entity, with some data of type `R`.\
+//! Unlike components, a source can hold the same relation toward multiple targets.
+//!
+//! When an entity is deleted or stripped, all pairs where it is either the source or the target are removed.\
+//! Entities deleted with [`Entities::delete_unchecked`] are removed from pairs the next time the storage is borrowed exclusively,
+//! until then [`RelationView`] skips them.
+//!
+//! ### Example
+//!
+//! ```
+//! use shipyard::relation::Relation;
+//! use shipyard::{RelationView, RelationViewMut, World};
+//!
+//! struct Targets {
+//!     damage: u32,
+//! }
+//! impl Relation for Targets {}
+//!
+//! let mut world = World::new();
+//!
+//! let tower = world.add_entity(());
+//! let enemy1 = world.add_entity(());
+//! let enemy2 = world.add_entity(());
+//!
+//! world.run(|mut targets: RelationViewMut<Targets>| {
+//!     targets.insert(tower, enemy1, Targets { damage: 1 });
+//!     targets.insert(tower, enemy2, Targets { damage: 2 });
+//!
+//!     assert_eq!(targets.targets(tower).count(), 2);
+//!     assert_eq!(targets.sources(enemy1).collect::<Vec<_>>(), [tower]);
+//! });
+//!
+//! world.delete_entity(enemy1);
+//!
+//! world.run(|targets: RelationView<Targets>| {
+//!     assert_eq!(targets.targets(tower).map(|(target, _)| target).collect::<Vec<_>>(), [enemy2]);
+//! });
+//! ```
+//!
+//! [`Entities::delete_unchecked`]: crate::advanced::Entities::delete_unchecked()
+//! [`RelationView`]: crate::RelationView
+
+use crate::entity_id::EntityId;
+use crate::memory_usage::StorageMemoryUsage;
+use crate::spin_lock::SpinLock;
+use crate::storage::Storage;
+use crate::tracking::TrackingTimestamp;
+use crate::{ShipHashMap, ShipHashSet};
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::any::type_name;
+use core::mem::size_of;
+
+/// Indicates that a `struct` or `enum` can be used as relation between two entities.
+pub trait Relation: Sized + 'static {}
+
+/// Relation storage.
+///
+/// Stores pairs of source and target entities for a given relation type.
+pub struct RelationStorage<R: Relation> {
+    /// Source to its targets
+    targets: ShipHashMap<EntityId, Vec<(EntityId, R)>>,
+    /// Target to its sources
+    sources: ShipHashMap<EntityId, Vec<EntityId>>,
+    len: usize,
+    /// Queue of entities deleted with `Entities::delete_unchecked`
+    deletions: Option<(Arc<DeletionQueues>, usize)>,
+}
+
+impl<R: Relation> Default for RelationStorage<R> {
+    fn default() -> Self {
+        RelationStorage::new()
+    }
+}
+
+impl<R: Relation> RelationStorage<R> {
+    /// Creates an empty relation storage.
+    pub fn new() -> RelationStorage<R> {
+        RelationStorage {
+            targets: ShipHashMap::default(),
+            sources: ShipHashMap::default(),
+            len: 0,
+            deletions: None,
+        }
+    }
+    /// Creates an empty relation storage notified of the entities deleted with `Entities::delete_unchecked`.
+    pub(crate) fn with_deletions(deletion_queues: Arc<DeletionQueues>) -> RelationStorage<R> {
+        let index = deletion_queues.register();
+
+        RelationStorage {
+            deletions: Some((deletion_queues, index)),
+            ..RelationStorage::new()
+        }
+    }
+    /// Removes the pairs of the entities deleted with `Entities::delete_unchecked` since the last call.
+    pub(crate) fn apply_deletions(&mut self) {
+        if let Some((deletion_queues, index)) = &self.deletions {
+            for entity in deletion_queues.take(*index) {
+                self.remove_source(entity);
+                self.remove_target(entity);
+            }
+        }
+    }
+    /// Returns the entities deleted with `Entities::delete_unchecked` not yet removed from the pairs.
+    ///
+    /// Entities without any pair are dropped from the queue, this keeps it from growing when the storage is only borrowed shared.
+    pub(crate) fn pending_deletions(&self) -> ShipHashSet<EntityId> {
+        match &self.deletions {
+            Some((deletion_queues, index)) => deletion_queues.retain(*index, |entity| {
+                self.targets.contains_key(&entity) || self.sources.contains_key(&entity)
+            }),
+            None => ShipHashSet::new(),
+        }
+    }
+    /// Links `source` to `target`.\
+    /// Returns the previous relation if this pair already existed.
+    pub fn insert(&mut self, source: EntityId, target: EntityId, relation: R) -> Option<R> {
+        let targets = self.targets.entry(source).or_default();
+
+        if let Some((_, old_relation)) = targets.iter_mut().find(|(t, _)| *t == target) {
+            return Some(core::mem::replace(old_relation, relation));
+        }
+
+        targets.push((target, relation));
+        self.sources.entry(target).or_default().push(source);
+        self.len += 1;
+
+        None
+    }
+    /// Removes the pair between `source` and `target`.\
+    /// Returns the relation if the pair existed.
+    pub fn remove(&mut self, source: EntityId, target: EntityId) -> Option<R> {
+        let targets = self.targets.get_mut(&source)?;
+        let index = targets.iter().position(|(t, _)| *t == target)?;
+        let (_, relation) = targets.swap_remove(index);
+
+        if targets.is_empty() {
+            self.targets.remove(&source);
+        }
+
+        remove_from(&mut self.sources, target, source);
+        self.len -= 1;
+
+        Some(relation)
+    }
+    /// Removes all pairs where `source` is the source.
+    pub fn remove_source(&mut self, source: EntityId) {
+        if let Some(targets) = self.targets.remove(&source) {
+            self.len -= targets.len();
+
+            for (target, _) in targets {
+                remove_from(&mut self.sources, target, source);
+            }
+        }
+    }
+    /// Removes all pairs where `target` is the target.
+    pub fn remove_target(&mut self, target: EntityId) {
+        if let Some(sources) = self.sources.remove(&target) {
+            self.len -= sources.len();
+
+            for source in sources {
+                if let Some(targets) = self.targets.get_mut(&source) {
+                    targets.retain(|(t, _)| *t != target);
+
+                    if targets.is_empty() {
+                        self.targets.remove(&source);
+                    }
+                }
+            }
+        }
+    }
+    /// Returns the relation between `source` and `target`.
+    pub fn get(&self, source: EntityId, target: EntityId) -> Option<&R> {
+        self.targets
+            .get(&source)?
+            .iter()
+            .find(|(t, _)| *t == target)
+            .map(|(_, relation)| relation)
+    }
+    /// Returns the relation between `source` and `target`.
+    pub fn get_mut(&mut self, source: EntityId, target: EntityId) -> Option<&mut R> {
+        self.targets
+            .get_mut(&source)?
+            .iter_mut()
+            .find(|(t, _)| *t == target)
+            .map(|(_, relation)| relation)
+    }
+    /// Returns `true` if `source` is linked to `target`.
+    pub fn contains(&self, source: EntityId, target: EntityId) -> bool {
+        self.get(source, target).is_some()
+    }
+    /// Returns an iterator over the targets of `source` and their relation.
+    pub fn targets(&self, source: EntityId) -> impl Iterator<Item = (EntityId, &R)> + '_ {
+        self.targets
+            .get(&source)
+            .into_iter()
+            .flatten()
+            .map(|(target, relation)| (*target, relation))
+    }
+    /// Returns an iterator over the entities having a relation toward `target`.
+    pub fn sources(&self, target: EntityId) -> impl Iterator<Item = EntityId> + '_ {
+        self.sources.get(&target).into_iter().flatten().copied()
+    }
+    /// Returns an iterator over all pairs as `(source, target, relation)`.
+    pub fn iter(&self) -> impl Iterator<Item = (EntityId, EntityId, &R)> + '_ {
+        self.targets.iter().flat_map(|(source, targets)| {
+            targets
+                .iter()
+                .map(move |(target, relation)| (*source, *target, relation))
+        })
+    }
+    /// Returns the number of pairs.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    /// Returns `true` if there is no pair.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+fn remove_from(map: &mut ShipHashMap<EntityId, Vec<EntityId>>, key: EntityId, value: EntityId) {
+    if let Some(values) = map.get_mut(&key) {
+        if let Some(index) = values.iter().position(|v| *v == value) {
+            values.swap_remove(index);
+        }
+
+        if values.is_empty() {
+            map.remove(&key);
+        }
+    }
+}
+
+impl<R: Relation> Storage for RelationStorage<R> {
+    #[inline]
+    fn delete(&mut self, entity: EntityId, _current: TrackingTimestamp) {
+        self.apply_deletions();
+        self.remove_source(entity);
+        self.remove_target(entity);
+    }
+    #[inline]
+    fn clear(&mut self, _current: TrackingTimestamp) {
+        if let Some((deletion_queues, index)) = &self.deletions {
+            deletion_queues.take(*index);
+        }
+
+        self.targets.clear();
+        self.sources.clear();
+        self.len = 0;
+    }
+    fn memory_usage(&self) -> Option<StorageMemoryUsage> {
+        let pair_size = size_of::<(EntityId, R)>() + size_of::<EntityId>();
+
+        Some(StorageMemoryUsage {
+            storage_name: type_name::<Self>().into(),
+            allocated_memory_bytes: (self.targets.capacity() + self.sources.capacity())
+                * (size_of::<EntityId>() + size_of::<Vec<EntityId>>())
+                + self.len * pair_size
+                + size_of::<Self>(),
+            used_memory_bytes: (self.targets.len() + self.sources.len())
+                * (size_of::<EntityId>() + size_of::<Vec<EntityId>>())
+                + self.len * pair_size
+                + size_of::<Self>(),
+            component_count: self.len,
+        })
+    }
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+/// Entities deleted with `Entities::delete_unchecked`, one queue per relation storage.
+///
+/// Shared between `Entities` and the relation storages without borrowing any of them.
+#[derive(Default)]
+pub(crate) struct DeletionQueues(SpinLock<Vec<Vec<EntityId>>>);
+
+impl DeletionQueues {
+    /// Adds a queue and returns its index.
+    fn register(&self) -> usize {
+        let mut queues = self.0.lock();
+        queues.push(Vec::new());
+
+        queues.len() - 1
+    }
+    /// Adds `entity` to all queues.
+    pub(crate) fn push(&self, entity: EntityId) {
+        for queue in self.0.lock().iter_mut() {
+            queue.push(entity);
+        }
+    }
+    fn take(&self, index: usize) -> Vec<EntityId> {
+        core::mem::take(&mut self.0.lock()[index])
+    }
+    /// Keeps the entities of a queue for which `f` returns `true` and returns them.
+    fn retain(&self, index: usize, mut f: impl FnMut(EntityId) -> bool) -> ShipHashSet<EntityId> {
+        let mut queues = self.0.lock();
+        let queue = &mut queues[index];
+
+        if queue.is_empty() {
+            return ShipHashSet::new();
+        }
+
+        queue.retain(|&entity| f(entity));
+        queue.iter().copied().collect()
+    }
+}
+
+#[test]
+fn deletion_queues() {
+    use crate::entities::Entities;
+
+    struct Link;
+    impl Relation for Link {}
+
+    let mut entities = Entities::new();
+    let mut relations = RelationStorage::<Link>::with_deletions(entities.deletion_queues.clone());
+
+    let source = entities.generate();
+    let target = entities.generate();
+    let unrelated = entities.generate();
+    relations.insert(source, target, Link);
+
+    // the caller strips the entity, relation storages are not notified
+    assert!(entities.delete_before_strip(unrelated));
+    assert!(entities.deletion_queues.0.lock()[0].is_empty());
+
+    let unrelated = entities.generate();
+    assert!(entities.delete_unchecked(target));
+    assert!(entities.delete_unchecked(unrelated));
+    assert_eq!(entities.deletion_queues.0.lock()[0].len(), 2);
+
+    // shared borrows drop the entities without pairs
+    assert_eq!(
+        relations
+            .pending_deletions()
+            .into_iter()
+            .collect::<Vec<_>>(),
+        [target]
+    );
+    assert_eq!(*entities.deletion_queues.0.lock()[0], [target]);
+
+    relations.apply_deletions();
+    assert!(relations.is_empty());
+    assert!(entities.deletion_queues.0.lock()[0].is_empty());
+}
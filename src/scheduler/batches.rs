@@ -1,7 +1,12 @@
 use crate::error;
+use crate::scheduler::info::WorkloadStats;
 use crate::scheduler::system::WorkloadRunIfFn;
+use crate::scheduler::{ErrorPolicy, Label, SystemLocals, SystemRecord};
 use crate::world::World;
+use crate::ShipHashMap;
 use alloc::boxed::Box;
+use alloc::format;
+use alloc::sync::Arc;
 use alloc::vec::Vec;
 
 /// List of indexes into both systems and system_names
@@ -16,8 +21,42 @@ pub(crate) struct Batches {
     pub(crate) sequential: Vec<usize>,
     /// Index into `systems_run_if`
     pub(crate) sequential_run_if: Vec<usize>,
+    /// `unique_id` of the systems in `parallel`
+    pub(crate) parallel_unique_ids: Vec<(usize, Vec<usize>)>,
+    /// `unique_id` of the systems in `sequential`
+    pub(crate) sequential_unique_ids: Vec<usize>,
+    /// Index into `parallel` of the batches that have to be preceded by a commands flush
+    pub(crate) parallel_flush: Vec<usize>,
+    /// Index into `sequential` of the systems that have to be preceded by a commands flush
+    pub(crate) sequential_flush: Vec<usize>,
     pub(crate) workload_run_if: Option<Box<dyn WorkloadRunIfFn>>,
-    pub(crate) systems_run_if: Vec<Box<dyn Fn(&World) -> Result<bool, error::Run> + Send + Sync>>,
+    pub(crate) systems_run_if: Vec<Arc<dyn Fn(&World) -> Result<bool, error::Run> + Send + Sync>>,
+    /// Index into the list of systems to the statistics of this system in this workload
+    pub(crate) stats: ShipHashMap<usize, SystemRecord>,
+    /// Index into the list of systems to the error policy of this system in this workload
+    pub(crate) error_policies: ShipHashMap<usize, ErrorPolicy>,
+    /// `unique_id` of a system to the state of its `Local` views in this workload
+    pub(crate) locals: ShipHashMap<usize, SystemLocals>,
+}
+
+impl Batches {
+    /// Returns the statistics of the systems in the order they run.
+    pub(crate) fn stats(&self, name: &dyn Label, system_names: &[Box<dyn Label>]) -> WorkloadStats {
+        WorkloadStats {
+            name: format!("{:?}", name),
+            systems: self
+                .sequential
+                .iter()
+                .map(|&index| self.stats[&index].stats(format!("{:?}", system_names[index])))
+                .collect(),
+        }
+    }
+
+    pub(crate) fn reset_stats(&self) {
+        for record in self.stats.values() {
+            record.reset();
+        }
+    }
 }
 
 #[cfg(test)]
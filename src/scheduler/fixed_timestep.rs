@@ -0,0 +1,172 @@
+use crate::component::Unique;
+use crate::error;
+use crate::scheduler::{AsLabel, Label};
+use crate::views::UniqueOrDefaultViewMut;
+use crate::world::World;
+use crate::ShipHashMap;
+use alloc::boxed::Box;
+use core::time::Duration;
+
+/// Runs a workload at a fixed rate, independently of the rate at which it is called.
+///
+/// Each call to [`run`](FixedTimestep::run) adds the elapsed time to an accumulator
+/// and runs the workload once for each full `step` it contains.\
+/// Each sub-step is a full [`World::run_workload`], tracking timestamps advance between them
+/// like they would between two frames.
+///
+/// After each call the fraction of a step left in the accumulator is stored in the [`FixedTimestepAlpha`] unique,
+/// under the workload's label, it can be used to interpolate between the last two states when rendering.
+///
+/// ### Example
+/// ```
+/// use core::time::Duration;
+/// use shipyard::scheduler::{FixedTimestep, FixedTimestepAlpha};
+/// use shipyard::{Unique, UniqueView, UniqueViewMut, Workload, World};
+///
+/// #[derive(Unique)]
+/// struct Ticks(u32);
+///
+/// fn tick(mut ticks: UniqueViewMut<Ticks>) {
+///     ticks.0 += 1;
+/// }
+///
+/// let world = World::new();
+/// world.add_unique(Ticks(0));
+///
+/// Workload::new("Physics")
+///     .with_system(tick)
+///     .add_to_world(&world)
+///     .unwrap();
+///
+/// let mut physics = FixedTimestep::new("Physics", Duration::from_millis(10));
+///
+/// assert_eq!(physics.run(&world, Duration::from_millis(25)).unwrap(), 2);
+/// assert_eq!(world.borrow::<UniqueView<Ticks>>().unwrap().0, 2);
+/// assert_eq!(
+///     world.borrow::<UniqueView<FixedTimestepAlpha>>().unwrap().get("Physics"),
+///     Some(0.5)
+/// );
+///
+/// assert_eq!(physics.run(&world, Duration::from_millis(5)).unwrap(), 1);
+/// assert_eq!(world.borrow::<UniqueView<Ticks>>().unwrap().0, 3);
+/// ```
+pub struct FixedTimestep {
+    workload: Box<dyn Label>,
+    step: Duration,
+    max_steps: u32,
+    accumulator: Duration,
+}
+
+/// Fraction of a step left in the accumulator of each [`FixedTimestep`], between `0.0` and `1.0`.\
+/// The fractions are identified by the label of the workload the `FixedTimestep` runs.
+#[derive(Default, Clone, Debug)]
+pub struct FixedTimestepAlpha {
+    alphas: ShipHashMap<Box<dyn Label>, f32>,
+}
+
+impl Unique for FixedTimestepAlpha {}
+
+impl FixedTimestepAlpha {
+    /// Returns the fraction of a step left after the last run of the `FixedTimestep` running `workload`.\
+    /// Returns `None` if no such `FixedTimestep` ran.
+    pub fn get<T>(&self, workload: impl AsLabel<T>) -> Option<f32> {
+        self.alphas.get(&workload.as_label()).copied()
+    }
+}
+
+impl FixedTimestep {
+    /// Default maximum number of sub-steps per [`run`](FixedTimestep::run).
+    pub const DEFAULT_MAX_STEPS: u32 = 5;
+
+    /// Creates a runner for the `workload` workload, running it every `step`.
+    ///
+    /// ### Panics
+    ///
+    /// - `step` is zero.
+    #[track_caller]
+    pub fn new<T>(workload: impl AsLabel<T>, step: Duration) -> FixedTimestep {
+        assert!(
+            !step.is_zero(),
+            "The step of a FixedTimestep can't be zero."
+        );
+
+        FixedTimestep {
+            workload: workload.as_label(),
+            step,
+            max_steps: FixedTimestep::DEFAULT_MAX_STEPS,
+            accumulator: Duration::ZERO,
+        }
+    }
+    /// Sets the maximum number of sub-steps a single [`run`](FixedTimestep::run) can catch up.\
+    /// When more time than this has accumulated, the extra steps are dropped.
+    pub fn with_max_steps(mut self, max_steps: u32) -> FixedTimestep {
+        self.max_steps = max_steps;
+
+        self
+    }
+    /// Returns the duration of a step.
+    pub fn step(&self) -> Duration {
+        self.step
+    }
+    /// Returns the time accumulated and not yet consumed by a step.
+    pub fn accumulator(&self) -> Duration {
+        self.accumulator
+    }
+    /// Returns the fraction of a step left in the accumulator, between `0.0` and `1.0`.
+    pub fn alpha(&self) -> f32 {
+        self.accumulator.as_secs_f32() / self.step.as_secs_f32()
+    }
+    /// Adds `delta` to the accumulator then runs the workload once per full step, up to the maximum number of sub-steps.\
+    /// Returns the number of sub-steps that ran.
+    ///
+    /// If a sub-step fails, its time stays in the accumulator and the error is returned,
+    /// [`FixedTimestepAlpha`] is still updated.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (shared)
+    /// - Systems' borrow as they are executed
+    /// - [`FixedTimestepAlpha`] unique storage (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - See [`World::run_workload`].
+    ///
+    /// ### Panics
+    ///
+    /// - [`FixedTimestepAlpha`] unique storage borrow failed.
+    #[track_caller]
+    pub fn run(&mut self, world: &World, delta: Duration) -> Result<u32, error::RunWorkload> {
+        self.accumulator += delta;
+
+        let mut steps = 0;
+        while self.accumulator >= self.step && steps < self.max_steps {
+            if let Err(err) = world.run_workload(self.workload.clone()) {
+                self.store_alpha(world);
+
+                return Err(err);
+            }
+
+            self.accumulator -= self.step;
+            steps += 1;
+        }
+
+        if self.accumulator >= self.step {
+            // drop the steps we couldn't catch up
+            self.accumulator =
+                Duration::from_nanos((self.accumulator.as_nanos() % self.step.as_nanos()) as u64);
+        }
+
+        self.store_alpha(world);
+
+        Ok(steps)
+    }
+    #[track_caller]
+    fn store_alpha(&self, world: &World) {
+        world
+            .borrow::<UniqueOrDefaultViewMut<'_, FixedTimestepAlpha>>()
+            .unwrap()
+            .alphas
+            .insert(self.workload.clone(), self.alpha());
+    }
+}
@@ -6,9 +6,12 @@ use crate::storage::StorageId;
 use crate::ShipHashMap;
 use alloc::borrow::Cow;
 use alloc::boxed::Box;
+use alloc::format;
 use alloc::string::String;
 use alloc::vec::Vec;
 use core::any::TypeId;
+use core::fmt::Write;
+use core::time::Duration;
 
 /// Contains information related to a workload.
 ///
@@ -20,6 +23,11 @@ pub struct WorkloadInfo {
     pub name: String,
     #[allow(missing_docs)]
     pub batches_info: Vec<BatchInfo>,
+    /// Pairs of systems whose order only depends on the order they were added to the workload.
+    ///
+    /// See [`Workload::allow_ambiguity`](crate::Workload::allow_ambiguity).
+    #[cfg_attr(feature = "serde1", serde(default))]
+    pub ambiguities: Vec<Ambiguity>,
 }
 
 /// Contains information related to a batch.
@@ -32,11 +40,318 @@ pub struct BatchInfo {
     pub systems: (Option<SystemInfo>, Vec<SystemInfo>),
 }
 
+impl WorkloadInfo {
+    /// Renders the workload as a [Graphviz](https://graphviz.org) DOT digraph.
+    ///
+    /// Each batch is a cluster of system nodes listing the storages they borrow.\
+    /// Solid edges are the ordering constraints, labelled with the `before_all`/`after_all` tag when there is one.\
+    /// Dashed edges point from the system that kept another out of the previous batch, labelled with the conflicting borrows.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, View, ViewMut, Workload};
+    ///
+    /// #[derive(Component)]
+    /// struct Health(u32);
+    ///
+    /// fn heal(mut healths: ViewMut<Health>) {}
+    /// fn display(healths: View<Health>) {}
+    ///
+    /// let (_, info) = Workload::new("Main")
+    ///     .with_system(heal)
+    ///     .with_system(display)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let dot = info.to_dot();
+    /// assert!(dot.starts_with("digraph \"Main\" {"));
+    /// assert!(dot.contains("subgraph cluster_1"));
+    /// ```
+    pub fn to_dot(&self) -> String {
+        render_dot(&self.name, self.batches_info.iter().enumerate())
+    }
+
+    /// Renders the workload as a [Mermaid](https://mermaid.js.org) flowchart.
+    ///
+    /// Uses the same layout as [`WorkloadInfo::to_dot`], each batch is a subgraph.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, View, ViewMut, Workload};
+    ///
+    /// #[derive(Component)]
+    /// struct Health(u32);
+    ///
+    /// fn heal(mut healths: ViewMut<Health>) {}
+    /// fn display(healths: View<Health>) {}
+    ///
+    /// let (_, info) = Workload::new("Main")
+    ///     .with_system(heal)
+    ///     .with_system(display)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let mermaid = info.to_mermaid();
+    /// assert!(mermaid.starts_with("flowchart TB"));
+    /// assert!(mermaid.contains("subgraph batch_1"));
+    /// ```
+    pub fn to_mermaid(&self) -> String {
+        render_mermaid(&self.name, self.batches_info.iter().enumerate())
+    }
+}
+
 impl BatchInfo {
     /// Returns an iterator of all systems in this batch
     pub fn systems(&self) -> impl Iterator<Item = &'_ SystemInfo> {
         self.systems.0.iter().chain(&self.systems.1)
     }
+
+    /// Renders this batch alone as a Graphviz DOT digraph.\
+    /// Edges to systems outside of the batch are omitted.
+    ///
+    /// See [`WorkloadInfo::to_dot`] for the layout.
+    pub fn to_dot(&self) -> String {
+        render_dot("batch", core::iter::once((0, self)))
+    }
+
+    /// Renders this batch alone as a Mermaid flowchart.\
+    /// Edges to systems outside of the batch are omitted.
+    ///
+    /// See [`WorkloadInfo::to_dot`] for the layout.
+    pub fn to_mermaid(&self) -> String {
+        render_mermaid("batch", core::iter::once((0, self)))
+    }
+}
+
+/// Ordering or conflict relation between two systems, identified by their `unique_id`.
+struct Edge {
+    from: usize,
+    to: usize,
+    label: Option<String>,
+    is_conflict: bool,
+}
+
+fn edges(systems: &[&SystemInfo]) -> Vec<Edge> {
+    let find = |unique_id: usize| {
+        systems
+            .iter()
+            .find(|system| system.unique_id == unique_id)
+            .copied()
+    };
+
+    let mut edges = Vec::new();
+
+    for system in systems {
+        let conflict = match &system.conflict {
+            Some(Conflict::Borrow {
+                type_info,
+                other_system,
+                other_type_info,
+            }) => Some((
+                *other_system,
+                format!(
+                    "{} / {}",
+                    borrow_name(other_type_info),
+                    borrow_name(type_info)
+                ),
+            )),
+            Some(Conflict::OtherNotSendSync { system, type_info }) => {
+                Some((*system, format!("!Send !Sync {}", borrow_name(type_info))))
+            }
+            Some(Conflict::NotSendSync(_)) | None => None,
+        };
+
+        for &other_id in &system.after {
+            let Some(other) = find(other_id) else {
+                continue;
+            };
+
+            let label = system
+                .after_all
+                .iter()
+                .find(|constraint| constraint.other_system == other_id)
+                .map(|constraint| format!("after_all {}", constraint.constraint))
+                .or_else(|| {
+                    other
+                        .before_all
+                        .iter()
+                        .find(|constraint| constraint.other_system == system.unique_id)
+                        .map(|constraint| format!("before_all {}", constraint.constraint))
+                });
+
+            // the conflict edge already shows this ordering
+            if label.is_none() && conflict.as_ref().is_some_and(|(id, _)| *id == other_id) {
+                continue;
+            }
+
+            edges.push(Edge {
+                from: other_id,
+                to: system.unique_id,
+                label,
+                is_conflict: false,
+            });
+        }
+
+        if let Some((other_id, label)) = conflict {
+            if find(other_id).is_some() {
+                edges.push(Edge {
+                    from: other_id,
+                    to: system.unique_id,
+                    label: Some(label),
+                    is_conflict: true,
+                });
+            }
+        }
+    }
+
+    edges
+}
+
+/// Lines displayed in a system's node: its name, borrows and `!Send`/`!Sync` conflict.
+fn node_lines(system: &SystemInfo) -> Vec<String> {
+    let mut lines = Vec::with_capacity(system.borrow.len() + 2);
+
+    lines.push(system.name.clone());
+    lines.extend(system.borrow.iter().map(borrow_name));
+
+    if let Some(Conflict::NotSendSync(type_info)) = &system.conflict {
+        lines.push(format!("!Send !Sync {}", borrow_name(type_info)));
+    }
+
+    lines
+}
+
+fn borrow_name(type_info: &TypeInfo) -> String {
+    match type_info.mutability {
+        Mutability::Shared => format!("&{}", type_info.name),
+        Mutability::Exclusive => format!("&mut {}", type_info.name),
+    }
+}
+
+fn render_dot<'a>(name: &str, batches: impl Iterator<Item = (usize, &'a BatchInfo)>) -> String {
+    let mut dot = String::new();
+    let mut systems = Vec::new();
+
+    let _ = writeln!(dot, "digraph \"{}\" {{", escape_dot(name));
+    dot.push_str("    node [shape=box];\n");
+
+    for (index, batch) in batches {
+        let _ = writeln!(dot, "    subgraph cluster_{} {{", index);
+        let _ = writeln!(dot, "        label=\"Batch {}\";", index);
+
+        for system in batch.systems() {
+            let label = node_lines(system)
+                .iter()
+                .map(|line| escape_dot(line))
+                .collect::<Vec<_>>()
+                .join("\\n");
+
+            let _ = writeln!(
+                dot,
+                "        system_{} [label=\"{}\"];",
+                system.unique_id, label
+            );
+
+            systems.push(system);
+        }
+
+        dot.push_str("    }\n");
+    }
+
+    for edge in edges(&systems) {
+        let _ = write!(dot, "    system_{} -> system_{}", edge.from, edge.to);
+
+        match (edge.label, edge.is_conflict) {
+            (Some(label), true) => {
+                let _ = write!(
+                    dot,
+                    " [style=dashed, color=red, label=\"{}\"]",
+                    escape_dot(&label)
+                );
+            }
+            (Some(label), false) => {
+                let _ = write!(dot, " [label=\"{}\"]", escape_dot(&label));
+            }
+            (None, true) => dot.push_str(" [style=dashed, color=red]"),
+            (None, false) => {}
+        }
+
+        dot.push_str(";\n");
+    }
+
+    dot.push_str("}\n");
+
+    dot
+}
+
+fn render_mermaid<'a>(name: &str, batches: impl Iterator<Item = (usize, &'a BatchInfo)>) -> String {
+    let mut mermaid = String::new();
+    let mut systems = Vec::new();
+
+    mermaid.push_str("flowchart TB\n");
+    let _ = writeln!(mermaid, "    %% {}", name.replace('\n', " "));
+
+    for (index, batch) in batches {
+        let _ = writeln!(mermaid, "    subgraph batch_{}[\"Batch {}\"]", index, index);
+
+        for system in batch.systems() {
+            let label = node_lines(system)
+                .iter()
+                .map(|line| escape_mermaid(line))
+                .collect::<Vec<_>>()
+                .join("<br/>");
+
+            let _ = writeln!(
+                mermaid,
+                "        system_{}[\"{}\"]",
+                system.unique_id, label
+            );
+
+            systems.push(system);
+        }
+
+        mermaid.push_str("    end\n");
+    }
+
+    for edge in edges(&systems) {
+        let (arrow_start, arrow_end) = if edge.is_conflict {
+            ("-.", ".->")
+        } else {
+            ("--", "-->")
+        };
+
+        let _ = match edge.label {
+            Some(label) => writeln!(
+                mermaid,
+                "    system_{} {} \"{}\" {} system_{}",
+                edge.from,
+                arrow_start,
+                escape_mermaid(&label),
+                arrow_end,
+                edge.to
+            ),
+            None => writeln!(
+                mermaid,
+                "    system_{} {} system_{}",
+                edge.from, arrow_end, edge.to
+            ),
+        };
+    }
+
+    mermaid
+}
+
+fn escape_dot(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn escape_mermaid(text: &str) -> String {
+    text.replace('&', "#amp;")
+        .replace('"', "#quot;")
+        .replace('<', "#lt;")
+        .replace('>', "#gt;")
 }
 
 /// Contains information related to a system.
@@ -49,7 +364,7 @@ pub struct SystemInfo {
     pub borrow: Vec<TypeInfo>,
     /// Information explaining why this system could not be part of the previous batch.
     pub conflict: Option<Conflict>,
-    /// Contains all ordering information the scheduler used to place this system
+    /// Contains all ordering information the scheduler used to place this system, as `unique_id`s of other systems
     pub after: Vec<usize>,
     /// List of all after_all constraints
     pub after_all: Vec<BeforeAfterConstraint>,
@@ -97,6 +412,56 @@ pub enum Conflict {
     },
 }
 
+/// Two systems borrowing the same storage, at least one of them mutably, without any ordering constraint between them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde1", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ambiguity {
+    /// `unique_id` of the system added first to the workload, it currently runs first.
+    pub system: usize,
+    #[allow(missing_docs)]
+    pub type_info: TypeInfo,
+    /// `unique_id` of the system added last to the workload.
+    pub other_system: usize,
+    #[allow(missing_docs)]
+    pub other_type_info: TypeInfo,
+}
+
+/// Execution statistics of a workload's systems, in the order they run.
+///
+/// Statistics are only collected once profiling is enabled with [`World::enable_profiling`].
+///
+/// [`World::enable_profiling`]: crate::World::enable_profiling
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde1", derive(serde::Serialize, serde::Deserialize))]
+pub struct WorkloadStats {
+    #[allow(missing_docs)]
+    pub name: String,
+    #[allow(missing_docs)]
+    pub systems: Vec<SystemStats>,
+}
+
+/// Execution statistics of a system, since profiling was enabled or the last reset.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde1", derive(serde::Serialize, serde::Deserialize))]
+pub struct SystemStats {
+    #[allow(missing_docs)]
+    pub name: String,
+    /// Number of times the system ran, including the runs returning an error.
+    pub calls: u64,
+    /// Number of times the system's `run_if` prevented it from running.
+    pub skipped: u64,
+    /// Number of times the system returned an error.
+    pub errors: u64,
+    /// Wall time of all runs.
+    pub total: Duration,
+    /// Shortest run, zero if the system never ran.
+    pub min: Duration,
+    /// Longest run.
+    pub max: Duration,
+    /// Average run.
+    pub mean: Duration,
+}
+
 /// Identify a type.
 #[derive(Clone, Eq)]
 #[cfg_attr(feature = "serde1", derive(serde::Serialize, serde::Deserialize))]
@@ -0,0 +1,472 @@
+use crate::all_storages::AllStorages;
+use crate::borrow::{BorrowInfo, WorldBorrow};
+use crate::error;
+use crate::scheduler::info::DedupedLabels;
+use crate::scheduler::into_workload_system::check_borrows;
+use crate::scheduler::label::SystemLabel;
+use crate::scheduler::{AsLabel, LocalSystem, SystemLocals, TypeInfo, WorkloadSystem};
+use crate::tracking::TrackingTimestamp;
+use crate::world::World;
+use alloc::boxed::Box;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::any::type_name;
+#[cfg(not(feature = "std"))]
+use core::any::Any;
+use core::any::TypeId;
+use core::sync::atomic::{AtomicU64, Ordering};
+#[cfg(feature = "std")]
+use std::error::Error;
+
+/// Combines a system with another one receiving its output.
+///
+/// This trait is implemented for all functions that can become a system, it has to be in scope to use its methods.
+pub trait SystemPipe<B, R>: Sized {
+    /// Combines two systems in a single [`WorkloadSystem`].\
+    /// `other` runs right after `self` and receives its return value as first argument,
+    /// the same way [`World::run_with_data`](crate::World::run_with_data) passes data.\
+    /// The return value of `other` is dropped, use [`try_pipe`](SystemPipe::try_pipe) to report its errors.
+    ///
+    /// The combined system borrows the storages of both systems, but never at the same time.\
+    /// Both systems share the same [`Local`](crate::views::Local) state.
+    ///
+    /// ### Panics
+    ///
+    /// - Either system is invalid, see [`IntoWorkloadSystem::into_workload_system`](crate::scheduler::IntoWorkloadSystem::into_workload_system) and [`IntoPipeSystem::into_pipe_system`].
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::scheduler::SystemPipe;
+    /// use shipyard::{Component, IntoIter, View, Workload, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Health(u32);
+    ///
+    /// #[derive(Component)]
+    /// struct Dead;
+    ///
+    /// fn count_dead(healths: View<Health>) -> usize {
+    ///     healths.iter().filter(|health| health.0 == 0).count()
+    /// }
+    ///
+    /// fn report(dead: usize, deads: View<Dead>) {
+    ///     assert_eq!(dead, 1);
+    ///     assert_eq!(deads.len(), 0);
+    /// }
+    ///
+    /// let mut world = World::new();
+    ///
+    /// world.add_entity(Health(0));
+    /// world.add_entity(Health(10));
+    ///
+    /// Workload::new("Update")
+    ///     .with_system(count_dead.pipe(report))
+    ///     .add_to_world(&world)
+    ///     .unwrap();
+    ///
+    /// world.run_workload("Update").unwrap();
+    /// ```
+    #[track_caller]
+    fn pipe<OB, OR, Other: IntoPipeSystem<R, OB, OR> + 'static>(
+        self,
+        other: Other,
+    ) -> WorkloadSystem {
+        self.pipe_with::<OB, OR, Other>(other.into_pipe_system().unwrap())
+    }
+    /// Same as [`pipe`](SystemPipe::pipe) but `other` is fallible.\
+    /// The workload will stop if `other` returns an error, unless a different [`ErrorPolicy`](crate::scheduler::ErrorPolicy) is set.
+    ///
+    /// ### Panics
+    ///
+    /// - Either system is invalid, see [`IntoWorkloadSystem::into_workload_system`](crate::scheduler::IntoWorkloadSystem::into_workload_system) and [`IntoPipeSystem::into_pipe_try_system`].
+    #[cfg(feature = "std")]
+    #[track_caller]
+    fn try_pipe<
+        OB,
+        OR,
+        Ok,
+        Err: Into<Box<dyn Error + Send + Sync>>,
+        Other: IntoPipeSystem<R, OB, OR> + 'static,
+    >(
+        self,
+        other: Other,
+    ) -> WorkloadSystem
+    where
+        OR: Into<Result<Ok, Err>>,
+    {
+        self.pipe_with::<OB, OR, Other>(other.into_pipe_try_system().unwrap())
+    }
+    /// Same as [`pipe`](SystemPipe::pipe) but `other` is fallible.\
+    /// The workload will stop if `other` returns an error, unless a different [`ErrorPolicy`](crate::scheduler::ErrorPolicy) is set.
+    ///
+    /// ### Panics
+    ///
+    /// - Either system is invalid, see [`IntoWorkloadSystem::into_workload_system`](crate::scheduler::IntoWorkloadSystem::into_workload_system) and [`IntoPipeSystem::into_pipe_try_system`].
+    #[cfg(not(feature = "std"))]
+    #[track_caller]
+    fn try_pipe<OB, OR, Ok, Err: 'static + Send + Any, Other: IntoPipeSystem<R, OB, OR> + 'static>(
+        self,
+        other: Other,
+    ) -> WorkloadSystem
+    where
+        OR: Into<Result<Ok, Err>>,
+    {
+        self.pipe_with::<OB, OR, Other>(other.into_pipe_try_system().unwrap())
+    }
+    #[doc(hidden)]
+    #[track_caller]
+    fn pipe_with<OB, OR, Other: IntoPipeSystem<R, OB, OR> + 'static>(
+        self,
+        other: PipeSystem<R>,
+    ) -> WorkloadSystem;
+}
+
+/// Validates a function can receive the output of another system.
+///
+/// The function takes the output of the previous system as first argument, the same way [`World::run_with_data`] passes data.\
+/// See [`SystemPipe::pipe`].
+pub trait IntoPipeSystem<Data, B, R> {
+    /// Wraps a function in a struct containing all information required to run it after another system.
+    fn into_pipe_system(self) -> Result<PipeSystem<Data>, error::InvalidSystem>;
+    /// Wraps a fallible function in a struct containing all information required to run it after another system.\
+    /// Its errors are returned by the combined system.
+    #[cfg(feature = "std")]
+    fn into_pipe_try_system<Ok, Err: Into<Box<dyn Error + Send + Sync>>>(
+        self,
+    ) -> Result<PipeSystem<Data>, error::InvalidSystem>
+    where
+        R: Into<Result<Ok, Err>>;
+    /// Wraps a fallible function in a struct containing all information required to run it after another system.\
+    /// Its errors are returned by the combined system.
+    #[cfg(not(feature = "std"))]
+    fn into_pipe_try_system<Ok, Err: 'static + Send + Any>(
+        self,
+    ) -> Result<PipeSystem<Data>, error::InvalidSystem>
+    where
+        R: Into<Result<Ok, Err>>;
+    #[doc(hidden)]
+    fn init_locals(locals: &mut SystemLocals);
+}
+
+/// Second half of a piped system.
+#[allow(clippy::type_complexity)]
+pub struct PipeSystem<Data> {
+    pub(crate) borrow_constraints: Vec<TypeInfo>,
+    pub(crate) tracking_to_enable: Vec<fn(&AllStorages) -> Result<(), error::GetStorage>>,
+    pub(crate) system_fn: Box<
+        dyn Fn(
+                Data,
+                &World,
+                Option<TrackingTimestamp>,
+                TrackingTimestamp,
+                &SystemLocals,
+            ) -> Result<(), error::Run>
+            + Send
+            + Sync
+            + 'static,
+    >,
+}
+
+pub struct Nothing;
+
+impl<Data, R, F> IntoPipeSystem<Data, Nothing, R> for F
+where
+    F: 'static + Send + Sync + Fn(Data) -> R,
+{
+    fn into_pipe_system(self) -> Result<PipeSystem<Data>, error::InvalidSystem> {
+        Ok(PipeSystem {
+            borrow_constraints: Vec::new(),
+            tracking_to_enable: Vec::new(),
+            system_fn: Box::new(move |data: Data, _: &World, _, _, _: &SystemLocals| {
+                (self)(data);
+                Ok(())
+            }),
+        })
+    }
+    #[cfg(feature = "std")]
+    fn into_pipe_try_system<Ok, Err: Into<Box<dyn Error + Send + Sync>>>(
+        self,
+    ) -> Result<PipeSystem<Data>, error::InvalidSystem>
+    where
+        R: Into<Result<Ok, Err>>,
+    {
+        Ok(PipeSystem {
+            borrow_constraints: Vec::new(),
+            tracking_to_enable: Vec::new(),
+            system_fn: Box::new(move |data: Data, _: &World, _, _, _: &SystemLocals| {
+                (self)(data).into().map_err(error::Run::from_custom)?;
+                Ok(())
+            }),
+        })
+    }
+    #[cfg(not(feature = "std"))]
+    fn into_pipe_try_system<Ok, Err: 'static + Send + Any>(
+        self,
+    ) -> Result<PipeSystem<Data>, error::InvalidSystem>
+    where
+        R: Into<Result<Ok, Err>>,
+    {
+        Ok(PipeSystem {
+            borrow_constraints: Vec::new(),
+            tracking_to_enable: Vec::new(),
+            system_fn: Box::new(move |data: Data, _: &World, _, _, _: &SystemLocals| {
+                (self)(data).into().map_err(error::Run::from_custom)?;
+                Ok(())
+            }),
+        })
+    }
+    fn init_locals(_: &mut SystemLocals) {}
+}
+
+impl<R, F> SystemPipe<crate::scheduler::into_workload_system::Nothing, R> for F
+where
+    R: 'static,
+    F: 'static + Send + Sync + Fn() -> R,
+{
+    fn pipe_with<OB, OR, Other: IntoPipeSystem<R, OB, OR> + 'static>(
+        self,
+        other: PipeSystem<R>,
+    ) -> WorkloadSystem {
+        let other_borrows = other.borrow_constraints.clone();
+        let other_fn = other.system_fn;
+        let label = SystemLabel {
+            type_id: TypeId::of::<(F, Other)>(),
+            name: type_name::<(F, Other)>().as_label(),
+        };
+        let last_run = AtomicU64::new(0);
+
+        let (system_fn, local_system) = LocalSystem::split(
+            move |world: &World, locals: &SystemLocals| {
+                let current = world.get_current();
+                let last_run =
+                    TrackingTimestamp::new(last_run.swap(current.get(), Ordering::Acquire));
+                (other_fn)((self)(), world, Some(last_run), current, locals.piped())
+            },
+            || {
+                let mut piped = SystemLocals::new();
+                Other::init_locals(&mut piped);
+
+                let mut locals = SystemLocals::new();
+                locals.set_piped(piped);
+
+                locals
+            },
+        );
+
+        WorkloadSystem {
+            borrow_constraints: other.borrow_constraints,
+            tracking_to_enable: other.tracking_to_enable,
+            display_name: Box::new(label.clone()),
+            tags: vec![Box::new(label)],
+            system_fn,
+            type_id: TypeId::of::<(F, Other)>(),
+            generator: Box::new(move |constraints| {
+                constraints.extend_from_slice(&other_borrows);
+
+                TypeId::of::<(F, Other)>()
+            }),
+            before_all: DedupedLabels::new(),
+            after_all: DedupedLabels::new(),
+            after: Vec::new(),
+            before: Vec::new(),
+            unique_id: 0,
+            run_if: None,
+            require_in_workload: DedupedLabels::new(),
+            require_before: DedupedLabels::new(),
+            require_after: DedupedLabels::new(),
+            error_policy: None,
+            local_system,
+        }
+    }
+}
+
+macro_rules! impl_into_pipe_system {
+    ($(($type: ident, $index: tt))+) => {
+        impl<Data, $($type: WorldBorrow + BorrowInfo,)+ Ret, Func> IntoPipeSystem<Data, ($($type,)+), Ret> for Func
+        where
+            Func: 'static
+                + Send
+                + Sync,
+            for<'a, 'b> &'b Func:
+                Fn(Data, $($type),+) -> Ret
+                + Fn(Data, $($type::WorldView<'a>),+) -> Ret {
+
+            fn into_pipe_system(self) -> Result<PipeSystem<Data>, error::InvalidSystem> {
+                let mut borrows = Vec::new();
+                $(
+                    $type::borrow_info(&mut borrows);
+                )+
+
+                check_borrows(&borrows)?;
+
+                let mut tracking_to_enable = Vec::new();
+                $(
+                    $type::enable_tracking(&mut tracking_to_enable);
+                )+
+
+                Ok(PipeSystem {
+                    borrow_constraints: borrows,
+                    tracking_to_enable,
+                    system_fn: Box::new(move |data: Data, world: &World, last_run, current, locals: &SystemLocals| {
+                        Ok(drop((&&self)(data, $($type::world_borrow_in_system(&world, last_run, current, locals)?),+)))
+                    }),
+                })
+            }
+            #[cfg(feature = "std")]
+            fn into_pipe_try_system<Ok, Err: Into<Box<dyn Error + Send + Sync>>>(self) -> Result<PipeSystem<Data>, error::InvalidSystem> where Ret: Into<Result<Ok, Err>> {
+                let mut borrows = Vec::new();
+                $(
+                    $type::borrow_info(&mut borrows);
+                )+
+
+                check_borrows(&borrows)?;
+
+                let mut tracking_to_enable = Vec::new();
+                $(
+                    $type::enable_tracking(&mut tracking_to_enable);
+                )+
+
+                Ok(PipeSystem {
+                    borrow_constraints: borrows,
+                    tracking_to_enable,
+                    system_fn: Box::new(move |data: Data, world: &World, last_run, current, locals: &SystemLocals| {
+                        Ok(drop((&&self)(data, $($type::world_borrow_in_system(&world, last_run, current, locals)?),+).into().map_err(error::Run::from_custom)?))
+                    }),
+                })
+            }
+            #[cfg(not(feature = "std"))]
+            fn into_pipe_try_system<Ok, Err: 'static + Send + Any>(self) -> Result<PipeSystem<Data>, error::InvalidSystem> where Ret: Into<Result<Ok, Err>> {
+                let mut borrows = Vec::new();
+                $(
+                    $type::borrow_info(&mut borrows);
+                )+
+
+                check_borrows(&borrows)?;
+
+                let mut tracking_to_enable = Vec::new();
+                $(
+                    $type::enable_tracking(&mut tracking_to_enable);
+                )+
+
+                Ok(PipeSystem {
+                    borrow_constraints: borrows,
+                    tracking_to_enable,
+                    system_fn: Box::new(move |data: Data, world: &World, last_run, current, locals: &SystemLocals| {
+                        Ok(drop((&&self)(data, $($type::world_borrow_in_system(&world, last_run, current, locals)?),+).into().map_err(error::Run::from_custom)?))
+                    }),
+                })
+            }
+            fn init_locals(locals: &mut SystemLocals) {
+                $(
+                    $type::init_locals(locals);
+                )+
+            }
+        }
+
+        impl<$($type: WorldBorrow + BorrowInfo,)+ Ret, Func> SystemPipe<($($type,)+), Ret> for Func
+        where
+            Ret: 'static,
+            Func: 'static
+                + Send
+                + Sync,
+            for<'a, 'b> &'b Func:
+                Fn($($type),+) -> Ret
+                + Fn($($type::WorldView<'a>),+) -> Ret {
+
+            #[track_caller]
+            fn pipe_with<OB, OR, Other: IntoPipeSystem<Ret, OB, OR> + 'static>(self, other: PipeSystem<Ret>) -> WorkloadSystem {
+                let mut borrows = Vec::new();
+                $(
+                    $type::borrow_info(&mut borrows);
+                )+
+
+                check_borrows(&borrows).unwrap();
+
+                let mut tracking_to_enable = Vec::new();
+                $(
+                    $type::enable_tracking(&mut tracking_to_enable);
+                )+
+                tracking_to_enable.extend(other.tracking_to_enable);
+
+                // both systems run one after the other, they can borrow the same storages
+                let other_borrows = other.borrow_constraints.clone();
+                borrows.extend(other.borrow_constraints);
+
+                let label = SystemLabel {
+                    type_id: TypeId::of::<(Func, Other)>(),
+                    name: type_name::<(Func, Other)>().as_label(),
+                };
+                let other_fn = other.system_fn;
+                let last_run = AtomicU64::new(0);
+                let (system_fn, local_system) = LocalSystem::split(
+                    move |world: &World, locals: &SystemLocals| {
+                        let current = world.get_current();
+                        let last_run = TrackingTimestamp::new(last_run.swap(current.get(), Ordering::Acquire));
+                        let data = (&&self)($($type::world_borrow_in_system(&world, Some(last_run), current, locals)?),+);
+                        (other_fn)(data, world, Some(last_run), current, locals.piped())
+                    },
+                    || {
+                        let mut locals = SystemLocals::new();
+                        $(
+                            $type::init_locals(&mut locals);
+                        )+
+
+                        let mut piped = SystemLocals::new();
+                        Other::init_locals(&mut piped);
+                        locals.set_piped(piped);
+
+                        locals
+                    },
+                );
+
+                WorkloadSystem {
+                    borrow_constraints: borrows,
+                    tracking_to_enable,
+                    display_name: Box::new(label.clone()),
+                    tags: vec![Box::new(label)],
+                    system_fn,
+                    type_id: TypeId::of::<(Func, Other)>(),
+                    before_all: DedupedLabels::new(),
+                    after_all: DedupedLabels::new(),
+                    after: Vec::new(),
+                    before: Vec::new(),
+                    unique_id: 0,
+                    generator: Box::new(move |constraints| {
+                        $(
+                            $type::borrow_info(constraints);
+                        )+
+                        constraints.extend_from_slice(&other_borrows);
+
+                        TypeId::of::<(Func, Other)>()
+                    }),
+                    run_if: None,
+                    require_in_workload: DedupedLabels::new(),
+                    require_before: DedupedLabels::new(),
+                    require_after: DedupedLabels::new(),
+                    error_policy: None,
+                    local_system,
+                }
+            }
+        }
+    }
+}
+
+macro_rules! into_pipe_system {
+    ($(($type: ident, $index: tt))*;($type1: ident, $index1: tt) $(($queue_type: ident, $queue_index: tt))*) => {
+        impl_into_pipe_system![$(($type, $index))*];
+        into_pipe_system![$(($type, $index))* ($type1, $index1); $(($queue_type, $queue_index))*];
+    };
+    ($(($type: ident, $index: tt))*;) => {
+        impl_into_pipe_system![$(($type, $index))*];
+    }
+}
+
+#[cfg(not(feature = "extended_tuple"))]
+into_pipe_system![(A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)];
+#[cfg(feature = "extended_tuple")]
+into_pipe_system![
+    (A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)
+    (K, 10) (L, 11) (M, 12) (N, 13) (O, 14) (P, 15) (Q, 16) (R, 17) (S, 18) (T, 19)
+    (U, 20) (V, 21) (W, 22) (X, 23) (Y, 24) (Z, 25) (AA, 26) (BB, 27) (CC, 28) (DD, 29)
+    (EE, 30) (FF, 31)
+];
@@ -182,6 +182,9 @@ where
                 require_before: DedupedLabels::new(),
                 require_after: DedupedLabels::new(),
                 barriers: Vec::new(),
+                allowed_ambiguities: Vec::new(),
+                deny_ambiguities: false,
+                error_policy: None,
             }
         }
     }
@@ -222,6 +225,9 @@ macro_rules! impl_into_workload {
                     require_before: DedupedLabels::new(),
                     require_after: DedupedLabels::new(),
                     barriers: Vec::new(),
+                    allowed_ambiguities: Vec::new(),
+                    deny_ambiguities: false,
+                    error_policy: None,
                 };
 
                 $(
@@ -250,6 +256,9 @@ macro_rules! impl_into_workload {
                     require_before: DedupedLabels::new(),
                     require_after: DedupedLabels::new(),
                     barriers: Vec::new(),
+                    allowed_ambiguities: Vec::new(),
+                    deny_ambiguities: false,
+                    error_policy: None,
                 };
 
                 let mut sequential_tags = Vec::new();
@@ -3,7 +3,9 @@ use crate::borrow::{BorrowInfo, Mutability, WorldBorrow};
 use crate::error;
 use crate::scheduler::info::DedupedLabels;
 use crate::scheduler::label::{SystemLabel, WorkloadLabel};
-use crate::scheduler::{AsLabel, Label, TypeInfo, Workload, WorkloadSystem};
+use crate::scheduler::{
+    AsLabel, Label, LocalSystem, SystemLocals, TypeInfo, Workload, WorkloadSystem,
+};
 use crate::storage::StorageId;
 use crate::tracking::TrackingTimestamp;
 use crate::world::World;
@@ -27,6 +29,40 @@ pub trait IntoWorkloadSystem<B, R> {
     fn call(&self) -> R;
 }
 
+/// Checks a system doesn't borrow the same storage multiple times.
+pub(super) fn check_borrows(borrows: &[TypeInfo]) -> Result<(), error::InvalidSystem> {
+    if borrows.contains(&TypeInfo {
+        name: "".into(),
+        storage_id: StorageId::of::<AllStorages>(),
+        mutability: Mutability::Exclusive,
+        thread_safe: true,
+    }) && borrows.len() > 1
+    {
+        return Err(error::InvalidSystem::AllStorages);
+    }
+
+    if borrows.len() > 1 {
+        for (i, a_type_info) in borrows[..borrows.len() - 1].iter().enumerate() {
+            for b_type_info in &borrows[i + 1..] {
+                if a_type_info.storage_id == b_type_info.storage_id {
+                    match (a_type_info.mutability, b_type_info.mutability) {
+                        (Mutability::Exclusive, Mutability::Exclusive) => {
+                            return Err(error::InvalidSystem::MultipleViewsMut)
+                        }
+                        (Mutability::Exclusive, Mutability::Shared)
+                        | (Mutability::Shared, Mutability::Exclusive) => {
+                            return Err(error::InvalidSystem::MultipleViews)
+                        }
+                        (Mutability::Shared, Mutability::Shared) => {}
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
 pub struct Nothing;
 
 impl<R, F> IntoWorkloadSystem<Nothing, R> for F
@@ -55,6 +91,8 @@ where
             require_in_workload: DedupedLabels::new(),
             require_before: DedupedLabels::new(),
             require_after: DedupedLabels::new(),
+            error_policy: None,
+            local_system: None,
         })
     }
     fn label(&self) -> Box<dyn Label> {
@@ -108,51 +146,37 @@ macro_rules! impl_into_workload_system {
                     $type::borrow_info(&mut borrows);
                 )+
 
-                if borrows.contains(&TypeInfo {
-                    name: "".into(),
-                    storage_id: StorageId::of::<AllStorages>(),
-                    mutability: Mutability::Exclusive,
-                    thread_safe: true,
-                }) && borrows.len() > 1
-                {
-                    return Err(error::InvalidSystem::AllStorages);
-                }
-
-                if borrows.len() > 1 {
-                    for (i, a_type_info) in borrows[..borrows.len() - 1].iter().enumerate() {
-                        for b_type_info in &borrows[i + 1..] {
-                            if a_type_info.storage_id == b_type_info.storage_id {
-                                match (a_type_info.mutability, b_type_info.mutability) {
-                                    (Mutability::Exclusive, Mutability::Exclusive) => {
-                                        return Err(error::InvalidSystem::MultipleViewsMut)
-                                    }
-                                    (Mutability::Exclusive, Mutability::Shared)
-                                    | (Mutability::Shared, Mutability::Exclusive) => {
-                                        return Err(error::InvalidSystem::MultipleViews)
-                                    }
-                                    (Mutability::Shared, Mutability::Shared) => {}
-                                }
-                            }
-                        }
-                    }
-                }
+                check_borrows(&borrows)?;
 
                 let mut tracking_to_enable = Vec::new();
                 $(
                     $type::enable_tracking(&mut tracking_to_enable);
                 )+
 
+                let label = self.label();
                 let last_run = AtomicU64::new(0);
+                let (system_fn, local_system) = LocalSystem::split(
+                    move |world: &World, locals: &SystemLocals| {
+                        let current = world.get_current();
+                        let last_run = TrackingTimestamp::new(last_run.swap(current.get(), Ordering::Acquire));
+                        Ok(drop((&&self)($($type::world_borrow_in_system(&world, Some(last_run), current, locals)?),+)))
+                    },
+                    || {
+                        let mut locals = SystemLocals::new();
+                        $(
+                            $type::init_locals(&mut locals);
+                        )+
+
+                        locals
+                    },
+                );
+
                 Ok(WorkloadSystem {
                     borrow_constraints: borrows,
                     tracking_to_enable,
-                    display_name: self.label(),
-                    tags: vec![self.label()],
-                    system_fn: Box::new(move |world: &World| {
-                        let current = world.get_current();
-                        let last_run = TrackingTimestamp::new(last_run.swap(current.get(), Ordering::Acquire));
-                        Ok(drop((&&self)($($type::world_borrow(&world, Some(last_run), current)?),+)))
-                    }),
+                    display_name: label.clone(),
+                    tags: vec![label],
+                    system_fn,
                     type_id: TypeId::of::<Func>(),
                     before_all: DedupedLabels::new(),
                     after_all: DedupedLabels::new(),
@@ -170,6 +194,8 @@ macro_rules! impl_into_workload_system {
                     require_in_workload: DedupedLabels::new(),
                     require_before: DedupedLabels::new(),
                     require_after: DedupedLabels::new(),
+                    error_policy: None,
+                    local_system,
                 })
             }
             fn label(&self) -> Box<dyn Label> {
@@ -1,11 +1,9 @@
-use crate::all_storages::AllStorages;
-use crate::borrow::{BorrowInfo, Mutability, WorldBorrow};
+use crate::borrow::{BorrowInfo, WorldBorrow};
 use crate::error;
 use crate::scheduler::info::DedupedLabels;
-use crate::scheduler::into_workload_system::Nothing;
+use crate::scheduler::into_workload_system::{check_borrows, Nothing};
 use crate::scheduler::label::SystemLabel;
-use crate::scheduler::{AsLabel, TypeInfo, WorkloadSystem};
-use crate::storage::StorageId;
+use crate::scheduler::{AsLabel, LocalSystem, SystemLocals, WorkloadSystem};
 use crate::tracking::TrackingTimestamp;
 use crate::World;
 use alloc::boxed::Box;
@@ -22,7 +20,7 @@ use std::error::Error;
 /// Validates a function can become a failable system.
 pub trait IntoWorkloadTrySystem<Views, R> {
     /// Wraps a fallible function in a struct containing all information required by a workload.  
-    /// The workload will stop if an error is returned.
+    /// The workload will stop if an error is returned, unless a different [`ErrorPolicy`](crate::scheduler::ErrorPolicy) is set.
     #[cfg(feature = "std")]
     fn into_workload_try_system<Ok, Err: Into<Box<dyn Error + Send + Sync>>>(
         self,
@@ -30,7 +28,7 @@ pub trait IntoWorkloadTrySystem<Views, R> {
     where
         R: Into<Result<Ok, Err>>;
     /// Wraps a fallible function in a struct containing all information required by a workload.  
-    /// The workload will stop if an error is returned.
+    /// The workload will stop if an error is returned, unless a different [`ErrorPolicy`](crate::scheduler::ErrorPolicy) is set.
     #[cfg(not(feature = "std"))]
     fn into_workload_try_system<Ok, Err: 'static + Send + Any>(
         self,
@@ -78,6 +76,8 @@ where
             require_in_workload: DedupedLabels::new(),
             require_before: DedupedLabels::new(),
             require_after: DedupedLabels::new(),
+            error_policy: None,
+            local_system: None,
         })
     }
     #[cfg(not(feature = "std"))]
@@ -115,6 +115,8 @@ where
             require_in_workload: DedupedLabels::new(),
             require_before: DedupedLabels::new(),
             require_after: DedupedLabels::new(),
+            error_policy: None,
+            local_system: None,
         })
     }
 }
@@ -122,7 +124,7 @@ where
 // The `Result` type is not actually used and the error type can be anything
 impl IntoWorkloadTrySystem<WorkloadSystem, Result<(), error::InvalidSystem>> for WorkloadSystem {
     /// Wraps a fallible function in a struct containing all information required by a workload.  
-    /// The workload will stop if an error is returned.
+    /// The workload will stop if an error is returned, unless a different [`ErrorPolicy`](crate::scheduler::ErrorPolicy) is set.
     #[cfg(feature = "std")]
     fn into_workload_try_system<Ok, Err: Into<Box<dyn Error + Send + Sync>>>(
         self,
@@ -130,7 +132,7 @@ impl IntoWorkloadTrySystem<WorkloadSystem, Result<(), error::InvalidSystem>> for
         Ok(self)
     }
     /// Wraps a fallible function in a struct containing all information required by a workload.  
-    /// The workload will stop if an error is returned.
+    /// The workload will stop if an error is returned, unless a different [`ErrorPolicy`](crate::scheduler::ErrorPolicy) is set.
     #[cfg(not(feature = "std"))]
     fn into_workload_try_system<Ok, Err: 'static + Send + Any>(
         self,
@@ -157,34 +159,7 @@ macro_rules! impl_into_workload_try_system {
                     $type::borrow_info(&mut borrows);
                 )+
 
-                if borrows.contains(&TypeInfo {
-                    name: "".into(),
-                    storage_id: StorageId::of::<AllStorages>(),
-                    mutability: Mutability::Exclusive,
-                    thread_safe: true,
-                }) && borrows.len() > 1
-                {
-                    return Err(error::InvalidSystem::AllStorages);
-                }
-
-                if borrows.len() > 1 {
-                    for (i, a_type_info) in borrows[..borrows.len() - 1].iter().enumerate() {
-                        for b_type_info in &borrows[i + 1..] {
-                            if a_type_info.storage_id == b_type_info.storage_id {
-                                match (a_type_info.mutability, b_type_info.mutability) {
-                                    (Mutability::Exclusive, Mutability::Exclusive) => {
-                                        return Err(error::InvalidSystem::MultipleViewsMut)
-                                    }
-                                    (Mutability::Exclusive, Mutability::Shared)
-                                    | (Mutability::Shared, Mutability::Exclusive) => {
-                                        return Err(error::InvalidSystem::MultipleViews)
-                                    }
-                                    (Mutability::Shared, Mutability::Shared) => {}
-                                }
-                            }
-                        }
-                    }
-                }
+                check_borrows(&borrows)?;
 
                 let mut tracking_to_enable = Vec::new();
                 $(
@@ -192,14 +167,26 @@ macro_rules! impl_into_workload_try_system {
                 )+
 
                 let last_run = AtomicU64::new(0);
+                let (system_fn, local_system) = LocalSystem::split(
+                    move |world: &World, locals: &SystemLocals| {
+                        let current = world.get_current();
+                        let last_run = TrackingTimestamp::new(last_run.swap(current.get(), Ordering::Acquire));
+                        Ok(drop((&&self)($($type::world_borrow_in_system(&world, Some(last_run), current, locals)?),+).into().map_err(error::Run::from_custom)?))
+                    },
+                    || {
+                        let mut locals = SystemLocals::new();
+                        $(
+                            $type::init_locals(&mut locals);
+                        )+
+
+                        locals
+                    },
+                );
+
                 Ok(WorkloadSystem {
                     borrow_constraints: borrows,
                     tracking_to_enable,
-                    system_fn: Box::new(move |world: &World| {
-                        let current = world.get_current();
-                        let last_run = TrackingTimestamp::new(last_run.swap(current.get(), Ordering::Acquire));
-                        Ok(drop((&&self)($($type::world_borrow(&world, Some(last_run), current)?),+).into().map_err(error::Run::from_custom)?))
-                    }),
+                    system_fn,
                     type_id: TypeId::of::<Func>(),
                     display_name: Box::new(SystemLabel {
                         type_id: TypeId::of::<Func>(),
@@ -225,6 +212,8 @@ macro_rules! impl_into_workload_try_system {
                     require_in_workload: DedupedLabels::new(),
                     require_before: DedupedLabels::new(),
                     require_after: DedupedLabels::new(),
+                    error_policy: None,
+                    local_system,
                 })
             }
             #[cfg(not(feature = "std"))]
@@ -234,34 +223,7 @@ macro_rules! impl_into_workload_try_system {
                     $type::borrow_info(&mut borrows);
                 )+
 
-                if borrows.contains(&TypeInfo {
-                    name: "".into(),
-                    storage_id: StorageId::of::<AllStorages>(),
-                    mutability: Mutability::Exclusive,
-                    thread_safe: true,
-                }) && borrows.len() > 1
-                {
-                    return Err(error::InvalidSystem::AllStorages);
-                }
-
-                if borrows.len() > 1 {
-                    for (i, a_type_info) in borrows[..borrows.len() - 1].iter().enumerate() {
-                        for b_type_info in &borrows[i + 1..] {
-                            if a_type_info.storage_id == b_type_info.storage_id {
-                                match (a_type_info.mutability, b_type_info.mutability) {
-                                    (Mutability::Exclusive, Mutability::Exclusive) => {
-                                        return Err(error::InvalidSystem::MultipleViewsMut)
-                                    }
-                                    (Mutability::Exclusive, Mutability::Shared)
-                                    | (Mutability::Shared, Mutability::Exclusive) => {
-                                        return Err(error::InvalidSystem::MultipleViews)
-                                    }
-                                    (Mutability::Shared, Mutability::Shared) => {}
-                                }
-                            }
-                        }
-                    }
-                }
+                check_borrows(&borrows)?;
 
                 let mut tracking_to_enable = Vec::new();
                 $(
@@ -269,14 +231,26 @@ macro_rules! impl_into_workload_try_system {
                 )+
 
                 let last_run = AtomicU64::new(0);
+                let (system_fn, local_system) = LocalSystem::split(
+                    move |world: &World, locals: &SystemLocals| {
+                        let current = world.get_current();
+                        let last_run = TrackingTimestamp::new(last_run.swap(current.get(), Ordering::Acquire));
+                        Ok(drop((&&self)($($type::world_borrow_in_system(&world, Some(last_run), current, locals)?),+).into().map_err(error::Run::from_custom)?))
+                    },
+                    || {
+                        let mut locals = SystemLocals::new();
+                        $(
+                            $type::init_locals(&mut locals);
+                        )+
+
+                        locals
+                    },
+                );
+
                 Ok(WorkloadSystem {
                     borrow_constraints: borrows,
                     tracking_to_enable,
-                    system_fn: Box::new(move |world: &World| {
-                        let current = world.get_current();
-                        let last_run = TrackingTimestamp::new(last_run.swap(current.get(), Ordering::Acquire));
-                        Ok(drop((&&self)($($type::world_borrow(&world, Some(last_run), current)?),+).into().map_err(error::Run::from_custom)?))
-                    }),
+                    system_fn,
                     type_id: TypeId::of::<Func>(),
                     display_name: Box::new(SystemLabel {
                         type_id: TypeId::of::<Func>(),
@@ -302,6 +276,8 @@ macro_rules! impl_into_workload_try_system {
                     require_in_workload: DedupedLabels::new(),
                     require_before: DedupedLabels::new(),
                     require_after: DedupedLabels::new(),
+                    error_policy: None,
+                    local_system,
                 })
             }
         }
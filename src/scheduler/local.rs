@@ -0,0 +1,106 @@
+use crate::atomic_refcell::AtomicRefCell;
+use crate::error;
+use crate::world::World;
+use crate::ShipHashMap;
+use alloc::boxed::Box;
+use alloc::sync::Arc;
+use core::any::{Any, TypeId};
+
+/// State of the [`Local`](crate::views::Local) views of a system in a workload.
+///
+/// Each workload keeps its own state for each of its systems.
+#[derive(Default)]
+pub struct SystemLocals {
+    locals: ShipHashMap<TypeId, Box<dyn Any + Send + Sync>>,
+    /// State of the system running after this one in a piped system.
+    piped: Option<Box<SystemLocals>>,
+}
+
+/// `AtomicRefCell` is only `Send` when `thread_local` is disabled but locals are always `Send`.
+struct LocalCell<T>(AtomicRefCell<T>);
+
+// SAFE: LocalCell can only be created with a Send + Sync T
+unsafe impl<T: Send + Sync> Send for LocalCell<T> {}
+
+impl SystemLocals {
+    /// Creates an empty `SystemLocals`.
+    pub fn new() -> SystemLocals {
+        SystemLocals::default()
+    }
+
+    /// Inserts the default value of `T` if it isn't already present.
+    pub(crate) fn insert<T: Default + Send + Sync + 'static>(&mut self) {
+        self.locals
+            .entry(TypeId::of::<T>())
+            .or_insert_with(|| Box::new(LocalCell(AtomicRefCell::new(T::default()))));
+    }
+
+    pub(crate) fn get<T: Send + Sync + 'static>(&self) -> Option<&AtomicRefCell<T>> {
+        self.locals
+            .get(&TypeId::of::<T>())?
+            .downcast_ref::<LocalCell<T>>()
+            .map(|cell| &cell.0)
+    }
+
+    /// Sets the state of the system running after this one in a piped system.
+    pub(crate) fn set_piped(&mut self, piped: SystemLocals) {
+        self.piped = Some(Box::new(piped));
+    }
+
+    /// Returns the state of the system running after this one in a piped system.\
+    /// Outside workloads there is no state, `self` is returned.
+    pub(crate) fn piped(&self) -> &SystemLocals {
+        self.piped.as_deref().unwrap_or(self)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.locals.is_empty() && self.piped.as_deref().is_none_or(SystemLocals::is_empty)
+    }
+}
+
+/// System borrowing [`Local`](crate::views::Local) views, workloads run it with the state they keep for it.
+#[allow(clippy::type_complexity)]
+pub(crate) struct LocalSystem {
+    pub(crate) system_fn:
+        Box<dyn Fn(&World, &SystemLocals) -> Result<(), error::Run> + Send + Sync + 'static>,
+    /// Creates the initial state of the system's `Local` views.
+    pub(crate) new_locals: fn() -> SystemLocals,
+}
+
+impl LocalSystem {
+    /// Returns the function running `system_fn` without any `Local` state, used outside workloads,
+    /// and the `LocalSystem` workloads run instead if the system borrows `Local` views.
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn split<F>(
+        system_fn: F,
+        new_locals: fn() -> SystemLocals,
+    ) -> (
+        Box<dyn Fn(&World) -> Result<(), error::Run> + Send + Sync + 'static>,
+        Option<LocalSystem>,
+    )
+    where
+        F: Fn(&World, &SystemLocals) -> Result<(), error::Run> + Send + Sync + 'static,
+    {
+        let no_locals = SystemLocals::new();
+
+        if new_locals().is_empty() {
+            return (
+                Box::new(move |world: &World| system_fn(world, &no_locals)),
+                None,
+            );
+        }
+
+        let system_fn = Arc::new(system_fn);
+        let local_system_fn = system_fn.clone();
+
+        (
+            Box::new(move |world: &World| system_fn(world, &no_locals)),
+            Some(LocalSystem {
+                system_fn: Box::new(move |world: &World, locals: &SystemLocals| {
+                    local_system_fn(world, locals)
+                }),
+                new_locals,
+            }),
+        )
+    }
+}
@@ -1,26 +1,38 @@
 mod batches;
+mod fixed_timestep;
 pub mod info;
+mod into_pipe_system;
 mod into_workload;
 mod into_workload_run_if;
 mod into_workload_system;
 mod into_workload_try_system;
 mod label;
+mod local;
+mod profiling;
 mod system;
 mod system_modificator;
 mod workload;
 mod workload_modificator;
 
+pub use fixed_timestep::{FixedTimestep, FixedTimestepAlpha};
+pub use into_pipe_system::{IntoPipeSystem, PipeSystem, SystemPipe};
 pub use into_workload::IntoWorkload;
 pub use into_workload_system::IntoWorkloadSystem;
 pub use into_workload_try_system::IntoWorkloadTrySystem;
 pub use label::{AsLabel, Label};
-pub use system::WorkloadSystem;
+pub use local::SystemLocals;
+pub use system::{ErrorPolicy, WorkloadSystem};
 pub use system_modificator::SystemModificator;
 pub use workload::{ScheduledWorkload, Workload};
 pub use workload_modificator::WorkloadModificator;
 
 pub(crate) use batches::Batches;
 pub(crate) use info::TypeInfo;
+pub(crate) use local::LocalSystem;
+#[cfg(feature = "std")]
+pub(crate) use profiling::std_clock;
+pub(crate) use profiling::SystemRecord;
+pub(crate) use workload::{modify_workload, WorkloadDefinition, WorkloadModification};
 
 use crate::scheduler::info::WorkloadInfo;
 use crate::world::World;
@@ -37,7 +49,8 @@ use core::any::TypeId;
 // a batch lists systems that can run in parallel
 #[allow(clippy::type_complexity)]
 pub(crate) struct Scheduler {
-    pub(crate) systems: Vec<Box<dyn Fn(&World) -> Result<(), error::Run> + Send + Sync + 'static>>,
+    pub(crate) systems:
+        Vec<Box<dyn Fn(&World, &SystemLocals) -> Result<(), error::Run> + Send + Sync + 'static>>,
     pub(crate) system_names: Vec<Box<dyn Label>>,
     pub(crate) system_generators:
         Vec<Box<dyn Fn(&mut Vec<TypeInfo>) -> TypeId + Send + Sync + 'static>>,
@@ -46,6 +59,8 @@ pub(crate) struct Scheduler {
     /// workload name to list of "batches"
     pub(crate) workloads: ShipHashMap<Box<dyn Label>, Batches>,
     pub(crate) workloads_info: ShipHashMap<Box<dyn Label>, WorkloadInfo>,
+    /// workload name to what is needed to schedule it again
+    pub(crate) definitions: ShipHashMap<Box<dyn Label>, WorkloadDefinition>,
     pub(crate) default: Box<dyn Label>,
 }
 
@@ -58,6 +73,7 @@ impl Default for Scheduler {
             lookup_table: ShipHashMap::new(),
             workloads: ShipHashMap::new(),
             workloads_info: ShipHashMap::new(),
+            definitions: ShipHashMap::new(),
             default: Box::new(""),
         }
     }
@@ -98,6 +114,10 @@ impl Scheduler {
                 self.default = new.clone();
             }
 
+            if let Some(definition) = self.definitions.remove(old) {
+                self.definitions.insert(new.clone(), definition);
+            }
+
             self.workloads.insert(new, batches);
         }
     }
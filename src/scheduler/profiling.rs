@@ -0,0 +1,82 @@
+use crate::scheduler::info::SystemStats;
+use alloc::string::String;
+use core::sync::atomic::{AtomicU64, Ordering};
+use core::time::Duration;
+
+/// Execution statistics of a system, updated by the workload runners when profiling is enabled.
+pub(crate) struct SystemRecord {
+    calls: AtomicU64,
+    skipped: AtomicU64,
+    errors: AtomicU64,
+    total: AtomicU64,
+    min: AtomicU64,
+    max: AtomicU64,
+}
+
+impl Default for SystemRecord {
+    fn default() -> Self {
+        SystemRecord {
+            calls: AtomicU64::new(0),
+            skipped: AtomicU64::new(0),
+            errors: AtomicU64::new(0),
+            total: AtomicU64::new(0),
+            min: AtomicU64::new(u64::MAX),
+            max: AtomicU64::new(0),
+        }
+    }
+}
+
+impl SystemRecord {
+    pub(crate) fn record(&self, duration: Duration, is_err: bool) {
+        let nanos = duration.as_nanos().min(u64::MAX as u128) as u64;
+
+        self.calls.fetch_add(1, Ordering::Relaxed);
+        if is_err {
+            self.errors.fetch_add(1, Ordering::Relaxed);
+        }
+        self.total.fetch_add(nanos, Ordering::Relaxed);
+        self.min.fetch_min(nanos, Ordering::Relaxed);
+        self.max.fetch_max(nanos, Ordering::Relaxed);
+    }
+
+    pub(crate) fn skip(&self) {
+        self.skipped.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn reset(&self) {
+        self.calls.store(0, Ordering::Relaxed);
+        self.skipped.store(0, Ordering::Relaxed);
+        self.errors.store(0, Ordering::Relaxed);
+        self.total.store(0, Ordering::Relaxed);
+        self.min.store(u64::MAX, Ordering::Relaxed);
+        self.max.store(0, Ordering::Relaxed);
+    }
+
+    pub(crate) fn stats(&self, name: String) -> SystemStats {
+        let calls = self.calls.load(Ordering::Relaxed);
+        let total = self.total.load(Ordering::Relaxed);
+
+        SystemStats {
+            name,
+            calls,
+            skipped: self.skipped.load(Ordering::Relaxed),
+            errors: self.errors.load(Ordering::Relaxed),
+            total: Duration::from_nanos(total),
+            min: Duration::from_nanos(if calls == 0 {
+                0
+            } else {
+                self.min.load(Ordering::Relaxed)
+            }),
+            max: Duration::from_nanos(self.max.load(Ordering::Relaxed)),
+            mean: Duration::from_nanos(total.checked_div(calls).unwrap_or(0)),
+        }
+    }
+}
+
+/// Time elapsed since the first call, used as the default profiling clock.
+#[cfg(feature = "std")]
+pub(crate) fn std_clock() -> Duration {
+    static START: std::sync::OnceLock<std::time::Instant> = std::sync::OnceLock::new();
+
+    START.get_or_init(std::time::Instant::now).elapsed()
+}
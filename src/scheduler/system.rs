@@ -3,6 +3,7 @@ use crate::all_storages::AllStorages;
 use crate::error;
 use crate::scheduler::info::DedupedLabels;
 use crate::scheduler::label::Label;
+use crate::scheduler::local::LocalSystem;
 use crate::scheduler::workload::Workload;
 use crate::world::World;
 use alloc::boxed::Box;
@@ -67,6 +68,68 @@ pub struct WorkloadSystem {
     pub require_before: DedupedLabels,
     #[allow(missing_docs)]
     pub require_after: DedupedLabels,
+    /// What the workload does when this system returns an error.\
+    /// Defaults to the policy of the workload.
+    pub error_policy: Option<ErrorPolicy>,
+    /// Used by workloads instead of `system_fn` when the system borrows [`Local`](crate::views::Local) views.
+    pub(crate) local_system: Option<LocalSystem>,
+}
+
+impl WorkloadSystem {
+    /// Sets what the workload does when this system returns an error.\
+    /// Overrides the policy set with [`Workload::on_error`].
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::scheduler::{ErrorPolicy, IntoWorkloadTrySystem};
+    /// use shipyard::{error, Workload, World};
+    ///
+    /// fn ai() -> Result<(), error::MissingComponent> {
+    ///     Err(error::MissingComponent {
+    ///         id: shipyard::EntityId::dead(),
+    ///         name: "Brain",
+    ///     })
+    /// }
+    /// fn physics() {}
+    ///
+    /// let world = World::new();
+    ///
+    /// Workload::new("Update")
+    ///     .with_system(
+    ///         ai.into_workload_try_system()
+    ///             .unwrap()
+    ///             .on_error(ErrorPolicy::Collect),
+    ///     )
+    ///     .with_system(physics)
+    ///     .add_to_world(&world)
+    ///     .unwrap();
+    ///
+    /// assert!(matches!(
+    ///     world.run_workload("Update"),
+    ///     Err(error::RunWorkload::Multiple(errors)) if errors.len() == 1
+    /// ));
+    /// ```
+    pub fn on_error(mut self, policy: ErrorPolicy) -> WorkloadSystem {
+        self.error_policy = Some(policy);
+
+        self
+    }
+}
+
+/// What a workload does when one of its systems returns an error.
+///
+/// Only errors returned by systems are affected, a failing `run_if` always stops the workload.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub enum ErrorPolicy {
+    /// Stop the workload and return [`error::RunWorkload::Run`].
+    #[default]
+    Abort,
+    /// Keep running the workload and drop the error.\
+    /// The error is only logged when the `tracing` feature is enabled, it is lost otherwise.
+    /// Use [`ErrorPolicy::Collect`] to get it back.
+    Continue,
+    /// Keep running the workload and return all errors with [`error::RunWorkload::Multiple`] once it's done.
+    Collect,
 }
 
 impl Extend<WorkloadSystem> for Workload {
@@ -1,13 +1,15 @@
 mod create_workload;
 
+pub(crate) use create_workload::{modify_workload, WorkloadDefinition, WorkloadModification};
+
 use crate::all_storages::AllStorages;
 use crate::component::{Component, Unique};
-use crate::scheduler::info::{DedupedLabels, TypeInfo, WorkloadInfo};
+use crate::scheduler::info::{DedupedLabels, TypeInfo, WorkloadInfo, WorkloadStats};
 use crate::scheduler::label::WorkloadLabel;
 use crate::scheduler::system::{ExtractWorkloadRunIf, WorkloadRunIfFn};
 use crate::scheduler::{
-    AsLabel, Batches, IntoWorkload, IntoWorkloadSystem, IntoWorkloadTrySystem, Label, Scheduler,
-    WorkloadSystem,
+    AsLabel, Batches, ErrorPolicy, IntoWorkload, IntoWorkloadSystem, IntoWorkloadTrySystem, Label,
+    Scheduler, SystemLocals, WorkloadSystem,
 };
 use crate::storage::StorageId;
 use crate::unique::UniqueStorage;
@@ -34,7 +36,8 @@ use std::error::Error;
 pub struct ScheduledWorkload {
     name: Box<dyn Label>,
     #[allow(clippy::type_complexity)]
-    systems: Vec<Box<dyn Fn(&World) -> Result<(), error::Run> + Send + Sync + 'static>>,
+    systems:
+        Vec<Box<dyn Fn(&World, &SystemLocals) -> Result<(), error::Run> + Send + Sync + 'static>>,
     system_names: Vec<Box<dyn Label>>,
     #[allow(unused)]
     system_generators: Vec<Box<dyn Fn(&mut Vec<TypeInfo>) -> TypeId + Send + Sync + 'static>>,
@@ -60,6 +63,16 @@ impl ScheduledWorkload {
         world.run_batches(&self.systems, &self.system_names, &self.batches, &self.name)
     }
 
+    /// Returns the statistics recorded for the systems of this workload, see [`World::enable_profiling`].
+    pub fn stats(&self) -> WorkloadStats {
+        self.batches.stats(&*self.name, &self.system_names)
+    }
+
+    /// Resets the statistics of this workload.
+    pub fn reset_stats(&self) {
+        self.batches.reset_stats();
+    }
+
     /// Apply tracking to all storages using it during this workload.
     ///
     /// ### Borrows
@@ -128,6 +141,9 @@ pub struct Workload {
     pub(super) require_before: DedupedLabels,
     pub(super) require_after: DedupedLabels,
     pub(super) barriers: Vec<usize>,
+    pub(super) allowed_ambiguities: Vec<(Box<dyn Label>, Box<dyn Label>)>,
+    pub(super) deny_ambiguities: bool,
+    pub(super) error_policy: Option<ErrorPolicy>,
 }
 
 impl Workload {
@@ -186,6 +202,9 @@ impl Workload {
             require_before: DedupedLabels::new(),
             require_after: DedupedLabels::new(),
             barriers: Vec::new(),
+            allowed_ambiguities: Vec::new(),
+            deny_ambiguities: false,
+            error_policy: None,
         }
     }
     /// Moves all systems of `other` into `Self`, leaving `other` empty.
@@ -196,15 +215,20 @@ impl Workload {
         }
 
         self.systems.append(&mut other.systems);
+        self.allowed_ambiguities
+            .append(&mut other.allowed_ambiguities);
 
         self
     }
     /// Propagates all information from `self` and `other` into their respective systems before merging their systems.
-    /// This includes `run_if`/`skip_if`, `tags`, `before`/`after` requirements.
+    /// This includes `run_if`/`skip_if`, `tags`, `before`/`after` requirements and the error policy.\
+    /// The merged workload denies ambiguities if either of them does.
     pub fn merge(mut self, mut other: Workload) -> Workload {
         self.propagate();
         other.propagate();
 
+        self.deny_ambiguities |= other.deny_ambiguities;
+
         let systems_len = self.systems.len();
         self.barriers.extend(
             other
@@ -216,7 +240,7 @@ impl Workload {
         self.append(&mut other)
     }
     /// Propagates all information into the systems.
-    /// This includes `run_if`/`skip_if`, `tags`, `before`/`after` requirements.
+    /// This includes `run_if`/`skip_if`, `tags`, `before`/`after` requirements and the error policy.
     fn propagate(&mut self) {
         for system in &mut self.systems {
             system.run_if = match (system.run_if.take(), self.run_if.clone()) {
@@ -230,6 +254,10 @@ impl Workload {
 
             system.tags.extend(self.tags.iter().cloned());
 
+            if system.error_policy.is_none() {
+                system.error_policy = self.error_policy;
+            }
+
             system.before_all.extend(self.before_all.iter().cloned());
             system.after_all.extend(self.after_all.iter().cloned());
             system
@@ -248,7 +276,7 @@ impl Workload {
         self.require_after.clear();
     }
     /// Propagates all information from `self` and `other` into their respective systems before merging their systems.
-    /// This includes `run_if`/`skip_if`, `tags`, `before`/`after` requirements.
+    /// This includes `run_if`/`skip_if`, `tags`, `before`/`after` requirements and the error policy.
     pub fn with_workload(self, other: Workload) -> Workload {
         self.merge(other)
     }
@@ -441,6 +469,7 @@ impl Workload {
             lookup_table,
             workloads,
             workloads_info,
+            definitions,
             default,
         } = &mut *world
             .scheduler
@@ -459,6 +488,7 @@ impl Workload {
             lookup_table,
             &mut tracking_to_enable,
             workloads,
+            definitions,
             default,
         )?;
 
@@ -540,6 +570,7 @@ impl Workload {
             &mut workload.lookup_table,
             &mut workload.tracking_to_enable,
             &mut workloads,
+            &mut ShipHashMap::new(),
             &mut default,
         )?;
 
@@ -552,6 +583,114 @@ impl Workload {
     pub fn with_barrier(mut self) -> Self {
         self.barriers.push(self.systems.len());
 
+        self
+    }
+    /// Stops reporting the systems matching `label` and `other_label` as ambiguous.\
+    /// Labels can be systems, workloads or tags.
+    ///
+    /// Two systems are ambiguous when they borrow the same storage, at least one of them mutably,
+    /// without any `before_all`/`after_all`/barrier constraint between them.
+    /// Which one runs first then only depends on the order they were added to the workload.\
+    /// Ambiguities are listed in [`WorkloadInfo::ambiguities`].
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, View, ViewMut, Workload};
+    ///
+    /// #[derive(Component)]
+    /// struct Health(u32);
+    ///
+    /// fn heal(mut healths: ViewMut<Health>) {}
+    /// fn display(healths: View<Health>) {}
+    ///
+    /// let (_, info) = Workload::new("Main")
+    ///     .with_system(heal)
+    ///     .with_system(display)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(info.ambiguities.len(), 1);
+    ///
+    /// let (_, info) = Workload::new("Main")
+    ///     .with_system(heal)
+    ///     .with_system(display)
+    ///     .allow_ambiguity(heal, display)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert!(info.ambiguities.is_empty());
+    /// ```
+    pub fn allow_ambiguity<T, U>(
+        mut self,
+        label: impl AsLabel<T>,
+        other_label: impl AsLabel<U>,
+    ) -> Self {
+        self.allowed_ambiguities
+            .push((label.as_label(), other_label.as_label()));
+
+        self
+    }
+
+    /// Makes building this workload fail with [`error::AddWorkload::Ambiguity`] instead of listing ambiguities in [`WorkloadInfo::ambiguities`].
+    ///
+    /// See [`Workload::allow_ambiguity`] for the definition of an ambiguity.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{error, Component, View, ViewMut, Workload};
+    ///
+    /// #[derive(Component)]
+    /// struct Health(u32);
+    ///
+    /// fn heal(mut healths: ViewMut<Health>) {}
+    /// fn display(healths: View<Health>) {}
+    ///
+    /// assert!(matches!(
+    ///     Workload::new("Main")
+    ///         .with_system(heal)
+    ///         .with_system(display)
+    ///         .deny_ambiguities()
+    ///         .build(),
+    ///     Err(error::AddWorkload::Ambiguity { .. })
+    /// ));
+    /// ```
+    pub fn deny_ambiguities(mut self) -> Self {
+        self.deny_ambiguities = true;
+
+        self
+    }
+    /// Sets what the workload does when one of its systems returns an error.\
+    /// Systems can override it with [`WorkloadSystem::on_error`].
+    ///
+    /// Defaults to [`ErrorPolicy::Abort`].
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::scheduler::ErrorPolicy;
+    /// use shipyard::{error, Workload, World};
+    ///
+    /// fn ai() -> Result<(), error::MissingComponent> {
+    ///     Err(error::MissingComponent {
+    ///         id: shipyard::EntityId::dead(),
+    ///         name: "Brain",
+    ///     })
+    /// }
+    /// fn physics() {}
+    ///
+    /// let world = World::new();
+    ///
+    /// Workload::new("Update")
+    ///     .with_try_system(ai)
+    ///     .with_system(physics)
+    ///     .on_error(ErrorPolicy::Continue)
+    ///     .add_to_world(&world)
+    ///     .unwrap();
+    ///
+    /// world.run_workload("Update").unwrap();
+    /// ```
+    pub fn on_error(mut self, policy: ErrorPolicy) -> Self {
+        self.error_policy = Some(policy);
+
         self
     }
 }
@@ -625,8 +764,15 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0],
                 sequential_run_if: Vec::new(),
+                parallel_unique_ids: Vec::new(),
+                sequential_unique_ids: Vec::new(),
+                parallel_flush: Vec::new(),
+                sequential_flush: Vec::new(),
                 workload_run_if: None,
                 systems_run_if: Vec::new(),
+                stats: ShipHashMap::default(),
+                error_policies: ShipHashMap::default(),
+                locals: ShipHashMap::default(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -654,8 +800,15 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0],
                 sequential_run_if: Vec::new(),
+                parallel_unique_ids: Vec::new(),
+                sequential_unique_ids: Vec::new(),
+                parallel_flush: Vec::new(),
+                sequential_flush: Vec::new(),
                 workload_run_if: None,
                 systems_run_if: Vec::new(),
+                stats: ShipHashMap::default(),
+                error_policies: ShipHashMap::default(),
+                locals: ShipHashMap::default(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -685,8 +838,15 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1],
                 sequential_run_if: Vec::new(),
+                parallel_unique_ids: Vec::new(),
+                sequential_unique_ids: Vec::new(),
+                parallel_flush: Vec::new(),
+                sequential_flush: Vec::new(),
                 workload_run_if: None,
                 systems_run_if: Vec::new(),
+                stats: ShipHashMap::default(),
+                error_policies: ShipHashMap::default(),
+                locals: ShipHashMap::default(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -716,8 +876,15 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1],
                 sequential_run_if: Vec::new(),
+                parallel_unique_ids: Vec::new(),
+                sequential_unique_ids: Vec::new(),
+                parallel_flush: Vec::new(),
+                sequential_flush: Vec::new(),
                 workload_run_if: None,
                 systems_run_if: Vec::new(),
+                stats: ShipHashMap::default(),
+                error_policies: ShipHashMap::default(),
+                locals: ShipHashMap::default(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -747,8 +914,15 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1],
                 sequential_run_if: Vec::new(),
+                parallel_unique_ids: Vec::new(),
+                sequential_unique_ids: Vec::new(),
+                parallel_flush: Vec::new(),
+                sequential_flush: Vec::new(),
                 workload_run_if: None,
                 systems_run_if: Vec::new(),
+                stats: ShipHashMap::default(),
+                error_policies: ShipHashMap::default(),
+                locals: ShipHashMap::default(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -772,8 +946,15 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1],
                 sequential_run_if: Vec::new(),
+                parallel_unique_ids: Vec::new(),
+                sequential_unique_ids: Vec::new(),
+                parallel_flush: Vec::new(),
+                sequential_flush: Vec::new(),
                 workload_run_if: None,
                 systems_run_if: Vec::new(),
+                stats: ShipHashMap::default(),
+                error_policies: ShipHashMap::default(),
+                locals: ShipHashMap::default(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -810,8 +991,15 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1, 2],
                 sequential_run_if: Vec::new(),
+                parallel_unique_ids: Vec::new(),
+                sequential_unique_ids: Vec::new(),
+                parallel_flush: Vec::new(),
+                sequential_flush: Vec::new(),
                 workload_run_if: None,
                 systems_run_if: Vec::new(),
+                stats: ShipHashMap::default(),
+                error_policies: ShipHashMap::default(),
+                locals: ShipHashMap::default(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -840,8 +1028,15 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0],
                 sequential_run_if: Vec::new(),
+                parallel_unique_ids: Vec::new(),
+                sequential_unique_ids: Vec::new(),
+                parallel_flush: Vec::new(),
+                sequential_flush: Vec::new(),
                 workload_run_if: None,
                 systems_run_if: Vec::new(),
+                stats: ShipHashMap::default(),
+                error_policies: ShipHashMap::default(),
+                locals: ShipHashMap::default(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -864,8 +1059,15 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 0],
                 sequential_run_if: Vec::new(),
+                parallel_unique_ids: Vec::new(),
+                sequential_unique_ids: Vec::new(),
+                parallel_flush: Vec::new(),
+                sequential_flush: Vec::new(),
                 workload_run_if: None,
                 systems_run_if: Vec::new(),
+                stats: ShipHashMap::default(),
+                error_policies: ShipHashMap::default(),
+                locals: ShipHashMap::default(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -889,8 +1091,15 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1],
                 sequential_run_if: Vec::new(),
+                parallel_unique_ids: Vec::new(),
+                sequential_unique_ids: Vec::new(),
+                parallel_flush: Vec::new(),
+                sequential_flush: Vec::new(),
                 workload_run_if: None,
                 systems_run_if: Vec::new(),
+                stats: ShipHashMap::default(),
+                error_policies: ShipHashMap::default(),
+                locals: ShipHashMap::default(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -913,8 +1122,15 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1],
                 sequential_run_if: Vec::new(),
+                parallel_unique_ids: Vec::new(),
+                sequential_unique_ids: Vec::new(),
+                parallel_flush: Vec::new(),
+                sequential_flush: Vec::new(),
                 workload_run_if: None,
                 systems_run_if: Vec::new(),
+                stats: ShipHashMap::default(),
+                error_policies: ShipHashMap::default(),
+                locals: ShipHashMap::default(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -956,8 +1172,15 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 0],
                 sequential_run_if: Vec::new(),
+                parallel_unique_ids: Vec::new(),
+                sequential_unique_ids: Vec::new(),
+                parallel_flush: Vec::new(),
+                sequential_flush: Vec::new(),
                 workload_run_if: None,
                 systems_run_if: Vec::new(),
+                stats: ShipHashMap::default(),
+                error_policies: ShipHashMap::default(),
+                locals: ShipHashMap::default(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -985,8 +1208,15 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1],
                 sequential_run_if: Vec::new(),
+                parallel_unique_ids: Vec::new(),
+                sequential_unique_ids: Vec::new(),
+                parallel_flush: Vec::new(),
+                sequential_flush: Vec::new(),
                 workload_run_if: None,
                 systems_run_if: Vec::new(),
+                stats: ShipHashMap::default(),
+                error_policies: ShipHashMap::default(),
+                locals: ShipHashMap::default(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -1009,8 +1239,15 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1],
                 sequential_run_if: Vec::new(),
+                parallel_unique_ids: Vec::new(),
+                sequential_unique_ids: Vec::new(),
+                parallel_flush: Vec::new(),
+                sequential_flush: Vec::new(),
                 workload_run_if: None,
                 systems_run_if: Vec::new(),
+                stats: ShipHashMap::default(),
+                error_policies: ShipHashMap::default(),
+                locals: ShipHashMap::default(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -1033,8 +1270,15 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1],
                 sequential_run_if: Vec::new(),
+                parallel_unique_ids: Vec::new(),
+                sequential_unique_ids: Vec::new(),
+                parallel_flush: Vec::new(),
+                sequential_flush: Vec::new(),
                 workload_run_if: None,
                 systems_run_if: Vec::new(),
+                stats: ShipHashMap::default(),
+                error_policies: ShipHashMap::default(),
+                locals: ShipHashMap::default(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -1062,8 +1306,15 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1],
                 sequential_run_if: Vec::new(),
+                parallel_unique_ids: Vec::new(),
+                sequential_unique_ids: Vec::new(),
+                parallel_flush: Vec::new(),
+                sequential_flush: Vec::new(),
                 workload_run_if: None,
                 systems_run_if: Vec::new(),
+                stats: ShipHashMap::default(),
+                error_policies: ShipHashMap::default(),
+                locals: ShipHashMap::default(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -1093,8 +1344,15 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1],
                 sequential_run_if: Vec::new(),
+                parallel_unique_ids: Vec::new(),
+                sequential_unique_ids: Vec::new(),
+                parallel_flush: Vec::new(),
+                sequential_flush: Vec::new(),
                 workload_run_if: None,
                 systems_run_if: Vec::new(),
+                stats: ShipHashMap::default(),
+                error_policies: ShipHashMap::default(),
+                locals: ShipHashMap::default(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -1117,8 +1375,15 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![],
                 sequential_run_if: Vec::new(),
+                parallel_unique_ids: Vec::new(),
+                sequential_unique_ids: Vec::new(),
+                parallel_flush: Vec::new(),
+                sequential_flush: Vec::new(),
                 workload_run_if: None,
                 systems_run_if: Vec::new(),
+                stats: ShipHashMap::default(),
+                error_policies: ShipHashMap::default(),
+                locals: ShipHashMap::default(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -1157,8 +1422,15 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1, 2, 3],
                 sequential_run_if: Vec::new(),
+                parallel_unique_ids: Vec::new(),
+                sequential_unique_ids: Vec::new(),
+                parallel_flush: Vec::new(),
+                sequential_flush: Vec::new(),
                 workload_run_if: None,
                 systems_run_if: Vec::new(),
+                stats: ShipHashMap::default(),
+                error_policies: ShipHashMap::default(),
+                locals: ShipHashMap::default(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -1442,8 +1714,15 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1, 2],
                 sequential_run_if: Vec::new(),
+                parallel_unique_ids: Vec::new(),
+                sequential_unique_ids: Vec::new(),
+                parallel_flush: Vec::new(),
+                sequential_flush: Vec::new(),
                 workload_run_if: None,
-                systems_run_if: Vec::new()
+                systems_run_if: Vec::new(),
+                stats: ShipHashMap::default(),
+                error_policies: ShipHashMap::default(),
+                locals: ShipHashMap::default(),
             }
         );
     }
@@ -2055,4 +2334,58 @@ mod info_tests {
             })
         );
     }
+
+    #[test]
+    fn graph_export() {
+        fn sys_a(_: ViewMut<'_, U32>) {}
+        fn sys_b(_: View<'_, U32>) {}
+        fn sys_c() {}
+
+        let (_, info) = Workload::new("graph")
+            .with_system(sys_a)
+            .with_system(sys_b)
+            .with_system(sys_c.after_all(sys_a))
+            .build()
+            .unwrap();
+
+        let sys_a_name = format!("{:?}", sys_a.as_label());
+        let sys_b_name = format!("{:?}", sys_b.as_label());
+        let u32_name = type_name::<SparseSet<U32>>();
+
+        let dot = info.to_dot();
+        assert!(dot.starts_with("digraph \"graph\" {\n"));
+        assert!(dot.contains("    subgraph cluster_1 {\n        label=\"Batch 1\";\n"));
+        assert!(dot.contains(&format!(
+            "        system_0 [label=\"{}\\n&mut {}\"];\n",
+            sys_a_name, u32_name
+        )));
+        assert!(dot.contains(&format!(
+            "        system_1 [label=\"{}\\n&{}\"];\n",
+            sys_b_name, u32_name
+        )));
+        assert!(dot.contains(&format!(
+            "    system_0 -> system_1 [style=dashed, color=red, label=\"&mut {} / &{}\"];\n",
+            u32_name, u32_name
+        )));
+        assert!(!dot.contains("    system_0 -> system_1;\n"));
+        assert!(dot.contains(&format!(
+            "    system_0 -> system_2 [label=\"after_all {}\"];\n",
+            sys_a_name
+        )));
+        assert!(dot.ends_with("}\n"));
+
+        let mermaid = info.to_mermaid();
+        assert!(mermaid.starts_with("flowchart TB\n"));
+        assert!(mermaid.contains("    subgraph batch_0[\"Batch 0\"]\n"));
+        assert!(mermaid.contains(&format!(
+            "        system_1[\"{}<br/>#amp;{}\"]\n",
+            sys_b_name,
+            u32_name.replace('<', "#lt;").replace('>', "#gt;")
+        )));
+        assert!(mermaid.contains(&format!(
+            "    system_0 -- \"after_all {}\" --> system_2\n",
+            sys_a_name
+        )));
+        assert!(mermaid.contains("    system_0 -. \""));
+    }
 }
@@ -2,13 +2,17 @@ use crate::all_storages::AllStorages;
 use crate::borrow::Mutability;
 use crate::error;
 use crate::scheduler::info::{
-    BatchInfo, BeforeAfterConstraint, Conflict, DedupedLabels, SystemInfo,
+    Ambiguity, BatchInfo, BeforeAfterConstraint, Conflict, DedupedLabels, SystemInfo,
+};
+use crate::scheduler::{
+    Batches, ErrorPolicy, Label, LocalSystem, Scheduler, SystemLocals, SystemRecord, TypeId,
+    TypeInfo, Workload, WorkloadInfo, WorkloadSystem,
 };
-use crate::scheduler::{Batches, Label, TypeId, TypeInfo, Workload, WorkloadInfo, WorkloadSystem};
 use crate::world::World;
 use crate::ShipHashMap;
 use alloc::boxed::Box;
 use alloc::format;
+use alloc::sync::Arc;
 // use alloc::string::String;
 use alloc::vec;
 use alloc::vec::Vec;
@@ -18,6 +22,7 @@ use core::hash::Hash;
 #[allow(clippy::type_complexity)]
 struct ToBePlacedSystem {
     index: usize,
+    unique_id: usize,
     display_name: Box<dyn Label>,
     borrow_constraints: Vec<TypeInfo>,
     tags: Vec<Box<dyn Label>>,
@@ -32,13 +37,91 @@ struct ToBePlacedSystem {
     soft_after: DedupedUniqueIds,
     // System must be after in both orders
     hard_after: DedupedUniqueIds,
+    /// Subset of `hard_after` coming from barriers and before/after constraints.
+    explicit_after: DedupedUniqueIds,
     // System must be before in both orders
     hard_before: DedupedLabels,
     require_in_workload: DedupedLabels,
     require_before: DedupedLabels,
     require_after: DedupedLabels,
-    run_if: Option<Box<dyn Fn(&World) -> Result<bool, error::Run> + Send + Sync + 'static>>,
+    run_if: Option<RunIf>,
     confict: Option<Conflict>,
+    /// Number of barriers before this system.
+    barrier_count: usize,
+}
+
+type RunIf = Arc<dyn Fn(&World) -> Result<bool, error::Run> + Send + Sync + 'static>;
+
+/// Everything needed to schedule a workload again once it has been registered.
+pub(crate) struct WorkloadDefinition {
+    systems: Vec<DefinedSystem>,
+    /// Index into `systems` of the first system after each barrier.
+    barriers: Vec<usize>,
+    allowed_ambiguities: Vec<(Box<dyn Label>, Box<dyn Label>)>,
+    deny_ambiguities: bool,
+    /// Policy of the systems without their own.
+    error_policy: ErrorPolicy,
+    /// `unique_id` given to the next system added to the registered workload.
+    next_unique_id: usize,
+}
+
+/// A system of a registered workload, its function is stored in the scheduler.
+#[derive(Clone)]
+struct DefinedSystem {
+    index: usize,
+    display_name: Box<dyn Label>,
+    borrow_constraints: Vec<TypeInfo>,
+    tags: Vec<Box<dyn Label>>,
+    before_all: DedupedLabels,
+    after_all: DedupedLabels,
+    hard_before: DedupedLabels,
+    unique_id: usize,
+    require_in_workload: DedupedLabels,
+    require_before: DedupedLabels,
+    require_after: DedupedLabels,
+    run_if: Option<RunIf>,
+    error_policy: Option<ErrorPolicy>,
+    new_locals: fn() -> SystemLocals,
+    enabled: bool,
+}
+
+impl DefinedSystem {
+    fn has_tag(&self, label: &dyn Label) -> bool {
+        self.tags.iter().any(|tag| tag.dyn_eq(label))
+    }
+
+    fn to_be_placed(&self) -> ToBePlacedSystem {
+        ToBePlacedSystem {
+            index: self.index,
+            unique_id: self.unique_id,
+            display_name: self.display_name.clone(),
+            borrow_constraints: self.borrow_constraints.clone(),
+            tags: self.tags.clone(),
+            before_all: self.before_all.clone(),
+            after_all_info: Vec::new(),
+            after_all: self.after_all.clone(),
+            before_all_info: Vec::new(),
+            after_info: DedupedUniqueIds::new(),
+            hard_after: DedupedUniqueIds::new(),
+            explicit_after: DedupedUniqueIds::new(),
+            hard_before: self.hard_before.clone(),
+            soft_after: DedupedUniqueIds::new(),
+            require_in_workload: self.require_in_workload.clone(),
+            require_before: self.require_before.clone(),
+            require_after: self.require_after.clone(),
+            run_if: self.run_if.clone(),
+            confict: None,
+            barrier_count: 0,
+        }
+    }
+}
+
+/// Change applied to a registered workload by [`modify_workload`].
+pub(crate) enum WorkloadModification {
+    Insert(WorkloadSystem),
+    Remove(Box<dyn Label>),
+    Replace(Box<dyn Label>, WorkloadSystem),
+    SetEnabled(Box<dyn Label>, bool),
 }
 
 #[derive(Clone)]
@@ -75,38 +158,30 @@ impl DedupedUniqueIds {
 
 #[allow(clippy::type_complexity, clippy::too_many_arguments)]
 pub(super) fn create_workload(
-    mut builder: Workload,
-    systems: &mut Vec<Box<dyn Fn(&World) -> Result<(), error::Run> + Send + Sync + 'static>>,
+    builder: Workload,
+    systems: &mut Vec<
+        Box<dyn Fn(&World, &SystemLocals) -> Result<(), error::Run> + Send + Sync + 'static>,
+    >,
     system_names: &mut Vec<Box<dyn Label>>,
     system_generators: &mut Vec<Box<dyn Fn(&mut Vec<TypeInfo>) -> TypeId + Send + Sync + 'static>>,
     lookup_table: &mut ShipHashMap<TypeId, usize>,
     tracking_to_enable: &mut Vec<fn(&AllStorages) -> Result<(), error::GetStorage>>,
     workloads: &mut ShipHashMap<Box<dyn Label>, Batches>,
+    definitions: &mut ShipHashMap<Box<dyn Label>, WorkloadDefinition>,
     default: &mut Box<dyn Label>,
 ) -> Result<WorkloadInfo, error::AddWorkload> {
     if workloads.contains_key(&builder.name) {
         return Err(error::AddWorkload::AlreadyExists);
     }
 
-    let mut to_be_placed_systems = insert_systems_in_scheduler(
-        &mut builder,
-        systems,
-        system_names,
-        system_generators,
-        lookup_table,
-        tracking_to_enable,
-    );
-
-    if let Err(err) = check_require_in_workload(&mut to_be_placed_systems) {
-        return Err(err);
-    }
-
     let Workload {
         name: workload_name,
         run_if: workload_run_if,
         barriers,
-        // Systems were emptied by insert_systems_in_scheduler
-        systems: _,
+        allowed_ambiguities,
+        deny_ambiguities,
+        error_policy,
+        systems: workload_systems,
         // This workload will not be ordered with anything else
         tags: _,
         before_all: _,
@@ -116,6 +191,253 @@ pub(super) fn create_workload(
         require_after: _,
     } = builder;
 
+    let defined_systems = insert_systems_in_scheduler(
+        workload_systems,
+        systems,
+        system_names,
+        system_generators,
+        lookup_table,
+        tracking_to_enable,
+    );
+
+    let definition = WorkloadDefinition {
+        next_unique_id: defined_systems
+            .iter()
+            .map(|system| system.unique_id + 1)
+            .max()
+            .unwrap_or(0),
+        systems: defined_systems,
+        barriers,
+        allowed_ambiguities,
+        deny_ambiguities,
+        error_policy: error_policy.unwrap_or_default(),
+    };
+
+    let (mut batches, workload_info) = schedule(&definition, &*workload_name)?;
+    batches.workload_run_if = workload_run_if;
+
+    if workloads.is_empty() {
+        *default = workload_name.clone();
+    }
+    workloads.insert(workload_name.clone(), batches);
+    definitions.insert(workload_name, definition);
+
+    Ok(workload_info)
+}
+
+/// Applies `modification` to the registered `workload_name` workload and schedules it again.\
+/// The workload is left unchanged if the modified workload is invalid.
+#[allow(clippy::type_complexity)]
+pub(crate) fn modify_workload(
+    scheduler: &mut Scheduler,
+    tracking_to_enable: &mut Vec<fn(&AllStorages) -> Result<(), error::GetStorage>>,
+    workload_name: &dyn Label,
+    modification: WorkloadModification,
+) -> Result<WorkloadInfo, error::ModifyWorkload> {
+    let Scheduler {
+        systems,
+        system_names,
+        system_generators,
+        lookup_table,
+        workloads,
+        workloads_info,
+        definitions,
+        default: _,
+    } = scheduler;
+
+    let Some(definition) = definitions.get(workload_name) else {
+        return Err(error::ModifyWorkload::MissingWorkload);
+    };
+
+    let mut new_systems = Vec::with_capacity(definition.systems.len() + 1);
+    let mut new_barriers = definition.barriers.clone();
+    let mut next_unique_id = definition.next_unique_id;
+
+    match modification {
+        WorkloadModification::Insert(mut system) => {
+            system.unique_id = next_unique_id;
+            next_unique_id += 1;
+
+            new_systems.extend(definition.systems.iter().cloned());
+            new_systems.extend(insert_systems_in_scheduler(
+                [system],
+                systems,
+                system_names,
+                system_generators,
+                lookup_table,
+                tracking_to_enable,
+            ));
+        }
+        WorkloadModification::Remove(label) => {
+            let mut removed = Vec::new();
+
+            for (position, system) in definition.systems.iter().enumerate() {
+                if system.has_tag(&*label) {
+                    removed.push(position);
+                } else {
+                    new_systems.push(system.clone());
+                }
+            }
+
+            if removed.is_empty() {
+                return Err(error::ModifyWorkload::MissingSystem(label));
+            }
+
+            remove_from_barriers(&mut new_barriers, &removed);
+        }
+        WorkloadModification::Replace(label, mut system) => {
+            let Some(replaced) = definition
+                .systems
+                .iter()
+                .position(|old_system| old_system.has_tag(&*label))
+            else {
+                return Err(error::ModifyWorkload::MissingSystem(label));
+            };
+            let old_system = &definition.systems[replaced];
+
+            // The new system takes the place of the old one in the schedule
+            system.unique_id = old_system.unique_id;
+            system.tags.extend(
+                old_system
+                    .tags
+                    .iter()
+                    .filter(|tag| !tag.dyn_eq(&UniqueSystemId(old_system.unique_id)))
+                    .cloned(),
+            );
+            system.before_all.extend(&old_system.before_all);
+            system.after_all.extend(&old_system.after_all);
+            system
+                .require_in_workload
+                .extend(&old_system.require_in_workload);
+            system.require_before.extend(&old_system.require_before);
+            system.require_after.extend(&old_system.require_after);
+
+            let mut new_system = insert_systems_in_scheduler(
+                [system],
+                systems,
+                system_names,
+                system_generators,
+                lookup_table,
+                tracking_to_enable,
+            );
+            new_system[0].hard_before.extend(&old_system.hard_before);
+            if new_system[0].run_if.is_none() {
+                new_system[0].run_if = old_system.run_if.clone();
+            }
+            if new_system[0].error_policy.is_none() {
+                new_system[0].error_policy = old_system.error_policy;
+            }
+
+            let mut removed = Vec::new();
+            for (position, old_system) in definition.systems.iter().enumerate() {
+                if position == replaced {
+                    new_systems.append(&mut new_system);
+                } else if old_system.has_tag(&*label) {
+                    removed.push(position);
+                } else {
+                    new_systems.push(old_system.clone());
+                }
+            }
+
+            remove_from_barriers(&mut new_barriers, &removed);
+        }
+        WorkloadModification::SetEnabled(label, enabled) => {
+            new_systems.extend(definition.systems.iter().cloned());
+
+            let mut found = false;
+            for system in &mut new_systems {
+                if system.has_tag(&*label) {
+                    system.enabled = enabled;
+                    found = true;
+                }
+            }
+
+            if !found {
+                return Err(error::ModifyWorkload::MissingSystem(label));
+            }
+        }
+    }
+
+    let new_definition = WorkloadDefinition {
+        systems: new_systems,
+        barriers: new_barriers,
+        allowed_ambiguities: definition.allowed_ambiguities.clone(),
+        deny_ambiguities: definition.deny_ambiguities,
+        error_policy: definition.error_policy,
+        next_unique_id,
+    };
+
+    let (mut batches, workload_info) =
+        schedule(&new_definition, workload_name).map_err(error::ModifyWorkload::Invalid)?;
+
+    let (label, mut old_batches) = workloads.remove_entry(workload_name).unwrap();
+    batches.workload_run_if = old_batches.workload_run_if.take();
+    for (index, record) in &mut batches.stats {
+        if let Some(old_record) = old_batches.stats.remove(index) {
+            *record = old_record;
+        }
+    }
+    for (unique_id, locals) in &mut batches.locals {
+        // A replaced system keeps the unique_id of the old one but not its state
+        let system_index = |definition: &WorkloadDefinition| {
+            definition
+                .systems
+                .iter()
+                .find(|system| system.unique_id == *unique_id)
+                .map(|system| system.index)
+        };
+
+        if system_index(definition) == system_index(&new_definition) {
+            if let Some(old_locals) = old_batches.locals.remove(unique_id) {
+                *locals = old_locals;
+            }
+        }
+    }
+
+    workloads.insert(label.clone(), batches);
+    definitions.insert(label.clone(), new_definition);
+    workloads_info.insert(label, workload_info.clone());
+
+    Ok(workload_info)
+}
+
+/// Moves barriers back to account for the systems removed at `removed` positions.
+fn remove_from_barriers(barriers: &mut [usize], removed: &[usize]) {
+    for barrier in barriers {
+        *barrier -= removed
+            .iter()
+            .filter(|&&position| position < *barrier)
+            .count();
+    }
+}
+
+/// Orders the enabled systems of `definition` and validates their requirements.
+fn schedule(
+    definition: &WorkloadDefinition,
+    workload_name: &dyn Label,
+) -> Result<(Batches, WorkloadInfo), error::AddWorkload> {
+    let mut to_be_placed_systems = Vec::with_capacity(definition.systems.len());
+    let mut barriers = Vec::with_capacity(definition.barriers.len());
+    let mut next_barrier = definition.barriers.iter().copied().peekable();
+
+    for (position, system) in definition.systems.iter().enumerate() {
+        while next_barrier
+            .next_if(|&barrier| barrier <= position)
+            .is_some()
+        {
+            barriers.push(to_be_placed_systems.len());
+        }
+
+        if system.enabled {
+            to_be_placed_systems.push(system.to_be_placed());
+        }
+    }
+    barriers.extend(next_barrier.map(|_| to_be_placed_systems.len()));
+
+    if let Err(err) = check_require_in_workload(&mut to_be_placed_systems) {
+        return Err(err);
+    }
+
     propagate_barriers(&mut to_be_placed_systems, barriers);
     propagate_implicit_hard_ordering(&mut to_be_placed_systems);
     propagate_implicit_soft_ordering(&mut to_be_placed_systems);
@@ -130,23 +452,58 @@ pub(super) fn create_workload(
         return Err(err);
     }
 
-    let mut batches = Batches {
-        workload_run_if,
-        ..Default::default()
-    };
+    let ambiguities = find_ambiguities(&to_be_placed_systems, &definition.allowed_ambiguities);
+    if definition.deny_ambiguities {
+        if let Some(ambiguity) = ambiguities.first() {
+            let system = to_be_placed_systems
+                .iter()
+                .find(|system| system.index == ambiguity.system)
+                .unwrap();
+            let other_system = to_be_placed_systems
+                .iter()
+                .find(|system| system.index == ambiguity.other_system)
+                .unwrap();
+
+            return Err(error::AddWorkload::Ambiguity {
+                system: system.display_name.clone(),
+                other_system: other_system.display_name.clone(),
+                type_info: ambiguity.type_info.clone(),
+            });
+        }
+    }
+
+    let mut batches = Batches::default();
 
     let batches_info = order_systems(&mut to_be_placed_systems, &mut batches)?;
+    batches.stats = batches
+        .sequential
+        .iter()
+        .map(|&index| (index, SystemRecord::default()))
+        .collect();
+    batches.error_policies = definition
+        .systems
+        .iter()
+        .filter(|system| system.enabled)
+        .map(|system| {
+            (
+                system.index,
+                system.error_policy.unwrap_or(definition.error_policy),
+            )
+        })
+        .collect();
+    // Disabled systems keep their state
+    batches.locals = definition
+        .systems
+        .iter()
+        .map(|system| (system.unique_id, (system.new_locals)()))
+        .collect();
     let workload_info = WorkloadInfo {
         name: format!("{:?}", workload_name),
         batches_info,
+        ambiguities,
     };
 
-    if workloads.is_empty() {
-        *default = workload_name.clone();
-    }
-    workloads.insert(workload_name, batches);
-
-    Ok(workload_info)
+    Ok((batches, workload_info))
 }
 
 fn check_require_in_workload(
@@ -283,18 +640,20 @@ fn check_require_after(
     Ok(())
 }
 
+/// Stores the systems' function in the scheduler and keeps everything else to schedule them.
 #[allow(clippy::type_complexity, clippy::too_many_arguments)]
 fn insert_systems_in_scheduler(
-    builder: &mut Workload,
-    systems: &mut Vec<Box<dyn Fn(&World) -> Result<(), error::Run> + Send + Sync + 'static>>,
+    workload_systems: impl IntoIterator<Item = WorkloadSystem>,
+    systems: &mut Vec<
+        Box<dyn Fn(&World, &SystemLocals) -> Result<(), error::Run> + Send + Sync + 'static>,
+    >,
     system_names: &mut Vec<Box<dyn Label>>,
     system_generators: &mut Vec<Box<dyn Fn(&mut Vec<TypeInfo>) -> TypeId + Send + Sync + 'static>>,
     lookup_table: &mut ShipHashMap<TypeId, usize>,
     all_tracking_to_enable: &mut Vec<fn(&AllStorages) -> Result<(), error::GetStorage>>,
-) -> Vec<ToBePlacedSystem> {
-    builder
-        .systems
-        .drain(..)
+) -> Vec<DefinedSystem> {
+    workload_systems
+        .into_iter()
         .map(
             |WorkloadSystem {
                  type_id,
@@ -307,13 +666,32 @@ fn insert_systems_in_scheduler(
                  mut tags,
                  before_all,
                  after_all,
-                 after,
+                 after: _,
                  before,
                  unique_id,
                  require_in_workload,
                  require_before,
                  require_after,
+                 error_policy,
+                 local_system,
              }| {
+                let (system_fn, new_locals): (_, fn() -> SystemLocals) = match local_system {
+                    Some(LocalSystem {
+                        system_fn,
+                        new_locals,
+                    }) => (system_fn, new_locals),
+                    None => {
+                        let system_fn: Box<
+                            dyn Fn(&World, &SystemLocals) -> Result<(), error::Run>
+                                + Send
+                                + Sync
+                                + 'static,
+                        > = Box::new(move |world: &World, _: &SystemLocals| system_fn(world));
+
+                        (system_fn, SystemLocals::new)
+                    }
+                };
+
                 let system_index = *lookup_table.entry(type_id).or_insert_with(|| {
                     systems.push(system_fn);
                     system_names.push(display_name.clone());
@@ -324,12 +702,6 @@ fn insert_systems_in_scheduler(
 
                 all_tracking_to_enable.append(&mut tracking_to_enable);
 
-                let mut hard_after = DedupedLabels::new();
-                hard_after.extend(after.into_iter().map(|id| {
-                    let id: Box<dyn Label> = Box::new(UniqueSystemId(id));
-
-                    id
-                }));
                 let mut hard_before = DedupedLabels::new();
                 hard_before.extend(before.into_iter().map(|id| {
                     let id: Box<dyn Label> = Box::new(UniqueSystemId(id));
@@ -339,24 +711,22 @@ fn insert_systems_in_scheduler(
 
                 tags.push(Box::new(UniqueSystemId(unique_id)));
 
-                ToBePlacedSystem {
+                DefinedSystem {
                     index: system_index,
                     display_name,
                     borrow_constraints,
                     tags,
                     before_all,
-                    after_all_info: Vec::new(),
                     after_all,
-                    before_all_info: Vec::new(),
-                    after_info: DedupedUniqueIds::new(),
-                    hard_after: DedupedUniqueIds::new(),
                     hard_before,
-                    soft_after: DedupedUniqueIds::new(),
+                    unique_id,
                     require_in_workload,
                     require_before,
                     require_after,
-                    run_if,
-                    confict: None,
+                    run_if: run_if.map(Arc::from),
+                    error_policy,
+                    new_locals,
+                    enabled: true,
                 }
             },
         )
@@ -400,8 +770,11 @@ impl Label for UniqueSystemId {
 fn propagate_barriers(to_be_placed_systems: &mut [ToBePlacedSystem], barriers: Vec<usize>) {
     for index in barriers {
         for system in &mut to_be_placed_systems[index..] {
+            system.barrier_count += 1;
+
             for i in 0..index {
                 system.hard_after.add(i);
+                system.explicit_after.add(i);
             }
         }
     }
@@ -466,6 +839,88 @@ fn propagate_implicit_soft_ordering(to_be_placed_systems: &mut [ToBePlacedSystem
     }
 }
 
+/// Lists the pairs of systems borrowing the same storage, at least one of them mutably,
+/// that are only ordered by the implicit ordering.
+#[allow(clippy::type_complexity)]
+fn find_ambiguities(
+    to_be_placed_systems: &[ToBePlacedSystem],
+    allowed_ambiguities: &[(Box<dyn Label>, Box<dyn Label>)],
+) -> Vec<Ambiguity> {
+    // systems_before[i] contains all systems explicitly ordered before i
+    let mut systems_before = Vec::with_capacity(to_be_placed_systems.len());
+    for i in 0..to_be_placed_systems.len() {
+        let mut before = DedupedUniqueIds::new();
+        let mut stack = to_be_placed_systems[i].explicit_after.0.clone();
+
+        while let Some(j) = stack.pop() {
+            if !before.add(j) {
+                stack.extend_from_slice(&to_be_placed_systems[j].explicit_after.0);
+            }
+        }
+
+        systems_before.push(before);
+    }
+
+    let is_allowed = |system: &ToBePlacedSystem, other_system: &ToBePlacedSystem| {
+        let has_tag = |system: &ToBePlacedSystem, label: &dyn Label| {
+            system.tags.iter().any(|tag| tag.dyn_eq(label))
+        };
+
+        allowed_ambiguities.iter().any(|(label, other_label)| {
+            (has_tag(system, &**label) && has_tag(other_system, &**other_label))
+                || (has_tag(system, &**other_label) && has_tag(other_system, &**label))
+        })
+    };
+
+    let mut ambiguities = Vec::new();
+    for (j, other_system) in to_be_placed_systems.iter().enumerate() {
+        for (i, system) in to_be_placed_systems[..j].iter().enumerate() {
+            // running the same system twice in any order has the same effect
+            if system.index == other_system.index
+                || systems_before[j].contains(i)
+                || systems_before[i].contains(j)
+            {
+                continue;
+            }
+
+            if let Some((type_info, other_type_info)) = find_write_conflict(system, other_system) {
+                if !is_allowed(system, other_system) {
+                    ambiguities.push(Ambiguity {
+                        system: system.index,
+                        type_info,
+                        other_system: other_system.index,
+                        other_type_info,
+                    });
+                }
+            }
+        }
+    }
+
+    ambiguities
+}
+
+/// Returns the first storage borrowed by both systems, at least one of them mutably.
+fn find_write_conflict(
+    system: &ToBePlacedSystem,
+    other_system: &ToBePlacedSystem,
+) -> Option<(TypeInfo, TypeInfo)> {
+    for type_info in &system.borrow_constraints {
+        for other_type_info in &other_system.borrow_constraints {
+            let same_storage = type_info.storage_id == other_type_info.storage_id
+                || type_info.storage_id == TypeId::of::<AllStorages>()
+                || other_type_info.storage_id == TypeId::of::<AllStorages>();
+            let either_storage_exclusive = type_info.mutability == Mutability::Exclusive
+                || other_type_info.mutability == Mutability::Exclusive;
+
+            if same_storage && either_storage_exclusive {
+                return Some((type_info.clone(), other_type_info.clone()));
+            }
+        }
+    }
+
+    None
+}
+
 /// Map the before_all list to after.
 ///
 /// The goal is to only have constraints in a single form: after.
@@ -485,6 +940,7 @@ fn map_before_all_to_after(to_be_placed_systems: &mut [ToBePlacedSystem]) {
                 .find(|tag| before_all.iter().any(|label| tag == &label))
             {
                 other_system.hard_after.add(i);
+                other_system.explicit_after.add(i);
                 before_all_info.push(BeforeAfterConstraint {
                     other_system: other_system.index,
                     constraint: format!("{:?}", tag),
@@ -512,9 +968,10 @@ fn map_after_all_to_after(to_be_placed_systems: &mut [ToBePlacedSystem]) {
             for label in after_all.iter() {
                 if to_be_placed_systems[j].tags.iter().any(|tag| tag == label) {
                     to_be_placed_systems[i].hard_after.add(j);
+                    to_be_placed_systems[i].explicit_after.add(j);
 
                     after_all_info.push(BeforeAfterConstraint {
-                        other_system: j,
+                        other_system: to_be_placed_systems[j].index,
                         constraint: format!("{:?}", label),
                     });
 
@@ -545,6 +1002,7 @@ fn map_before_to_after(to_be_placed_systems: &mut [ToBePlacedSystem]) {
                 .any(|tag| before.iter().any(|label| tag == label))
             {
                 other_system.hard_after.add(i);
+                other_system.explicit_after.add(i);
 
                 break;
             }
@@ -563,8 +1021,16 @@ fn order_systems(
         return Ok(Vec::new());
     }
 
+    // hard_after contains positions in to_be_placed_systems, the info uses system indices
+    let system_indices: Vec<usize> = to_be_placed_systems
+        .iter()
+        .map(|system| system.index)
+        .collect();
     for system in &mut *to_be_placed_systems {
-        system.after_info = system.hard_after.clone();
+        system.after_info = DedupedUniqueIds::new();
+        for &position in &system.hard_after.0 {
+            system.after_info.add(system_indices[position]);
+        }
     }
 
     let mut constraint_free_systems = alloc::collections::VecDeque::new();
@@ -581,18 +1047,33 @@ fn order_systems(
     }];
     batches.parallel.push((None, Vec::new()));
     batches.parallel_run_if.push((usize::MAX, Vec::new()));
+    batches.parallel_unique_ids.push((usize::MAX, Vec::new()));
     let mut latest_batch = &mut batches.parallel[0];
     let mut latest_batch_run_if = &mut batches.parallel_run_if[0];
+    let mut latest_batch_unique_ids = &mut batches.parallel_unique_ids[0];
     let mut latest_batch_info = &mut batches_info[0];
     let mut to_delete_tags = Vec::new();
+    let mut barrier_count = 0;
+    let mut batch_index = 0;
     loop {
         while let Some(mut system) = constraint_free_systems.pop_front() {
+            // Systems after a barrier can only be placed once all systems before it are,
+            // they always start a new batch.
+            let is_after_barrier = system.barrier_count != barrier_count;
+            if is_after_barrier {
+                barrier_count = system.barrier_count;
+                batches.sequential_flush.push(batches.sequential.len());
+            }
+
             batches.sequential.push(system.index);
+            batches.sequential_unique_ids.push(system.unique_id);
 
             let conflict = check_can_go_in_parallel_batch(latest_batch_info, &system);
             if conflict.is_some() {
+                batch_index += 1;
                 batches.parallel.push((None, Vec::new()));
                 batches.parallel_run_if.push((usize::MAX, Vec::new()));
+                batches.parallel_unique_ids.push((usize::MAX, Vec::new()));
                 batches_info.push(BatchInfo {
                     systems: (None, Vec::new()),
                 });
@@ -600,15 +1081,21 @@ fn order_systems(
 
                 latest_batch = batches.parallel.last_mut().unwrap();
                 latest_batch_run_if = batches.parallel_run_if.last_mut().unwrap();
+                latest_batch_unique_ids = batches.parallel_unique_ids.last_mut().unwrap();
                 latest_batch_info = batches_info.last_mut().unwrap();
             }
 
+            if is_after_barrier {
+                batches.parallel_flush.push(batch_index);
+            }
+
             let is_single_system = system.borrow_constraints.iter().any(|constraint| {
                 !constraint.thread_safe || constraint.storage_id == TypeId::of::<AllStorages>()
             });
 
             if is_single_system {
                 latest_batch.0 = Some(system.index);
+                latest_batch_unique_ids.0 = system.unique_id;
                 latest_batch_info.systems.0 = Some(SystemInfo {
                     name: format!("{:?}", system.display_name),
                     borrow: system.borrow_constraints,
@@ -620,6 +1107,7 @@ fn order_systems(
                 });
             } else {
                 latest_batch.1.push(system.index);
+                latest_batch_unique_ids.1.push(system.unique_id);
                 latest_batch_info.systems.1.push(SystemInfo {
                     name: format!("{:?}", system.display_name),
                     borrow: system.borrow_constraints,
@@ -670,8 +1158,10 @@ fn order_systems(
         if constraint_free_systems.is_empty() {
             break;
         } else {
+            batch_index += 1;
             batches.parallel.push((None, Vec::new()));
             batches.parallel_run_if.push((usize::MAX, Vec::new()));
+            batches.parallel_unique_ids.push((usize::MAX, Vec::new()));
             batches_info.push(BatchInfo {
                 systems: (None, Vec::new()),
             });
@@ -679,6 +1169,7 @@ fn order_systems(
 
             latest_batch = batches.parallel.last_mut().unwrap();
             latest_batch_run_if = batches.parallel_run_if.last_mut().unwrap();
+            latest_batch_unique_ids = batches.parallel_unique_ids.last_mut().unwrap();
             latest_batch_info = batches_info.last_mut().unwrap();
         }
     }
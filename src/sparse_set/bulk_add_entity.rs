@@ -45,6 +45,7 @@ impl BulkInsert for () {
         let len = iter.size_hint().0;
 
         let entities = all_storages.exclusive_storage_mut::<Entities>().unwrap();
+        entities.materialize_reserved();
         let entities_len = entities.data.len();
 
         entities.bulk_generate(len);
@@ -74,6 +75,7 @@ impl<T: Send + Sync + Component> BulkInsert for T {
         sparse_set.data.extend(iter);
 
         // generate new EntityId for the entities created
+        entities.materialize_reserved();
         let entities_len = entities.data.len();
         let old_len = sparse_set.dense.len();
         let new_entities_count = sparse_set.data.len() - old_len;
@@ -103,6 +105,8 @@ impl<T: Send + Sync + Component> BulkInsert for T {
                 *sparse.get_mut_unchecked(entity) = EntityId::new((old_len + i) as u64);
             }
         }
+        sparse_set.restore_disabled_partition(old_len);
+        sparse_set.index_entities(new_entities);
 
         drop((entities, sparse_set));
 
@@ -149,6 +153,8 @@ macro_rules! impl_bulk_insert {
                     )*
                 }
 
+                entities.materialize_reserved();
+
                 let entities_len = entities.data.len();
                 let new_entities_count = $sparse_set1.data.len() - $sparse_set1.dense.len();
                 let new_entities = entities.bulk_generate(new_entities_count);
@@ -182,6 +188,8 @@ macro_rules! impl_bulk_insert {
                         *sparse.get_mut_unchecked(entity) = EntityId::new((old_len + i) as u64);
                     }
                 }
+                $sparse_set1.restore_disabled_partition(old_len);
+                $sparse_set1.index_entities(new_entities);
                 $(
                     let old_len = $sparse_set.dense.len() - new_entities_count;
                     let SparseSet { sparse, dense, .. } = &mut *$sparse_set;
@@ -192,6 +200,8 @@ macro_rules! impl_bulk_insert {
                             *sparse.get_mut_unchecked(entity) = EntityId::new((old_len + i) as u64);
                         }
                     }
+                    $sparse_set.restore_disabled_partition(old_len);
+                    $sparse_set.index_entities(new_entities);
                 )*
 
                 drop((entities, $sparse_set1, $($sparse_set),*));
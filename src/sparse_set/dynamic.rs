@@ -0,0 +1,524 @@
+use crate::all_storages::AllStorages;
+use crate::entity_id::EntityId;
+use crate::memory_usage::StorageMemoryUsage;
+use crate::sparse_set::{RawComponentAccess, SparseArray, BUCKET_SIZE};
+use crate::storage::{SBoxBuilder, Storage, StorageId};
+use crate::tracking::TrackingTimestamp;
+use alloc::alloc::{alloc, dealloc, handle_alloc_error};
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+use core::alloc::Layout;
+use core::mem::size_of;
+use core::ptr::{self, NonNull};
+
+/// Describes a component type only known at runtime.
+///
+/// Used to create a [`DynamicSparseSet`].
+#[derive(Clone, Debug)]
+pub struct DynamicComponentInfo {
+    name: Cow<'static, str>,
+    layout: Layout,
+    drop: Option<unsafe fn(*mut u8)>,
+    clone: Option<unsafe fn(*const u8, *mut u8)>,
+}
+
+impl DynamicComponentInfo {
+    /// Describes a component named `name` with the given `layout`.\
+    /// `drop` is called on each component leaving the storage without being removed, `None` if the type doesn't need to be dropped.
+    ///
+    /// # Safety
+    ///
+    /// - All components added to the storage must match `layout` and be safe to send and share across threads.
+    /// - `drop` must be safe to call on a pointer to any of these components.
+    pub unsafe fn new(
+        name: impl Into<Cow<'static, str>>,
+        layout: Layout,
+        drop: Option<unsafe fn(*mut u8)>,
+    ) -> DynamicComponentInfo {
+        DynamicComponentInfo {
+            name: name.into(),
+            layout,
+            drop,
+            clone: None,
+        }
+    }
+    /// Describes the Rust type `T` under the name `name`.
+    pub fn of<T: Send + Sync + 'static>(
+        name: impl Into<Cow<'static, str>>,
+    ) -> DynamicComponentInfo {
+        unsafe fn drop_in_place<T>(component: *mut u8) {
+            ptr::drop_in_place(component.cast::<T>());
+        }
+
+        let drop: unsafe fn(*mut u8) = drop_in_place::<T>;
+
+        DynamicComponentInfo {
+            name: name.into(),
+            layout: Layout::new::<T>(),
+            drop: core::mem::needs_drop::<T>().then_some(drop),
+            clone: None,
+        }
+    }
+    /// Sets the function used to clone components.\
+    /// It reads the component at the first pointer and writes the clone to the second one.
+    ///
+    /// Allows the storage to be cloned, snapshotted and used with [`World::clone_entity_to`](crate::World::clone_entity_to).
+    ///
+    /// # Safety
+    ///
+    /// `clone` must be safe to call with a pointer to any component of the storage and a pointer to uninitialized memory matching the layout.
+    pub unsafe fn with_clone(
+        mut self,
+        clone: unsafe fn(*const u8, *mut u8),
+    ) -> DynamicComponentInfo {
+        self.clone = Some(clone);
+
+        self
+    }
+    /// Returns the component's name.
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    /// Returns the component's layout.
+    #[inline]
+    pub fn layout(&self) -> Layout {
+        self.layout
+    }
+    /// Returns the distance in bytes between two components in the storage.
+    #[inline]
+    fn stride(&self) -> usize {
+        self.layout.pad_to_align().size()
+    }
+    /// Returns the [`StorageId`] of the storage holding these components.
+    ///
+    /// It is derived from the name, see [`StorageId::from_name`].
+    #[inline]
+    pub fn storage_id(&self) -> StorageId {
+        StorageId::from_name(&self.name)
+    }
+}
+
+/// Storage for components whose type is only known at runtime.
+///
+/// Components are stored as raw bytes matching the layout of its [`DynamicComponentInfo`].
+/// All functions taking or returning pointers are type-erased, it's up to the caller to interpret the bytes.
+pub struct DynamicSparseSet {
+    info: DynamicComponentInfo,
+    sparse: SparseArray<EntityId, BUCKET_SIZE>,
+    dense: Vec<EntityId>,
+    data: NonNull<u8>,
+    capacity: usize,
+}
+
+// SAFE DynamicComponentInfo::new requires the components to be Send and Sync
+unsafe impl Send for DynamicSparseSet {}
+unsafe impl Sync for DynamicSparseSet {}
+
+impl DynamicSparseSet {
+    /// Creates an empty storage for the components described by `info`.
+    pub fn new(info: DynamicComponentInfo) -> DynamicSparseSet {
+        // zero-sized components never allocate
+        let capacity = if info.layout.size() == 0 {
+            usize::MAX
+        } else {
+            0
+        };
+
+        DynamicSparseSet {
+            data: Self::dangling(info.layout),
+            info,
+            sparse: SparseArray::new(),
+            dense: Vec::new(),
+            capacity,
+        }
+    }
+    /// Returns the description of the components stored.
+    #[inline]
+    pub fn info(&self) -> &DynamicComponentInfo {
+        &self.info
+    }
+    /// Returns the number of components in this storage.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.dense.len()
+    }
+    /// Returns `true` if this storage contains no components.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.dense.is_empty()
+    }
+    /// Returns `true` if `entity` owns a component in this storage.
+    #[inline]
+    pub fn contains(&self, entity: EntityId) -> bool {
+        self.index_of(entity).is_some()
+    }
+    /// Returns the index of `entity`'s component in the dense and data vectors.
+    #[inline]
+    pub fn index_of(&self, entity: EntityId) -> Option<usize> {
+        self.sparse.get(entity).and_then(|sparse_entity| {
+            if entity.gen() == sparse_entity.gen() {
+                Some(sparse_entity.uindex())
+            } else {
+                None
+            }
+        })
+    }
+    /// Returns a pointer to `entity`'s component.
+    #[inline]
+    pub fn get(&self, entity: EntityId) -> Option<*const u8> {
+        self.index_of(entity)
+            .map(|index| self.ptr_at(index) as *const u8)
+    }
+    /// Returns a mutable pointer to `entity`'s component.
+    #[inline]
+    pub fn get_mut(&mut self, entity: EntityId) -> Option<*mut u8> {
+        self.index_of(entity).map(|index| self.ptr_at(index))
+    }
+    /// Returns an iterator over all entities and a pointer to their component.
+    pub fn iter(&self) -> impl Iterator<Item = (EntityId, *const u8)> + '_ {
+        self.dense
+            .iter()
+            .enumerate()
+            .map(|(index, &entity)| (entity, self.ptr_at(index) as *const u8))
+    }
+    /// Moves the component pointed by `component` into the storage.\
+    /// If `entity` already had a component, it is dropped.\
+    /// If `entity` is older than the entity currently at its index, the new component is dropped.
+    ///
+    /// # Safety
+    ///
+    /// `component` has to point to a component matching [`DynamicComponentInfo::layout`].
+    /// The storage takes ownership of it, the caller must not drop it.
+    #[track_caller]
+    pub unsafe fn insert(&mut self, entity: EntityId, component: *const u8) {
+        self.sparse.allocate_at(entity);
+
+        // at this point there can't be nothing at the sparse index
+        let sparse_entity = *self.sparse.get_mut_unchecked(entity);
+
+        if sparse_entity.is_dead() {
+            self.reserve_one();
+
+            let index = self.dense.len();
+            ptr::copy_nonoverlapping(component, self.ptr_at(index), self.info.layout.size());
+
+            *self.sparse.get_mut_unchecked(entity) =
+                EntityId::new_from_index_and_gen(index as u64, entity.gen());
+            self.dense.push(entity);
+        } else if entity.gen() >= sparse_entity.gen() {
+            let dst = self.ptr_at(sparse_entity.uindex());
+
+            if let Some(drop) = self.info.drop {
+                drop(dst);
+            }
+            ptr::copy_nonoverlapping(component, dst, self.info.layout.size());
+
+            self.sparse.get_mut_unchecked(entity).copy_gen(entity);
+            self.dense
+                .get_unchecked_mut(sparse_entity.uindex())
+                .copy_index_gen(entity);
+        } else if let Some(drop) = self.info.drop {
+            drop(component.cast_mut());
+        }
+    }
+    /// Removes `entity`'s component and moves it to `dst`.\
+    /// Returns `false` if `entity` didn't have a component, `dst` is left untouched.
+    ///
+    /// # Safety
+    ///
+    /// `dst` has to be valid for writes of [`DynamicComponentInfo::layout`].
+    /// The caller takes ownership of the component.
+    pub unsafe fn remove(&mut self, entity: EntityId, dst: *mut u8) -> bool {
+        match self.index_of(entity) {
+            Some(index) => {
+                ptr::copy_nonoverlapping(self.ptr_at(index), dst, self.info.layout.size());
+                self.swap_remove(entity, index);
+
+                true
+            }
+            None => false,
+        }
+    }
+    /// Deletes `entity`'s component.\
+    /// Returns `false` if `entity` didn't have a component.
+    pub fn delete(&mut self, entity: EntityId) -> bool {
+        match self.index_of(entity) {
+            Some(index) => {
+                if let Some(drop) = self.info.drop {
+                    unsafe { drop(self.ptr_at(index)) };
+                }
+                self.swap_remove(entity, index);
+
+                true
+            }
+            None => false,
+        }
+    }
+    /// Deletes all components in this storage.
+    pub fn clear(&mut self) {
+        for (index, &entity) in self.dense.iter().enumerate() {
+            unsafe {
+                *self.sparse.get_mut_unchecked(entity) = EntityId::dead();
+
+                if let Some(drop) = self.info.drop {
+                    drop(self.ptr_at(index));
+                }
+            }
+        }
+
+        self.dense.clear();
+    }
+
+    /// Removes the component at `index` from the storage without dropping it.
+    fn swap_remove(&mut self, entity: EntityId, index: usize) {
+        unsafe {
+            *self.sparse.get_mut_unchecked(entity) = EntityId::dead();
+        }
+
+        let last_index = self.dense.len() - 1;
+        self.dense.swap_remove(index);
+
+        if index < last_index {
+            unsafe {
+                ptr::copy_nonoverlapping(
+                    self.ptr_at(last_index),
+                    self.ptr_at(index),
+                    self.info.layout.size(),
+                );
+
+                let last = *self.dense.get_unchecked(index);
+                self.sparse
+                    .get_mut_unchecked(last)
+                    .copy_index(EntityId::new_from_index_and_gen(index as u64, 0));
+            }
+        }
+    }
+    #[inline]
+    fn ptr_at(&self, index: usize) -> *mut u8 {
+        unsafe { self.data.as_ptr().add(index * self.info.stride()) }
+    }
+    fn dangling(layout: Layout) -> NonNull<u8> {
+        // SAFE alignment is never 0
+        unsafe { NonNull::new_unchecked(layout.align() as *mut u8) }
+    }
+    fn array_layout(&self, capacity: usize) -> Layout {
+        Layout::from_size_align(self.info.stride() * capacity, self.info.layout.align())
+            .expect("Capacity overflow.")
+    }
+    fn reserve_one(&mut self) {
+        if self.dense.len() < self.capacity {
+            return;
+        }
+
+        let new_capacity = (self.capacity * 2).max(4);
+        let new_layout = self.array_layout(new_capacity);
+
+        unsafe {
+            let new_data = alloc(new_layout);
+            if new_data.is_null() {
+                handle_alloc_error(new_layout);
+            }
+
+            ptr::copy_nonoverlapping(
+                self.data.as_ptr(),
+                new_data,
+                self.dense.len() * self.info.stride(),
+            );
+
+            if self.capacity > 0 {
+                dealloc(self.data.as_ptr(), self.array_layout(self.capacity));
+            }
+
+            self.data = NonNull::new_unchecked(new_data);
+        }
+
+        self.capacity = new_capacity;
+    }
+    fn data_bytes(&self, count: usize) -> usize {
+        if self.info.layout.size() == 0 {
+            0
+        } else {
+            count * self.info.stride()
+        }
+    }
+    /// Returns a copy of this storage if it has a clone function.
+    fn try_clone_storage(&self) -> Option<DynamicSparseSet> {
+        let clone = self.info.clone?;
+
+        let mut storage = DynamicSparseSet::new(self.info.clone());
+
+        for (entity, component) in self.iter() {
+            let mut buffer = Buffer::new(self.info.layout);
+
+            unsafe {
+                clone(component, buffer.as_mut_ptr());
+                storage.insert(entity, buffer.as_mut_ptr());
+            }
+        }
+
+        Some(storage)
+    }
+}
+
+impl Drop for DynamicSparseSet {
+    fn drop(&mut self) {
+        self.clear();
+
+        if self.info.layout.size() != 0 && self.capacity > 0 {
+            unsafe { dealloc(self.data.as_ptr(), self.array_layout(self.capacity)) };
+        }
+    }
+}
+
+impl Storage for DynamicSparseSet {
+    #[inline]
+    fn delete(&mut self, entity: EntityId, _current: TrackingTimestamp) {
+        DynamicSparseSet::delete(self, entity);
+    }
+    #[inline]
+    fn clear(&mut self, _current: TrackingTimestamp) {
+        DynamicSparseSet::clear(self);
+    }
+    fn memory_usage(&self) -> Option<StorageMemoryUsage> {
+        let capacity = if self.info.layout.size() == 0 {
+            0
+        } else {
+            self.capacity
+        };
+
+        Some(StorageMemoryUsage {
+            storage_name: self.info.name.clone(),
+            allocated_memory_bytes: self.sparse.reserved_memory()
+                + self.dense.capacity() * size_of::<EntityId>()
+                + self.data_bytes(capacity)
+                + size_of::<Self>(),
+            used_memory_bytes: self.sparse.used_memory()
+                + self.dense.len() * size_of::<EntityId>()
+                + self.data_bytes(self.dense.len())
+                + size_of::<Self>(),
+            component_count: self.dense.len(),
+        })
+    }
+    #[inline]
+    fn name(&self) -> Cow<'static, str> {
+        self.info.name.clone()
+    }
+    #[inline]
+    fn sparse_array(&self) -> Option<&SparseArray<EntityId, 32>> {
+        Some(&self.sparse)
+    }
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.dense.is_empty()
+    }
+    #[inline]
+    fn dense(&self) -> Option<&[EntityId]> {
+        Some(&self.dense)
+    }
+    #[inline]
+    fn component_ptr(&self, entity: EntityId) -> Option<*const u8> {
+        self.get(entity)
+    }
+    #[inline]
+    fn component_ptr_mut(
+        &mut self,
+        entity: EntityId,
+        _current: TrackingTimestamp,
+    ) -> Option<*mut u8> {
+        self.get_mut(entity)
+    }
+    #[inline]
+    fn raw_component_access(&mut self) -> Option<RawComponentAccess> {
+        // SAFE data holds dense.len() components spaced by stride() bytes
+        Some(unsafe {
+            RawComponentAccess::from_dynamic(
+                &mut self.sparse,
+                &self.dense,
+                self.data.as_ptr(),
+                self.info.stride(),
+            )
+        })
+    }
+    fn move_component_from(
+        &mut self,
+        other_all_storages: &mut AllStorages,
+        from: EntityId,
+        to: EntityId,
+        _current: TrackingTimestamp,
+        _other_current: TrackingTimestamp,
+    ) {
+        let mut buffer = Buffer::new(self.info.layout);
+
+        if unsafe { self.remove(from, buffer.as_mut_ptr()) } {
+            let info = self.info.clone();
+            let other_storage = other_all_storages
+                .exclusive_storage_or_insert_mut(info.storage_id(), || DynamicSparseSet::new(info));
+
+            unsafe { other_storage.insert(to, buffer.as_mut_ptr()) };
+        }
+    }
+    fn try_clone(&self, _other_current: TrackingTimestamp) -> Option<SBoxBuilder> {
+        self.try_clone_storage().map(SBoxBuilder::new)
+    }
+    fn try_snapshot(&self) -> Option<SBoxBuilder> {
+        self.try_clone_storage().map(SBoxBuilder::new)
+    }
+    fn restore_snapshot(&mut self, snapshot: &dyn Storage, _current: TrackingTimestamp) {
+        if let Some(snapshot) = snapshot.any().downcast_ref::<DynamicSparseSet>() {
+            if let Some(storage) = snapshot.try_clone_storage() {
+                *self = storage;
+            }
+        }
+    }
+    fn clone_component_to(
+        &self,
+        other_all_storages: &mut AllStorages,
+        from: EntityId,
+        to: EntityId,
+        _other_current: TrackingTimestamp,
+    ) {
+        if let (Some(clone), Some(component)) = (self.info.clone, self.get(from)) {
+            let mut buffer = Buffer::new(self.info.layout);
+            let info = self.info.clone();
+            let other_storage = other_all_storages
+                .exclusive_storage_or_insert_mut(info.storage_id(), || DynamicSparseSet::new(info));
+
+            unsafe {
+                clone(component, buffer.as_mut_ptr());
+                other_storage.insert(to, buffer.as_mut_ptr());
+            }
+        }
+    }
+}
+
+/// Uninitialized memory able to hold a single component.
+struct Buffer {
+    data: NonNull<u8>,
+    layout: Layout,
+}
+
+impl Buffer {
+    fn new(layout: Layout) -> Buffer {
+        let data = if layout.size() == 0 {
+            DynamicSparseSet::dangling(layout)
+        } else {
+            let data = unsafe { alloc(layout) };
+
+            NonNull::new(data).unwrap_or_else(|| handle_alloc_error(layout))
+        };
+
+        Buffer { data, layout }
+    }
+    fn as_mut_ptr(&mut self) -> *mut u8 {
+        self.data.as_ptr()
+    }
+}
+
+impl Drop for Buffer {
+    fn drop(&mut self) {
+        if self.layout.size() != 0 {
+            unsafe { dealloc(self.data.as_ptr(), self.layout) };
+        }
+    }
+}
@@ -2,6 +2,7 @@ mod add_component;
 mod bulk_add_entity;
 mod delete;
 mod drain;
+mod dynamic;
 mod memory_usage;
 mod remove;
 mod sparse_array;
@@ -13,11 +14,12 @@ pub use add_component::TupleAddComponent;
 pub use bulk_add_entity::BulkAddEntity;
 pub use delete::TupleDelete;
 pub use drain::SparseSetDrain;
+pub use dynamic::{DynamicComponentInfo, DynamicSparseSet};
 pub use memory_usage::{SparseSetMemory, SparseSetMemoryUsage};
 pub use remove::TupleRemove;
 pub use sparse_array::SparseArray;
 #[doc(hidden)]
-pub use window::RawEntityIdAccess;
+pub use window::{RawComponentAccess, RawEntityIdAccess};
 
 pub(crate) use window::{FullRawWindow, FullRawWindowMut};
 
@@ -25,10 +27,14 @@ use crate::all_storages::AllStorages;
 use crate::component::Component;
 use crate::entity_id::EntityId;
 use crate::error;
+use crate::group::GroupMember;
+use crate::index::{AnyComponentIndex, ComponentIndex};
+use crate::map_entities::{EntityMap, MapEntities};
 use crate::memory_usage::StorageMemoryUsage;
 use crate::r#mut::Mut;
 use crate::storage::{SBoxBuilder, Storage, StorageId};
 use crate::tracking::{Tracking, TrackingTimestamp};
+use crate::ShipHashSet;
 use alloc::boxed::Box;
 use alloc::vec::Vec;
 use core::any::type_name;
@@ -49,10 +55,14 @@ pub(crate) const BUCKET_SIZE: usize = 256 / size_of::<EntityId>();
 // and if set dense[sparse[number]] != number.
 // We can't be limited to store solely integers, this is why there is a third vector.
 // It mimics the dense vector in regard to insertion/deletion.
+// Components of disabled entities are kept packed at the end of dense and data,
+// the last `disabled_len` elements are skipped by iteration and lookups.
 pub struct SparseSet<T: Component> {
     pub(crate) sparse: SparseArray<EntityId, BUCKET_SIZE>,
     pub(crate) dense: Vec<EntityId>,
     pub(crate) data: Vec<T>,
+    pub(crate) disabled_len: usize,
+    pub(crate) group: Option<GroupMember>,
     pub(crate) last_insert: TrackingTimestamp,
     pub(crate) last_modified: TrackingTimestamp,
     pub(crate) insertion_data: Vec<TrackingTimestamp>,
@@ -68,6 +78,8 @@ pub struct SparseSet<T: Component> {
     #[allow(clippy::type_complexity)]
     on_removal: Option<Box<dyn FnMut(EntityId, &T) + Send + Sync>>,
     clone: Option<fn(&T) -> T>,
+    map_entities: Option<fn(&mut T, &EntityMap)>,
+    pub(crate) index: Option<Box<dyn AnyComponentIndex<T>>>,
 }
 
 impl<T: fmt::Debug + Component> fmt::Debug for SparseSet<T> {
@@ -85,6 +97,8 @@ impl<T: Component> SparseSet<T> {
             sparse: SparseArray::new(),
             dense: Vec::new(),
             data: Vec::new(),
+            disabled_len: 0,
+            group: None,
             last_insert: TrackingTimestamp::new(0),
             last_modified: TrackingTimestamp::new(0),
             insertion_data: Vec::new(),
@@ -98,6 +112,8 @@ impl<T: Component> SparseSet<T> {
             on_insertion: None,
             on_removal: None,
             clone: None,
+            map_entities: None,
+            index: None,
         }
     }
     /// Returns a new [`SparseSet`] to be used in custom storage.
@@ -154,6 +170,17 @@ impl<T: Component> SparseSet<T> {
     pub unsafe fn index_of_unchecked(&self, entity: EntityId) -> usize {
         self.sparse.get_unchecked(entity).uindex()
     }
+    /// Returns the index of `entity`'s component if `entity` is not disabled.
+    #[inline]
+    pub(crate) fn enabled_index_of(&self, entity: EntityId) -> Option<usize> {
+        self.index_of(entity)
+            .filter(|&index| index < self.enabled_len())
+    }
+    /// Returns the number of components not belonging to a disabled entity.
+    #[inline]
+    pub(crate) fn enabled_len(&self) -> usize {
+        self.dense.len() - self.disabled_len
+    }
     /// Returns the `EntityId` at a given `index`.
     #[inline]
     pub fn id_at(&self, index: usize) -> Option<EntityId> {
@@ -191,6 +218,12 @@ impl<T: Component> SparseSet<T> {
         self.index_of(entity)
             .map(|index| unsafe { self.data.get_unchecked(index) })
     }
+
+    #[inline]
+    pub(crate) fn private_get_enabled(&self, entity: EntityId) -> Option<&T> {
+        self.enabled_index_of(entity)
+            .map(|index| unsafe { self.data.get_unchecked(index) })
+    }
 }
 
 /// [`SparseSet::insert`]'s return value.
@@ -239,6 +272,7 @@ impl<T: Component> SparseSet<T> {
     ) -> InsertionResult<T> {
         self.sparse.allocate_at(entity);
 
+        let enabled_len = self.enabled_len();
         // at this point there can't be nothing at the sparse index
         let sparse_entity = unsafe { self.sparse.get_mut_unchecked(entity) };
 
@@ -248,6 +282,9 @@ impl<T: Component> SparseSet<T> {
             if let Some(on_insertion) = &mut self.on_insertion {
                 on_insertion(entity, &value);
             }
+            if let Some(index) = &mut self.index {
+                index.insert(entity, &value);
+            }
 
             *sparse_entity =
                 EntityId::new_from_index_and_gen(self.dense.len() as u64, entity.gen());
@@ -262,11 +299,23 @@ impl<T: Component> SparseSet<T> {
             self.dense.push(entity);
             self.data.push(value);
 
+            // keep disabled components at the end
+            let index = self.enabled_len() - 1;
+            self.swap_dense(index, self.dense.len() - 1);
+
+            if let Some(group) = &mut self.group {
+                group.pending(entity, index);
+            }
+
             old_component = InsertionResult::Inserted;
         } else if entity.gen() == sparse_entity.gen() {
             if let Some(on_insertion) = &mut self.on_insertion {
                 on_insertion(entity, &value);
             }
+            // disabled components are not indexed
+            if let (Some(index), true) = (&mut self.index, sparse_entity.uindex() < enabled_len) {
+                index.insert(entity, &value);
+            }
 
             let old_data = unsafe {
                 core::mem::replace(self.data.get_unchecked_mut(sparse_entity.uindex()), value)
@@ -291,6 +340,13 @@ impl<T: Component> SparseSet<T> {
             if let Some(on_insertion) = &mut self.on_insertion {
                 on_insertion(entity, &value);
             }
+            if let Some(index) = &mut self.index {
+                index.remove(self.dense[sparse_entity.uindex()]);
+
+                if sparse_entity.uindex() < enabled_len {
+                    index.insert(entity, &value);
+                }
+            }
 
             let _ = unsafe {
                 core::mem::replace(self.data.get_unchecked_mut(sparse_entity.uindex()), value)
@@ -298,6 +354,10 @@ impl<T: Component> SparseSet<T> {
 
             old_component = InsertionResult::OtherComponentOverride;
 
+            if let Some(group) = &mut self.group {
+                group.unpack();
+            }
+
             sparse_entity.copy_gen(entity);
 
             let dense_entity = unsafe { self.dense.get_unchecked_mut(sparse_entity.uindex()) };
@@ -348,14 +408,34 @@ impl<T: Component> SparseSet<T> {
 
     #[inline]
     pub(crate) fn actual_remove(&mut self, entity: EntityId) -> Option<T> {
-        let sparse_entity = self.sparse.get(entity)?;
+        let mut sparse_entity = self.sparse.get(entity)?;
 
         if entity.gen() >= sparse_entity.gen() {
+            if let Some(group) = &mut self.group {
+                group.pending(entity, sparse_entity.uindex());
+            }
+
+            if self.disabled_len > 0 {
+                let enabled_len = self.enabled_len();
+
+                if sparse_entity.uindex() < enabled_len {
+                    // move the component to the end without mixing enabled and disabled components
+                    self.swap_dense(sparse_entity.uindex(), enabled_len - 1);
+                    self.swap_dense(enabled_len - 1, self.dense.len() - 1);
+                    sparse_entity.set_index((self.dense.len() - 1) as u64);
+                } else {
+                    self.disabled_len -= 1;
+                }
+            }
+
             unsafe {
                 *self.sparse.get_mut_unchecked(entity) = EntityId::dead();
             }
 
-            self.dense.swap_remove(sparse_entity.uindex());
+            let removed = self.dense.swap_remove(sparse_entity.uindex());
+            if let Some(index) = &mut self.index {
+                index.remove(removed);
+            }
             if self.is_tracking_insertion() {
                 self.insertion_data.swap_remove(sparse_entity.uindex());
             }
@@ -389,6 +469,104 @@ impl<T: Component> SparseSet<T> {
     }
 }
 
+impl<T: Component> SparseSet<T> {
+    /// Swaps the components at `a` and `b` and updates the sparse array.
+    fn swap_dense(&mut self, a: usize, b: usize) {
+        if a == b {
+            return;
+        }
+
+        self.dense.swap(a, b);
+        self.data.swap(a, b);
+        if self.is_tracking_insertion {
+            self.insertion_data.swap(a, b);
+        }
+        if self.is_tracking_modification {
+            self.modification_data.swap(a, b);
+        }
+
+        unsafe {
+            let entity_a = *self.dense.get_unchecked(a);
+            let entity_b = *self.dense.get_unchecked(b);
+
+            self.sparse.get_mut_unchecked(entity_a).set_index(a as u64);
+            self.sparse.get_mut_unchecked(entity_b).set_index(b as u64);
+        }
+    }
+    /// Moves disabled components back to the end of the storage after `dense` grew past `old_len`.
+    pub(crate) fn restore_disabled_partition(&mut self, old_len: usize) {
+        let first_disabled = old_len - self.disabled_len;
+        let new_len = self.dense.len() - old_len;
+
+        for i in 0..self.disabled_len.min(new_len) {
+            self.swap_dense(first_disabled + i, self.dense.len() - 1 - i);
+        }
+    }
+    /// Hides `entity`'s component from iteration and lookups.
+    pub(crate) fn private_disable(&mut self, entity: EntityId) {
+        if let Some(index) = self.index_of(entity) {
+            let enabled_len = self.enabled_len();
+
+            if index < enabled_len {
+                if let Some(group) = &mut self.group {
+                    group.pending(entity, index);
+                }
+                if let Some(component_index) = &mut self.index {
+                    component_index.remove(entity);
+                }
+
+                self.swap_dense(index, enabled_len - 1);
+                self.disabled_len += 1;
+            }
+        }
+    }
+    /// Makes `entity`'s component visible to iteration and lookups again.
+    pub(crate) fn private_enable(&mut self, entity: EntityId) {
+        if let Some(index) = self.index_of(entity) {
+            let enabled_len = self.enabled_len();
+
+            if index >= enabled_len {
+                if let Some(group) = &mut self.group {
+                    group.pending(entity, index);
+                }
+
+                self.swap_dense(index, enabled_len);
+                self.disabled_len -= 1;
+
+                if let Some(component_index) = &mut self.index {
+                    component_index.insert(entity, &self.data[enabled_len]);
+                }
+            }
+        }
+    }
+}
+
+impl<T: Component> SparseSet<T> {
+    /// Returns a type-erased pointer to `entity`'s component.
+    #[inline]
+    pub(crate) fn private_component_ptr(&self, entity: EntityId) -> Option<*const u8> {
+        self.enabled_index_of(entity)
+            .map(|index| unsafe { self.data.as_ptr().add(index).cast() })
+    }
+    /// Returns a type-erased mutable pointer to `entity`'s component and flags it as modified.
+    #[inline]
+    pub(crate) fn private_component_ptr_mut(
+        &mut self,
+        entity: EntityId,
+        current: TrackingTimestamp,
+    ) -> Option<*mut u8> {
+        let index = self.enabled_index_of(entity)?;
+
+        if self.is_tracking_modification {
+            unsafe {
+                *self.modification_data.get_unchecked_mut(index) = current;
+            }
+        }
+
+        Some(unsafe { self.data.as_mut_ptr().add(index).cast() })
+    }
+}
+
 impl<T: Component> SparseSet<T> {
     /// Removes the *inserted* flag on all components of this storage.
     pub(crate) fn private_clear_all_inserted(&mut self, current: TrackingTimestamp) {
@@ -546,31 +724,102 @@ impl<T: Component> SparseSet<T> {
     }
     /// Sorts the `SparseSet` with a comparator function, but may not preserve the order of equal elements.
     pub fn sort_unstable_by<F: FnMut(&T, &T) -> Ordering>(&mut self, mut compare: F) {
-        let mut transform: Vec<usize> = (0..self.dense.len()).collect();
+        let mut order: Vec<usize> = (0..self.enabled_len()).collect();
+
+        order.sort_unstable_by(|&i, &j| {
+            // SAFE dense and data have the same length
+            compare(unsafe { self.data.get_unchecked(i) }, unsafe {
+                self.data.get_unchecked(j)
+            })
+        });
 
-        transform.sort_unstable_by(|&i, &j| {
+        self.apply_order(order);
+    }
+    /// Sorts the `SparseSet` with a comparator function, preserving the order of equal elements.
+    ///
+    /// Sorting by the least significant key first then by the most significant one sorts by multiple keys.
+    pub fn sort_by<F: FnMut(&T, &T) -> Ordering>(&mut self, mut compare: F) {
+        let mut order: Vec<usize> = (0..self.enabled_len()).collect();
+
+        order.sort_by(|&i, &j| {
             // SAFE dense and data have the same length
             compare(unsafe { self.data.get_unchecked(i) }, unsafe {
                 self.data.get_unchecked(j)
             })
         });
 
+        self.apply_order(order);
+    }
+    /// Sorts the `SparseSet` with a key extraction function, preserving the order of equal elements.
+    ///
+    /// A tuple key sorts by multiple keys.
+    pub fn sort_by_key<K: Ord, F: FnMut(&T) -> K>(&mut self, mut f: F) {
+        self.sort_by(|a, b| f(a).cmp(&f(b)))
+    }
+    /// Reorders the `SparseSet` to follow `other`'s order.
+    ///
+    /// Entities present in both storages are moved to the front in the order they have in `other`.
+    /// The other entities keep their relative order after them.\
+    /// Iterating both storages then walks their components in the same order.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, IntoIter, View, ViewMut, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Depth(u32);
+    ///
+    /// #[derive(Component)]
+    /// struct Sprite(&'static str);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// world.add_entity((Depth(2), Sprite("background")));
+    /// world.add_entity((Depth(0), Sprite("player")));
+    /// world.add_entity((Sprite("cursor"),));
+    ///
+    /// world.run(|mut depths: ViewMut<Depth>, mut sprites: ViewMut<Sprite>| {
+    ///     depths.sort_by_key(|depth| depth.0);
+    ///     sprites.respect(&depths);
+    ///
+    ///     assert_eq!(
+    ///         sprites.iter().map(|sprite| sprite.0).collect::<Vec<_>>(),
+    ///         ["player", "background", "cursor"]
+    ///     );
+    /// });
+    /// ```
+    pub fn respect<U: Component>(&mut self, other: &SparseSet<U>) {
+        let enabled_len = self.enabled_len();
+        let mut placed = alloc::vec![false; enabled_len];
+        let mut order = Vec::with_capacity(enabled_len);
+
+        for &entity in &other.dense[..other.enabled_len()] {
+            if let Some(index) = self.enabled_index_of(entity) {
+                placed[index] = true;
+                order.push(index);
+            }
+        }
+
+        order.extend((0..enabled_len).filter(|&index| !placed[index]));
+
+        self.apply_order(order);
+    }
+    /// Moves the component at `order[i]` to index `i`.
+    fn apply_order(&mut self, order: Vec<usize>) {
+        if let Some(group) = &mut self.group {
+            group.unpack();
+        }
+
         let mut pos;
-        for i in 0..transform.len() {
+        for i in 0..order.len() {
             // SAFE we're in bound
-            pos = unsafe { *transform.get_unchecked(i) };
+            pos = unsafe { *order.get_unchecked(i) };
             while pos < i {
                 // SAFE we're in bound
-                pos = unsafe { *transform.get_unchecked(pos) };
-            }
-            self.dense.swap(i, pos);
-            self.data.swap(i, pos);
-        }
-
-        for (i, id) in self.dense.iter().enumerate() {
-            unsafe {
-                self.sparse.get_mut_unchecked(*id).set_index(i as u64);
+                pos = unsafe { *order.get_unchecked(pos) };
             }
+            self.swap_dense(i, pos);
         }
     }
 
@@ -661,6 +910,14 @@ impl<T: Component> SparseSet<T> {
 
     /// Deletes all components in this storage.
     pub(crate) fn private_clear(&mut self, current: TrackingTimestamp) {
+        self.disabled_len = 0;
+        if let Some(group) = &mut self.group {
+            group.unpack();
+        }
+        if let Some(index) = &mut self.index {
+            index.clear();
+        }
+
         for &id in &self.dense {
             unsafe {
                 *self.sparse.get_mut_unchecked(id) = EntityId::dead();
@@ -685,6 +942,13 @@ impl<T: Component> SparseSet<T> {
 
     /// Creates a draining iterator that empties the storage and yields the removed items.
     pub(crate) fn private_drain(&mut self, current: TrackingTimestamp) -> SparseSetDrain<'_, T> {
+        if let Some(group) = &mut self.group {
+            group.unpack();
+        }
+        if let Some(index) = &mut self.index {
+            index.clear();
+        }
+
         if self.is_tracking_removal {
             self.removal_data
                 .extend(self.dense.iter().map(|&entity| (entity, current)));
@@ -699,6 +963,7 @@ impl<T: Component> SparseSet<T> {
 
         self.insertion_data.clear();
         self.modification_data.clear();
+        self.disabled_len = 0;
 
         let dense_ptr = self.dense.as_ptr();
         let dense_len = self.dense.len();
@@ -757,6 +1022,31 @@ impl<T: Component> SparseSet<T> {
     }
 }
 
+impl<T: Component> SparseSet<T> {
+    /// Clones `from`'s component to all `to` entities using the registered clone function.
+    pub(crate) fn private_duplicate_component(
+        &mut self,
+        from: EntityId,
+        to: &[EntityId],
+        current: TrackingTimestamp,
+    ) {
+        if let Some(clone) = self.clone {
+            self.reserve(to.len());
+
+            for &entity in to {
+                if self.index_of(entity).is_some() {
+                    continue;
+                }
+
+                // inserting can move the components of disabled entities
+                if let Some(component) = self.private_get(from).map(clone) {
+                    let _ = self.insert(entity, component, current);
+                }
+            }
+        }
+    }
+}
+
 impl<T: Ord + Component> SparseSet<T> {
     /// Sorts the `SparseSet`, but may not preserve the order of equal elements.
     pub fn sort_unstable(&mut self) {
@@ -772,6 +1062,154 @@ impl<T: Clone + Component> SparseSet<T> {
     }
 }
 
+impl<T: MapEntities + Component> SparseSet<T> {
+    /// Registers the function to update the [`EntityId`]s stored in this component.
+    #[inline]
+    pub fn register_map_entities(&mut self) {
+        self.map_entities = Some(T::map_entities)
+    }
+}
+
+impl<T: Component> SparseSet<T> {
+    /// Indexes the components of this storage by the key returned by `key`, replacing the previous index.
+    pub(crate) fn add_index<K: Ord + Clone + Send + Sync + 'static>(&mut self, key: fn(&T) -> K) {
+        let enabled_len = self.enabled_len();
+        let mut index = ComponentIndex::new(key);
+        index.rebuild(&self.dense[..enabled_len], &self.data[..enabled_len]);
+
+        self.index = Some(Box::new(index));
+    }
+
+    /// Returns the index of this storage if its key is `K`.
+    ///
+    /// See the [`index`](crate::index) module for more details.
+    #[inline]
+    pub fn component_index<K: 'static>(&self) -> Option<&ComponentIndex<T, K>> {
+        self.index.as_ref()?.as_any().downcast_ref()
+    }
+
+    /// Re-keys the components modified since the last sync.\
+    /// Without modification tracking, components can be modified without a trace so all of them are checked.
+    #[inline]
+    pub(crate) fn sync_index(&mut self) {
+        let enabled_len = self.enabled_len();
+
+        if let Some(index) = &mut self.index {
+            let modification_data =
+                T::Tracking::track_modification().then(|| &self.modification_data[..enabled_len]);
+
+            index.sync(
+                &self.dense[..enabled_len],
+                &self.data[..enabled_len],
+                modification_data,
+            );
+        }
+    }
+
+    /// Indexes all enabled components from scratch.
+    pub(crate) fn rebuild_index(&mut self) {
+        let enabled_len = self.enabled_len();
+
+        if let Some(index) = &mut self.index {
+            index.rebuild(&self.dense[..enabled_len], &self.data[..enabled_len]);
+        }
+    }
+
+    /// Indexes the components of `entities`, used after bulk insertions.
+    pub(crate) fn index_entities(&mut self, entities: &[EntityId]) {
+        if let Some(index) = &mut self.index {
+            for &entity in entities {
+                if let Some(sparse_entity) = self.sparse.get(entity) {
+                    index.insert(entity, &self.data[sparse_entity.uindex()]);
+                }
+            }
+        }
+    }
+}
+
+impl<T: Component> SparseSet<T> {
+    /// Overwrites components with the ones from `snapshot` and tracks the changes at `current`.
+    ///
+    /// Components the snapshot brings back are inserted, the ones present before and after are modified
+    /// and the ones missing from the snapshot are deleted.
+    fn rollback_to(
+        &mut self,
+        snapshot: &SparseSet<T>,
+        clone: fn(&T) -> T,
+        current: TrackingTimestamp,
+    ) {
+        let old_dense = core::mem::take(&mut self.dense);
+        let old_data = core::mem::take(&mut self.data);
+
+        self.restore_from(snapshot, clone);
+
+        let old_entities = old_dense.iter().copied().collect::<ShipHashSet<_>>();
+        for (index, entity) in self.dense.iter().enumerate() {
+            if old_entities.contains(entity) {
+                if self.is_tracking_modification {
+                    self.modification_data[index] = current;
+                }
+            } else if self.is_tracking_insertion {
+                self.insertion_data[index] = current;
+            }
+        }
+
+        if self.is_tracking_deletion {
+            for (entity, component) in old_dense.into_iter().zip(old_data) {
+                if self
+                    .sparse
+                    .get(entity)
+                    .map(|sparse_entity| sparse_entity.gen())
+                    != Some(entity.gen())
+                {
+                    self.deletion_data.push((entity, current, component));
+                }
+            }
+        }
+    }
+    /// Overwrites components and tracking information with the ones from `snapshot`.
+    ///
+    /// Callbacks and tracking that was enabled after the snapshot are kept.
+    fn restore_from(&mut self, snapshot: &SparseSet<T>, clone: fn(&T) -> T) {
+        self.sparse.clone_from(&snapshot.sparse);
+        self.dense.clone_from(&snapshot.dense);
+        self.disabled_len = snapshot.disabled_len;
+        if let Some(group) = &mut self.group {
+            group.unpack();
+        }
+        self.data.clear();
+        self.data.extend(snapshot.data.iter().map(clone));
+        self.rebuild_index();
+        self.last_insert = snapshot.last_insert;
+        self.last_modified = snapshot.last_modified;
+        self.insertion_data.clone_from(&snapshot.insertion_data);
+        self.modification_data
+            .clone_from(&snapshot.modification_data);
+        self.deletion_data.clear();
+        self.deletion_data.extend(
+            snapshot
+                .deletion_data
+                .iter()
+                .map(|(entity, timestamp, component)| (*entity, *timestamp, clone(component))),
+        );
+        self.removal_data.clone_from(&snapshot.removal_data);
+
+        self.is_tracking_insertion |= snapshot.is_tracking_insertion;
+        self.is_tracking_modification |= snapshot.is_tracking_modification;
+        self.is_tracking_deletion |= snapshot.is_tracking_deletion;
+        self.is_tracking_removal |= snapshot.is_tracking_removal;
+
+        if self.is_tracking_insertion {
+            self.insertion_data
+                .resize(self.dense.len(), TrackingTimestamp::origin());
+        }
+        if self.is_tracking_modification {
+            self.modification_data
+                .resize(self.dense.len(), TrackingTimestamp::origin());
+        }
+    }
+}
+
 impl<T: Component + Send + Sync> Storage for SparseSet<T> {
     #[inline]
     fn delete(&mut self, entity: EntityId, current: TrackingTimestamp) {
@@ -790,6 +1228,42 @@ impl<T: Component + Send + Sync> Storage for SparseSet<T> {
     fn is_empty(&self) -> bool {
         self.is_empty()
     }
+    #[inline]
+    fn disable(&mut self, entity: EntityId) {
+        self.private_disable(entity);
+    }
+    #[inline]
+    fn enable(&mut self, entity: EntityId) {
+        self.private_enable(entity);
+    }
+    #[inline]
+    fn dense(&self) -> Option<&[EntityId]> {
+        Some(&self.dense[..self.enabled_len()])
+    }
+    #[inline]
+    fn group_member_mut(&mut self) -> Option<&mut Option<GroupMember>> {
+        Some(&mut self.group)
+    }
+    #[inline]
+    fn group_swap(&mut self, first: usize, second: usize) {
+        self.swap_dense(first, second);
+    }
+    #[inline]
+    fn component_ptr(&self, entity: EntityId) -> Option<*const u8> {
+        self.private_component_ptr(entity)
+    }
+    #[inline]
+    fn component_ptr_mut(
+        &mut self,
+        entity: EntityId,
+        current: TrackingTimestamp,
+    ) -> Option<*mut u8> {
+        self.private_component_ptr_mut(entity, current)
+    }
+    #[inline]
+    fn raw_component_access(&mut self) -> Option<RawComponentAccess> {
+        Some(RawComponentAccess::from_sparse_set(self))
+    }
     fn clear_all_inserted(&mut self, current: TrackingTimestamp) {
         self.last_insert = current;
     }
@@ -822,6 +1296,10 @@ impl<T: Component + Send + Sync> Storage for SparseSet<T> {
                 SparseSet::<T>::new,
             );
 
+            if other_sparse_set.map_entities.is_none() {
+                other_sparse_set.map_entities = self.map_entities;
+            }
+
             let _ = other_sparse_set.insert(to, component, other_current);
         }
     }
@@ -832,6 +1310,7 @@ impl<T: Component + Send + Sync> Storage for SparseSet<T> {
 
             sparse_set.sparse = self.sparse.clone();
             sparse_set.dense = self.dense.clone();
+            sparse_set.disabled_len = self.disabled_len;
             sparse_set.data = self.data.iter().map(clone).collect();
 
             if sparse_set.is_tracking_insertion {
@@ -849,6 +1328,25 @@ impl<T: Component + Send + Sync> Storage for SparseSet<T> {
         })
     }
 
+    fn try_snapshot(&self) -> Option<SBoxBuilder> {
+        self.clone.map(|clone| {
+            let mut sparse_set = SparseSet::<T>::new();
+
+            sparse_set.restore_from(self, clone);
+            sparse_set.clone = Some(clone);
+
+            SBoxBuilder::new(sparse_set)
+        })
+    }
+
+    fn restore_snapshot(&mut self, snapshot: &dyn Storage, current: TrackingTimestamp) {
+        if let Some(snapshot) = snapshot.any().downcast_ref::<SparseSet<T>>() {
+            if let Some(clone) = snapshot.clone {
+                self.rollback_to(snapshot, clone, current);
+            }
+        }
+    }
+
     fn clone_component_to(
         &self,
         other_all_storages: &mut AllStorages,
@@ -863,10 +1361,30 @@ impl<T: Component + Send + Sync> Storage for SparseSet<T> {
                     SparseSet::<T>::new,
                 );
 
+                if other_sparse_set.map_entities.is_none() {
+                    other_sparse_set.map_entities = self.map_entities;
+                }
+
                 let _ = other_sparse_set.insert(to, (clone)(component), other_current);
             }
         }
     }
+
+    fn duplicate_component(&mut self, from: EntityId, to: &[EntityId], current: TrackingTimestamp) {
+        self.private_duplicate_component(from, to, current);
+    }
+
+    fn map_entities(&mut self, entity_map: &EntityMap) {
+        if let Some(map_entities) = self.map_entities {
+            for (_, entity) in entity_map.iter() {
+                if let Some(index) = self.index_of(entity) {
+                    map_entities(&mut self.data[index], entity_map);
+                }
+            }
+
+            self.rebuild_index();
+        }
+    }
 }
 
 #[cfg(test)]
@@ -12,6 +12,13 @@ pub trait TupleRemove {
     type Out;
     /// Trait used as bound for [`World::remove`] and [`AllStorages::remove`].
     fn remove(all_storages: &mut AllStorages, entity: EntityId) -> Self::Out;
+    /// Returns `true` if `entity` owns all the components [`remove`](TupleRemove::remove) would take.\
+    /// Defaults to `true`.
+    #[inline]
+    #[allow(unused_variables)]
+    fn contains(all_storages: &mut AllStorages, entity: EntityId) -> bool {
+        true
+    }
 }
 
 impl<T: Send + Sync + Component> TupleRemove for T {
@@ -25,6 +32,13 @@ impl<T: Send + Sync + Component> TupleRemove for T {
             .exclusive_storage_or_insert_mut(StorageId::of::<SparseSet<T>>(), SparseSet::new)
             .dyn_remove(entity, current)
     }
+
+    #[inline]
+    fn contains(all_storages: &mut AllStorages, entity: EntityId) -> bool {
+        all_storages
+            .exclusive_storage_mut::<SparseSet<T>>()
+            .is_ok_and(|sparse_set| sparse_set.contains(entity))
+    }
 }
 
 macro_rules! impl_remove_component {
@@ -41,6 +55,14 @@ macro_rules! impl_remove_component {
                         .dyn_remove(entity, current),
                 )+)
             }
+
+            fn contains(all_storages: &mut AllStorages, entity: EntityId) -> bool {
+                $(
+                    all_storages
+                        .exclusive_storage_mut::<SparseSet<$type>>()
+                        .is_ok_and(|sparse_set| sparse_set.contains(entity))
+                )&&+
+            }
         }
     };
 }
@@ -25,6 +25,30 @@ impl<T: Component + Sync> Storage for NonSend<SparseSet<T>> {
     fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
+    #[inline]
+    fn disable(&mut self, entity: EntityId) {
+        self.private_disable(entity);
+    }
+    #[inline]
+    fn enable(&mut self, entity: EntityId) {
+        self.private_enable(entity);
+    }
+    #[inline]
+    fn dense(&self) -> Option<&[EntityId]> {
+        Some(&self.dense[..self.enabled_len()])
+    }
+    #[inline]
+    fn component_ptr(&self, entity: EntityId) -> Option<*const u8> {
+        self.private_component_ptr(entity)
+    }
+    #[inline]
+    fn component_ptr_mut(
+        &mut self,
+        entity: EntityId,
+        current: TrackingTimestamp,
+    ) -> Option<*mut u8> {
+        self.private_component_ptr_mut(entity, current)
+    }
     fn clear_all_removed_and_deleted(&mut self) {
         self.deletion_data.clear();
         self.removal_data.clear();
@@ -66,6 +90,7 @@ impl<T: Component + Sync> Storage for NonSend<SparseSet<T>> {
 
             sparse_set.sparse = self.sparse.clone();
             sparse_set.dense = self.dense.clone();
+            sparse_set.disabled_len = self.disabled_len;
             sparse_set.data = self.data.iter().map(clone).collect();
 
             if sparse_set.is_tracking_insertion {
@@ -83,6 +108,11 @@ impl<T: Component + Sync> Storage for NonSend<SparseSet<T>> {
         })
     }
 
+    #[inline]
+    fn duplicate_component(&mut self, from: EntityId, to: &[EntityId], current: TrackingTimestamp) {
+        self.private_duplicate_component(from, to, current);
+    }
+
     fn clone_component_to(
         &self,
         other_all_storages: &mut AllStorages,
@@ -121,6 +151,30 @@ impl<T: Component + Send> Storage for NonSync<SparseSet<T>> {
     fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
+    #[inline]
+    fn disable(&mut self, entity: EntityId) {
+        self.private_disable(entity);
+    }
+    #[inline]
+    fn enable(&mut self, entity: EntityId) {
+        self.private_enable(entity);
+    }
+    #[inline]
+    fn dense(&self) -> Option<&[EntityId]> {
+        Some(&self.dense[..self.enabled_len()])
+    }
+    #[inline]
+    fn component_ptr(&self, entity: EntityId) -> Option<*const u8> {
+        self.private_component_ptr(entity)
+    }
+    #[inline]
+    fn component_ptr_mut(
+        &mut self,
+        entity: EntityId,
+        current: TrackingTimestamp,
+    ) -> Option<*mut u8> {
+        self.private_component_ptr_mut(entity, current)
+    }
     fn clear_all_removed_and_deleted(&mut self) {
         self.deletion_data.clear();
         self.removal_data.clear();
@@ -161,6 +215,7 @@ impl<T: Component + Send> Storage for NonSync<SparseSet<T>> {
 
             sparse_set.sparse = self.sparse.clone();
             sparse_set.dense = self.dense.clone();
+            sparse_set.disabled_len = self.disabled_len;
             sparse_set.data = self.data.iter().map(clone).collect();
 
             if sparse_set.is_tracking_insertion {
@@ -178,6 +233,11 @@ impl<T: Component + Send> Storage for NonSync<SparseSet<T>> {
         })
     }
 
+    #[inline]
+    fn duplicate_component(&mut self, from: EntityId, to: &[EntityId], current: TrackingTimestamp) {
+        self.private_duplicate_component(from, to, current);
+    }
+
     fn clone_component_to(
         &self,
         other_all_storages: &mut AllStorages,
@@ -216,6 +276,30 @@ impl<T: Component> Storage for NonSendSync<SparseSet<T>> {
     fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
+    #[inline]
+    fn disable(&mut self, entity: EntityId) {
+        self.private_disable(entity);
+    }
+    #[inline]
+    fn enable(&mut self, entity: EntityId) {
+        self.private_enable(entity);
+    }
+    #[inline]
+    fn dense(&self) -> Option<&[EntityId]> {
+        Some(&self.dense[..self.enabled_len()])
+    }
+    #[inline]
+    fn component_ptr(&self, entity: EntityId) -> Option<*const u8> {
+        self.private_component_ptr(entity)
+    }
+    #[inline]
+    fn component_ptr_mut(
+        &mut self,
+        entity: EntityId,
+        current: TrackingTimestamp,
+    ) -> Option<*mut u8> {
+        self.private_component_ptr_mut(entity, current)
+    }
     fn clear_all_removed_and_deleted(&mut self) {
         self.deletion_data.clear();
         self.removal_data.clear();
@@ -258,6 +342,7 @@ impl<T: Component> Storage for NonSendSync<SparseSet<T>> {
 
             sparse_set.sparse = self.sparse.clone();
             sparse_set.dense = self.dense.clone();
+            sparse_set.disabled_len = self.disabled_len;
             sparse_set.data = self.data.iter().map(clone).collect();
 
             if sparse_set.is_tracking_insertion {
@@ -275,6 +360,11 @@ impl<T: Component> Storage for NonSendSync<SparseSet<T>> {
         })
     }
 
+    #[inline]
+    fn duplicate_component(&mut self, from: EntityId, to: &[EntityId], current: TrackingTimestamp) {
+        self.private_duplicate_component(from, to, current);
+    }
+
     fn clone_component_to(
         &self,
         other_all_storages: &mut AllStorages,
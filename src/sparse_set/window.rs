@@ -1,11 +1,15 @@
 use crate::atomic_refcell::{ExclusiveBorrow, SharedBorrow};
 use crate::component::Component;
 use crate::entity_id::EntityId;
+use crate::group::GroupMember;
+use crate::index::IndexSync;
+use crate::sparse_set::{SparseArray, SparseSet};
 use crate::tracking::{Tracking, TrackingTimestamp};
 use crate::views::{View, ViewMut};
 use alloc::boxed::Box;
 use alloc::vec::Vec;
 use core::marker::PhantomData;
+use core::mem::size_of;
 use core::ptr::{self, NonNull};
 
 pub struct FullRawWindow<'a, T> {
@@ -13,6 +17,7 @@ pub struct FullRawWindow<'a, T> {
     sparse_len: usize,
     pub(crate) dense: NonNull<EntityId>,
     pub(crate) dense_len: usize,
+    pub(crate) group: Option<GroupMember>,
     pub(crate) data: *const T,
     pub(crate) insertion_data: *const TrackingTimestamp,
     pub(crate) modification_data: *const TrackingTimestamp,
@@ -35,7 +40,8 @@ impl<'w, T: Component> FullRawWindow<'w, T> {
             sparse,
             sparse_len,
             dense: NonNull::new(view.dense.as_ptr().cast_mut()).unwrap(),
-            dense_len: view.dense.len(),
+            dense_len: view.enabled_len(),
+            group: view.group,
             data: view.data.as_ptr(),
             insertion_data: view.insertion_data.as_ptr(),
             modification_data: view.modification_data.as_ptr(),
@@ -69,7 +75,8 @@ impl<'w, T: Component> FullRawWindow<'w, T> {
                 sparse,
                 sparse_len,
                 dense: NonNull::new(sparse_set.dense.as_ptr().cast_mut()).unwrap(),
-                dense_len: sparse_set.dense.len(),
+                dense_len: sparse_set.enabled_len(),
+                group: sparse_set.group,
                 data: sparse_set.data.as_ptr(),
                 insertion_data: sparse_set.insertion_data.as_ptr(),
                 modification_data: sparse_set.modification_data.as_ptr(),
@@ -93,7 +100,8 @@ impl<'w, T: Component> FullRawWindow<'w, T> {
             sparse,
             sparse_len,
             dense: NonNull::new(view.dense.as_ptr().cast_mut()).unwrap(),
-            dense_len: view.dense.len(),
+            dense_len: view.enabled_len(),
+            group: view.group,
             data: view.data.as_ptr(),
             insertion_data: view.insertion_data.as_ptr(),
             modification_data: view.modification_data.as_ptr(),
@@ -107,7 +115,7 @@ impl<'w, T: Component> FullRawWindow<'w, T> {
     #[inline]
     pub(crate) fn index_of(&self, entity: EntityId) -> Option<usize> {
         self.sparse_index(entity).and_then(|sparse_entity| {
-            if entity.gen() == sparse_entity.gen() {
+            if entity.gen() == sparse_entity.gen() && sparse_entity.uindex() < self.dense_len {
                 Some(sparse_entity.uindex())
             } else {
                 None
@@ -149,6 +157,7 @@ impl<T: Component> Clone for FullRawWindow<'_, T> {
             sparse_len: self.sparse_len,
             dense: self.dense,
             dense_len: self.dense_len,
+            group: self.group,
             data: self.data,
             insertion_data: self.insertion_data,
             modification_data: self.modification_data,
@@ -165,6 +174,7 @@ pub struct FullRawWindowMut<'a, T, Track> {
     sparse_len: usize,
     pub(crate) dense: NonNull<EntityId>,
     pub(crate) dense_len: usize,
+    pub(crate) group: Option<GroupMember>,
     pub(crate) data: *mut T,
     pub(crate) insertion_data: *const TrackingTimestamp,
     pub(crate) modification_data: *mut TrackingTimestamp,
@@ -188,7 +198,8 @@ impl<'w, T: Component, Track> FullRawWindowMut<'w, T, Track> {
             sparse,
             sparse_len,
             dense: NonNull::new(view.dense.as_mut_ptr().cast()).unwrap(),
-            dense_len: view.dense.len(),
+            dense_len: view.enabled_len(),
+            group: view.group,
             data: view.data.as_mut_ptr(),
             insertion_data: view.insertion_data.as_ptr(),
             modification_data: view.modification_data.as_mut_ptr(),
@@ -203,9 +214,14 @@ impl<'w, T: Component, Track> FullRawWindowMut<'w, T, Track> {
     #[inline]
     pub(crate) fn new_owned<TRACK>(
         view: ViewMut<'_, T, TRACK>,
-    ) -> (Self, Option<SharedBorrow<'_>>, ExclusiveBorrow<'_>) {
+    ) -> (
+        Self,
+        Option<SharedBorrow<'_>>,
+        (Option<IndexSync>, ExclusiveBorrow<'_>),
+    ) {
         let ViewMut {
             sparse_set,
+            index_sync,
             all_borrow,
             borrow,
             last_insertion,
@@ -224,7 +240,8 @@ impl<'w, T: Component, Track> FullRawWindowMut<'w, T, Track> {
                 sparse,
                 sparse_len,
                 dense: NonNull::new(sparse_set.dense.as_mut_ptr().cast()).unwrap(),
-                dense_len: sparse_set.dense.len(),
+                dense_len: sparse_set.enabled_len(),
+                group: sparse_set.group,
                 data: sparse_set.data.as_mut_ptr(),
                 insertion_data: sparse_set.insertion_data.as_ptr(),
                 modification_data: sparse_set.modification_data.as_mut_ptr(),
@@ -235,14 +252,14 @@ impl<'w, T: Component, Track> FullRawWindowMut<'w, T, Track> {
                 _phantom: PhantomData,
             },
             all_borrow,
-            borrow,
+            (index_sync, borrow),
         )
     }
 
     #[inline]
     pub(crate) fn index_of(&self, entity: EntityId) -> Option<usize> {
         self.sparse_index(entity).and_then(|sparse_entity| {
-            if entity.gen() == sparse_entity.gen() {
+            if entity.gen() == sparse_entity.gen() && sparse_entity.uindex() < self.dense_len {
                 Some(sparse_entity.uindex())
             } else {
                 None
@@ -284,6 +301,7 @@ impl<T: Component, Track> Clone for FullRawWindowMut<'_, T, Track> {
             sparse_len: self.sparse_len,
             dense: self.dense,
             dense_len: self.dense_len,
+            group: self.group,
             data: self.data,
             insertion_data: self.insertion_data,
             modification_data: self.modification_data,
@@ -391,3 +409,123 @@ impl RawEntityIdAccess {
         (self, other)
     }
 }
+
+/// Type-erased pointers to a storage's components, taken once from an exclusive borrow.
+///
+/// Used by [`DynamicQuery`](crate::dynamic_query::DynamicQuery) to access components
+/// without borrowing the storage again for each entity.
+#[doc(hidden)]
+pub struct RawComponentAccess {
+    sparse: *const *const EntityId,
+    sparse_len: usize,
+    dense: *const EntityId,
+    dense_len: usize,
+    data: *mut u8,
+    component_size: usize,
+    modification_data: *mut TrackingTimestamp,
+    is_tracking_modification: bool,
+}
+
+unsafe impl Send for RawComponentAccess {}
+unsafe impl Sync for RawComponentAccess {}
+
+impl RawComponentAccess {
+    #[inline]
+    pub(crate) fn from_sparse_set<T: Component>(sparse_set: &mut SparseSet<T>) -> Self {
+        let sparse: *mut Option<Box<[EntityId; super::BUCKET_SIZE]>> =
+            sparse_set.sparse.as_mut_ptr();
+
+        RawComponentAccess {
+            sparse: sparse as *const *const EntityId,
+            sparse_len: sparse_set.sparse.len(),
+            dense: sparse_set.dense.as_ptr(),
+            dense_len: sparse_set.enabled_len(),
+            data: sparse_set.data.as_mut_ptr().cast(),
+            component_size: size_of::<T>(),
+            modification_data: sparse_set.modification_data.as_mut_ptr(),
+            is_tracking_modification: sparse_set.is_tracking_modification,
+        }
+    }
+    /// `data` has to point to `dense.len()` components spaced by `component_size` bytes.
+    #[inline]
+    pub(crate) unsafe fn from_dynamic(
+        sparse: &mut SparseArray<EntityId, { super::BUCKET_SIZE }>,
+        dense: &[EntityId],
+        data: *mut u8,
+        component_size: usize,
+    ) -> Self {
+        let sparse_len = sparse.len();
+        let sparse: *mut Option<Box<[EntityId; super::BUCKET_SIZE]>> = sparse.as_mut_ptr();
+
+        RawComponentAccess {
+            sparse: sparse as *const *const EntityId,
+            sparse_len,
+            dense: dense.as_ptr(),
+            dense_len: dense.len(),
+            data,
+            component_size,
+            modification_data: ptr::null_mut(),
+            is_tracking_modification: false,
+        }
+    }
+    /// Returns the entities with an enabled component.
+    #[inline]
+    pub(crate) fn dense(&self) -> &[EntityId] {
+        unsafe { core::slice::from_raw_parts(self.dense, self.dense_len) }
+    }
+    /// Returns `true` if `entity` owns a component, even if it is disabled.
+    #[inline]
+    pub(crate) fn contains(&self, entity: EntityId) -> bool {
+        self.sparse_index(entity)
+            .is_some_and(|sparse_entity| sparse_entity.gen() == entity.gen())
+    }
+    #[inline]
+    fn enabled_index_of(&self, entity: EntityId) -> Option<usize> {
+        self.sparse_index(entity).and_then(|sparse_entity| {
+            if entity.gen() == sparse_entity.gen() && sparse_entity.uindex() < self.dense_len {
+                Some(sparse_entity.uindex())
+            } else {
+                None
+            }
+        })
+    }
+    #[inline]
+    fn sparse_index(&self, entity: EntityId) -> Option<EntityId> {
+        if entity.bucket() < self.sparse_len {
+            let bucket = unsafe { ptr::read(self.sparse.add(entity.bucket())) };
+
+            if !bucket.is_null() {
+                Some(unsafe { ptr::read(bucket.add(entity.bucket_index())) })
+            } else {
+                None
+            }
+        } else {
+            None
+        }
+    }
+    /// Returns a pointer to `entity`'s component.
+    #[inline]
+    pub(crate) fn component_ptr(&self, entity: EntityId) -> Option<*const u8> {
+        self.enabled_index_of(entity)
+            .map(|index| unsafe { self.data.add(index * self.component_size).cast_const() })
+    }
+    /// Returns a mutable pointer to `entity`'s component and flags it as modified.
+    ///
+    /// # Safety
+    ///
+    /// No other reference to this component or its modification timestamp can be alive.
+    #[inline]
+    pub(crate) unsafe fn component_ptr_mut(
+        &self,
+        entity: EntityId,
+        current: TrackingTimestamp,
+    ) -> Option<*mut u8> {
+        let index = self.enabled_index_of(entity)?;
+
+        if self.is_tracking_modification {
+            *self.modification_data.add(index) = current;
+        }
+
+        Some(self.data.add(index * self.component_size))
+    }
+}
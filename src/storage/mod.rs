@@ -8,10 +8,13 @@ pub(crate) use sbox::SBox;
 
 use crate::all_storages::AllStorages;
 use crate::entity_id::EntityId;
+use crate::group::GroupMember;
+use crate::map_entities::EntityMap;
 use crate::memory_usage::StorageMemoryUsage;
-use crate::sparse_set::SparseArray;
+use crate::sparse_set::{RawComponentAccess, SparseArray};
 use crate::tracking::TrackingTimestamp;
 use alloc::borrow::Cow;
+use alloc::vec::Vec;
 use core::any::Any;
 
 pub trait SizedAny {
@@ -46,6 +49,20 @@ pub trait Storage: SizedAny {
     #[inline]
     #[allow(unused_variables)]
     fn delete(&mut self, entity: EntityId, current: TrackingTimestamp) {}
+    /// Lists the entities that have to be deleted along with `entity`.
+    ///
+    /// Called before [`Storage::delete`] when `entity` is stripped or deleted.
+    #[inline]
+    #[allow(unused_variables)]
+    fn dependents(&self, entity: EntityId, dependents: &mut Vec<EntityId>) {}
+    /// Hides `entity`'s component from iteration and lookups without removing it.
+    #[inline]
+    #[allow(unused_variables)]
+    fn disable(&mut self, entity: EntityId) {}
+    /// Makes `entity`'s component visible to iteration and lookups again.
+    #[inline]
+    #[allow(unused_variables)]
+    fn enable(&mut self, entity: EntityId) {}
     /// Deletes all components of this storage.
     #[inline]
     #[allow(unused_variables)]
@@ -68,6 +85,52 @@ pub trait Storage: SizedAny {
     fn sparse_array(&self) -> Option<&SparseArray<EntityId, 32>> {
         None
     }
+    /// Returns the entities owning a component in this storage.
+    ///
+    /// Used to iterate [`DynamicQuery`](crate::dynamic_query::DynamicQuery).
+    #[inline]
+    fn dense(&self) -> Option<&[EntityId]> {
+        None
+    }
+    /// Returns a type-erased pointer to `entity`'s component.
+    #[inline]
+    #[allow(unused_variables)]
+    fn component_ptr(&self, entity: EntityId) -> Option<*const u8> {
+        None
+    }
+    /// Returns a type-erased mutable pointer to `entity`'s component and flags it as modified.
+    #[inline]
+    #[allow(unused_variables)]
+    fn component_ptr_mut(
+        &mut self,
+        entity: EntityId,
+        current: TrackingTimestamp,
+    ) -> Option<*mut u8> {
+        None
+    }
+    /// Returns pointers to access the components without borrowing the storage again.\
+    /// `None` if the storage doesn't support type-erased access.
+    #[doc(hidden)]
+    #[inline]
+    fn raw_component_access(&mut self) -> Option<RawComponentAccess> {
+        None
+    }
+    /// Clones `from`'s component to all `to` entities that don't already have a component in this storage.\
+    /// Does nothing if the storage doesn't have a clone function registered.
+    #[inline]
+    #[allow(unused_variables)]
+    fn duplicate_component(&mut self, from: EntityId, to: &[EntityId], current: TrackingTimestamp) {
+    }
+    /// Returns the group this storage is part of.\
+    /// `None` if the storage can't be part of a group.
+    #[inline]
+    fn group_member_mut(&mut self) -> Option<&mut Option<GroupMember>> {
+        None
+    }
+    /// Swaps the components at `first` and `second` without flagging the storage's group as unpacked.
+    #[inline]
+    #[allow(unused_variables)]
+    fn group_swap(&mut self, first: usize, second: usize) {}
     /// Returns `true` if the storage is empty.
     #[inline]
     fn is_empty(&self) -> bool {
@@ -108,6 +171,29 @@ pub trait Storage: SizedAny {
         None
     }
 
+    /// Attempts to clone the entire storage, including tracking information.
+    ///
+    /// Used by [`World::snapshot`](crate::World::snapshot).
+    #[inline]
+    fn try_snapshot(&self) -> Option<SBoxBuilder> {
+        None
+    }
+
+    /// Overwrites this storage with `snapshot`, a storage of the same type created by [`Storage::try_snapshot`].\
+    /// The changes made by the rollback are tracked at `current`.
+    ///
+    /// Used by [`World::restore`](crate::World::restore).
+    #[inline]
+    #[allow(unused_variables)]
+    fn restore_snapshot(&mut self, snapshot: &dyn Storage, current: TrackingTimestamp) {}
+
+    /// Updates the [`EntityId`]s stored in the components of the entities `entity_map` maps to.
+    ///
+    /// Used by operations creating entities from another `World`, like [`World::move_entities`](crate::World::move_entities).
+    #[inline]
+    #[allow(unused_variables)]
+    fn map_entities(&mut self, entity_map: &EntityMap) {}
+
     /// Clones a component from a `World` to another.
     #[inline]
     #[allow(unused_variables)]
@@ -14,6 +14,20 @@ impl StorageId {
     pub fn of<T: 'static>() -> Self {
         TypeId::of::<T>().into()
     }
+    /// Returns the [`StorageId::Custom`] derived from `name`.
+    ///
+    /// The same name always gives the same id, across `World`s and program runs.
+    pub fn from_name(name: &str) -> Self {
+        // 64-bit FNV-1a
+        let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+
+        for byte in name.bytes() {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+        }
+
+        StorageId::Custom(hash)
+    }
 }
 
 impl From<TypeId> for StorageId {
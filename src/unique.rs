@@ -52,6 +52,32 @@ impl<T: Unique + Send + Sync> Storage for UniqueStorage<T> {
             })
         })
     }
+
+    fn try_snapshot(&self) -> Option<SBoxBuilder> {
+        self.clone.map(|clone| {
+            SBoxBuilder::new(UniqueStorage {
+                value: clone(&self.value),
+                insert: self.insert,
+                modification: self.modification,
+                last_insert: self.last_insert,
+                last_modification: self.last_modification,
+                clone: Some(clone),
+            })
+        })
+    }
+
+    fn restore_snapshot(&mut self, snapshot: &dyn Storage, current: TrackingTimestamp) {
+        if let Some(snapshot) = snapshot.any().downcast_ref::<UniqueStorage<T>>() {
+            if let Some(clone) = snapshot.clone {
+                self.value = clone(&snapshot.value);
+                self.insert = snapshot.insert;
+                // the value changed back, systems see it as modified
+                self.modification = current;
+                self.last_insert = snapshot.last_insert;
+                self.last_modification = snapshot.last_modification;
+            }
+        }
+    }
 }
 
 impl<T: Unique> UniqueStorage<T> {
@@ -3,7 +3,12 @@
 pub mod serde;
 
 mod all_storages;
+mod commands;
 mod entities;
+mod events;
+mod hierarchy;
+mod local;
+mod relation;
 mod unique_or_default;
 mod unique_or_default_mut;
 mod unique_or_init;
@@ -14,7 +19,12 @@ mod view;
 mod view_mut;
 
 pub use all_storages::{AllStoragesView, AllStoragesViewMut};
+pub use commands::Commands;
 pub use entities::{EntitiesView, EntitiesViewMut};
+pub use events::{EventReader, EventWriter};
+pub use hierarchy::{HierarchyView, HierarchyViewMut};
+pub use local::Local;
+pub use relation::{RelationView, RelationViewMut};
 pub use unique_or_default::UniqueOrDefaultView;
 pub use unique_or_default_mut::UniqueOrDefaultViewMut;
 pub use unique_or_init::UniqueOrInitView;
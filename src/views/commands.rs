@@ -0,0 +1,131 @@
+use crate::all_storages::AllStorages;
+use crate::atomic_refcell::SharedBorrow;
+use crate::commands::{Command, CommandQueue};
+use crate::entities::Entities;
+use crate::entity_id::EntityId;
+use crate::sparse_set::{TupleAddComponent, TupleDelete, TupleRemove};
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+/// Records operations that need an exclusive access to [`AllStorages`] and applies them later.
+///
+/// Commands are applied at the end of the workload and at each [`Workload::with_barrier`].\
+/// Outside of workloads they can be applied with [`World::apply_commands`] or [`AllStorages::apply_commands`].
+///
+/// `Commands` only borrows [`Entities`] and its queue immutably, multiple systems using it can run in parallel.
+///
+/// ### Example
+///
+/// ```
+/// use shipyard::{Commands, Component, EntityId, IntoIter, View, Workload, World};
+///
+/// #[derive(Component)]
+/// struct Health(u32);
+///
+/// #[derive(Component)]
+/// struct Corpse;
+///
+/// fn kill(mut commands: Commands, healths: View<Health>) {
+///     for (entity, health) in healths.iter().with_id() {
+///         if health.0 == 0 {
+///             commands.delete_entity(entity);
+///             commands.add_entity((Corpse,));
+///         }
+///     }
+/// }
+///
+/// let mut world = World::new();
+///
+/// let entity = world.add_entity((Health(0),));
+///
+/// Workload::new("Kill").with_system(kill).add_to_world(&world).unwrap();
+/// world.run_workload("Kill").unwrap();
+///
+/// assert!(!world.is_entity_alive(entity));
+/// assert_eq!(world.borrow::<View<Corpse>>().unwrap().len(), 1);
+/// ```
+///
+/// [`Workload::with_barrier`]: crate::Workload::with_barrier()
+/// [`World::apply_commands`]: crate::World::apply_commands()
+/// [`AllStorages::apply_commands`]: crate::all_storages::AllStorages::apply_commands()
+pub struct Commands<'a> {
+    pub(crate) entities: &'a Entities,
+    pub(crate) queue: &'a CommandQueue,
+    pub(crate) commands: Vec<Command>,
+    pub(crate) _entities_borrow: SharedBorrow<'a>,
+    pub(crate) _queue_borrow: SharedBorrow<'a>,
+    pub(crate) _all_borrow: Option<SharedBorrow<'a>>,
+}
+
+impl Commands<'_> {
+    /// Reserves a new entity and records the addition of `component` to it.\
+    /// `component` must always be a tuple, even for a single component.
+    ///
+    /// The returned [`EntityId`] can be used right away in other commands
+    /// but the entity will only be alive once the commands are applied.
+    #[inline]
+    pub fn add_entity<T: TupleAddComponent + Send + 'static>(&mut self, component: T) -> EntityId {
+        let entity = self.entities.reserve();
+
+        self.add_component(entity, component);
+
+        entity
+    }
+    /// Records the addition of `component` to `entity`.\
+    /// `component` must always be a tuple, even for a single component.
+    ///
+    /// Nothing happens if `entity` is not alive when the commands are applied.
+    #[inline]
+    pub fn add_component<T: TupleAddComponent + Send + 'static>(
+        &mut self,
+        entity: EntityId,
+        component: T,
+    ) {
+        self.push(move |all_storages| {
+            if all_storages.is_entity_alive(entity) {
+                all_storages.add_component(entity, component);
+            }
+        });
+    }
+    /// Records the deletion of `C` components from `entity`.\
+    /// `C` must always be a tuple, even for a single component.
+    #[inline]
+    pub fn delete_component<C: TupleDelete + 'static>(&mut self, entity: EntityId) {
+        self.push(move |all_storages| all_storages.delete_component::<C>(entity));
+    }
+    /// Records the removal of `C` components from `entity`.\
+    /// `C` must always be a tuple, even for a single component.
+    ///
+    /// The removed components are dropped, only the removal tracking is different from [`Commands::delete_component`].
+    #[inline]
+    pub fn remove<C: TupleRemove + 'static>(&mut self, entity: EntityId) {
+        self.push(move |all_storages| {
+            all_storages.remove::<C>(entity);
+        });
+    }
+    /// Records the deletion of `entity` and all its components.
+    #[inline]
+    pub fn delete_entity(&mut self, entity: EntityId) {
+        self.push(move |all_storages| {
+            all_storages.delete_entity(entity);
+        });
+    }
+    /// Records a custom command.
+    #[inline]
+    pub fn push(&mut self, command: impl FnOnce(&mut AllStorages) + Send + 'static) {
+        self.commands.push(Box::new(command));
+    }
+    /// Returns `true` if no command was recorded by this view.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.commands.is_empty()
+    }
+}
+
+impl Drop for Commands<'_> {
+    fn drop(&mut self) {
+        if !self.commands.is_empty() {
+            self.queue.push(core::mem::take(&mut self.commands));
+        }
+    }
+}
@@ -0,0 +1,120 @@
+use crate::atomic_refcell::{ExclusiveBorrow, SharedBorrow};
+use crate::events::{is_expired, EventExpiry, Events};
+use crate::tracking::TrackingTimestamp;
+
+/// Sends events of type `T`.
+///
+/// Events are stored in an [`Events`] storage, created the first time it is borrowed.\
+/// Borrowing an `EventWriter` also drops the events that expired.
+pub struct EventWriter<'a, T: 'static> {
+    pub(crate) events: &'a mut Events<T>,
+    pub(crate) current: TrackingTimestamp,
+    pub(crate) workload_runs: u64,
+    pub(crate) _borrow: Option<ExclusiveBorrow<'a>>,
+    pub(crate) _all_borrow: Option<SharedBorrow<'a>>,
+}
+
+impl<T: 'static> EventWriter<'_, T> {
+    /// Sends an event.
+    #[inline]
+    pub fn send(&mut self, event: T) {
+        self.events.push(event, self.current, self.workload_runs);
+    }
+    /// Sends multiple events.
+    pub fn send_batch<I: IntoIterator<Item = T>>(&mut self, events: I) {
+        for event in events {
+            self.send(event);
+        }
+    }
+    /// Returns the number of events currently stored.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+    /// Returns `true` if no event is stored.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+    /// Returns the expiry policy.
+    #[inline]
+    pub fn expiry(&self) -> EventExpiry {
+        self.events.expiry
+    }
+    /// Sets when events are dropped.
+    #[inline]
+    pub fn set_expiry(&mut self, expiry: EventExpiry) {
+        self.events.expiry = expiry;
+    }
+}
+
+/// Reads events of type `T`.
+///
+/// In workloads, each system keeps its own cursor and only sees the events sent since its last run.\
+/// Outside of workloads, all events that haven't expired are visible.
+pub struct EventReader<'a, T: 'static> {
+    pub(crate) events: &'a Events<T>,
+    pub(crate) last_run: Option<TrackingTimestamp>,
+    pub(crate) current: TrackingTimestamp,
+    pub(crate) workload_runs: u64,
+    pub(crate) borrow: Option<SharedBorrow<'a>>,
+    pub(crate) all_borrow: Option<SharedBorrow<'a>>,
+}
+
+impl<'a, T: 'static> EventReader<'a, T> {
+    /// Returns an iterator over the events this reader hasn't seen yet, in the order they were sent.
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> + 'a {
+        let events = self.events;
+        let last_run = self.last_run;
+        let current = self.current;
+        let workload_runs = self.workload_runs;
+
+        events
+            .events
+            .iter()
+            .filter(move |event| {
+                let is_new = match last_run {
+                    Some(last_run) => event.timestamp.is_within(last_run, current),
+                    None => true,
+                };
+
+                let is_alive = match events.expiry {
+                    EventExpiry::AllReaders => true,
+                    EventExpiry::Runs(runs) => !is_expired(event, runs, workload_runs),
+                };
+
+                is_new && is_alive
+            })
+            .map(|event| &event.value)
+    }
+    /// Returns `true` if this reader has no event to read.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.iter().next().is_none()
+    }
+    /// Returns `true` if events sent since this reader last ran were dropped before it could read them.
+    ///
+    /// This happens when the reader didn't run for [`Events::stale_reader_runs`] workload runs
+    /// or when events expire with [`EventExpiry::Runs`].\
+    /// Outside of workloads it always returns `false`.
+    pub fn missed_events(&self) -> bool {
+        match (self.last_run, self.events.last_dropped) {
+            (Some(last_run), Some(last_dropped)) => last_dropped.is_within(last_run, self.current),
+            _ => false,
+        }
+    }
+}
+
+impl<T: 'static> Clone for EventReader<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        EventReader {
+            events: self.events,
+            last_run: self.last_run,
+            current: self.current,
+            workload_runs: self.workload_runs,
+            borrow: self.borrow.clone(),
+            all_borrow: self.all_borrow.clone(),
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use crate::atomic_refcell::{ExclusiveBorrow, SharedBorrow};
+use crate::entities::Entities;
+use crate::entity_id::EntityId;
+use crate::hierarchy::Hierarchy;
+use core::ops::{Deref, DerefMut};
+
+/// Shared view over a [`Hierarchy`] storage.
+pub struct HierarchyView<'a, H: 'static = ()> {
+    pub(crate) hierarchy: &'a Hierarchy<H>,
+    pub(crate) borrow: Option<SharedBorrow<'a>>,
+    pub(crate) all_borrow: Option<SharedBorrow<'a>>,
+}
+
+impl<H: 'static> Deref for HierarchyView<'_, H> {
+    type Target = Hierarchy<H>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.hierarchy
+    }
+}
+
+impl<H: 'static> Clone for HierarchyView<'_, H> {
+    #[inline]
+    fn clone(&self) -> Self {
+        HierarchyView {
+            hierarchy: self.hierarchy,
+            borrow: self.borrow.clone(),
+            all_borrow: self.all_borrow.clone(),
+        }
+    }
+}
+
+/// Exclusive view over a [`Hierarchy`] storage.
+pub struct HierarchyViewMut<'a, H: 'static = ()> {
+    pub(crate) hierarchy: &'a mut Hierarchy<H>,
+    pub(crate) entities: &'a Entities,
+    pub(crate) _borrow: Option<ExclusiveBorrow<'a>>,
+    pub(crate) _entities_borrow: SharedBorrow<'a>,
+    pub(crate) _all_borrow: Option<SharedBorrow<'a>>,
+}
+
+impl<H: 'static> HierarchyViewMut<'_, H> {
+    /// Attaches `child` to `parent`, as its last child.\
+    /// If `child` already had a parent, it is detached first.
+    ///
+    /// ### Panics
+    ///
+    /// - `child` or `parent` is not alive.
+    /// - `child` is `parent` or one of its ancestors.
+    #[track_caller]
+    pub fn attach(&mut self, child: EntityId, parent: EntityId) {
+        for entity in [child, parent] {
+            if !self.entities.is_alive(entity) {
+                panic!(
+                    "Entity {:?} has to be alive to be attached in a hierarchy.",
+                    entity
+                );
+            }
+        }
+
+        self.hierarchy.attach(child, parent);
+    }
+}
+
+impl<H: 'static> Deref for HierarchyViewMut<'_, H> {
+    type Target = Hierarchy<H>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.hierarchy
+    }
+}
+
+impl<H: 'static> DerefMut for HierarchyViewMut<'_, H> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.hierarchy
+    }
+}
@@ -0,0 +1,110 @@
+use crate::all_storages::AllStorages;
+use crate::atomic_refcell::{ARefMut, SharedBorrow};
+use crate::borrow::{Borrow, BorrowInfo};
+use crate::error;
+use crate::scheduler::info::TypeInfo;
+use crate::scheduler::SystemLocals;
+use crate::storage::StorageId;
+use crate::tracking::TrackingTimestamp;
+use alloc::vec::Vec;
+use core::any::type_name;
+use core::ops::{Deref, DerefMut};
+
+/// State owned by a system, kept between runs.
+///
+/// Each workload gives its own `T` to each of its systems, starting from `T::default()`.\
+/// `Local` does not borrow any storage, it never conflicts with other systems.
+///
+/// `Local` can only be borrowed by systems running in a workload.\
+/// The state is identified by `T`, a system borrowing multiple `Local<T>` with the same `T` will fail to run.
+///
+/// ### Example
+/// ```
+/// use shipyard::views::Local;
+/// use shipyard::{Workload, World};
+///
+/// fn count_frames(mut frames: Local<u32>) {
+///     *frames += 1;
+///
+///     assert!(*frames <= 2);
+/// }
+///
+/// let world = World::new();
+///
+/// Workload::new("Update")
+///     .with_system(count_frames)
+///     .add_to_world(&world)
+///     .unwrap();
+///
+/// world.run_workload("Update").unwrap();
+/// world.run_workload("Update").unwrap();
+/// ```
+pub struct Local<'v, T: Default + Send + Sync + 'static> {
+    value: ARefMut<'v, &'v mut T>,
+}
+
+impl<T: Default + Send + Sync + 'static> Deref for Local<'_, T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<T: Default + Send + Sync + 'static> DerefMut for Local<'_, T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.value
+    }
+}
+
+impl<T: Default + Send + Sync + 'static> Borrow for Local<'_, T> {
+    type View<'a> = Local<'a, T>;
+
+    fn borrow<'a>(
+        _all_storages: &'a AllStorages,
+        _all_borrow: Option<SharedBorrow<'a>>,
+        _last_run: Option<TrackingTimestamp>,
+        _current: TrackingTimestamp,
+    ) -> Result<Self::View<'a>, error::GetStorage> {
+        Err(error::GetStorage::MissingLocal {
+            name: type_name::<Local<'_, T>>(),
+        })
+    }
+
+    fn borrow_in_system<'a>(
+        _all_storages: &'a AllStorages,
+        _all_borrow: Option<SharedBorrow<'a>>,
+        _last_run: Option<TrackingTimestamp>,
+        _current: TrackingTimestamp,
+        locals: &'a SystemLocals,
+    ) -> Result<Self::View<'a>, error::GetStorage> {
+        let cell = locals
+            .get::<T>()
+            .ok_or_else(|| error::GetStorage::MissingLocal {
+                name: type_name::<Local<'_, T>>(),
+            })?;
+
+        let value = cell
+            .borrow_mut()
+            .map_err(|borrow| error::GetStorage::StorageBorrow {
+                name: Some(type_name::<Local<'_, T>>()),
+                id: StorageId::of::<Local<'static, T>>(),
+                borrow,
+            })?;
+
+        Ok(Local { value })
+    }
+}
+
+// SAFE: Local does not borrow any storage
+unsafe impl<T: Default + Send + Sync + 'static> BorrowInfo for Local<'_, T> {
+    fn borrow_info(_info: &mut Vec<TypeInfo>) {}
+
+    fn enable_tracking(_: &mut Vec<fn(&AllStorages) -> Result<(), error::GetStorage>>) {}
+
+    fn init_locals(locals: &mut SystemLocals) {
+        locals.insert::<T>();
+    }
+}
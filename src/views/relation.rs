@@ -0,0 +1,105 @@
+use crate::atomic_refcell::{ExclusiveBorrow, SharedBorrow};
+use crate::entity_id::EntityId;
+use crate::relation::{Relation, RelationStorage};
+use crate::ShipHashSet;
+use core::ops::{Deref, DerefMut};
+
+/// Shared view over a [`RelationStorage`].
+///
+/// Pairs of entities deleted with [`Entities::delete_unchecked`] since the last exclusive borrow are skipped.
+///
+/// [`Entities::delete_unchecked`]: crate::advanced::Entities::delete_unchecked()
+pub struct RelationView<'a, R: Relation> {
+    pub(crate) relations: &'a RelationStorage<R>,
+    pub(crate) pending_deletions: ShipHashSet<EntityId>,
+    pub(crate) borrow: Option<SharedBorrow<'a>>,
+    pub(crate) all_borrow: Option<SharedBorrow<'a>>,
+}
+
+impl<'a, R: Relation> RelationView<'a, R> {
+    #[inline]
+    fn is_deleted(&self, entity: EntityId) -> bool {
+        !self.pending_deletions.is_empty() && self.pending_deletions.contains(&entity)
+    }
+    /// Returns the relation between `source` and `target`.
+    pub fn get(&self, source: EntityId, target: EntityId) -> Option<&'a R> {
+        if self.is_deleted(source) || self.is_deleted(target) {
+            return None;
+        }
+
+        self.relations.get(source, target)
+    }
+    /// Returns `true` if `source` is linked to `target`.
+    pub fn contains(&self, source: EntityId, target: EntityId) -> bool {
+        self.get(source, target).is_some()
+    }
+    /// Returns an iterator over the targets of `source` and their relation.
+    pub fn targets(&self, source: EntityId) -> impl Iterator<Item = (EntityId, &'a R)> + '_ {
+        let source_deleted = self.is_deleted(source);
+
+        self.relations
+            .targets(source)
+            .filter(move |(target, _)| !source_deleted && !self.is_deleted(*target))
+    }
+    /// Returns an iterator over the entities having a relation toward `target`.
+    pub fn sources(&self, target: EntityId) -> impl Iterator<Item = EntityId> + '_ {
+        let target_deleted = self.is_deleted(target);
+
+        self.relations
+            .sources(target)
+            .filter(move |source| !target_deleted && !self.is_deleted(*source))
+    }
+    /// Returns an iterator over all pairs as `(source, target, relation)`.
+    pub fn iter(&self) -> impl Iterator<Item = (EntityId, EntityId, &'a R)> + '_ {
+        self.relations.iter().filter(move |(source, target, _)| {
+            !self.is_deleted(*source) && !self.is_deleted(*target)
+        })
+    }
+    /// Returns the number of pairs.
+    pub fn len(&self) -> usize {
+        if self.pending_deletions.is_empty() {
+            self.relations.len()
+        } else {
+            self.iter().count()
+        }
+    }
+    /// Returns `true` if there is no pair.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<R: Relation> Clone for RelationView<'_, R> {
+    #[inline]
+    fn clone(&self) -> Self {
+        RelationView {
+            relations: self.relations,
+            pending_deletions: self.pending_deletions.clone(),
+            borrow: self.borrow.clone(),
+            all_borrow: self.all_borrow.clone(),
+        }
+    }
+}
+
+/// Exclusive view over a [`RelationStorage`].
+pub struct RelationViewMut<'a, R: Relation> {
+    pub(crate) relations: &'a mut RelationStorage<R>,
+    pub(crate) _borrow: Option<ExclusiveBorrow<'a>>,
+    pub(crate) _all_borrow: Option<SharedBorrow<'a>>,
+}
+
+impl<R: Relation> Deref for RelationViewMut<'_, R> {
+    type Target = RelationStorage<R>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.relations
+    }
+}
+
+impl<R: Relation> DerefMut for RelationViewMut<'_, R> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.relations
+    }
+}
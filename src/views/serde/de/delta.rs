@@ -0,0 +1,359 @@
+use crate::add_component::AddComponent;
+use crate::component::Component;
+use crate::entity_id::EntityId;
+use crate::tracking::Tracking;
+use crate::views::{EntitiesViewMut, ViewMut};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use serde::de::{DeserializeOwned, DeserializeSeed, Error, SeqAccess, Visitor};
+use serde::Deserializer;
+
+/// Applies a delta serialized with [`ViewDeltaSerializer`] to a [`ViewMut`].
+///
+/// Deleted and removed components are deleted, inserted and modified components are added or replaced.\
+/// Returns the entities that received a component, they might not be alive in this `World` yet.
+///
+/// [`ViewDeltaSerializer`]: crate::views::serde::ser::delta::ViewDeltaSerializer
+pub struct ViewMutDeltaDeserializer<'tmp, 'view, T: Component, Track> {
+    #[allow(missing_docs)]
+    pub view: &'tmp mut ViewMut<'view, T, Track>,
+}
+
+impl<'tmp, 'view, T: Component, Track> ViewMutDeltaDeserializer<'tmp, 'view, T, Track> {
+    #[allow(missing_docs)]
+    pub fn new(
+        view: &'tmp mut ViewMut<'view, T, Track>,
+    ) -> ViewMutDeltaDeserializer<'tmp, 'view, T, Track> {
+        ViewMutDeltaDeserializer { view }
+    }
+
+    fn apply(self, removed: Vec<EntityId>, changed: Vec<(EntityId, T)>) -> Vec<EntityId>
+    where
+        Track: Tracking,
+    {
+        let current = self.view.current;
+        for entity in removed {
+            self.view.sparse_set.dyn_delete(entity, current);
+        }
+
+        let mut entities = Vec::with_capacity(changed.len());
+        for (entity, component) in changed {
+            self.view.add_component_unchecked(entity, component);
+            entities.push(entity);
+        }
+
+        entities
+    }
+}
+
+impl<'tmp, 'view, 'de, T: Component + DeserializeOwned, Track: Tracking> DeserializeSeed<'de>
+    for ViewMutDeltaDeserializer<'tmp, 'view, T, Track>
+{
+    type Value = Vec<EntityId>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct StructVisitor<'tmp, 'view, T: Component, Track> {
+            place: ViewMutDeltaDeserializer<'tmp, 'view, T, Track>,
+        }
+
+        impl<'tmp, 'view, 'de, T: Component + DeserializeOwned, Track: Tracking> Visitor<'de>
+            for StructVisitor<'tmp, 'view, T, Track>
+        {
+            type Value = Vec<EntityId>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("a struct with removed and changed fields")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let removed = seq
+                    .next_element::<Vec<EntityId>>()?
+                    .ok_or_else(|| A::Error::invalid_length(0, &self))?;
+                let changed = seq
+                    .next_element::<Vec<(EntityId, T)>>()?
+                    .ok_or_else(|| A::Error::invalid_length(1, &self))?;
+
+                Ok(self.place.apply(removed, changed))
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                let mut removed = None;
+                let mut changed = None;
+
+                while let Some(key) = map.next_key::<String>()? {
+                    if key == "removed" {
+                        removed = Some(map.next_value::<Vec<EntityId>>()?);
+                    } else if key == "changed" {
+                        changed = Some(map.next_value::<Vec<(EntityId, T)>>()?);
+                    } else {
+                        // Skip unknown fields
+                        map.next_value::<serde::de::IgnoredAny>()?;
+                    }
+                }
+
+                let removed = removed.ok_or_else(|| A::Error::missing_field("removed"))?;
+                let changed = changed.ok_or_else(|| A::Error::missing_field("changed"))?;
+
+                Ok(self.place.apply(removed, changed))
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "ViewDelta",
+            &["removed", "changed"],
+            StructVisitor { place: self },
+        )
+    }
+}
+
+/// Views a delta serialized with [`DeltaSerializer`] can be applied to.
+///
+/// Implemented for [`ViewMut`] and tuples of views.
+///
+/// [`DeltaSerializer`]: crate::views::serde::ser::delta::DeltaSerializer
+pub trait DeltaViewsMut<'de> {
+    /// Number of views.
+    const LEN: usize;
+
+    /// Deletes `entity`'s components in all views.
+    fn delete_entity(&mut self, entity: EntityId);
+
+    /// Applies the delta of each view, read as a tuple element.\
+    /// Pushes the entities that received a component to `entities`, an entity can be pushed multiple times.
+    fn deserialize_deltas<Access: SeqAccess<'de>>(
+        &mut self,
+        seq: &mut Access,
+        entities: &mut Vec<EntityId>,
+    ) -> Result<(), Access::Error>;
+}
+
+impl<'view, 'de, T: Component + DeserializeOwned, Track: Tracking> DeltaViewsMut<'de>
+    for ViewMut<'view, T, Track>
+{
+    const LEN: usize = 1;
+
+    fn delete_entity(&mut self, entity: EntityId) {
+        let current = self.current;
+        self.sparse_set.dyn_delete(entity, current);
+    }
+
+    fn deserialize_deltas<Access: SeqAccess<'de>>(
+        &mut self,
+        seq: &mut Access,
+        entities: &mut Vec<EntityId>,
+    ) -> Result<(), Access::Error> {
+        let view_entities = seq
+            .next_element_seed(ViewMutDeltaDeserializer::new(self))?
+            .ok_or_else(|| Access::Error::custom("missing view delta"))?;
+
+        entities.extend(view_entities);
+
+        Ok(())
+    }
+}
+
+/// Applies a delta serialized with [`DeltaSerializer`] to multiple views.
+///
+/// Deleted entities are deleted, entities receiving a component are spawned.\
+/// The views have to be in the same order as when serializing.
+///
+/// Use [`World::deserialize_delta`] to borrow the views and deserialize in one step.
+///
+/// [`DeltaSerializer`]: crate::views::serde::ser::delta::DeltaSerializer
+/// [`World::deserialize_delta`]: crate::World::deserialize_delta
+pub struct DeltaDeserializer<'tmp, 'view, V> {
+    #[allow(missing_docs)]
+    pub entities: &'tmp mut EntitiesViewMut<'view>,
+    #[allow(missing_docs)]
+    pub views: &'tmp mut V,
+}
+
+impl<'tmp, 'view, V> DeltaDeserializer<'tmp, 'view, V> {
+    #[allow(missing_docs)]
+    pub fn new(
+        entities: &'tmp mut EntitiesViewMut<'view>,
+        views: &'tmp mut V,
+    ) -> DeltaDeserializer<'tmp, 'view, V> {
+        DeltaDeserializer { entities, views }
+    }
+
+    fn delete_entities<'de>(&mut self, deleted_entities: Vec<EntityId>)
+    where
+        V: DeltaViewsMut<'de>,
+    {
+        for entity in deleted_entities {
+            self.views.delete_entity(entity);
+            self.entities.delete_unchecked(entity);
+        }
+    }
+
+    fn spawn_entities(&mut self, entities: Vec<EntityId>) {
+        for entity in entities {
+            self.entities.spawn(entity);
+        }
+    }
+}
+
+struct ViewsSeed<'tmp, V> {
+    views: &'tmp mut V,
+}
+
+impl<'tmp, 'de, V: DeltaViewsMut<'de>> DeserializeSeed<'de> for ViewsSeed<'tmp, V> {
+    type Value = Vec<EntityId>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct TupleVisitor<'tmp, V> {
+            views: &'tmp mut V,
+        }
+
+        impl<'tmp, 'de, V: DeltaViewsMut<'de>> Visitor<'de> for TupleVisitor<'tmp, V> {
+            type Value = Vec<EntityId>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(formatter, "a tuple of {} view deltas", V::LEN)
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let mut entities = Vec::new();
+                self.views.deserialize_deltas(&mut seq, &mut entities)?;
+
+                Ok(entities)
+            }
+        }
+
+        deserializer.deserialize_tuple(V::LEN, TupleVisitor { views: self.views })
+    }
+}
+
+impl<'tmp, 'view, 'de, V: DeltaViewsMut<'de>> DeserializeSeed<'de>
+    for DeltaDeserializer<'tmp, 'view, V>
+{
+    type Value = ();
+
+    fn deserialize<D>(self, deserializer: D) -> Result<(), D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct StructVisitor<'tmp, 'view, V> {
+            place: DeltaDeserializer<'tmp, 'view, V>,
+        }
+
+        impl<'tmp, 'view, 'de, V: DeltaViewsMut<'de>> Visitor<'de> for StructVisitor<'tmp, 'view, V> {
+            type Value = ();
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("a struct with deleted_entities and views fields")
+            }
+
+            fn visit_seq<A>(mut self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let deleted_entities = seq
+                    .next_element::<Vec<EntityId>>()?
+                    .ok_or_else(|| A::Error::invalid_length(0, &self))?;
+                self.place.delete_entities(deleted_entities);
+
+                let entities = seq
+                    .next_element_seed(ViewsSeed {
+                        views: &mut *self.place.views,
+                    })?
+                    .ok_or_else(|| A::Error::invalid_length(1, &self))?;
+                self.place.spawn_entities(entities);
+
+                Ok(())
+            }
+
+            fn visit_map<A>(mut self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                let mut entities = Vec::new();
+
+                while let Some(key) = map.next_key::<String>()? {
+                    if key == "deleted_entities" {
+                        let deleted_entities = map.next_value::<Vec<EntityId>>()?;
+                        self.place.delete_entities(deleted_entities);
+                    } else if key == "views" {
+                        entities = map.next_value_seed(ViewsSeed {
+                            views: &mut *self.place.views,
+                        })?;
+                    } else {
+                        // Skip unknown fields
+                        map.next_value::<serde::de::IgnoredAny>()?;
+                    }
+                }
+
+                self.place.spawn_entities(entities);
+
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "Delta",
+            &["deleted_entities", "views"],
+            StructVisitor { place: self },
+        )
+    }
+}
+
+macro_rules! impl_delta_views_mut {
+    ($(($type: ident, $index: tt))+) => {
+        impl<'de, $($type: DeltaViewsMut<'de>),+> DeltaViewsMut<'de> for ($($type,)+) {
+            const LEN: usize = 0 $(+ $type::LEN)+;
+
+            fn delete_entity(&mut self, entity: EntityId) {
+                $(
+                    self.$index.delete_entity(entity);
+                )+
+            }
+
+            fn deserialize_deltas<Access: SeqAccess<'de>>(
+                &mut self,
+                seq: &mut Access,
+                entities: &mut Vec<EntityId>,
+            ) -> Result<(), Access::Error> {
+                $(
+                    self.$index.deserialize_deltas(seq, entities)?;
+                )+
+
+                Ok(())
+            }
+        }
+    }
+}
+
+macro_rules! delta_views_mut {
+    ($(($type: ident, $index: tt))*;($type1: ident, $index1: tt) $(($queue_type: ident, $queue_index: tt))*) => {
+        impl_delta_views_mut![$(($type, $index))* ($type1, $index1)];
+        delta_views_mut![$(($type, $index))* ($type1, $index1); $(($queue_type, $queue_index))*];
+    };
+    ($(($type: ident, $index: tt))*;) => {}
+}
+
+#[cfg(not(feature = "extended_tuple"))]
+delta_views_mut![; (A, 0) (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)];
+#[cfg(feature = "extended_tuple")]
+delta_views_mut![;
+    (A, 0) (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)
+    (K, 10) (L, 11) (M, 12) (N, 13) (O, 14) (P, 15) (Q, 16) (R, 17) (S, 18) (T, 19)
+    (U, 20) (V, 21) (W, 22) (X, 23) (Y, 24) (Z, 25) (AA, 26) (BB, 27) (CC, 28) (DD, 29)
+    (EE, 30) (FF, 31)
+];
@@ -1,15 +1,12 @@
-///
-// #[allow(missing_docs)] for the module only
+/// Deserialization of the changes recorded by a delta serialization.
+pub mod delta;
+/// Deserialization of [`EntitiesViewMut`](crate::views::EntitiesViewMut).
 pub mod entities_mut;
-///
-// #[allow(missing_docs)] for the module only
+/// Deserialization of [`UniqueViewMut`](crate::views::UniqueViewMut).
 pub mod unique_mut;
-///
-// #[allow(missing_docs)] for the module only
+/// Deserialization of [`UniqueOrDefaultViewMut`](crate::views::UniqueOrDefaultViewMut).
 pub mod unique_or_default_mut;
-///
-// #[allow(missing_docs)] for the module only
+/// Deserialization of [`UniqueOrInitViewMut`](crate::views::UniqueOrInitViewMut).
 pub mod unique_or_init_mut;
-///
-// #[allow(missing_docs)] for the module only
+/// Deserialization of [`ViewMut`](crate::views::ViewMut).
 pub mod view_mut;
@@ -1,6 +1,4 @@
-///
-// #[allow(missing_docs)] for the module only
+/// Deserialization of views.
 pub mod de;
-///
-// #[allow(missing_docs)] for the module only
+/// Serialization of views.
 pub mod ser;
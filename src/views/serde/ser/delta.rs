@@ -0,0 +1,272 @@
+use crate::component::Component;
+use crate::entities::Entities;
+use crate::entity_id::EntityId;
+use crate::sparse_set::SparseSet;
+use crate::tracking::{Tracking, TrackingTimestamp};
+use crate::views::{EntitiesView, View};
+use alloc::vec::Vec;
+use serde::ser::{SerializeStruct, SerializeTuple};
+use serde::{Serialize, Serializer};
+
+/// Returns `true` if `timestamp` is within `[since, until)`.
+#[inline]
+pub(crate) fn in_delta_range(
+    timestamp: TrackingTimestamp,
+    since: TrackingTimestamp,
+    until: TrackingTimestamp,
+) -> bool {
+    !timestamp.is_older_than(since) && timestamp.is_older_than(until)
+}
+
+/// Serializes the changes made to a [`View`] between two [`TrackingTimestamp`]s.
+///
+/// `since` is inclusive and `until` exclusive, [`World::get_tracking_timestamp`] can be used to get both.
+/// Consecutive deltas can then use the previous `until` as their `since`.
+///
+/// Only tracked operations are part of the delta, inserted and modified components are serialized with their value,
+/// deleted and removed components with their [`EntityId`].
+///
+/// [`World::get_tracking_timestamp`]: crate::World::get_tracking_timestamp
+pub struct ViewDeltaSerializer<'tmp, 'view, T: Component, Track> {
+    #[allow(missing_docs)]
+    pub view: &'tmp View<'view, T, Track>,
+    #[allow(missing_docs)]
+    pub since: TrackingTimestamp,
+    #[allow(missing_docs)]
+    pub until: TrackingTimestamp,
+}
+
+impl<'tmp, 'view, T: Component, Track> ViewDeltaSerializer<'tmp, 'view, T, Track> {
+    #[allow(missing_docs)]
+    pub fn new(
+        view: &'tmp View<'view, T, Track>,
+        since: TrackingTimestamp,
+        until: TrackingTimestamp,
+    ) -> ViewDeltaSerializer<'tmp, 'view, T, Track> {
+        ViewDeltaSerializer { view, since, until }
+    }
+}
+
+impl<'tmp, 'view, T: Component + Serialize, Track: Tracking> Serialize
+    for ViewDeltaSerializer<'tmp, 'view, T, Track>
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let sparse_set: &SparseSet<T> = self.view.sparse_set;
+
+        let removed = sparse_set
+            .deletion_data
+            .iter()
+            .map(|(entity, timestamp, _)| (*entity, *timestamp))
+            .chain(sparse_set.removal_data.iter().copied())
+            .filter(|(_, timestamp)| in_delta_range(*timestamp, self.since, self.until))
+            .map(|(entity, _)| entity)
+            .collect::<Vec<_>>();
+
+        let changed = sparse_set
+            .dense
+            .iter()
+            .zip(&sparse_set.data)
+            .enumerate()
+            .filter(|(index, _)| {
+                let inserted = sparse_set.is_tracking_insertion
+                    && in_delta_range(sparse_set.insertion_data[*index], self.since, self.until);
+                let modified = sparse_set.is_tracking_modification
+                    && in_delta_range(sparse_set.modification_data[*index], self.since, self.until);
+
+                inserted || modified
+            })
+            .map(|(_, (entity, component))| (*entity, component))
+            .collect::<Vec<_>>();
+
+        let mut state = serializer.serialize_struct("ViewDelta", 2)?;
+        state.serialize_field("removed", &removed)?;
+        state.serialize_field("changed", &changed)?;
+        state.end()
+    }
+}
+
+/// Views whose changes can be serialized with [`DeltaSerializer`].
+///
+/// Implemented for [`View`] and tuples of views.
+pub trait DeltaViews {
+    /// Number of views.
+    const LEN: usize;
+
+    /// Pushes the entities that had a component deleted between `since` and `until` and are no longer alive.\
+    /// An entity can be pushed multiple times.
+    fn deleted_entities(
+        &self,
+        entities: &Entities,
+        since: TrackingTimestamp,
+        until: TrackingTimestamp,
+        deleted_entities: &mut Vec<EntityId>,
+    );
+
+    /// Serializes the delta of each view as a tuple element.
+    fn serialize_deltas<S: SerializeTuple>(
+        &self,
+        since: TrackingTimestamp,
+        until: TrackingTimestamp,
+        tuple: &mut S,
+    ) -> Result<(), S::Error>;
+}
+
+impl<'view, T: Component + Serialize, Track: Tracking> DeltaViews for View<'view, T, Track> {
+    const LEN: usize = 1;
+
+    fn deleted_entities(
+        &self,
+        entities: &Entities,
+        since: TrackingTimestamp,
+        until: TrackingTimestamp,
+        deleted_entities: &mut Vec<EntityId>,
+    ) {
+        for (entity, timestamp, _) in &self.sparse_set.deletion_data {
+            if in_delta_range(*timestamp, since, until) && !entities.is_alive(*entity) {
+                deleted_entities.push(*entity);
+            }
+        }
+    }
+
+    fn serialize_deltas<S: SerializeTuple>(
+        &self,
+        since: TrackingTimestamp,
+        until: TrackingTimestamp,
+        tuple: &mut S,
+    ) -> Result<(), S::Error> {
+        tuple.serialize_element(&ViewDeltaSerializer::new(self, since, until))
+    }
+}
+
+/// Serializes the changes made to multiple views between two [`TrackingTimestamp`]s.
+///
+/// In addition to each [`ViewDeltaSerializer`], lists the entities deleted in the meantime.
+/// Only entities that had a tracked component deleted in one of the views can be listed.
+///
+/// Use [`World::serialize_delta`] to borrow the views and serialize in one step.
+///
+/// [`World::serialize_delta`]: crate::World::serialize_delta
+pub struct DeltaSerializer<'tmp, 'view, V> {
+    #[allow(missing_docs)]
+    pub entities: &'tmp EntitiesView<'view>,
+    #[allow(missing_docs)]
+    pub views: &'tmp V,
+    #[allow(missing_docs)]
+    pub since: TrackingTimestamp,
+    #[allow(missing_docs)]
+    pub until: TrackingTimestamp,
+}
+
+impl<'tmp, 'view, V: DeltaViews> DeltaSerializer<'tmp, 'view, V> {
+    #[allow(missing_docs)]
+    pub fn new(
+        entities: &'tmp EntitiesView<'view>,
+        views: &'tmp V,
+        since: TrackingTimestamp,
+        until: TrackingTimestamp,
+    ) -> DeltaSerializer<'tmp, 'view, V> {
+        DeltaSerializer {
+            entities,
+            views,
+            since,
+            until,
+        }
+    }
+}
+
+impl<'tmp, 'view, V: DeltaViews> Serialize for DeltaSerializer<'tmp, 'view, V> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        struct Views<'a, V> {
+            views: &'a V,
+            since: TrackingTimestamp,
+            until: TrackingTimestamp,
+        }
+
+        impl<V: DeltaViews> Serialize for Views<'_, V> {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                let mut tuple = serializer.serialize_tuple(V::LEN)?;
+                self.views
+                    .serialize_deltas(self.since, self.until, &mut tuple)?;
+                tuple.end()
+            }
+        }
+
+        let mut deleted_entities = Vec::new();
+        self.views
+            .deleted_entities(self.entities, self.since, self.until, &mut deleted_entities);
+        deleted_entities.sort_unstable();
+        deleted_entities.dedup();
+
+        let mut state = serializer.serialize_struct("Delta", 2)?;
+        state.serialize_field("deleted_entities", &deleted_entities)?;
+        state.serialize_field(
+            "views",
+            &Views {
+                views: self.views,
+                since: self.since,
+                until: self.until,
+            },
+        )?;
+        state.end()
+    }
+}
+
+macro_rules! impl_delta_views {
+    ($(($type: ident, $index: tt))+) => {
+        impl<$($type: DeltaViews),+> DeltaViews for ($($type,)+) {
+            const LEN: usize = 0 $(+ $type::LEN)+;
+
+            fn deleted_entities(
+                &self,
+                entities: &Entities,
+                since: TrackingTimestamp,
+                until: TrackingTimestamp,
+                deleted_entities: &mut Vec<EntityId>,
+            ) {
+                $(
+                    self.$index.deleted_entities(entities, since, until, deleted_entities);
+                )+
+            }
+
+            fn serialize_deltas<Ser: SerializeTuple>(
+                &self,
+                since: TrackingTimestamp,
+                until: TrackingTimestamp,
+                tuple: &mut Ser,
+            ) -> Result<(), Ser::Error> {
+                $(
+                    self.$index.serialize_deltas(since, until, tuple)?;
+                )+
+
+                Ok(())
+            }
+        }
+    }
+}
+
+macro_rules! delta_views {
+    ($(($type: ident, $index: tt))*;($type1: ident, $index1: tt) $(($queue_type: ident, $queue_index: tt))*) => {
+        impl_delta_views![$(($type, $index))* ($type1, $index1)];
+        delta_views![$(($type, $index))* ($type1, $index1); $(($queue_type, $queue_index))*];
+    };
+    ($(($type: ident, $index: tt))*;) => {}
+}
+
+#[cfg(not(feature = "extended_tuple"))]
+delta_views![; (A, 0) (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)];
+#[cfg(feature = "extended_tuple")]
+delta_views![;
+    (A, 0) (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)
+    (K, 10) (L, 11) (M, 12) (N, 13) (O, 14) (P, 15) (Q, 16) (R, 17) (S, 18) (T, 19)
+    (U, 20) (V, 21) (W, 22) (X, 23) (Y, 24) (Z, 25) (AA, 26) (BB, 27) (CC, 28) (DD, 29)
+    (EE, 30) (FF, 31)
+];
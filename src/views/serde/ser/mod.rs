@@ -1,30 +1,22 @@
-///
-// #[allow(missing_docs)] for the module only
+/// Serialization of the changes tracked since a [`TrackingTimestamp`](crate::tracking::TrackingTimestamp).
+pub mod delta;
+/// Serialization of [`EntitiesView`](crate::views::EntitiesView).
 pub mod entities;
-///
-// #[allow(missing_docs)] for the module only
+/// Serialization of [`EntitiesViewMut`](crate::views::EntitiesViewMut).
 pub mod entities_mut;
-///
-// #[allow(missing_docs)] for the module only
+/// Serialization of [`UniqueView`](crate::views::UniqueView).
 pub mod unique;
-///
-// #[allow(missing_docs)] for the module only
+/// Serialization of [`UniqueViewMut`](crate::views::UniqueViewMut).
 pub mod unique_mut;
-///
-// #[allow(missing_docs)] for the module only
+/// Serialization of [`UniqueOrDefaultView`](crate::views::UniqueOrDefaultView).
 pub mod unique_or_default;
-///
-// #[allow(missing_docs)] for the module only
+/// Serialization of [`UniqueOrDefaultViewMut`](crate::views::UniqueOrDefaultViewMut).
 pub mod unique_or_default_mut;
-///
-// #[allow(missing_docs)] for the module only
+/// Serialization of [`UniqueOrInitView`](crate::views::UniqueOrInitView).
 pub mod unique_or_init;
-///
-// #[allow(missing_docs)] for the module only
+/// Serialization of [`UniqueOrInitViewMut`](crate::views::UniqueOrInitViewMut).
 pub mod unique_or_init_mut;
-///
-// #[allow(missing_docs)] for the module only
+/// Serialization of [`View`](crate::views::View).
 pub mod view;
-///
-// #[allow(missing_docs)] for the module only
+/// Serialization of [`ViewMut`](crate::views::ViewMut).
 pub mod view_mut;
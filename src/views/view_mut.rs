@@ -4,6 +4,7 @@ use crate::component::Component;
 use crate::entity_id::EntityId;
 use crate::error;
 use crate::get::Get;
+use crate::index::{ComponentIndex, IndexSync};
 use crate::r#mut::Mut;
 use crate::sparse_set::{SparseSet, SparseSetDrain};
 use crate::storage::StorageId;
@@ -20,6 +21,7 @@ use core::ops::{Deref, DerefMut};
 /// Exclusive view over a component storage.
 pub struct ViewMut<'a, T: Component, Track = <T as Component>::Tracking> {
     pub(crate) sparse_set: &'a mut SparseSet<T>,
+    pub(crate) index_sync: Option<IndexSync>,
     pub(crate) all_borrow: Option<SharedBorrow<'a>>,
     pub(crate) borrow: ExclusiveBorrow<'a>,
     pub(crate) last_insertion: TrackingTimestamp,
@@ -168,8 +170,11 @@ impl<'a, T: Component> ViewMut<'a, T, track::Untracked> {
         let name = storage.name();
 
         if let Some(sparse_set) = storage.any_mut().downcast_mut() {
+            let (sparse_set, index_sync) = IndexSync::new(sparse_set);
+
             Ok(ViewMut {
                 sparse_set,
+                index_sync,
                 all_borrow: Some(all_borrow),
                 borrow,
                 last_insertion: TrackingTimestamp::new(0),
@@ -233,6 +238,16 @@ where
     pub fn retain_mut<F: FnMut(EntityId, Mut<'_, T>) -> bool>(&mut self, f: F) {
         self.sparse_set.private_retain_mut(self.current, f);
     }
+
+    /// Returns the index of this storage if its key is `K`.\
+    /// Components modified through this view are re-keyed first.
+    ///
+    /// See the [`index`](crate::index) module for more details.
+    pub fn component_index<K: 'static>(&mut self) -> Option<&ComponentIndex<T, K>> {
+        self.sparse_set.sync_index();
+
+        self.sparse_set.component_index()
+    }
 }
 
 impl<'v, Track, T: Component + Default> ViewMut<'v, T, Track>
@@ -526,7 +541,7 @@ impl<'a, T: Component, Track> core::ops::IndexMut<EntityId> for ViewMut<'a, T, T
     #[inline]
     fn index_mut(&mut self, entity: EntityId) -> &mut Self::Output {
         let index = self
-            .index_of(entity)
+            .enabled_index_of(entity)
             .ok_or_else(|| error::MissingComponent {
                 id: entity,
                 name: core::any::type_name::<T>(),
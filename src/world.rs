@@ -4,7 +4,8 @@ mod run_batches;
 pub use builder::WorldBuilder;
 
 use crate::all_storages::{
-    AllStorages, CustomStorageAccess, TupleClone, TupleDeleteAny, TupleRetainStorage,
+    AllStorages, CustomStorageAccess, Snapshot, TupleClone, TupleDeleteAny, TupleMapEntities,
+    TupleRetainStorage,
 };
 use crate::atomic_refcell::{ARef, ARefMut, AtomicRefCell};
 use crate::borrow::WorldBorrow;
@@ -14,28 +15,53 @@ use crate::entity_id::EntityId;
 use crate::error;
 use crate::get_component::GetComponent;
 use crate::get_unique::GetUnique;
+use crate::group::TupleGroup;
 use crate::iter::{ShiperatorCaptain, ShiperatorSailor};
 use crate::iter_component::{into_iter, IntoIterRef, IterComponent};
+use crate::map_entities::EntityMap;
 use crate::memory_usage::WorldMemoryUsage;
+use crate::prefab::PrefabComponents;
 use crate::r#mut::Mut;
+use crate::registry::{ComponentRegistrar, UniqueRegistrar};
+#[cfg(feature = "serde1")]
+use crate::registry::{RegistryDeserializer, RegistrySerializer};
 use crate::reserve::BulkEntityIter;
-use crate::scheduler::info::WorkloadsInfo;
-use crate::scheduler::{AsLabel, Batches, Label, Scheduler};
-use crate::sparse_set::{BulkAddEntity, TupleAddComponent, TupleDelete, TupleRemove};
+use crate::scheduler::info::{WorkloadStats, WorkloadsInfo};
+use crate::scheduler::{
+    modify_workload, AsLabel, Batches, IntoWorkloadSystem, Label, Scheduler, SystemLocals,
+    WorkloadModification,
+};
+use crate::sparse_set::{
+    BulkAddEntity, DynamicComponentInfo, TupleAddComponent, TupleDelete, TupleRemove,
+};
 use crate::storage::{Storage, StorageId};
 use crate::system::System;
 use crate::tracking::{TrackingTimestamp, TupleTrack};
+#[cfg(feature = "serde1")]
+use crate::views::serde::{
+    de::delta::{DeltaDeserializer, DeltaViewsMut},
+    ser::delta::{DeltaSerializer, DeltaViews},
+};
+#[cfg(feature = "serde1")]
+use crate::views::EntitiesView;
 use crate::views::EntitiesViewMut;
+use alloc::borrow::Cow;
 use alloc::boxed::Box;
 use alloc::format;
 use alloc::sync::Arc;
+use alloc::vec::Vec;
 use core::sync::atomic::AtomicU64;
+use core::time::Duration;
 
 /// `World` contains all data this library will manipulate.
 pub struct World {
     pub(crate) all_storages: AtomicRefCell<AllStorages>,
     pub(crate) scheduler: AtomicRefCell<Scheduler>,
     counter: Arc<AtomicU64>,
+    pub(crate) profiling_clock: Option<fn() -> Duration>,
+    deterministic: bool,
+    #[cfg(feature = "parallel")]
+    deterministic_chunk_size: usize,
     #[cfg(feature = "parallel")]
     thread_pool: Option<rayon::ThreadPool>,
 }
@@ -55,6 +81,10 @@ impl Default for World {
             ),
             scheduler: AtomicRefCell::new(Default::default()),
             counter,
+            profiling_clock: None,
+            deterministic: false,
+            #[cfg(feature = "parallel")]
+            deterministic_chunk_size: World::DEFAULT_DETERMINISTIC_CHUNK_SIZE,
             #[cfg(feature = "parallel")]
             thread_pool: None,
         }
@@ -79,6 +109,10 @@ impl Clone for World {
 }
 
 impl World {
+    /// Default number of components per chunk `par_iter` uses in a deterministic `World`, see [`World::set_deterministic_chunk_size`].
+    #[cfg(feature = "parallel")]
+    pub const DEFAULT_DETERMINISTIC_CHUNK_SIZE: usize = 1024;
+
     /// Creates an empty `World`.
     #[cfg(feature = "std")]
     pub fn new() -> World {
@@ -517,6 +551,8 @@ world.run_with_data(sys1, (EntityId::dead(), [0., 0.]));
         let system_span = tracing::info_span!("system", name = ?core::any::type_name::<S>());
         #[cfg(feature = "tracing")]
         let _system_span = system_span.enter();
+        #[cfg(feature = "parallel")]
+        let _deterministic = self.deterministic_scope();
 
         system
             .run((data,), self)
@@ -646,6 +682,8 @@ let i = world.run(sys1);
         let system_span = tracing::info_span!("system", name = ?core::any::type_name::<S>());
         #[cfg(feature = "tracing")]
         let _system_span = system_span.enter();
+        #[cfg(feature = "parallel")]
+        let _deterministic = self.deterministic_scope();
 
         system
             .run((), self)
@@ -719,6 +757,160 @@ let i = world.run(sys1);
             &*label,
         )
     }
+    /// Adds `system` to the `workload` workload, after its last barrier, then schedules the workload again.\
+    /// Only the modified workload is scheduled again, its ordering constraints and requirements are validated
+    /// like [`Workload::add_to_world`] does.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (exclusive)
+    /// - [`AllStorages`] (shared)
+    /// - System's storage (exclusive) to enable tracking
+    ///
+    /// ### Errors
+    ///
+    /// - Scheduler borrow failed.
+    /// - Workload did not exist.
+    /// - Invalid system.
+    /// - The modified workload is invalid, it is left unchanged.
+    /// - [`AllStorages`] borrow failed.
+    /// - Storage borrow failed.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, EntitiesViewMut, ViewMut, Workload, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Enemy;
+    ///
+    /// fn spawn_enemy(mut entities: EntitiesViewMut, mut enemies: ViewMut<Enemy>) {
+    ///     entities.add_entity(&mut enemies, Enemy);
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// Workload::new("Update").add_to_world(&world).unwrap();
+    ///
+    /// world.add_system_to_workload("Update", spawn_enemy).unwrap();
+    /// world.run_workload("Update").unwrap();
+    ///
+    /// world.disable_system_in_workload("Update", spawn_enemy).unwrap();
+    /// world.run_workload("Update").unwrap();
+    ///
+    /// world.remove_system_from_workload("Update", spawn_enemy).unwrap();
+    /// world.run_workload("Update").unwrap();
+    /// ```
+    ///
+    /// [`Workload::add_to_world`]: crate::Workload::add_to_world
+    pub fn add_system_to_workload<T, B, R, S: IntoWorkloadSystem<B, R>>(
+        &self,
+        workload: impl AsLabel<T>,
+        system: S,
+    ) -> Result<(), error::ModifyWorkload> {
+        let system = system
+            .into_workload_system()
+            .map_err(error::ModifyWorkload::InvalidSystem)?;
+
+        self.modify_workload(&*workload.as_label(), WorkloadModification::Insert(system))
+    }
+    /// Removes all systems matching `system` from the `workload` workload, then schedules the workload again.\
+    /// `system` can be a system or a tag.
+    ///
+    /// See [`World::add_system_to_workload`] for the borrows and validation.
+    pub fn remove_system_from_workload<T, U>(
+        &self,
+        workload: impl AsLabel<T>,
+        system: impl AsLabel<U>,
+    ) -> Result<(), error::ModifyWorkload> {
+        self.modify_workload(
+            &*workload.as_label(),
+            WorkloadModification::Remove(system.as_label()),
+        )
+    }
+    /// Replaces the first system matching `old_system` in the `workload` workload with `new_system`
+    /// and removes the other matching systems, then schedules the workload again.\
+    /// `new_system` keeps the tags, ordering constraints and requirements of the replaced system,
+    /// and its `run_if` if it doesn't have one.
+    ///
+    /// See [`World::add_system_to_workload`] for the borrows and validation.
+    pub fn replace_system_in_workload<T, U, B, R, S: IntoWorkloadSystem<B, R>>(
+        &self,
+        workload: impl AsLabel<T>,
+        old_system: impl AsLabel<U>,
+        new_system: S,
+    ) -> Result<(), error::ModifyWorkload> {
+        let new_system = new_system
+            .into_workload_system()
+            .map_err(error::ModifyWorkload::InvalidSystem)?;
+
+        self.modify_workload(
+            &*workload.as_label(),
+            WorkloadModification::Replace(old_system.as_label(), new_system),
+        )
+    }
+    /// Runs again the systems matching `system` in the `workload` workload, see [`World::disable_system_in_workload`].
+    ///
+    /// See [`World::add_system_to_workload`] for the borrows and validation.
+    pub fn enable_system_in_workload<T, U>(
+        &self,
+        workload: impl AsLabel<T>,
+        system: impl AsLabel<U>,
+    ) -> Result<(), error::ModifyWorkload> {
+        self.modify_workload(
+            &*workload.as_label(),
+            WorkloadModification::SetEnabled(system.as_label(), true),
+        )
+    }
+    /// Stops running the systems matching `system` in the `workload` workload, without removing them.\
+    /// `system` can be a system or a tag. The workload is scheduled as if they were not part of it.
+    ///
+    /// See [`World::add_system_to_workload`] for the borrows and validation.
+    pub fn disable_system_in_workload<T, U>(
+        &self,
+        workload: impl AsLabel<T>,
+        system: impl AsLabel<U>,
+    ) -> Result<(), error::ModifyWorkload> {
+        self.modify_workload(
+            &*workload.as_label(),
+            WorkloadModification::SetEnabled(system.as_label(), false),
+        )
+    }
+    fn modify_workload(
+        &self,
+        workload: &dyn Label,
+        modification: WorkloadModification,
+    ) -> Result<(), error::ModifyWorkload> {
+        let mut tracking_to_enable = Vec::new();
+
+        modify_workload(
+            &mut *self
+                .scheduler
+                .borrow_mut()
+                .map_err(|_| error::ModifyWorkload::Borrow)?,
+            &mut tracking_to_enable,
+            workload,
+            modification,
+        )?;
+
+        let all_storages = self.all_storages().map_err(|_| {
+            error::ModifyWorkload::Invalid(error::AddWorkload::TrackingAllStoragesBorrow)
+        })?;
+
+        for enable_tracking_fn in &tracking_to_enable {
+            (enable_tracking_fn)(&all_storages).map_err(|err| match err {
+                error::GetStorage::StorageBorrow { name, id, borrow } => {
+                    error::ModifyWorkload::Invalid(error::AddWorkload::TrackingStorageBorrow {
+                        name,
+                        id,
+                        borrow,
+                    })
+                }
+                _ => unreachable!(),
+            })?;
+        }
+
+        Ok(())
+    }
     /// Returns `true` if the world contains the `name` workload.
     ///
     /// ### Borrows
@@ -749,7 +941,9 @@ let i = world.run(sys1);
     #[allow(clippy::type_complexity)]
     pub(crate) fn run_batches(
         &self,
-        systems: &[Box<dyn Fn(&World) -> Result<(), error::Run> + Send + Sync + 'static>],
+        systems: &[Box<
+            dyn Fn(&World, &SystemLocals) -> Result<(), error::Run> + Send + Sync + 'static,
+        >],
         system_names: &[Box<dyn Label>],
         batches: &Batches,
         workload_name: &dyn Label,
@@ -764,14 +958,50 @@ let i = world.run(sys1);
         }
 
         #[cfg(feature = "parallel")]
-        {
+        let result = if self.deterministic {
+            let _deterministic = self.deterministic_scope();
+
+            self.run_batches_sequential(systems, system_names, batches, workload_name)
+        } else {
             self.run_batches_parallel(systems, system_names, batches, workload_name)
-        }
+        };
 
         #[cfg(not(feature = "parallel"))]
-        {
-            self.run_batches_sequential(systems, system_names, batches, workload_name)
+        let result = self.run_batches_sequential(systems, system_names, batches, workload_name);
+
+        if let Ok(all_storages) = self.all_storages.borrow() {
+            all_storages
+                .workload_runs
+                .fetch_add(1, core::sync::atomic::Ordering::Relaxed);
+        }
+
+        // Commands recorded by the systems that ran are applied even if another system failed
+        match (result, self.flush_commands()) {
+            (Err(error::RunWorkload::Multiple(mut errors)), Err(flush_error)) => {
+                errors.push(flush_error);
+
+                Err(error::RunWorkload::Multiple(errors))
+            }
+            (result, flush_result) => result.and(flush_result),
+        }
+    }
+    /// Applies the recorded commands if there are any.
+    pub(crate) fn flush_commands(&self) -> Result<(), error::RunWorkload> {
+        let all_storages = self
+            .all_storages
+            .borrow()
+            .map_err(error::RunWorkload::AllStoragesBorrow)?;
+
+        if all_storages.has_pending_commands() {
+            drop(all_storages);
+
+            self.all_storages
+                .borrow_mut()
+                .map_err(error::RunWorkload::AllStoragesBorrow)?
+                .apply_commands();
         }
+
+        Ok(())
     }
     /// Run the default workload if there is one.
     ///
@@ -1278,6 +1508,170 @@ impl World {
         )
     }
 
+    /// Starts recording the wall time, call count, skipped count and error count of each system
+    /// when workloads run.\
+    /// Statistics are read with [`World::workload_stats`].
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::scheduler::SystemModificator;
+    /// use shipyard::{Workload, World};
+    ///
+    /// fn sys1() {}
+    /// fn sys2() {}
+    ///
+    /// let mut world = World::new();
+    ///
+    /// world.enable_profiling();
+    ///
+    /// Workload::new("Update")
+    ///     .with_system(sys1)
+    ///     .with_system(sys2.run_if(|| false))
+    ///     .add_to_world(&world)
+    ///     .unwrap();
+    ///
+    /// world.run_workload("Update").unwrap();
+    ///
+    /// let stats = world.workload_stats("Update").unwrap();
+    /// assert_eq!(stats.systems[0].calls, 1);
+    /// assert_eq!(stats.systems[1].skipped, 1);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn enable_profiling(&mut self) {
+        self.profiling_clock = Some(crate::scheduler::std_clock);
+    }
+
+    /// Same as [`World::enable_profiling`] but measures time using `clock`.\
+    /// `clock` returns the time elapsed since an arbitrary point, it has to be monotonic.
+    pub fn enable_profiling_with_clock(&mut self, clock: fn() -> Duration) {
+        self.profiling_clock = Some(clock);
+    }
+
+    /// Stops recording systems statistics. Already recorded statistics are kept.
+    pub fn disable_profiling(&mut self) {
+        self.profiling_clock = None;
+    }
+
+    /// When `deterministic` is `true`, workloads run their systems one at a time on the calling thread,
+    /// always in the order they would run without the `parallel` feature.\
+    /// `EntityId` allocation and tracking timestamps then only depend on the workloads and their inputs,
+    /// not on thread timing.
+    ///
+    /// `par_iter` used by these systems, by [`World::run`] or by [`AllStorages::run`], uses [`ParShiperator::fixed_chunks`]
+    /// with [`World::deterministic_chunk_size`] components per chunk.\
+    /// Parallel iterators created on other threads, inside another parallel iterator for example, are not affected.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, EntitiesViewMut, ViewMut, Workload, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Bullet;
+    ///
+    /// #[derive(Component)]
+    /// struct Spark;
+    ///
+    /// fn spawn_bullet(mut entities: EntitiesViewMut, mut bullets: ViewMut<Bullet>) {
+    ///     entities.add_entity(&mut bullets, Bullet);
+    /// }
+    ///
+    /// fn spawn_spark(mut entities: EntitiesViewMut, mut sparks: ViewMut<Spark>) {
+    ///     entities.add_entity(&mut sparks, Spark);
+    /// }
+    ///
+    /// let mut world = World::new();
+    /// world.set_deterministic(true);
+    ///
+    /// Workload::new("Spawn")
+    ///     .with_system(spawn_bullet)
+    ///     .with_system(spawn_spark)
+    ///     .add_to_world(&world)
+    ///     .unwrap();
+    ///
+    /// world.run_workload("Spawn").unwrap();
+    /// ```
+    ///
+    /// [`ParShiperator::fixed_chunks`]: crate::iter::ParShiperator::fixed_chunks
+    pub fn set_deterministic(&mut self, deterministic: bool) {
+        self.deterministic = deterministic;
+        #[cfg(feature = "parallel")]
+        self.sync_fixed_chunk_size();
+    }
+
+    /// Returns `true` if workloads run their systems in a fixed order, see [`World::set_deterministic`].
+    pub fn is_deterministic(&self) -> bool {
+        self.deterministic
+    }
+
+    /// Sets the number of components per chunk `par_iter` uses in a deterministic `World`, see [`World::set_deterministic`].\
+    /// A `chunk_size` of 0 is treated as 1.\
+    /// Defaults to [`World::DEFAULT_DETERMINISTIC_CHUNK_SIZE`].
+    #[cfg(feature = "parallel")]
+    pub fn set_deterministic_chunk_size(&mut self, chunk_size: usize) {
+        self.deterministic_chunk_size = chunk_size.max(1);
+        self.sync_fixed_chunk_size();
+    }
+
+    /// Returns the number of components per chunk `par_iter` uses in a deterministic `World`.
+    #[cfg(feature = "parallel")]
+    pub fn deterministic_chunk_size(&self) -> usize {
+        self.deterministic_chunk_size
+    }
+
+    /// Makes [`AllStorages::run`] use the same chunks as this `World`.
+    #[cfg(feature = "parallel")]
+    fn sync_fixed_chunk_size(&mut self) {
+        self.all_storages.get_mut().fixed_chunk_size =
+            self.deterministic.then_some(self.deterministic_chunk_size);
+    }
+
+    /// Makes `par_iter` use fixed chunks on this thread while the scope lives, if this `World` is deterministic.
+    #[cfg(feature = "parallel")]
+    fn deterministic_scope(&self) -> Option<crate::iter::parallel::FixedChunksScope> {
+        self.deterministic
+            .then(|| crate::iter::parallel::FixedChunksScope::enter(self.deterministic_chunk_size))
+    }
+
+    /// Returns the statistics recorded for the systems of the `label` workload, see [`World::enable_profiling`].\
+    /// Returns `None` if the workload does not exist.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (shared)
+    ///
+    /// ### Panics
+    ///
+    /// - Scheduler borrow failed.
+    #[track_caller]
+    pub fn workload_stats<T>(&self, label: impl AsLabel<T>) -> Option<WorkloadStats> {
+        let scheduler = self.scheduler.borrow().unwrap();
+        let label = label.as_label();
+
+        scheduler
+            .workloads
+            .get(&label)
+            .map(|batches| batches.stats(&*label, &scheduler.system_names))
+    }
+
+    /// Resets the statistics of all workloads, to read them per frame for example.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (shared)
+    ///
+    /// ### Panics
+    ///
+    /// - Scheduler borrow failed.
+    #[track_caller]
+    pub fn reset_workload_stats(&self) {
+        let scheduler = self.scheduler.borrow().unwrap();
+
+        for batches in scheduler.workloads.values() {
+            batches.reset_stats();
+        }
+    }
+
     /// Enable insertion tracking for the given components.
     #[track_caller]
     pub fn track_insertion<T: TupleTrack>(&mut self) -> &mut World {
@@ -1568,6 +1962,73 @@ for (i, j) in &mut iter {
             .is_alive(entity)
     }
 
+    /// Disables `entity`, its components are kept but skipped by iterators, [`Get`] and [`Contains`].  
+    /// Use [`View::include_disabled`] to access them anyway.  
+    /// Components added to a disabled entity are disabled too.  
+    /// Returns `true` if `entity` was alive and enabled.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, Get, View, World};
+    ///
+    /// #[derive(Component, Debug, PartialEq, Eq)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity = world.add_entity(U32(0));
+    ///
+    /// assert!(world.disable_entity(entity));
+    /// assert!(world.borrow::<View<U32>>().unwrap().get(entity).is_err());
+    ///
+    /// assert!(world.enable_entity(entity));
+    /// assert_eq!(world.borrow::<View<U32>>().unwrap().get(entity), Ok(&U32(0)));
+    /// ```
+    ///
+    /// [`Get`]: crate::Get
+    /// [`Contains`]: crate::Contains
+    /// [`View::include_disabled`]: crate::View::include_disabled()
+    pub fn disable_entity(&mut self, entity: EntityId) -> bool {
+        self.all_storages.get_mut().disable_entity(entity)
+    }
+
+    /// Enables a disabled entity and all its components.  
+    /// Returns `true` if `entity` was alive and disabled.
+    pub fn enable_entity(&mut self, entity: EntityId) -> bool {
+        self.all_storages.get_mut().enable_entity(entity)
+    }
+
+    /// Returns true if entity matches a living disabled entity.
+    pub fn is_entity_disabled(&mut self, entity: EntityId) -> bool {
+        self.all_storages.get_mut().is_entity_disabled(entity)
+    }
+
+    /// Applies all commands recorded by [`Commands`](crate::views::Commands) views.
+    ///
+    /// This is done automatically at the end of each workload and at each [`Workload::with_barrier`].
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Commands, Component, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity = world.run(|mut commands: Commands| commands.add_entity((U32(0),)));
+    /// assert!(!world.is_entity_alive(entity));
+    ///
+    /// world.apply_commands();
+    /// assert!(world.is_entity_alive(entity));
+    /// ```
+    ///
+    /// [`Workload::with_barrier`]: crate::Workload::with_barrier()
+    pub fn apply_commands(&mut self) {
+        self.all_storages.get_mut().apply_commands();
+    }
+
     /// Moves an entity from a `World` to another.
     ///
     /// ### Panics
@@ -1602,12 +2063,15 @@ for (i, j) in &mut iter {
             .move_entity(other_all_storages, entity);
     }
 
-    /// Moves all components from an entity to another in another `World`.
+    /// Moves an entity from a `World` to another, it gets a new [`EntityId`] in `other`.
+    ///
+    /// Returns the new [`EntityId`].\
+    /// Components with a registered [`MapEntities`](crate::MapEntities) function have their [`EntityId`]s updated,
+    /// see [`World::move_entities`].
     ///
     /// ### Panics
     ///
-    /// - `from` is not alive
-    /// - `to` is not alive
+    /// - `entity` is not alive
     ///
     /// ### Example
     ///
@@ -1620,83 +2084,458 @@ for (i, j) in &mut iter {
     /// let mut world1 = World::new();
     /// let mut world2 = World::new();
     ///
-    /// let from = world1.add_entity(USIZE(1));
-    /// let to = world2.add_entity(());
+    /// let entity = world1.add_entity(USIZE(1));
     ///
-    /// world1.move_components(&mut world2, from, to);
+    /// let new_entity = world1.move_entity_mapped(&mut world2, entity);
     ///
-    /// assert!(world1.get::<&USIZE>(from).is_err());
-    /// assert_eq!(world2.get::<&USIZE>(to).as_deref(), Ok(&&USIZE(1)));
+    /// assert!(!world1.is_entity_alive(entity));
+    /// assert_eq!(world2.get::<&USIZE>(new_entity).as_deref(), Ok(&&USIZE(1)));
     /// ```
     #[inline]
     #[track_caller]
-    pub fn move_components(&mut self, other: &mut World, from: EntityId, to: EntityId) {
+    pub fn move_entity_mapped(&mut self, other: &mut World, entity: EntityId) -> EntityId {
         let other_all_storages = other.all_storages.get_mut();
 
         self.all_storages
             .get_mut()
-            .move_components(other_all_storages, from, to);
+            .move_entity_mapped(other_all_storages, entity)
     }
 
-    /// Registers the function to clone these components.
+    /// Moves entities from a `World` to another, they get a new [`EntityId`] in `other`.
     ///
-    /// The type of the storage is used and not the component itself.\
-    /// That would be [`SparseSet<T>`](crate::sparse_set::SparseSet) and [`UniqueStorage<T>`](crate::unique::UniqueStorage).
+    /// Returns the [`EntityMap`] from the old to the new ids.\
+    /// Components with a registered [`MapEntities`](crate::MapEntities) function have their [`EntityId`]s updated.
+    ///
+    /// ### Panics
+    ///
+    /// - one of the `entities` is not alive, no entity is moved in this case
     ///
     /// ### Example
     ///
     /// ```
-    /// use shipyard::{Component, sparse_set::SparseSet, Unique, UniqueStorage, World};
+    /// use shipyard::{sparse_set::SparseSet, Component, EntityId, MapEntities, World};
     ///
-    /// #[derive(Component, Clone)]
-    /// struct USIZE(usize);
+    /// #[derive(Component, MapEntities)]
+    /// struct Target(EntityId);
     ///
-    /// #[derive(Unique, Clone)]
-    /// struct U32(u32);
+    /// let mut world1 = World::new();
+    /// let mut world2 = World::new();
     ///
-    /// let mut world = World::new();
+    /// world2.add_entity(());
+    /// let entity0 = world1.add_entity(());
+    /// let entity1 = world1.add_entity(Target(entity0));
     ///
-    /// world.add_unique(U32(0));
+    /// world2.register_map_entities::<SparseSet<Target>>();
+    /// let entity_map = world1.move_entities(&mut world2, &[entity0, entity1]);
     ///
-    /// world.register_clone::<(SparseSet<USIZE>, UniqueStorage<U32>)>();
+    /// assert_ne!(entity_map.map(entity0), entity0);
+    /// assert_eq!(
+    ///     world2.get::<&Target>(entity_map.map(entity1)).unwrap().0,
+    ///     entity_map.map(entity0)
+    /// );
     /// ```
     #[inline]
     #[track_caller]
-    pub fn register_clone<T: TupleClone>(&mut self) {
-        self.all_storages.get_mut().register_clone::<T>();
+    pub fn move_entities(&mut self, other: &mut World, entities: &[EntityId]) -> EntityMap {
+        let other_all_storages = other.all_storages.get_mut();
+
+        self.all_storages
+            .get_mut()
+            .move_entities(other_all_storages, entities)
     }
 
-    /// Clones `entity` from this `World` to `other` alongside all its with a registered clone function.
-    ///
-    /// ### Borrows
-    ///
-    /// - AllStorages (shared)
-    /// - Every Storage (shared)
+    /// Moves all entities from this `World` to `other`, they get a new [`EntityId`] in `other`.
     ///
-    /// ### Panics
-    ///
-    /// - `entity` is not alive
+    /// Can be used to load a scene in a live `World`, deserialize it in an empty `World` then move its entities.\
+    /// See [`World::move_entities`] for more details.
     ///
     /// ### Example
     ///
     /// ```
-    /// use shipyard::{Component, sparse_set::SparseSet, World};
+    /// use shipyard::{sparse_set::SparseSet, Component, EntityId, MapEntities, World};
     ///
-    /// #[derive(Component, Clone, Debug, PartialEq, Eq)]
-    /// struct USIZE(usize);
-    ///
-    /// let mut world1 = World::new();
-    /// let mut world2 = World::new();
+    /// #[derive(Component, MapEntities)]
+    /// struct Target(EntityId);
     ///
-    /// world1.register_clone::<SparseSet<USIZE>>();
+    /// let mut world = World::new();
+    /// world.add_entity(());
+    /// world.register_map_entities::<SparseSet<Target>>();
     ///
-    /// let entity = world1.add_entity(USIZE(1));
+    /// let mut scene = World::new();
+    /// let entity0 = scene.add_entity(());
+    /// let entity1 = scene.add_entity(Target(entity0));
     ///
-    /// world1.clone_entity_to(&mut world2, entity);
+    /// let entity_map = scene.move_all_entities(&mut world);
     ///
-    /// assert_eq!(world1.get::<&USIZE>(entity).as_deref(), Ok(&&USIZE(1)));
-    /// assert_eq!(world2.get::<&USIZE>(entity).as_deref(), Ok(&&USIZE(1)));
-    #[inline]
+    /// assert_eq!(
+    ///     world.get::<&Target>(entity_map.map(entity1)).unwrap().0,
+    ///     entity_map.map(entity0)
+    /// );
+    /// ```
+    #[track_caller]
+    pub fn move_all_entities(&mut self, other: &mut World) -> EntityMap {
+        let all_storages = self.all_storages.get_mut();
+        let entities = all_storages.entities().unwrap().iter().collect::<Vec<_>>();
+
+        all_storages.move_entities(other.all_storages.get_mut(), &entities)
+    }
+
+    /// Moves all components from an entity to another in another `World`.
+    ///
+    /// ### Panics
+    ///
+    /// - `from` is not alive
+    /// - `to` is not alive
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, World};
+    ///
+    /// #[derive(Component, Debug, PartialEq, Eq)]
+    /// struct USIZE(usize);
+    ///
+    /// let mut world1 = World::new();
+    /// let mut world2 = World::new();
+    ///
+    /// let from = world1.add_entity(USIZE(1));
+    /// let to = world2.add_entity(());
+    ///
+    /// world1.move_components(&mut world2, from, to);
+    ///
+    /// assert!(world1.get::<&USIZE>(from).is_err());
+    /// assert_eq!(world2.get::<&USIZE>(to).as_deref(), Ok(&&USIZE(1)));
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn move_components(&mut self, other: &mut World, from: EntityId, to: EntityId) {
+        let other_all_storages = other.all_storages.get_mut();
+
+        self.all_storages
+            .get_mut()
+            .move_components(other_all_storages, from, to);
+    }
+
+    /// Registers the function to clone these components.
+    ///
+    /// The type of the storage is used and not the component itself.\
+    /// That would be [`SparseSet<T>`](crate::sparse_set::SparseSet) and [`UniqueStorage<T>`](crate::unique::UniqueStorage).
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, sparse_set::SparseSet, Unique, UniqueStorage, World};
+    ///
+    /// #[derive(Component, Clone)]
+    /// struct USIZE(usize);
+    ///
+    /// #[derive(Unique, Clone)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// world.add_unique(U32(0));
+    ///
+    /// world.register_clone::<(SparseSet<USIZE>, UniqueStorage<U32>)>();
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn register_clone<T: TupleClone>(&mut self) {
+        self.all_storages.get_mut().register_clone::<T>();
+    }
+
+    /// Creates a prefab named `name`, replacing any prefab with the same name.\
+    /// Returns the template entity, it is disabled and holds a copy of `components`.
+    ///
+    /// See the [`prefab`](crate::prefab) module for an example.
+    #[inline]
+    pub fn add_prefab<C: PrefabComponents>(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+        components: C,
+    ) -> EntityId {
+        self.all_storages.get_mut().add_prefab(name, components)
+    }
+
+    /// Adds the prefab `child` to the prefab `parent`.\
+    /// Each instance of `parent` will have an instance of `child` attached to it in the default [`Hierarchy`](crate::hierarchy::Hierarchy).
+    ///
+    /// ### Panics
+    ///
+    /// - `parent` or `child` is not a prefab.
+    /// - `child` is `parent` or one of its ancestors.
+    #[inline]
+    #[track_caller]
+    pub fn add_prefab_child(&mut self, parent: &str, child: &str) {
+        self.all_storages.get_mut().add_prefab_child(parent, child);
+    }
+
+    /// Returns the template entity of the prefab `name`.
+    #[inline]
+    pub fn prefab(&mut self, name: &str) -> Option<EntityId> {
+        self.all_storages.get_mut().prefab(name)
+    }
+
+    /// Creates `count` instances of the prefab `name` and returns them.
+    ///
+    /// ### Panics
+    ///
+    /// - `name` or one of its children is not a prefab.
+    #[inline]
+    #[track_caller]
+    pub fn instantiate(&mut self, name: &str, count: usize) -> Vec<EntityId> {
+        self.all_storages.get_mut().instantiate(name, count)
+    }
+
+    /// Creates an instance of the prefab `name` for each item of `overrides` and returns them.\
+    /// The components of each item are used instead of the prefab's, all components are tracked as inserted.
+    ///
+    /// ### Panics
+    ///
+    /// - `name` or one of its children is not a prefab.
+    #[inline]
+    #[track_caller]
+    pub fn instantiate_with<O: TupleAddComponent, I: IntoIterator<Item = O>>(
+        &mut self,
+        name: &str,
+        overrides: I,
+    ) -> Vec<EntityId> {
+        self.all_storages
+            .get_mut()
+            .instantiate_with(name, overrides)
+    }
+
+    /// Creates an owning group over the storages of `Owned`.\
+    /// The components of the entities owning all of them will be kept at the front of each storage,
+    /// making iteration over all these storages as fast as iterating a single one.
+    ///
+    /// See the [`group`](crate::group) module for more details.
+    ///
+    /// ### Panics
+    ///
+    /// - One of the storages is already part of a group.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, IntoIter, View, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// #[derive(Component)]
+    /// struct USIZE(usize);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// world.add_group::<(U32, USIZE)>();
+    ///
+    /// world.add_entity((U32(0), USIZE(1)));
+    /// world.add_entity((U32(2),));
+    ///
+    /// world.run(|u32s: View<U32>, usizes: View<USIZE>| {
+    ///     assert_eq!((&u32s, &usizes).iter().count(), 1);
+    /// });
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn add_group<Owned: TupleGroup>(&mut self) {
+        self.all_storages.get_mut().add_group::<Owned>();
+    }
+
+    /// Creates a group owning the storages of `Owned` and observing the ones of `Observed`.\
+    /// Entities in the group own a component in all these storages but only the owned storages are sorted.
+    ///
+    /// ### Panics
+    ///
+    /// - `Owned` is empty.
+    /// - One of the storages is already part of a group.
+    #[inline]
+    #[track_caller]
+    pub fn add_partial_group<Owned: TupleGroup, Observed: TupleGroup>(&mut self) {
+        self.all_storages
+            .get_mut()
+            .add_partial_group::<Owned, Observed>();
+    }
+
+    /// Indexes the components of `T` by the key returned by `key`.\
+    /// Replaces the previous index of this storage.
+    ///
+    /// See the [`index`](crate::index) module for more details.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, View, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Name(&'static str);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// world.add_index(|name: &Name| name.0);
+    ///
+    /// let entity = world.add_entity((Name("Alice"),));
+    ///
+    /// world.run(|names: View<Name>| {
+    ///     let index = names.component_index::<&str>().unwrap();
+    ///     assert_eq!(index.get("Alice"), [entity]);
+    /// });
+    /// ```
+    #[inline]
+    pub fn add_index<T: Component + Send + Sync, K: Ord + Clone + Send + Sync + 'static>(
+        &mut self,
+        key: fn(&T) -> K,
+    ) {
+        self.all_storages.get_mut().add_index(key);
+    }
+
+    /// Registers `T` in the [`TypeRegistry`](crate::registry::TypeRegistry) under `name` and creates its storage.\
+    /// Hooks are added with the returned [`ComponentRegistrar`].
+    ///
+    /// The name should be stable, it is used to find the type when deserializing with [`World::deserialize_registered`].
+    ///
+    /// ### Panics
+    ///
+    /// - `name` is already used by another type.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, World};
+    ///
+    /// #[derive(Component, Clone, Debug, Default)]
+    /// struct Health(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// world.register_component::<Health>("Health").clone().debug().default();
+    /// ```
+    #[track_caller]
+    pub fn register_component<T: Component + Send + Sync>(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+    ) -> ComponentRegistrar<'_, T> {
+        self.all_storages.get_mut().register_component::<T>(name)
+    }
+
+    /// Registers the unique `T` in the [`TypeRegistry`](crate::registry::TypeRegistry) under `name`.\
+    /// Hooks are added with the returned [`UniqueRegistrar`].
+    ///
+    /// The name should be stable, it is used to find the type when deserializing with [`World::deserialize_registered`].
+    ///
+    /// ### Panics
+    ///
+    /// - `name` is already used by another type.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Unique, World};
+    ///
+    /// #[derive(Unique, Debug, Default)]
+    /// struct Score(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// world.register_unique::<Score>("Score").debug().default();
+    /// ```
+    #[track_caller]
+    pub fn register_unique<T: Unique + Send + Sync>(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+    ) -> UniqueRegistrar<'_, T> {
+        self.all_storages.get_mut().register_unique::<T>(name)
+    }
+
+    /// Creates the storage described by `info` if it doesn't already exist.\
+    /// Returns the [`StorageId`] of the storage.
+    ///
+    /// The storage can then be accessed with [`AllStorages::dynamic_storage`] and [`AllStorages::dynamic_storage_mut`].
+    ///
+    /// ### Panics
+    ///
+    /// - A storage that isn't a [`DynamicSparseSet`](crate::sparse_set::DynamicSparseSet) or has a different layout already uses this id.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{sparse_set::DynamicComponentInfo, AllStoragesViewMut, World};
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let health = world.add_dynamic_storage(DynamicComponentInfo::of::<u32>("Health"));
+    /// let entity = world.add_entity(());
+    ///
+    /// let all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    /// let mut storage = all_storages.dynamic_storage_mut(health).unwrap();
+    ///
+    /// let component = 10u32;
+    /// unsafe {
+    ///     storage.insert(entity, (&component as *const u32).cast());
+    ///     assert_eq!(*storage.get(entity).unwrap().cast::<u32>(), 10);
+    /// }
+    /// ```
+    #[track_caller]
+    pub fn add_dynamic_storage(&mut self, info: DynamicComponentInfo) -> StorageId {
+        self.all_storages.get_mut().add_dynamic_storage(info)
+    }
+
+    /// Registers the function to update the [`EntityId`]s stored in these components.
+    ///
+    /// Like [`World::register_clone`], the type of the storage is used.\
+    /// Operations creating entities from another `World` then apply their [`EntityMap`] to these components.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{sparse_set::SparseSet, Component, EntityId, MapEntities, World};
+    ///
+    /// #[derive(Component, MapEntities)]
+    /// struct Parent(EntityId);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// world.register_map_entities::<SparseSet<Parent>>();
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn register_map_entities<T: TupleMapEntities>(&mut self) {
+        self.all_storages.get_mut().register_map_entities::<T>();
+    }
+
+    /// Clones `entity` from this `World` to `other` alongside all its components with a registered clone function.
+    ///
+    /// ### Borrows
+    ///
+    /// - AllStorages (shared)
+    /// - Every Storage (shared)
+    ///
+    /// ### Panics
+    ///
+    /// - `entity` is not alive
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, sparse_set::SparseSet, World};
+    ///
+    /// #[derive(Component, Clone, Debug, PartialEq, Eq)]
+    /// struct USIZE(usize);
+    ///
+    /// let mut world1 = World::new();
+    /// let mut world2 = World::new();
+    ///
+    /// world1.register_clone::<SparseSet<USIZE>>();
+    ///
+    /// let entity = world1.add_entity(USIZE(1));
+    ///
+    /// world1.clone_entity_to(&mut world2, entity);
+    ///
+    /// assert_eq!(world1.get::<&USIZE>(entity).as_deref(), Ok(&&USIZE(1)));
+    /// assert_eq!(world2.get::<&USIZE>(entity).as_deref(), Ok(&&USIZE(1)));
+    /// ```
+    #[inline]
     #[track_caller]
     pub fn clone_entity_to(&self, other: &mut World, entity: EntityId) {
         let other_all_storages = other.all_storages.get_mut();
@@ -1707,6 +2546,78 @@ for (i, j) in &mut iter {
             .clone_entity_to(other_all_storages, entity);
     }
 
+    /// Clones `entity` from this `World` to `other` alongside all its components with a registered clone function.\
+    /// The clone gets a new [`EntityId`] in `other`.
+    ///
+    /// Returns the [`EntityId`] of the clone.\
+    /// Components with a registered [`MapEntities`](crate::MapEntities) function have their [`EntityId`]s updated,
+    /// see [`World::clone_entities_to`].
+    ///
+    /// ### Borrows
+    ///
+    /// - AllStorages (shared)
+    /// - Every Storage (shared)
+    ///
+    /// ### Panics
+    ///
+    /// - `entity` is not alive
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, sparse_set::SparseSet, World};
+    ///
+    /// #[derive(Component, Clone, Debug, PartialEq, Eq)]
+    /// struct USIZE(usize);
+    ///
+    /// let mut world1 = World::new();
+    /// let mut world2 = World::new();
+    ///
+    /// world1.register_clone::<SparseSet<USIZE>>();
+    ///
+    /// let entity = world1.add_entity(USIZE(1));
+    ///
+    /// let clone = world1.clone_entity_to_mapped(&mut world2, entity);
+    ///
+    /// assert_eq!(world1.get::<&USIZE>(entity).as_deref(), Ok(&&USIZE(1)));
+    /// assert_eq!(world2.get::<&USIZE>(clone).as_deref(), Ok(&&USIZE(1)));
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn clone_entity_to_mapped(&self, other: &mut World, entity: EntityId) -> EntityId {
+        let other_all_storages = other.all_storages.get_mut();
+
+        self.all_storages
+            .borrow()
+            .unwrap()
+            .clone_entity_to_mapped(other_all_storages, entity)
+    }
+
+    /// Clones `entities` from this `World` to `other` alongside all their components with a registered clone function.\
+    /// The clones get a new [`EntityId`] in `other`.
+    ///
+    /// Returns the [`EntityMap`] from the original to the cloned ids.\
+    /// Components with a registered [`MapEntities`](crate::MapEntities) function have their [`EntityId`]s updated.
+    ///
+    /// ### Borrows
+    ///
+    /// - AllStorages (shared)
+    /// - Every Storage (shared)
+    ///
+    /// ### Panics
+    ///
+    /// - one of the `entities` is not alive, no entity is cloned in this case
+    #[inline]
+    #[track_caller]
+    pub fn clone_entities_to(&self, other: &mut World, entities: &[EntityId]) -> EntityMap {
+        let other_all_storages = other.all_storages.get_mut();
+
+        self.all_storages
+            .borrow()
+            .unwrap()
+            .clone_entities_to(other_all_storages, entities)
+    }
+
     /// Clones all components of `from` entity with a registered clone function from
     /// this `World` to `other`'s `to` entity.
     ///
@@ -1751,6 +2662,70 @@ for (i, j) in &mut iter {
             .unwrap()
             .clone_components_to(other_all_storages, from, to);
     }
+
+    /// Copies [`Entities`] and all storages with a registered clone function, tracking information included.\
+    /// The `World` can later be rolled back to this state with [`World::restore`].
+    ///
+    /// Entity generations and the list of deleted entities are part of the snapshot,
+    /// entities created after a restore will have the same [`EntityId`] as the first time around.
+    ///
+    /// `!Send` and `!Sync` storages are not part of the snapshot.
+    ///
+    /// ### Borrows
+    ///
+    /// - AllStorages (shared)
+    /// - Every Storage (shared)
+    ///
+    /// ### Panics
+    ///
+    /// - AllStorages borrow failed.
+    /// - Storage borrow failed.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, sparse_set::SparseSet, World};
+    ///
+    /// #[derive(Component, Clone, Debug, PartialEq, Eq)]
+    /// struct Health(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// world.add_entity(Health(10));
+    /// world.register_clone::<SparseSet<Health>>();
+    ///
+    /// let snapshot = world.snapshot();
+    ///
+    /// let entity = world.add_entity(Health(5));
+    /// world.restore(&snapshot);
+    ///
+    /// assert!(!world.is_entity_alive(entity));
+    /// assert_eq!(world.add_entity(Health(5)), entity);
+    /// ```
+    ///
+    /// [`Entities`]: crate::advanced::Entities
+    #[track_caller]
+    pub fn snapshot(&self) -> Snapshot {
+        self.all_storages.borrow().unwrap().snapshot()
+    }
+
+    /// Rolls back [`Entities`] and all storages in `snapshot` to the state they were in when [`World::snapshot`] was called.
+    ///
+    /// The same snapshot can be restored multiple times.\
+    /// Storages that are not part of the snapshot keep their components,
+    /// except for entities that are no longer alive after the rollback.
+    ///
+    /// The tracking counter keeps moving forward, the rollback is tracked like any other change:
+    /// - components the snapshot brings back are flagged inserted
+    /// - components present before and after the rollback are flagged modified, uniques included
+    /// - components missing from the snapshot are flagged deleted
+    ///
+    /// [`Entities`]: crate::advanced::Entities
+    #[inline]
+    #[track_caller]
+    pub fn restore(&mut self, snapshot: &Snapshot) {
+        self.all_storages.get_mut().restore(snapshot);
+    }
 }
 
 impl core::fmt::Debug for World {
@@ -1856,6 +2831,8 @@ impl World {
     ///
     /// assert_eq!(world.is_entity_alive(alice_eid), true);
     /// ```
+    ///
+    /// The [`EntityId`]s are used as is, to load entities in a `World` that already has entities use [`World::deserialize_scene`].
     pub fn deserialize<'w, 'de, D: serde::Deserializer<'de>, V: WorldBorrow>(
         &'w self,
         deserializer: D,
@@ -1872,4 +2849,237 @@ impl World {
             Err(err) => Err(error::Deserialize::Borrow(err)),
         }
     }
+
+    /// Deserializes the view in `scene` then moves all of `scene`'s entities to this `World`, they get a new [`EntityId`].
+    ///
+    /// Entities that have a component in `scene` but are not alive are spawned before the move.\
+    /// Returns the [`EntityMap`] from the serialized to the new ids,
+    /// components with a registered [`MapEntities`](crate::MapEntities) function have their [`EntityId`]s updated.\
+    /// Uniques are not moved, they stay in `scene`.
+    ///
+    /// ### Borrows
+    ///
+    /// - `scene`'s AllStorages (exclusive)
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{sparse_set::SparseSet, Component, EntityId, MapEntities, ViewMut, World};
+    ///
+    /// #[derive(Component, MapEntities, serde::Deserialize)]
+    /// struct Target(EntityId);
+    ///
+    /// let mut world = World::new();
+    /// world.add_entity(());
+    /// world.register_map_entities::<SparseSet<Target>>();
+    ///
+    /// let serialized = r#"[[{"index":0,"gen":0},{"index":1,"gen":0}],[{"index":1,"gen":0},{"index":0,"gen":0}]]"#;
+    /// let entity_map = world
+    ///     .deserialize_scene::<_, ViewMut<Target>>(
+    ///         &World::new(),
+    ///         &mut serde_json::de::Deserializer::from_str(serialized),
+    ///     )
+    ///     .unwrap_or_else(|_| panic!());
+    ///
+    /// let entity0 = entity_map.map(EntityId::new_from_index_and_gen(0, 0));
+    /// let entity1 = entity_map.map(EntityId::new_from_index_and_gen(1, 0));
+    ///
+    /// assert_eq!(world.get::<&Target>(entity0).unwrap().0, entity1);
+    /// assert_eq!(world.get::<&Target>(entity1).unwrap().0, entity0);
+    /// ```
+    pub fn deserialize_scene<'s, 'de, D: serde::Deserializer<'de>, V: WorldBorrow>(
+        &mut self,
+        scene: &'s World,
+        deserializer: D,
+    ) -> Result<EntityMap, error::Deserialize<'de, D>>
+    where
+        V::WorldView<'s>: serde::Deserialize<'de>,
+    {
+        scene.deserialize::<D, V>(deserializer)?;
+
+        let mut scene_all_storages = scene
+            .all_storages
+            .borrow_mut()
+            .map_err(|err| error::Deserialize::Borrow(error::GetStorage::AllStoragesBorrow(err)))?;
+
+        scene_all_storages.spawn_stored_entities();
+        let entities = scene_all_storages
+            .entities()
+            .unwrap()
+            .iter()
+            .collect::<Vec<_>>();
+
+        Ok(scene_all_storages.move_entities(self.all_storages.get_mut(), &entities))
+    }
+
+    /// Serializes all registered types with a serde hook, as a map from their registered name to their storage.
+    ///
+    /// Unlike [`World::serialize`], the types don't have to be known at compile time.\
+    /// The alive entities are part of the map, under [`ENTITIES_NAME`](crate::registry::ENTITIES_NAME).
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, EntityId, Unique, World};
+    ///
+    /// #[derive(Component, serde::Serialize, serde::Deserialize)]
+    /// struct Name(String);
+    ///
+    /// #[derive(Unique, serde::Serialize, serde::Deserialize)]
+    /// struct Score(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// world.register_component::<Name>("Name").serde();
+    /// world.register_unique::<Score>("Score").serde();
+    ///
+    /// world.add_entity(Name("Alice".to_string()));
+    /// world.add_unique(Score(5));
+    ///
+    /// let mut serialized = Vec::new();
+    /// world
+    ///     .serialize_registered(&mut serde_json::ser::Serializer::new(&mut serialized))
+    ///     .unwrap();
+    ///
+    /// assert_eq!(
+    ///     String::from_utf8(serialized.clone()).unwrap(),
+    ///     r#"{"Entities":[{"index":0,"gen":0}],"Name":[[{"index":0,"gen":0},"Alice"]],"Score":5}"#
+    /// );
+    ///
+    /// let mut other = World::new();
+    /// other.register_component::<Name>("Name").serde();
+    /// other.register_unique::<Score>("Score").serde();
+    ///
+    /// other
+    ///     .deserialize_registered(&mut serde_json::de::Deserializer::from_slice(&serialized))
+    ///     .unwrap();
+    ///
+    /// let alice = EntityId::new_from_index_and_gen(0, 0);
+    /// assert!(other.is_entity_alive(alice));
+    /// assert_eq!(other.get::<&Name>(alice).unwrap().0, "Alice");
+    /// ```
+    pub fn serialize_registered<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, error::Serialize<S>> {
+        use serde::Serialize;
+
+        let all_storages = self
+            .all_storages
+            .borrow()
+            .map_err(|err| error::Serialize::Borrow(error::GetStorage::AllStoragesBorrow(err)))?;
+
+        RegistrySerializer {
+            all_storages: &all_storages,
+        }
+        .serialize(serializer)
+        .map_err(error::Serialize::Serialization)
+    }
+
+    /// Deserializes a map created by [`World::serialize_registered`].
+    ///
+    /// Each storage is looked up by its registered name, unknown names are an error.\
+    /// The entities under [`ENTITIES_NAME`](crate::registry::ENTITIES_NAME) are spawned with the same [`EntityId`].
+    pub fn deserialize_registered<'de, D: serde::Deserializer<'de>>(
+        &self,
+        deserializer: D,
+    ) -> Result<(), error::Deserialize<'de, D>> {
+        use serde::de::DeserializeSeed;
+
+        let all_storages = self
+            .all_storages
+            .borrow()
+            .map_err(|err| error::Deserialize::Borrow(error::GetStorage::AllStoragesBorrow(err)))?;
+
+        RegistryDeserializer {
+            all_storages: &all_storages,
+        }
+        .deserialize(deserializer)
+        .map_err(error::Deserialize::Deserialization)
+    }
+
+    /// Serializes the changes made to the views between `since` (inclusive) and `until` (exclusive).
+    ///
+    /// Contains inserted, modified, deleted and removed components as well as deleted entities.
+    /// Only tracked operations are part of the delta.\
+    /// See [`DeltaSerializer`] for more details.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{track, Component, View, ViewMut, World};
+    ///
+    /// #[derive(Component, serde::Serialize, serde::Deserialize)]
+    /// #[track(All)]
+    /// struct Name(String);
+    ///
+    /// let mut server = World::new();
+    /// let mut client = World::new();
+    ///
+    /// let since = server.get_tracking_timestamp();
+    /// let alice = server.add_entity(Name("Alice".to_string()));
+    /// let until = server.get_tracking_timestamp();
+    ///
+    /// let mut serialized = Vec::new();
+    /// server
+    ///     .serialize_delta::<_, View<Name>>(
+    ///         &mut serde_json::ser::Serializer::new(&mut serialized),
+    ///         since,
+    ///         until,
+    ///     )
+    ///     .unwrap_or_else(|_| panic!());
+    ///
+    /// client
+    ///     .deserialize_delta::<_, ViewMut<Name>>(&mut serde_json::de::Deserializer::from_slice(
+    ///         &serialized,
+    ///     ))
+    ///     .unwrap_or_else(|_| panic!());
+    ///
+    /// assert!(client.is_entity_alive(alice));
+    /// assert_eq!(client.get::<&Name>(alice).unwrap().0, "Alice");
+    /// ```
+    pub fn serialize_delta<'w, S: serde::Serializer, V: WorldBorrow>(
+        &'w self,
+        serializer: S,
+        since: TrackingTimestamp,
+        until: TrackingTimestamp,
+    ) -> Result<S::Ok, error::Serialize<S>>
+    where
+        V::WorldView<'w>: DeltaViews,
+    {
+        use serde::Serialize;
+
+        let (entities, views) = match (self.borrow::<EntitiesView<'_>>(), self.borrow::<V>()) {
+            (Ok(entities), Ok(views)) => (entities, views),
+            (Err(err), _) | (_, Err(err)) => return Err(error::Serialize::Borrow(err)),
+        };
+
+        DeltaSerializer::new(&entities, &views, since, until)
+            .serialize(serializer)
+            .map_err(error::Serialize::Serialization)
+    }
+
+    /// Applies a delta serialized with [`World::serialize_delta`].
+    ///
+    /// Deleted entities are deleted, entities receiving a component are spawned.\
+    /// The views have to be in the same order as when serializing.
+    pub fn deserialize_delta<'w, 'de, D: serde::Deserializer<'de>, V: WorldBorrow>(
+        &'w self,
+        deserializer: D,
+    ) -> Result<(), error::Deserialize<'de, D>>
+    where
+        V::WorldView<'w>: DeltaViewsMut<'de>,
+    {
+        use serde::de::DeserializeSeed;
+
+        let (mut entities, mut views) =
+            match (self.borrow::<EntitiesViewMut<'_>>(), self.borrow::<V>()) {
+                (Ok(entities), Ok(views)) => (entities, views),
+                (Err(err), _) | (_, Err(err)) => return Err(error::Deserialize::Borrow(err)),
+            };
+
+        DeltaDeserializer::new(&mut entities, &mut views)
+            .deserialize(deserializer)
+            .map_err(error::Deserialize::Deserialization)
+    }
 }
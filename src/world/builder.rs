@@ -113,6 +113,10 @@ impl WorldBuilder<LockPresent, ThreadIdPresent> {
             all_storages,
             scheduler: AtomicRefCell::new(Default::default()),
             counter,
+            profiling_clock: None,
+            deterministic: false,
+            #[cfg(feature = "parallel")]
+            deterministic_chunk_size: World::DEFAULT_DETERMINISTIC_CHUNK_SIZE,
             #[cfg(feature = "parallel")]
             thread_pool: self.thread_pool,
         }
@@ -1,14 +1,20 @@
 use crate::error;
-use crate::scheduler::{Batches, Label};
+use crate::scheduler::{Batches, ErrorPolicy, Label, SystemLocals};
 use crate::world::World;
 use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+/// Error of a system using [`ErrorPolicy::Collect`].
+type SystemError = (Box<dyn Label>, error::Run);
 
 impl World {
     #[cfg(feature = "parallel")]
     #[allow(clippy::type_complexity)]
     pub(crate) fn run_batches_parallel(
         &self,
-        systems: &[Box<dyn Fn(&World) -> Result<(), error::Run> + Send + Sync + 'static>],
+        systems: &[Box<
+            dyn Fn(&World, &SystemLocals) -> Result<(), error::Run> + Send + Sync + 'static,
+        >],
         system_names: &[Box<dyn Label>],
         batches: &Batches,
         #[cfg_attr(not(feature = "tracing"), allow(unused))] workload_name: &dyn Label,
@@ -18,9 +24,23 @@ impl World {
         #[cfg(feature = "tracing")]
         let _parent_span = parent_span.enter();
 
-        let run_batch = || -> Result<(), error::RunWorkload> {
-            for (batch, batch_run_if) in batches.parallel.iter().zip(&batches.parallel_run_if) {
-                let mut result = Ok(());
+        let mut errors = Vec::new();
+
+        let mut run_batch = || -> Result<(), error::RunWorkload> {
+            let mut flush = batches.parallel_flush.iter().copied().peekable();
+
+            for (batch_index, ((batch, batch_run_if), batch_unique_ids)) in batches
+                .parallel
+                .iter()
+                .zip(&batches.parallel_run_if)
+                .zip(&batches.parallel_unique_ids)
+                .enumerate()
+            {
+                if flush.next_if_eq(&batch_index).is_some() {
+                    self.flush_commands()?;
+                }
+
+                let mut result = Ok(Vec::new());
 
                 let run_if = (
                     if batch_run_if.0 == usize::MAX {
@@ -56,19 +76,31 @@ impl World {
                                 }
                             }
                         })
-                        .collect::<Result<alloc::vec::Vec<_>, error::RunWorkload>>()?,
+                        .collect::<Result<Vec<_>, error::RunWorkload>>()?,
                 );
 
+                if let (Some(index), false) = (batch.0, run_if.0) {
+                    self.record_skip(batches, index);
+                }
+
                 let mut start = 0;
-                let single_system = batch.0.filter(|_| run_if.0).or_else(|| {
-                    let system = batch.1.first().copied().filter(|_| run_if.1[0]);
+                let single_system = batch
+                    .0
+                    .map(|index| (index, batch_unique_ids.0))
+                    .filter(|_| run_if.0)
+                    .or_else(|| {
+                        let system = batch
+                            .1
+                            .first()
+                            .map(|&index| (index, batch_unique_ids.1[0]))
+                            .filter(|_| run_if.1[0]);
 
-                    if system.is_some() {
-                        start = 1;
-                    }
+                        if system.is_some() {
+                            start = 1;
+                        }
 
-                    system
-                });
+                        system
+                    });
 
                 rayon::in_place_scope(|scope| {
                     // This check exists to avoid spawning a parallel job when possible.
@@ -79,10 +111,13 @@ impl World {
 
                             result = batch.1[start..]
                                 .par_iter()
+                                .zip(&batch_unique_ids.1[start..])
                                 .zip(&run_if.1[start..])
-                                .try_for_each(|(&index, should_run)| {
+                                .map(|((&index, &unique_id), should_run)| {
                                     if !should_run {
-                                        return Ok(());
+                                        self.record_skip(batches, index);
+
+                                        return Ok(None);
                                     }
 
                                     #[cfg(feature = "tracing")]
@@ -90,47 +125,74 @@ impl World {
                                         self.run_single_system(
                                             systems,
                                             system_names,
+                                            batches,
                                             &parent_span,
                                             index,
+                                            unique_id,
                                         )
                                     }
                                     #[cfg(not(feature = "tracing"))]
                                     {
-                                        self.run_single_system(systems, system_names, index)
+                                        self.run_single_system(
+                                            systems,
+                                            system_names,
+                                            batches,
+                                            index,
+                                            unique_id,
+                                        )
                                     }
-                                });
+                                })
+                                .collect::<Result<Vec<_>, error::RunWorkload>>();
                         });
                     }
 
-                    if let Some(index) = single_system {
+                    if let Some((index, unique_id)) = single_system {
                         #[cfg(feature = "tracing")]
-                        self.run_single_system(systems, system_names, &parent_span, index)?;
+                        let error = self.run_single_system(
+                            systems,
+                            system_names,
+                            batches,
+                            &parent_span,
+                            index,
+                            unique_id,
+                        )?;
                         #[cfg(not(feature = "tracing"))]
-                        self.run_single_system(systems, system_names, index)?;
+                        let error = self.run_single_system(
+                            systems,
+                            system_names,
+                            batches,
+                            index,
+                            unique_id,
+                        )?;
+
+                        errors.extend(error);
                     }
 
                     Ok(())
                 })?;
 
-                result?;
+                errors.extend(result?.into_iter().flatten());
             }
 
             Ok(())
         };
 
-        if let Some(thread_pool) = &self.thread_pool {
+        let result = if let Some(thread_pool) = &self.thread_pool {
             thread_pool.scope(|_| run_batch())
         } else {
             // Use non local ThreadPool
             run_batch()
-        }
+        };
+
+        merge_errors(result, errors)
     }
 
-    #[cfg(not(feature = "parallel"))]
     #[allow(clippy::type_complexity)]
     pub(crate) fn run_batches_sequential(
         &self,
-        systems: &[Box<dyn Fn(&World) -> Result<(), error::Run> + Send + Sync + 'static>],
+        systems: &[Box<
+            dyn Fn(&World, &SystemLocals) -> Result<(), error::Run> + Send + Sync + 'static,
+        >],
         system_names: &[Box<dyn Label>],
         batches: &Batches,
         #[cfg_attr(not(feature = "tracing"), allow(unused))] workload_name: &dyn Label,
@@ -140,11 +202,20 @@ impl World {
         #[cfg(feature = "tracing")]
         let _parent_span = parent_span.enter();
 
-        batches
+        let mut flush = batches.sequential_flush.iter().copied().peekable();
+        let mut errors = Vec::new();
+
+        let result = batches
             .sequential
             .iter()
+            .zip(&batches.sequential_unique_ids)
             .zip(&batches.sequential_run_if)
-            .try_for_each(|(&index, &run_if_index)| {
+            .enumerate()
+            .try_for_each(|(position, ((&index, &unique_id), &run_if_index))| {
+                if flush.next_if_eq(&position).is_some() {
+                    self.flush_commands()?;
+                }
+
                 let should_run = if run_if_index == usize::MAX {
                     // There is no run_if for this system
 
@@ -162,35 +233,101 @@ impl World {
                 };
 
                 if !should_run {
+                    self.record_skip(batches, index);
+
                     return Ok(());
                 }
 
                 #[cfg(feature = "tracing")]
-                {
-                    self.run_single_system(systems, system_names, &parent_span, index)
-                }
+                let error = self.run_single_system(
+                    systems,
+                    system_names,
+                    batches,
+                    &parent_span,
+                    index,
+                    unique_id,
+                )?;
                 #[cfg(not(feature = "tracing"))]
-                {
-                    self.run_single_system(systems, system_names, index)
-                }
-            })
+                let error =
+                    self.run_single_system(systems, system_names, batches, index, unique_id)?;
+
+                errors.extend(error);
+
+                Ok(())
+            });
+
+        merge_errors(result, errors)
     }
 
+    /// Runs the system at `index`, with the state of the `unique_id` system, and applies its error policy.
+    ///
+    /// Returns the error to collect if the system failed with [`ErrorPolicy::Collect`].
     #[allow(clippy::type_complexity)]
     fn run_single_system(
         &self,
-        systems: &[Box<dyn Fn(&World) -> Result<(), error::Run> + Send + Sync>],
+        systems: &[Box<dyn Fn(&World, &SystemLocals) -> Result<(), error::Run> + Send + Sync>],
         system_names: &[Box<dyn Label>],
+        batches: &Batches,
         #[cfg(feature = "tracing")] parent_span: &tracing::Span,
         index: usize,
-    ) -> Result<(), error::RunWorkload> {
+        unique_id: usize,
+    ) -> Result<Option<SystemError>, error::RunWorkload> {
         #[cfg(feature = "tracing")]
         let system_span =
             tracing::info_span!(parent: parent_span.clone(), "system", name = ?system_names[index]);
         #[cfg(feature = "tracing")]
         let _system_span = system_span.enter();
 
-        (systems[index])(self)
-            .map_err(|err| error::RunWorkload::Run((system_names[index].clone(), err)))
+        let result = if let Some(clock) = self.profiling_clock {
+            let start = clock();
+            let result = (systems[index])(self, &batches.locals[&unique_id]);
+
+            batches.stats[&index].record(clock().saturating_sub(start), result.is_err());
+
+            result
+        } else {
+            (systems[index])(self, &batches.locals[&unique_id])
+        };
+
+        let Err(err) = result else {
+            return Ok(None);
+        };
+
+        match batches.error_policies[&index] {
+            ErrorPolicy::Abort => Err(error::RunWorkload::Run((system_names[index].clone(), err))),
+            ErrorPolicy::Continue => {
+                #[cfg(feature = "tracing")]
+                tracing::error!(system = ?system_names[index], error = ?err, "system failed");
+                #[cfg(not(feature = "tracing"))]
+                drop(err);
+
+                Ok(None)
+            }
+            ErrorPolicy::Collect => Ok(Some((system_names[index].clone(), err))),
+        }
     }
+
+    fn record_skip(&self, batches: &Batches, index: usize) {
+        if self.profiling_clock.is_some() {
+            batches.stats[&index].skip();
+        }
+    }
+}
+
+/// Returns the collected `errors` as [`error::RunWorkload::Multiple`], including the error that stopped the workload if any.
+fn merge_errors(
+    result: Result<(), error::RunWorkload>,
+    errors: Vec<SystemError>,
+) -> Result<(), error::RunWorkload> {
+    if errors.is_empty() {
+        return result;
+    }
+
+    let mut errors: Vec<_> = errors.into_iter().map(error::RunWorkload::Run).collect();
+
+    if let Err(err) = result {
+        errors.push(err);
+    }
+
+    Err(error::RunWorkload::Multiple(errors))
 }
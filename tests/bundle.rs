@@ -0,0 +1,111 @@
+use shipyard::{Bundle, Component, IntoIter, View, World};
+
+#[derive(Component, Debug, PartialEq, Eq)]
+struct Position(u32, u32);
+
+#[derive(Component, Debug, PartialEq, Eq)]
+struct Health(u32);
+
+#[derive(Component, Debug, PartialEq, Eq)]
+struct Name(&'static str);
+
+#[derive(Bundle, Debug, PartialEq, Eq)]
+struct Body {
+    position: Position,
+    health: Health,
+}
+
+#[derive(Bundle, Debug, PartialEq, Eq)]
+struct Player {
+    name: Name,
+    body: Body,
+}
+
+#[derive(Bundle, Debug, PartialEq, Eq)]
+struct Pair<T: Component + Send + Sync>(T, Health);
+
+#[test]
+fn add_remove() {
+    let mut world = World::new();
+
+    let entity = world.add_entity(Player {
+        name: Name("Alice"),
+        body: Body {
+            position: Position(1, 2),
+            health: Health(10),
+        },
+    });
+
+    {
+        let (names, positions, healths) = world
+            .borrow::<(View<Name>, View<Position>, View<Health>)>()
+            .unwrap();
+
+        assert_eq!(
+            (&names, &positions, &healths).iter().collect::<Vec<_>>(),
+            [(&Name("Alice"), &Position(1, 2), &Health(10))]
+        );
+    }
+
+    assert_eq!(
+        world.remove::<Body>(entity),
+        Some(Body {
+            position: Position(1, 2),
+            health: Health(10),
+        })
+    );
+    assert_eq!(world.remove::<Body>(entity), None);
+    assert_eq!(world.get::<&Name>(entity).as_deref(), Ok(&&Name("Alice")));
+
+    world.add_component(
+        entity,
+        Body {
+            position: Position(3, 4),
+            health: Health(20),
+        },
+    );
+    assert_eq!(
+        world.remove::<Player>(entity),
+        Some(Player {
+            name: Name("Alice"),
+            body: Body {
+                position: Position(3, 4),
+                health: Health(20),
+            },
+        })
+    );
+}
+
+#[test]
+fn delete() {
+    let mut world = World::new();
+
+    let entity = world.add_entity(Pair(Name("Bob"), Health(5)));
+
+    world.delete_component::<Pair<Name>>(entity);
+    assert!(world.get::<&Name>(entity).is_err());
+    assert!(world.get::<&Health>(entity).is_err());
+}
+
+#[test]
+fn partial_remove() {
+    let mut world = World::new();
+
+    let entity = world.add_entity((Position(0, 0),));
+
+    assert_eq!(world.remove::<Body>(entity), None);
+    assert_eq!(
+        world.get::<&Position>(entity).as_deref(),
+        Ok(&&Position(0, 0))
+    );
+
+    let entity = world.add_entity((Name("Bob"), Position(1, 1), Health(5)));
+    world.remove::<Health>(entity);
+
+    assert_eq!(world.remove::<Player>(entity), None);
+    assert_eq!(world.get::<&Name>(entity).as_deref(), Ok(&&Name("Bob")));
+    assert_eq!(
+        world.get::<&Position>(entity).as_deref(),
+        Ok(&&Position(1, 1))
+    );
+}
@@ -0,0 +1,167 @@
+use shipyard::*;
+
+#[derive(PartialEq, Eq, Debug)]
+struct U32(u32);
+impl Component for U32 {
+    type Tracking = track::Untracked;
+}
+
+#[derive(PartialEq, Eq, Debug)]
+struct U64(u64);
+impl Component for U64 {
+    type Tracking = track::Untracked;
+}
+
+#[test]
+fn add_entity() {
+    let mut world = World::new();
+
+    let entity = world.run(|mut commands: Commands| commands.add_entity((U32(0), U64(1))));
+
+    assert!(!world.is_entity_alive(entity));
+
+    let other = world.add_entity(());
+    assert_ne!(entity, other);
+
+    world.apply_commands();
+
+    assert!(world.is_entity_alive(entity));
+    assert!(world.is_entity_alive(other));
+    let (u32s, u64s) = world.borrow::<(View<U32>, View<U64>)>().unwrap();
+    assert_eq!(u32s[entity], U32(0));
+    assert_eq!(u64s[entity], U64(1));
+}
+
+#[test]
+fn component_operations() {
+    let mut world = World::new();
+
+    let entity1 = world.add_entity((U32(0),));
+    let entity2 = world.add_entity((U32(1), U64(1)));
+    let entity3 = world.add_entity((U32(2),));
+
+    world.run(|mut commands: Commands| {
+        commands.add_component(entity1, (U64(10),));
+        commands.delete_component::<(U32,)>(entity2);
+        commands.remove::<(U32,)>(entity3);
+        commands.delete_entity(entity1);
+    });
+
+    assert!(world.borrow::<View<U64>>().unwrap().get(entity1).is_err());
+
+    world.apply_commands();
+
+    assert!(!world.is_entity_alive(entity1));
+    let (u32s, u64s) = world.borrow::<(View<U32>, View<U64>)>().unwrap();
+    assert!(u32s.get(entity2).is_err());
+    assert_eq!(u64s[entity2], U64(1));
+    assert!(u32s.get(entity3).is_err());
+    assert_eq!(u32s.len(), 0);
+}
+
+#[test]
+fn add_component_to_dead_entity() {
+    let mut world = World::new();
+
+    let entity = world.add_entity(());
+
+    world.run(|mut commands: Commands| {
+        commands.delete_entity(entity);
+        commands.add_component(entity, (U32(0),));
+    });
+
+    world.apply_commands();
+
+    assert!(!world.is_entity_alive(entity));
+    assert!(world.borrow::<View<U32>>().unwrap().is_empty());
+}
+
+#[test]
+fn workload_end() {
+    fn spawn(mut commands: Commands) {
+        commands.add_entity((U32(0),));
+    }
+
+    fn count(u32s: View<U32>) {
+        assert_eq!(u32s.len(), 0);
+    }
+
+    let world = World::new();
+
+    Workload::new("")
+        .with_system(spawn)
+        .with_system(count)
+        .add_to_world(&world)
+        .unwrap();
+
+    world.run_default_workload().unwrap();
+
+    assert_eq!(world.borrow::<View<U32>>().unwrap().len(), 1);
+}
+
+#[test]
+fn barrier() {
+    fn spawn(mut commands: Commands) {
+        commands.add_entity((U32(0),));
+    }
+
+    fn count(u32s: View<U32>) {
+        assert_eq!(u32s.len(), 1);
+    }
+
+    let world = World::new();
+
+    let (_, info) = Workload::new("")
+        .with_system(spawn)
+        .with_barrier()
+        .with_system(count)
+        .build()
+        .unwrap();
+    assert_eq!(info.batches_info.len(), 2);
+
+    Workload::new("")
+        .with_system(spawn)
+        .with_barrier()
+        .with_system(count)
+        .add_to_world(&world)
+        .unwrap();
+
+    world.run_default_workload().unwrap();
+
+    assert_eq!(world.borrow::<View<U32>>().unwrap().len(), 1);
+}
+
+#[test]
+fn parallel_commands() {
+    fn spawn_u32(mut commands: Commands) {
+        commands.add_entity((U32(0),));
+    }
+
+    fn spawn_u64(mut commands: Commands) {
+        commands.add_entity((U64(0),));
+    }
+
+    let world = World::new();
+
+    let (_, info) = Workload::new("")
+        .with_system(spawn_u32)
+        .with_system(spawn_u64)
+        .build()
+        .unwrap();
+    assert_eq!(info.batches_info.len(), 1);
+
+    Workload::new("")
+        .with_system(spawn_u32)
+        .with_system(spawn_u64)
+        .add_to_world(&world)
+        .unwrap();
+
+    world.run_default_workload().unwrap();
+
+    let (entities, u32s, u64s) = world
+        .borrow::<(EntitiesView, View<U32>, View<U64>)>()
+        .unwrap();
+    assert_eq!(entities.iter().count(), 2);
+    assert_eq!(u32s.len(), 1);
+    assert_eq!(u64s.len(), 1);
+}
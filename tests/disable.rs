@@ -0,0 +1,128 @@
+use shipyard::{
+    AddComponent, Component, Contains, EntitiesViewMut, Get, IntoIter, View, ViewMut, World,
+};
+
+#[derive(Component, Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct U32(u32);
+
+#[derive(Component, Debug, PartialEq, Eq)]
+struct USize(usize);
+
+#[test]
+fn iter_get_contains() {
+    let mut world = World::new();
+
+    let entity0 = world.add_entity((U32(0), USize(0)));
+    let entity1 = world.add_entity((U32(1), USize(1)));
+    let entity2 = world.add_entity((U32(2),));
+
+    assert!(world.disable_entity(entity1));
+    assert!(!world.disable_entity(entity1));
+    assert!(world.is_entity_disabled(entity1));
+    assert!(!world.is_entity_disabled(entity0));
+
+    {
+        let (u32s, usizes) = world.borrow::<(View<U32>, View<USize>)>().unwrap();
+
+        assert_eq!(
+            u32s.iter().with_id().map(|(id, _)| id).collect::<Vec<_>>(),
+            [entity0, entity2]
+        );
+        assert_eq!((&u32s, &usizes).iter().count(), 1);
+        assert!((&u32s, &usizes).get(entity1).is_err());
+        assert!(!(&u32s, &usizes).contains(entity1));
+
+        assert_eq!(u32s.include_disabled().iter().count(), 3);
+        assert_eq!(
+            (u32s.include_disabled(), usizes.include_disabled())
+                .iter()
+                .collect::<Vec<_>>(),
+            [(&U32(0), &USize(0)), (&U32(1), &USize(1))]
+        );
+        assert_eq!(u32s.include_disabled().get(entity1), Ok(&U32(1)));
+        assert!(u32s.include_disabled().contains(entity1));
+    }
+
+    assert!(world.enable_entity(entity1));
+    assert!(!world.enable_entity(entity1));
+
+    let (u32s, usizes) = world.borrow::<(View<U32>, View<USize>)>().unwrap();
+    assert_eq!(u32s.iter().count(), 3);
+    assert_eq!((&u32s, &usizes).get(entity1), Ok((&U32(1), &USize(1))));
+}
+
+#[test]
+fn modify_while_disabled() {
+    let mut world = World::new();
+
+    let entity0 = world.add_entity((U32(0),));
+    let entity1 = world.add_entity((U32(1),));
+    let entity2 = world.add_entity((U32(2),));
+    world.disable_entity(entity0);
+    world.disable_entity(entity1);
+
+    let entity3 = world.add_entity((U32(3),));
+    world.add_component(entity1, USize(1));
+    world.delete_component::<U32>(entity2);
+    world.delete_component::<U32>(entity0);
+
+    {
+        let (u32s, usizes) = world.borrow::<(View<U32>, View<USize>)>().unwrap();
+        assert_eq!(u32s.iter().collect::<Vec<_>>(), [&U32(3)]);
+        assert_eq!(usizes.iter().count(), 0);
+        assert_eq!(u32s.include_disabled().get(entity1), Ok(&U32(1)));
+        assert_eq!(usizes.include_disabled().get(entity1), Ok(&USize(1)));
+    }
+
+    world.bulk_add_entity((4..7).map(|i| (U32(i),)));
+    world
+        .borrow::<ViewMut<U32>>()
+        .unwrap()
+        .sort_unstable_by(|a, b| b.cmp(a));
+
+    {
+        let mut u32s = world.borrow::<ViewMut<U32>>().unwrap();
+        assert_eq!(
+            u32s.iter().map(|u32| u32.0).collect::<Vec<_>>(),
+            [6, 5, 4, 3]
+        );
+
+        for u32 in u32s.include_disabled_mut().iter() {
+            u32.0 += 10;
+        }
+    }
+
+    assert!(world.delete_entity(entity1));
+    assert!(!world.is_entity_disabled(entity1));
+    assert!(!world.enable_entity(entity1));
+
+    let u32s = world.borrow::<View<U32>>().unwrap();
+    assert_eq!(u32s.iter().count(), 4);
+    assert_eq!(u32s.include_disabled().iter().count(), 4);
+    assert_eq!(u32s.get(entity3), Ok(&U32(13)));
+}
+
+#[test]
+fn entities_view_mut() {
+    let world = World::new();
+
+    let (mut entities, mut u32s, mut usizes) = world
+        .borrow::<(EntitiesViewMut, ViewMut<U32>, ViewMut<USize>)>()
+        .unwrap();
+
+    let entity0 = entities.add_entity(&mut u32s, U32(0));
+    let entity1 = entities.add_entity(&mut u32s, U32(1));
+
+    assert!(entities.disable_entity(entity0, (&mut u32s, &mut usizes)));
+    assert!(entities.is_disabled(entity0));
+
+    entities.add_component(entity0, &mut usizes, USize(0));
+    usizes.add_component_unchecked(entity1, USize(1));
+
+    assert_eq!((&u32s, &usizes).iter().count(), 1);
+    assert!(!(&usizes).contains(entity0));
+    assert!((&usizes).contains(entity1));
+
+    assert!(entities.enable_entity(entity0, (&mut u32s, &mut usizes)));
+    assert_eq!((&u32s, &usizes).iter().count(), 2);
+}
@@ -0,0 +1,167 @@
+use core::alloc::Layout;
+use shipyard::advanced::StorageId;
+use shipyard::sparse_set::DynamicComponentInfo;
+use shipyard::{AllStoragesViewMut, World};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static DROPPED: AtomicUsize = AtomicUsize::new(0);
+
+unsafe fn count_drop(_: *mut u8) {
+    DROPPED.fetch_add(1, Ordering::Relaxed);
+}
+
+unsafe fn clone_u64(src: *const u8, dst: *mut u8) {
+    dst.cast::<u64>().write(src.cast::<u64>().read());
+}
+
+fn counted_info(name: &'static str) -> DynamicComponentInfo {
+    unsafe { DynamicComponentInfo::new(name, Layout::new::<u64>(), Some(count_drop)) }
+}
+
+#[test]
+fn insert_get_remove() {
+    let mut world = World::new();
+
+    let storage_id = world.add_dynamic_storage(DynamicComponentInfo::of::<String>("Name"));
+    assert_eq!(storage_id, StorageId::from_name("Name"));
+
+    let entity0 = world.add_entity(());
+    let entity1 = world.add_entity(());
+
+    let all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    let mut storage = all_storages.dynamic_storage_mut(storage_id).unwrap();
+
+    unsafe {
+        let name = std::mem::ManuallyDrop::new(String::from("Alice"));
+        storage.insert(entity0, (&*name as *const String).cast());
+        let name = std::mem::ManuallyDrop::new(String::from("Bob"));
+        storage.insert(entity1, (&*name as *const String).cast());
+
+        assert_eq!(storage.len(), 2);
+        assert_eq!(&*storage.get(entity0).unwrap().cast::<String>(), "Alice");
+
+        storage
+            .get_mut(entity1)
+            .unwrap()
+            .cast::<String>()
+            .as_mut()
+            .unwrap()
+            .push('!');
+
+        let mut removed = std::mem::MaybeUninit::<String>::uninit();
+        assert!(storage.remove(entity0, removed.as_mut_ptr().cast()));
+        assert!(!storage.remove(entity0, removed.as_mut_ptr().cast()));
+        assert_eq!(removed.assume_init(), "Alice");
+
+        assert_eq!(&*storage.get(entity1).unwrap().cast::<String>(), "Bob!");
+    }
+
+    assert!(!storage.contains(entity0));
+    assert_eq!(storage.info().name(), "Name");
+}
+
+#[test]
+fn delete_entity_and_memory_usage() {
+    let mut world = World::new();
+
+    let storage_id = world.add_dynamic_storage(counted_info("Counted"));
+    let entity0 = world.add_entity(());
+    let entity1 = world.add_entity(());
+
+    {
+        let all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+        let mut storage = all_storages.dynamic_storage_mut(storage_id).unwrap();
+
+        unsafe {
+            storage.insert(entity0, (&0u64 as *const u64).cast());
+            storage.insert(entity1, (&1u64 as *const u64).cast());
+        }
+    }
+
+    assert!(format!("{:?}", world.memory_usage()).contains("Counted: "));
+
+    let dropped = DROPPED.load(Ordering::Relaxed);
+    world.delete_entity(entity0);
+    assert_eq!(DROPPED.load(Ordering::Relaxed), dropped + 1);
+
+    let all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    let storage = all_storages.dynamic_storage(storage_id).unwrap();
+
+    assert!(!storage.contains(entity0));
+    assert_eq!(unsafe { *storage.get(entity1).unwrap().cast::<u64>() }, 1);
+}
+
+#[test]
+fn clone_entity_to() {
+    let mut world1 = World::new();
+    let mut world2 = World::new();
+
+    let info = unsafe { DynamicComponentInfo::of::<u64>("Value").with_clone(clone_u64) };
+    let storage_id = world1.add_dynamic_storage(info);
+    let entity = world1.add_entity(());
+
+    unsafe {
+        world1
+            .borrow::<AllStoragesViewMut>()
+            .unwrap()
+            .dynamic_storage_mut(storage_id)
+            .unwrap()
+            .insert(entity, (&7u64 as *const u64).cast());
+    }
+
+    let copy = world2.add_entity(());
+    world1.clone_components_to(&mut world2, entity, copy);
+
+    let all_storages = world2.borrow::<AllStoragesViewMut>().unwrap();
+    let storage = all_storages.dynamic_storage(storage_id).unwrap();
+
+    assert_eq!(unsafe { *storage.get(copy).unwrap().cast::<u64>() }, 7);
+}
+
+#[test]
+#[should_panic(expected = "with a different layout")]
+fn layout_mismatch() {
+    let mut world = World::new();
+
+    let storage_id = world.add_dynamic_storage(DynamicComponentInfo::of::<u64>("Value"));
+    assert_eq!(
+        world.add_dynamic_storage(DynamicComponentInfo::of::<u64>("Value")),
+        storage_id
+    );
+
+    world.add_dynamic_storage(DynamicComponentInfo::of::<u32>("Value"));
+}
+
+#[test]
+fn unpadded_size() {
+    let mut world = World::new();
+
+    // three bytes with an alignment of four, the storage pads them but must only read three
+    let info =
+        unsafe { DynamicComponentInfo::new("Rgb", Layout::from_size_align(3, 4).unwrap(), None) };
+    assert_eq!(info.layout().size(), 3);
+
+    let storage_id = world.add_dynamic_storage(info);
+    let entities = [(); 5].map(|_| world.add_entity(()));
+
+    let all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    let mut storage = all_storages.dynamic_storage_mut(storage_id).unwrap();
+
+    unsafe {
+        for (i, &entity) in entities.iter().enumerate() {
+            let rgb = [i as u8; 3];
+            storage.insert(entity, rgb.as_ptr());
+        }
+
+        assert!(storage.delete(entities[1]));
+
+        let mut rgb = [0u8; 3];
+        assert!(storage.remove(entities[4], rgb.as_mut_ptr()));
+        assert_eq!(rgb, [4; 3]);
+
+        for i in [0, 2, 3] {
+            let component = storage.get(entities[i]).unwrap();
+            assert_eq!(component.cast::<[u8; 3]>().read(), [i as u8; 3]);
+        }
+    }
+}
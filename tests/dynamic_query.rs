@@ -0,0 +1,228 @@
+use shipyard::advanced::StorageId;
+use shipyard::dynamic_query::DynamicQuery;
+use shipyard::error;
+use shipyard::sparse_set::{DynamicComponentInfo, SparseSet};
+use shipyard::{AllStoragesViewMut, Component, View, World};
+
+#[derive(Component, Debug, PartialEq)]
+#[track(Modification)]
+struct U32(u32);
+
+#[derive(Component, Debug, PartialEq)]
+struct USize(usize);
+
+#[derive(Component)]
+struct Tag;
+
+fn id<T: Component>() -> StorageId {
+    StorageId::of::<SparseSet<T>>()
+}
+
+#[test]
+fn read_write_without() {
+    let mut world = World::new();
+
+    let entity0 = world.add_entity((U32(0), USize(10)));
+    let entity1 = world.add_entity((U32(1), USize(11), Tag));
+    let entity2 = world.add_entity((U32(2), USize(12)));
+    world.add_entity(U32(3));
+
+    world.clear_all_modified();
+
+    {
+        let all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+        let mut view = DynamicQuery::new()
+            .write(id::<U32>())
+            .read(id::<USize>())
+            .without(id::<Tag>())
+            .borrow(&all_storages)
+            .unwrap();
+
+        let mut entities = Vec::new();
+        for mut item in view.iter() {
+            let usize = item.get::<USize>(1).unwrap().0;
+            item.get_mut::<U32>(0).unwrap().0 += usize as u32;
+
+            assert!(item.ptr(2).is_none());
+            entities.push(item.entity());
+        }
+
+        assert_eq!(entities, [entity0, entity2]);
+    }
+
+    let u32s = world.borrow::<View<U32>>().unwrap();
+    assert_eq!(u32s[entity0], U32(10));
+    assert_eq!(u32s[entity2], U32(14));
+    assert!(u32s.is_modified(entity0));
+    assert!(!u32s.is_modified(entity1));
+    assert!(u32s.is_modified(entity2));
+}
+
+#[test]
+fn optional_and_or() {
+    let mut world = World::new();
+
+    let entity0 = world.add_entity(U32(0));
+    let entity1 = world.add_entity(USize(1));
+    let entity2 = world.add_entity((U32(2), USize(2)));
+    world.add_entity(Tag);
+
+    let all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+
+    let mut view = DynamicQuery::new()
+        .or([id::<U32>(), id::<USize>()])
+        .borrow(&all_storages)
+        .unwrap();
+    let items = view
+        .iter()
+        .map(|item| {
+            (
+                item.entity(),
+                item.get::<U32>(0).map(|u32| u32.0),
+                item.get::<USize>(1).map(|usize| usize.0),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        items,
+        [
+            (entity0, Some(0), None),
+            (entity1, None, Some(1)),
+            (entity2, Some(2), Some(2))
+        ]
+    );
+    drop(view);
+
+    let mut view = DynamicQuery::new()
+        .read(id::<USize>())
+        .optional_mut(id::<U32>())
+        .borrow(&all_storages)
+        .unwrap();
+    assert!(view.get(entity0).is_none());
+    assert!(view.get(entity1).unwrap().get_mut::<U32>(1).is_none());
+    view.get(entity2).unwrap().get_mut::<U32>(1).unwrap().0 = 20;
+    drop(view);
+
+    assert_eq!(*all_storages.get::<&U32>(entity2).unwrap(), &U32(20));
+}
+
+#[test]
+fn missing_storage() {
+    let mut world = World::new();
+
+    let entity = world.add_entity(U32(0));
+
+    let all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+
+    let mut view = DynamicQuery::new()
+        .read(id::<U32>())
+        .without(id::<Tag>())
+        .borrow(&all_storages)
+        .unwrap();
+    assert_eq!(view.iter().count(), 1);
+    drop(view);
+
+    let mut view = DynamicQuery::new()
+        .read(id::<U32>())
+        .read(id::<Tag>())
+        .borrow(&all_storages)
+        .unwrap();
+    assert_eq!(view.iter().count(), 0);
+    drop(view);
+
+    let mut view = DynamicQuery::new()
+        .without(id::<U32>())
+        .borrow(&all_storages)
+        .unwrap();
+    assert!(view.get(entity).is_none());
+    assert_eq!(view.iter().count(), 0);
+}
+
+#[test]
+fn dynamic_storage() {
+    let mut world = World::new();
+
+    let health = world.add_dynamic_storage(DynamicComponentInfo::of::<u64>("Health"));
+    let entity0 = world.add_entity(U32(0));
+    let entity1 = world.add_entity(());
+
+    let all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    unsafe {
+        let mut storage = all_storages.dynamic_storage_mut(health).unwrap();
+        storage.insert(entity0, (&5u64 as *const u64).cast());
+        storage.insert(entity1, (&6u64 as *const u64).cast());
+    }
+
+    let mut view = DynamicQuery::new()
+        .write(health)
+        .read(id::<U32>())
+        .borrow(&all_storages)
+        .unwrap();
+    let mut items = view.iter().collect::<Vec<_>>();
+
+    assert_eq!(items.len(), 1);
+    unsafe {
+        *items[0].ptr_mut(0).unwrap().cast::<u64>() += 1;
+        assert_eq!(*items[0].ptr(0).unwrap().cast::<u64>(), 6);
+    }
+}
+
+#[test]
+fn borrow_conflict() {
+    let mut world = World::new();
+    world.add_entity(U32(0));
+
+    let all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+
+    assert!(matches!(
+        DynamicQuery::new()
+            .write(id::<U32>())
+            .read(id::<U32>())
+            .borrow(&all_storages),
+        Err(error::GetStorage::StorageBorrow { .. })
+    ));
+}
+
+#[test]
+#[should_panic(expected = "is not borrowed mutably")]
+fn write_read_term() {
+    let mut world = World::new();
+    let entity = world.add_entity(U32(0));
+
+    let all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    let mut view = DynamicQuery::new()
+        .read(id::<U32>())
+        .borrow(&all_storages)
+        .unwrap();
+
+    view.get(entity).unwrap().get_mut::<U32>(0);
+}
+
+#[test]
+fn items_alive_together() {
+    let mut world = World::new();
+
+    let entity0 = world.add_entity(U32(0));
+    let entity1 = world.add_entity(U32(1));
+
+    {
+        let all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+        let mut view = DynamicQuery::new()
+            .write(id::<U32>())
+            .borrow(&all_storages)
+            .unwrap();
+
+        let mut items = view.iter().collect::<Vec<_>>();
+        let mut u32s = items
+            .iter_mut()
+            .map(|item| item.get_mut::<U32>(0).unwrap())
+            .collect::<Vec<_>>();
+
+        let (first, second) = u32s.split_at_mut(1);
+        core::mem::swap(first[0], second[0]);
+    }
+
+    let u32s = world.borrow::<View<U32>>().unwrap();
+    assert_eq!(u32s[entity0], U32(1));
+    assert_eq!(u32s[entity1], U32(0));
+}
@@ -0,0 +1,188 @@
+use shipyard::events::EventExpiry;
+use shipyard::*;
+
+#[derive(PartialEq, Eq, Debug)]
+struct Hit(u32);
+
+fn sum_hits(hits: &EventReader<Hit>) -> u32 {
+    hits.iter().map(|hit| hit.0).sum()
+}
+
+#[test]
+fn multiple_readers() {
+    fn send(mut hits: EventWriter<Hit>) {
+        hits.send_batch([Hit(1), Hit(2)]);
+    }
+
+    fn read_a(hits: EventReader<Hit>) {
+        assert_eq!(sum_hits(&hits), 3);
+    }
+
+    fn read_b(hits: EventReader<Hit>) {
+        assert_eq!(sum_hits(&hits), 3);
+    }
+
+    let world = World::new();
+
+    Workload::new("")
+        .with_system(send)
+        .with_system(read_a)
+        .with_system(read_b)
+        .add_to_world(&world)
+        .unwrap();
+
+    for _ in 0..3 {
+        world.run_default_workload().unwrap();
+    }
+
+    // Both readers have seen all events
+    assert!(world.borrow::<EventWriter<Hit>>().unwrap().is_empty());
+}
+
+#[test]
+fn reader_before_writer() {
+    fn read(hits: EventReader<Hit>) {
+        assert!(hits.iter().count() <= 1);
+    }
+
+    fn send(mut hits: EventWriter<Hit>) {
+        hits.send(Hit(0));
+    }
+
+    let world = World::new();
+
+    Workload::new("")
+        .with_system(read)
+        .with_system(send)
+        .add_to_world(&world)
+        .unwrap();
+
+    for _ in 0..3 {
+        world.run_default_workload().unwrap();
+    }
+
+    // The event sent in the last run hasn't been read yet
+    assert_eq!(world.borrow::<EventWriter<Hit>>().unwrap().len(), 1);
+}
+
+#[test]
+fn runs_expiry() {
+    fn send(mut hits: EventWriter<Hit>) {
+        hits.set_expiry(EventExpiry::Runs(2));
+        hits.send(Hit(1));
+    }
+
+    let world = World::new();
+
+    Workload::new("")
+        .with_system(send)
+        .add_to_world(&world)
+        .unwrap();
+
+    for _ in 0..4 {
+        world.run_default_workload().unwrap();
+    }
+
+    let hits = world.borrow::<EventReader<Hit>>().unwrap();
+    // Only the event sent during the last run is still alive
+    assert_eq!(sum_hits(&hits), 1);
+}
+
+#[test]
+fn outside_workload() {
+    let world = World::new();
+
+    world.run(|mut hits: EventWriter<Hit>| hits.send(Hit(5)));
+
+    world.run(|hits: EventReader<Hit>| {
+        assert_eq!(hits.iter().collect::<Vec<_>>(), [&Hit(5)]);
+    });
+    world.run(|hits: EventReader<Hit>| {
+        assert_eq!(sum_hits(&hits), 5);
+    });
+}
+
+#[test]
+fn no_reader() {
+    fn send(mut hits: EventWriter<Hit>) {
+        hits.send(Hit(1));
+    }
+
+    let world = World::new();
+
+    Workload::new("")
+        .with_system(send)
+        .add_to_world(&world)
+        .unwrap();
+
+    for _ in 0..4 {
+        world.run_default_workload().unwrap();
+    }
+
+    // Without reader, events only live for one workload run
+    assert_eq!(
+        world.borrow::<EventReader<Hit>>().unwrap().iter().count(),
+        1
+    );
+}
+
+#[test]
+fn stale_reader() {
+    struct Missed(bool);
+    impl Unique for Missed {}
+
+    fn send(mut hits: EventWriter<Hit>) {
+        hits.send(Hit(1));
+    }
+
+    fn read(hits: EventReader<Hit>, mut missed: UniqueViewMut<Missed>) {
+        missed.0 = hits.missed_events();
+    }
+
+    let world = World::new();
+    world.add_unique(Missed(false));
+    world
+        .add_custom_storage(
+            advanced::StorageId::of::<events::Events<Hit>>(),
+            events::Events::<Hit>::new(EventExpiry::AllReaders).with_stale_reader_runs(4),
+        )
+        .unwrap();
+
+    Workload::new("")
+        .with_system(send)
+        .with_system(read)
+        .add_to_world(&world)
+        .unwrap();
+
+    world.run_default_workload().unwrap();
+    world.disable_system_in_workload("", read).unwrap();
+
+    for _ in 0..4 {
+        world.run_default_workload().unwrap();
+    }
+
+    // The disabled reader still holds the events back
+    assert_eq!(
+        world.borrow::<EventReader<Hit>>().unwrap().iter().count(),
+        4
+    );
+
+    world.run_default_workload().unwrap();
+
+    // Once forgotten, events expire again
+    assert_eq!(
+        world.borrow::<EventReader<Hit>>().unwrap().iter().count(),
+        1
+    );
+    assert!(!world.borrow::<UniqueView<Missed>>().unwrap().0);
+
+    world.enable_system_in_workload("", read).unwrap();
+    world.run_default_workload().unwrap();
+
+    // The reader is told it missed events
+    assert!(world.borrow::<UniqueView<Missed>>().unwrap().0);
+
+    world.run_default_workload().unwrap();
+
+    assert!(!world.borrow::<UniqueView<Missed>>().unwrap().0);
+}
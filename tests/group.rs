@@ -0,0 +1,198 @@
+use shipyard::{
+    track, Component, EntitiesViewMut, EntityId, Get, IntoIter, Remove, View, ViewMut, World,
+};
+
+#[derive(Component, Debug, PartialEq, Eq, Clone, Copy)]
+struct U32(u32);
+
+#[derive(Component, Debug, PartialEq, Eq, Clone, Copy)]
+struct USize(usize);
+
+#[derive(Component, Debug, PartialEq, Eq, Clone, Copy)]
+struct Name(&'static str);
+
+/// Checks the first `len` entities of both storages are the same.
+fn assert_packed(u32s: &View<U32>, usizes: &View<USize>, len: usize) {
+    for index in 0..len {
+        assert_eq!(u32s.id_at(index), usizes.id_at(index));
+    }
+
+    // only packed iteration knows its exact length
+    assert_eq!((u32s, usizes).iter().size_hint(), (len, Some(len)));
+}
+
+fn sorted(mut entities: Vec<EntityId>) -> Vec<EntityId> {
+    entities.sort_unstable();
+    entities
+}
+
+#[test]
+fn owning() {
+    let mut world = World::new();
+
+    let entity0 = world.add_entity((U32(0),));
+    let entity1 = world.add_entity((U32(1), USize(1)));
+
+    world.add_group::<(U32, USize)>();
+
+    let entity2 = world.add_entity((USize(2),));
+    let entity3 = world.add_entity((U32(3), USize(3)));
+    world.add_component(entity0, (USize(0),));
+    let bulk = world
+        .bulk_add_entity((4..6).map(|i| (U32(i), USize(i as usize))))
+        .collect::<Vec<_>>();
+
+    world.run(|u32s: View<U32>, usizes: View<USize>| {
+        assert_packed(&u32s, &usizes, 5);
+        assert_eq!((&u32s, &usizes).iter().count(), 5);
+        assert!((&u32s, &usizes)
+            .iter()
+            .all(|(u32, usize)| u32.0 as usize == usize.0));
+    });
+
+    world.remove::<(USize,)>(entity1);
+    world.delete_component::<(U32,)>(entity3);
+    world.add_component(entity2, (U32(2),));
+    world.delete_entity(entity0);
+
+    world.run(|u32s: View<U32>, usizes: View<USize>| {
+        assert_packed(&u32s, &usizes, 3);
+        assert_eq!(
+            sorted(
+                (&u32s, &usizes)
+                    .iter()
+                    .with_id()
+                    .map(|(id, _)| id)
+                    .collect()
+            ),
+            sorted([entity2].into_iter().chain(bulk.iter().copied()).collect())
+        );
+        assert_eq!((&u32s, &usizes).iter().count(), 3);
+        assert_eq!((&u32s, &usizes).get(entity2), Ok((&U32(2), &USize(2))));
+        assert!((&u32s, &usizes).get(entity1).is_err());
+    });
+}
+
+#[test]
+fn partial() {
+    let mut world = World::new();
+
+    world.add_partial_group::<(U32, USize), Name>();
+
+    world.add_entity((U32(0), USize(0)));
+    let entity1 = world.add_entity((U32(1), USize(1), Name("1")));
+    world.add_entity((U32(2), USize(2), Name("2")));
+
+    world.run(|u32s: View<U32>, usizes: View<USize>, names: View<Name>| {
+        for index in 0..2 {
+            assert_eq!(u32s.id_at(index), usizes.id_at(index));
+        }
+        assert_eq!((&u32s, &usizes, &names).iter().size_hint(), (0, Some(2)));
+        assert_eq!((&u32s, &usizes, &names).iter().count(), 2);
+        assert_eq!((&u32s, &usizes).iter().count(), 3);
+    });
+
+    world.delete_component::<(Name,)>(entity1);
+
+    world.run(|u32s: View<U32>, usizes: View<USize>, names: View<Name>| {
+        assert_eq!(
+            (&u32s, &usizes, &names).iter().collect::<Vec<_>>(),
+            [(&U32(2), &USize(2), &Name("2"))]
+        );
+    });
+}
+
+#[test]
+fn views() {
+    let mut world = World::new();
+
+    world.add_group::<(U32, USize)>();
+
+    world.add_entity((U32(0), USize(0)));
+    let entity1 = world.add_entity((U32(1), USize(1)));
+
+    world.run(
+        |mut entities: EntitiesViewMut, mut u32s: ViewMut<U32>, mut usizes: ViewMut<USize>| {
+            entities.add_entity((&mut u32s, &mut usizes), (U32(2), USize(2)));
+            usizes.remove(entity1);
+
+            // the group is unpacked, iteration still works
+            assert_eq!((&u32s, &usizes).iter().size_hint().0, 0);
+            assert_eq!((&u32s, &usizes).iter().count(), 2);
+        },
+    );
+
+    world.add_entity((U32(3), USize(3)));
+
+    world.run(|u32s: View<U32>, usizes: View<USize>| {
+        assert_packed(&u32s, &usizes, 3);
+        assert_eq!((&u32s, &usizes).iter().count(), 3);
+    });
+
+    world.run(|mut u32s: ViewMut<U32>| u32s.sort_unstable_by(|a, b| b.0.cmp(&a.0)));
+    world.add_entity(());
+
+    world.run(|u32s: View<U32>, usizes: View<USize>| {
+        assert_packed(&u32s, &usizes, 3);
+        assert_eq!((&u32s, &usizes).iter().count(), 3);
+    });
+}
+
+#[test]
+fn tracking_and_disable() {
+    let mut world = World::new();
+
+    world.add_group::<(U32, USize)>();
+    world.track_all::<(U32,)>();
+
+    let entity0 = world.add_entity((U32(0), USize(0)));
+    let entity1 = world.add_entity((U32(1), USize(1)));
+
+    world.run(|mut u32s: ViewMut<U32, track::All>, usizes: View<USize>| {
+        for (mut u32, usize) in (&mut u32s, &usizes).iter() {
+            if usize.0 == 1 {
+                u32.0 += 10;
+            }
+        }
+    });
+
+    world.run(|u32s: View<U32, track::All>, usizes: View<USize>| {
+        assert_eq!(
+            (u32s.modified(), &usizes).iter().collect::<Vec<_>>(),
+            [(&U32(11), &USize(1))]
+        );
+    });
+
+    world.disable_entity(entity1);
+
+    world.run(|u32s: View<U32>, usizes: View<USize>| {
+        assert_eq!(
+            (&u32s, &usizes)
+                .iter()
+                .with_id()
+                .map(|(id, _)| id)
+                .collect::<Vec<_>>(),
+            [entity0]
+        );
+    });
+
+    world.enable_entity(entity1);
+    world.delete_entity(entity0);
+
+    world.run(|u32s: View<U32>, usizes: View<USize>| {
+        assert_packed(&u32s, &usizes, 1);
+        assert_eq!(
+            (&u32s, &usizes).iter().collect::<Vec<_>>(),
+            [(&U32(11), &USize(1))]
+        );
+    });
+}
+
+#[test]
+#[should_panic(expected = "is already part of a group")]
+fn already_grouped() {
+    let mut world = World::new();
+
+    world.add_group::<(U32, USize)>();
+    world.add_partial_group::<(Name,), USize>();
+}
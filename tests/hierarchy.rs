@@ -0,0 +1,188 @@
+use shipyard::hierarchy::{DeletionPolicy, Hierarchy};
+use shipyard::*;
+
+#[derive(PartialEq, Eq, Debug)]
+struct U32(u32);
+impl Component for U32 {
+    type Tracking = track::Untracked;
+}
+
+struct Other;
+
+#[test]
+fn attach_detach() {
+    let mut hierarchy = Hierarchy::<()>::default();
+
+    let root = EntityId::new_from_index_and_gen(0, 0);
+    let child1 = EntityId::new_from_index_and_gen(1, 0);
+    let child2 = EntityId::new_from_index_and_gen(2, 0);
+    let child3 = EntityId::new_from_index_and_gen(3, 0);
+
+    hierarchy.attach(child1, root);
+    hierarchy.attach(child2, root);
+    hierarchy.attach(child3, root);
+
+    assert_eq!(hierarchy.num_children(root), 3);
+    assert_eq!(
+        hierarchy.children(root).collect::<Vec<_>>(),
+        [child1, child2, child3]
+    );
+    assert_eq!(hierarchy.parent(child2), Some(root));
+
+    assert_eq!(hierarchy.detach(child2), Some(root));
+    assert_eq!(hierarchy.detach(child2), None);
+    assert_eq!(
+        hierarchy.children(root).collect::<Vec<_>>(),
+        [child1, child3]
+    );
+    assert!(!hierarchy.contains(child2));
+
+    hierarchy.attach(child2, child1);
+    hierarchy.attach(child3, child1);
+    assert_eq!(hierarchy.children(root).collect::<Vec<_>>(), [child1]);
+    assert_eq!(
+        hierarchy.children(child1).collect::<Vec<_>>(),
+        [child2, child3]
+    );
+
+    hierarchy.detach(child1);
+    assert!(!hierarchy.contains(root));
+    assert_eq!(hierarchy.parent(child1), None);
+    assert_eq!(hierarchy.num_children(child1), 2);
+}
+
+#[test]
+fn traversal() {
+    let mut hierarchy = Hierarchy::<()>::default();
+
+    let [root, a, b, aa, ab, ba] =
+        [0, 1, 2, 3, 4, 5].map(|index| EntityId::new_from_index_and_gen(index, 0));
+
+    hierarchy.attach(a, root);
+    hierarchy.attach(b, root);
+    hierarchy.attach(aa, a);
+    hierarchy.attach(ab, a);
+    hierarchy.attach(ba, b);
+
+    assert_eq!(
+        hierarchy.descendants(root).collect::<Vec<_>>(),
+        [a, aa, ab, b, ba]
+    );
+    assert_eq!(hierarchy.descendants(b).collect::<Vec<_>>(), [ba]);
+    assert_eq!(hierarchy.descendants(ab).count(), 0);
+    assert_eq!(hierarchy.ancestors(ab).collect::<Vec<_>>(), [a, root]);
+    assert_eq!(hierarchy.ancestors(root).count(), 0);
+}
+
+#[test]
+#[should_panic(expected = "it would create a cycle")]
+fn cycle() {
+    let mut hierarchy = Hierarchy::<()>::default();
+
+    let root = EntityId::new_from_index_and_gen(0, 0);
+    let child = EntityId::new_from_index_and_gen(1, 0);
+    let grandchild = EntityId::new_from_index_and_gen(2, 0);
+
+    hierarchy.attach(child, root);
+    hierarchy.attach(grandchild, child);
+    hierarchy.attach(root, grandchild);
+}
+
+#[test]
+#[should_panic(expected = "has to be alive to be attached in a hierarchy")]
+fn attach_dead() {
+    let mut world = World::new();
+
+    let root = world.add_entity(());
+    let child = world.add_entity(());
+    world.delete_entity(root);
+
+    world.run(|mut hierarchy: HierarchyViewMut| {
+        hierarchy.attach(child, root);
+    });
+}
+
+#[test]
+fn orphan() {
+    let mut world = World::new();
+
+    let root = world.add_entity((U32(0),));
+    let child = world.add_entity((U32(1),));
+    let grandchild = world.add_entity((U32(2),));
+
+    world.run(|mut hierarchy: HierarchyViewMut| {
+        hierarchy.attach(child, root);
+        hierarchy.attach(grandchild, child);
+    });
+
+    world.delete_entity(child);
+
+    assert!(world.is_entity_alive(root));
+    assert!(world.is_entity_alive(grandchild));
+
+    world.run(|hierarchy: HierarchyView| {
+        assert!(!hierarchy.contains(root));
+        assert_eq!(hierarchy.parent(grandchild), None);
+    });
+}
+
+#[test]
+fn cascade() {
+    let mut world = World::new();
+
+    let root = world.add_entity((U32(0),));
+    let child = world.add_entity((U32(1),));
+    let grandchild = world.add_entity((U32(2),));
+    let sibling = world.add_entity((U32(3),));
+
+    world.run(|mut hierarchy: HierarchyViewMut| {
+        hierarchy.set_deletion_policy(DeletionPolicy::Cascade);
+
+        hierarchy.attach(child, root);
+        hierarchy.attach(sibling, root);
+        hierarchy.attach(grandchild, child);
+    });
+
+    world.delete_entity(child);
+
+    assert!(world.is_entity_alive(root));
+    assert!(world.is_entity_alive(sibling));
+    assert!(!world.is_entity_alive(grandchild));
+    assert_eq!(world.borrow::<View<U32>>().unwrap().len(), 2);
+
+    world.strip(root);
+
+    assert!(world.is_entity_alive(root));
+    assert!(!world.is_entity_alive(sibling));
+    assert!(world.borrow::<View<U32>>().unwrap().is_empty());
+    assert!(world
+        .borrow::<HierarchyView>()
+        .unwrap()
+        .descendants(root)
+        .next()
+        .is_none());
+}
+
+#[test]
+fn independent_hierarchies() {
+    let mut world = World::new();
+
+    let root = world.add_entity(());
+    let child = world.add_entity(());
+
+    world.run(
+        |mut hierarchy: HierarchyViewMut, mut other: HierarchyViewMut<Other>| {
+            hierarchy.attach(child, root);
+            other.set_deletion_policy(DeletionPolicy::Cascade);
+            other.attach(child, root);
+        },
+    );
+
+    world.run(|mut hierarchy: HierarchyViewMut| {
+        hierarchy.detach(child);
+    });
+
+    world.delete_entity(root);
+
+    assert!(!world.is_entity_alive(child));
+}
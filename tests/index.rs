@@ -0,0 +1,197 @@
+use shipyard::{Component, Get, IntoIter, Remove, View, ViewMut, World};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[derive(Component, Debug, PartialEq, Eq, Clone, Copy)]
+struct Name(&'static str);
+
+#[derive(Component, Debug, PartialEq, Eq, Clone, Copy)]
+struct Position(i32, i32);
+
+#[derive(Component, Debug, PartialEq, Eq, Clone, Copy)]
+#[track(Modification)]
+struct Level(u32);
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+struct GridCell(i32, i32);
+
+fn grid_cell(position: &Position) -> GridCell {
+    GridCell(position.0.div_euclid(10), position.1.div_euclid(10))
+}
+
+#[test]
+fn insert_remove_delete() {
+    let mut world = World::new();
+
+    let alice = world.add_entity((Name("Alice"),));
+
+    world.add_index(|name: &Name| name.0);
+
+    let bob = world.add_entity((Name("Bob"),));
+    let bulk = world
+        .bulk_add_entity([(Name("Carol"),), (Name("Carol"),)])
+        .collect::<Vec<_>>();
+
+    world.run(|names: View<Name>| {
+        let index = names.component_index::<&str>().unwrap();
+
+        assert_eq!(index.get("Alice"), [alice]);
+        assert_eq!(index.get("Bob"), [bob]);
+        assert_eq!(index.get("Carol"), bulk);
+        assert_eq!(index.key_of(bob), Some(&"Bob"));
+        assert_eq!(index.len(), 3);
+    });
+
+    world.add_component(alice, (Name("Dave"),));
+    world.remove::<(Name,)>(bob);
+    world.delete_entity(bulk[0]);
+
+    world.run(|names: View<Name>| {
+        let index = names.component_index::<&str>().unwrap();
+
+        assert!(index.get("Alice").is_empty());
+        assert_eq!(index.get("Dave"), [alice]);
+        assert!(index.get("Bob").is_empty());
+        assert_eq!(index.get("Carol"), [bulk[1]]);
+        assert_eq!(index.key_of(bob), None);
+    });
+
+    world.run(|mut names: ViewMut<Name>| {
+        names.remove(alice);
+        names.clear();
+    });
+
+    world.run(|names: View<Name>| {
+        assert!(names.component_index::<&str>().unwrap().is_empty());
+        assert!(names.component_index::<u32>().is_none());
+    });
+}
+
+#[test]
+fn modification() {
+    let mut world = World::new();
+
+    world.add_index(grid_cell);
+
+    let entity0 = world.add_entity((Position(0, 0),));
+    let entity1 = world.add_entity((Position(5, 5),));
+
+    world.run(|mut positions: ViewMut<Position>| {
+        for position in (&mut positions).iter() {
+            position.0 += 10;
+        }
+
+        assert_eq!(
+            positions
+                .component_index::<GridCell>()
+                .unwrap()
+                .get(&GridCell(1, 0)),
+            [entity0, entity1]
+        );
+    });
+
+    world.run(|positions: View<Position>| {
+        let index = positions.component_index::<GridCell>().unwrap();
+
+        assert!(index.get(&GridCell(0, 0)).is_empty());
+        assert_eq!(index.get(&GridCell(1, 0)), [entity0, entity1]);
+    });
+
+    world.get::<&mut Position>(entity0).unwrap().1 = 20;
+    for position in &mut world.iter::<&mut Position>() {
+        position.0 = -1;
+    }
+
+    world.run(|positions: View<Position>| {
+        let index = positions.component_index::<GridCell>().unwrap();
+
+        assert_eq!(
+            index.iter().collect::<Vec<_>>(),
+            [
+                (&GridCell(-1, 0), &[entity1][..]),
+                (&GridCell(-1, 2), &[entity0][..])
+            ]
+        );
+        assert_eq!(
+            index.range(GridCell(-1, 1)..).collect::<Vec<_>>(),
+            [(&GridCell(-1, 2), &[entity0][..])]
+        );
+    });
+}
+
+#[test]
+fn tracked_modification() {
+    static KEYED: AtomicUsize = AtomicUsize::new(0);
+
+    fn level(level: &Level) -> u32 {
+        KEYED.fetch_add(1, Ordering::Relaxed);
+        level.0
+    }
+
+    let mut world = World::new();
+
+    world.add_index(level);
+
+    let entities = world
+        .bulk_add_entity((0..3).map(|i| (Level(i),)))
+        .collect::<Vec<_>>();
+
+    KEYED.store(0, Ordering::Relaxed);
+
+    world.run(|mut levels: ViewMut<Level>| {
+        (&mut levels).get(entities[1]).unwrap().0 = 10;
+    });
+
+    // only the modified component is keyed again
+    assert_eq!(KEYED.load(Ordering::Relaxed), 1);
+
+    world.run(|mut levels: ViewMut<Level>| {
+        for mut level in (&mut levels).iter() {
+            level.0 += 1;
+        }
+    });
+    world.run(|_: ViewMut<Level>| {});
+
+    assert_eq!(KEYED.load(Ordering::Relaxed), 4);
+
+    world.run(|levels: View<Level>| {
+        let index = levels.component_index::<u32>().unwrap();
+
+        assert_eq!(index.get(&1), [entities[0]]);
+        assert_eq!(index.get(&11), [entities[1]]);
+        assert_eq!(index.get(&3), [entities[2]]);
+    });
+}
+
+#[test]
+fn disabled() {
+    let mut world = World::new();
+
+    world.add_index(|name: &Name| name.0);
+
+    let alice = world.add_entity((Name("Alice"),));
+    world.disable_entity(alice);
+
+    world.run(|names: View<Name>| {
+        assert!(names.component_index::<&str>().unwrap().is_empty());
+    });
+
+    world.add_component(alice, (Name("Bob"),));
+    world.run(|mut names: ViewMut<Name>| {
+        for name in names.include_disabled_mut().iter() {
+            name.0 = "Carol";
+        }
+    });
+
+    world.run(|names: View<Name>| {
+        assert!(names.component_index::<&str>().unwrap().is_empty());
+    });
+
+    world.enable_entity(alice);
+
+    world.run(|names: View<Name>| {
+        assert_eq!(
+            names.component_index::<&str>().unwrap().get("Carol"),
+            [alice]
+        );
+    });
+}
@@ -190,6 +190,95 @@ fn parallel_iterator() {
     });
 }
 
+#[cfg(feature = "parallel")]
+#[cfg_attr(miri, ignore)]
+#[test]
+fn parallel_fixed_chunks() {
+    use rayon::prelude::*;
+
+    let mut world = World::new();
+
+    world.bulk_add_entity((0..1000).map(|i| (USIZE(i), U32(i as u32))));
+    world.bulk_add_entity((0..10).map(|i| (USIZE(i),)));
+
+    world.run(|(mut usizes, u32s): (ViewMut<USIZE>, View<U32>)| {
+        let items = (&usizes, &u32s)
+            .par_iter()
+            .fixed_chunks(7)
+            .fold(Vec::new, |mut items, (x, y)| {
+                items.push((x.0, y.0));
+                items
+            })
+            .reduce(Vec::new, |mut left, mut right| {
+                left.append(&mut right);
+                left
+            });
+        assert_eq!(
+            items,
+            (&usizes, &u32s)
+                .iter()
+                .map(|(x, y)| (x.0, y.0))
+                .collect::<Vec<_>>()
+        );
+
+        (&mut usizes).par_iter().fixed_chunks(16).for_each(|x| {
+            x.0 += 1;
+        });
+        assert_eq!(
+            usizes.iter().map(|x| x.0).sum::<usize>(),
+            499_500 + 45 + 1010
+        );
+    });
+}
+
+#[cfg(feature = "parallel")]
+#[cfg_attr(miri, ignore)]
+#[test]
+fn parallel_deterministic_world() {
+    use rayon::prelude::*;
+
+    #[derive(Component)]
+    struct Mass(f32);
+
+    let mut world = World::new();
+    world.set_deterministic(true);
+
+    world.bulk_add_entity((0..10_000).map(|i| (Mass(i as f32 / 7.0),)));
+
+    world.run(|masses: View<Mass>| {
+        let fixed = masses
+            .par_iter()
+            .fixed_chunks(World::DEFAULT_DETERMINISTIC_CHUNK_SIZE)
+            .map(|mass| mass.0)
+            .sum::<f32>();
+
+        for _ in 0..20 {
+            let sum = masses.par_iter().map(|mass| mass.0).sum::<f32>();
+
+            assert_eq!(sum.to_bits(), fixed.to_bits());
+        }
+    });
+
+    world.set_deterministic_chunk_size(100);
+
+    let fixed = world.run(|masses: View<Mass>| {
+        masses
+            .par_iter()
+            .fixed_chunks(100)
+            .map(|mass| mass.0)
+            .sum::<f32>()
+    });
+
+    for _ in 0..20 {
+        let sum = world
+            .all_storages()
+            .unwrap()
+            .run(|masses: View<Mass>| masses.par_iter().map(|mass| mass.0).sum::<f32>());
+
+        assert_eq!(sum.to_bits(), fixed.to_bits());
+    }
+}
+
 #[cfg(feature = "parallel")]
 #[cfg_attr(miri, ignore)]
 #[test]
@@ -0,0 +1,249 @@
+use shipyard::{sparse_set::SparseSet, Component, EntityId, EntityMap, MapEntities, World};
+
+#[derive(Component, MapEntities, Clone, Debug, PartialEq)]
+struct Target {
+    entity: EntityId,
+    #[shipyard(map_entities_skip)]
+    fallback: EntityId,
+}
+
+#[derive(Component, MapEntities, Clone, Debug, PartialEq)]
+struct Children(Vec<EntityId>, Option<EntityId>);
+
+#[derive(MapEntities, Debug, PartialEq)]
+enum Order {
+    Idle,
+    Follow(EntityId),
+    Attack {
+        target: EntityId,
+        #[shipyard(map_entities_skip)]
+        previous: EntityId,
+    },
+}
+
+#[test]
+fn derive() {
+    let from0 = EntityId::new_from_index_and_gen(0, 0);
+    let from1 = EntityId::new_from_index_and_gen(1, 0);
+    let to0 = EntityId::new_from_index_and_gen(5, 1);
+    let to1 = EntityId::new_from_index_and_gen(6, 1);
+
+    let mut entity_map = EntityMap::new();
+    entity_map.insert(from0, to0);
+    entity_map.insert(from1, to1);
+
+    let mut target = Target {
+        entity: from0,
+        fallback: from1,
+    };
+    target.map_entities(&entity_map);
+    assert_eq!(
+        target,
+        Target {
+            entity: to0,
+            fallback: from1
+        }
+    );
+
+    let unmapped = EntityId::new_from_index_and_gen(9, 0);
+    let mut children = Children(vec![from1, unmapped], Some(from0));
+    children.map_entities(&entity_map);
+    assert_eq!(children, Children(vec![to1, unmapped], Some(to0)));
+
+    let mut orders = [
+        Order::Idle,
+        Order::Follow(from1),
+        Order::Attack {
+            target: from0,
+            previous: from1,
+        },
+    ];
+    orders.map_entities(&entity_map);
+    assert_eq!(
+        orders,
+        [
+            Order::Idle,
+            Order::Follow(to1),
+            Order::Attack {
+                target: to0,
+                previous: from1
+            }
+        ]
+    );
+}
+
+#[test]
+fn move_entities() {
+    let mut world1 = World::new();
+    let mut world2 = World::new();
+
+    let existing = world2.add_entity(Children(vec![], None));
+    let parent = world1.add_entity(());
+    let child0 = world1.add_entity(Target {
+        entity: parent,
+        fallback: parent,
+    });
+    let child1 = world1.add_entity(());
+    world1.add_component(parent, Children(vec![child0, child1], None));
+
+    world2.register_map_entities::<(SparseSet<Target>, SparseSet<Children>)>();
+
+    let entity_map = world1.move_entities(&mut world2, &[parent, child0, child1]);
+
+    assert_eq!(entity_map.len(), 3);
+    assert!(!world1.is_entity_alive(parent));
+
+    let new_parent = entity_map.map(parent);
+    let new_child0 = entity_map.map(child0);
+    let new_child1 = entity_map.map(child1);
+    assert_ne!(new_parent, existing);
+    assert!(world2.is_entity_alive(new_child1));
+
+    assert_eq!(
+        **world2.get::<&Children>(new_parent).unwrap(),
+        Children(vec![new_child0, new_child1], None)
+    );
+    assert_eq!(
+        **world2.get::<&Target>(new_child0).unwrap(),
+        Target {
+            entity: new_parent,
+            fallback: parent
+        }
+    );
+    assert_eq!(
+        **world2.get::<&Children>(existing).unwrap(),
+        Children(vec![], None)
+    );
+}
+
+#[test]
+fn clone_entities_to() {
+    let mut world1 = World::new();
+    let mut world2 = World::new();
+
+    let entity0 = world1.add_entity(());
+    let entity1 = world1.add_entity(Target {
+        entity: entity0,
+        fallback: entity0,
+    });
+
+    // Registered on the source, the destination storage inherits it
+    world1.register_clone::<SparseSet<Target>>();
+    world1.register_map_entities::<SparseSet<Target>>();
+
+    world2.add_entity(());
+    let entity_map = world1.clone_entities_to(&mut world2, &[entity0, entity1]);
+
+    assert!(world1.is_entity_alive(entity1));
+    assert_eq!(world1.get::<&Target>(entity1).unwrap().entity, entity0);
+    assert_eq!(
+        world2
+            .get::<&Target>(entity_map.map(entity1))
+            .unwrap()
+            .entity,
+        entity_map.map(entity0)
+    );
+}
+
+#[test]
+fn move_all_entities() {
+    let mut scene = World::new();
+    let mut world = World::new();
+
+    world.add_entity(());
+    world.register_map_entities::<SparseSet<Target>>();
+
+    let entity0 = scene.add_entity(());
+    let entity1 = scene.add_entity(Target {
+        entity: entity0,
+        fallback: entity0,
+    });
+
+    let entity_map = scene.move_all_entities(&mut world);
+
+    assert_eq!(entity_map.len(), 2);
+    assert!(!scene.is_entity_alive(entity0));
+    assert_eq!(
+        world
+            .get::<&Target>(entity_map.map(entity1))
+            .unwrap()
+            .entity,
+        entity_map.map(entity0)
+    );
+}
+
+#[test]
+fn move_entity_mapped() {
+    let mut world1 = World::new();
+    let mut world2 = World::new();
+
+    world2.add_entity(());
+    world2.register_map_entities::<SparseSet<Target>>();
+
+    let entity = world1.add_entity(());
+    world1.add_component(
+        entity,
+        Target {
+            entity,
+            fallback: entity,
+        },
+    );
+
+    let new_entity = world1.move_entity_mapped(&mut world2, entity);
+
+    assert_ne!(new_entity, entity);
+    assert_eq!(
+        world2.get::<&Target>(new_entity).unwrap().entity,
+        new_entity
+    );
+}
+
+#[test]
+fn clone_entity_to_mapped() {
+    let mut world1 = World::new();
+    let mut world2 = World::new();
+
+    world1.register_clone::<SparseSet<Target>>();
+    world1.register_map_entities::<SparseSet<Target>>();
+
+    let entity = world1.add_entity(());
+    world1.add_component(
+        entity,
+        Target {
+            entity,
+            fallback: entity,
+        },
+    );
+
+    world2.add_entity(());
+    let clone = world1.clone_entity_to_mapped(&mut world2, entity);
+
+    assert_ne!(clone, entity);
+    assert_eq!(world1.get::<&Target>(entity).unwrap().entity, entity);
+    assert_eq!(world2.get::<&Target>(clone).unwrap().entity, clone);
+}
+
+#[test]
+fn move_entities_dead() {
+    let mut world1 = World::new();
+    let mut world2 = World::new();
+
+    let entity0 = world1.add_entity(());
+    let entity1 = world1.add_entity(());
+    world1.delete_entity(entity1);
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        world1.move_entities(&mut world2, &[entity0, entity1]);
+    }));
+
+    assert!(result.is_err());
+    assert!(world1.is_entity_alive(entity0));
+    assert_eq!(
+        world2
+            .borrow::<shipyard::EntitiesView>()
+            .unwrap()
+            .iter()
+            .count(),
+        0
+    );
+}
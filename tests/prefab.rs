@@ -0,0 +1,126 @@
+use shipyard::{Component, Get, HierarchyView, IntoIter, View, World};
+
+#[derive(Component, Clone, Debug, PartialEq, Eq)]
+#[track(Insertion, Modification)]
+struct Health(u32);
+
+#[derive(Component, Clone, Debug, PartialEq, Eq)]
+struct Name(&'static str);
+
+#[derive(Component, Clone, Debug, PartialEq, Eq)]
+struct Weapon;
+
+#[test]
+fn instantiate() {
+    let mut world = World::new();
+
+    let template = world.add_prefab("goblin", (Name("Goblin"), Health(10)));
+    assert_eq!(world.prefab("goblin"), Some(template));
+    assert!(world.is_entity_disabled(template));
+
+    world.add_entity((Name("Alice"),));
+    let goblins = world.instantiate("goblin", 3);
+    assert_eq!(goblins.len(), 3);
+
+    let (names, healths) = world.borrow::<(View<Name>, View<Health>)>().unwrap();
+
+    assert_eq!(names.iter().count(), 4);
+    assert_eq!(healths.inserted().iter().count(), 3);
+    for goblin in goblins {
+        assert_eq!(
+            (&names, &healths).get(goblin),
+            Ok((&Name("Goblin"), &Health(10)))
+        );
+    }
+    assert!(names.get(template).is_err());
+}
+
+#[test]
+fn overrides_and_children() {
+    let mut world = World::new();
+
+    world.add_prefab("goblin", (Name("Goblin"), Health(10)));
+    world.add_prefab("spear", (Weapon, Name("Spear")));
+    world.add_prefab("tip", Name("Tip"));
+    world.add_prefab_child("spear", "tip");
+    world.add_prefab_child("goblin", "spear");
+
+    let goblins = world.instantiate_with("goblin", [Health(1), Health(2)]);
+
+    let (names, healths, weapons, hierarchy) = world
+        .borrow::<(View<Name>, View<Health>, View<Weapon>, HierarchyView)>()
+        .unwrap();
+
+    assert_eq!(healths[goblins[0]], Health(1));
+    assert_eq!(healths[goblins[1]], Health(2));
+    assert_eq!(healths.inserted().iter().count(), 2);
+    assert_eq!(healths.modified().iter().count(), 0);
+    assert_eq!(weapons.iter().count(), 2);
+
+    for goblin in goblins {
+        let spear = hierarchy.children(goblin).next().unwrap();
+        assert_eq!(names[spear], Name("Spear"));
+        assert!(weapons.get(spear).is_ok());
+
+        let tip = hierarchy.children(spear).next().unwrap();
+        assert_eq!(names[tip], Name("Tip"));
+        assert_eq!(
+            hierarchy.ancestors(tip).collect::<Vec<_>>(),
+            [spear, goblin]
+        );
+    }
+}
+
+#[test]
+fn replace_and_delete() {
+    let mut world = World::new();
+
+    let old_template = world.add_prefab("goblin", (Name("Goblin"),));
+    let template = world.add_prefab("goblin", (Name("Hobgoblin"),));
+
+    assert!(!world.is_entity_alive(old_template));
+    assert_eq!(world.prefab("goblin"), Some(template));
+
+    let goblin = world.instantiate("goblin", 1)[0];
+    assert_eq!(
+        world.get::<&Name>(goblin).as_deref(),
+        Ok(&&Name("Hobgoblin"))
+    );
+
+    world.delete_entity(template);
+    assert_eq!(world.prefab("goblin"), None);
+}
+
+#[test]
+fn delete_child() {
+    let mut world = World::new();
+
+    world.add_prefab("goblin", (Name("Goblin"),));
+    let spear = world.add_prefab("spear", (Name("Spear"),));
+    world.add_prefab_child("goblin", "spear");
+
+    world.delete_entity(spear);
+
+    let goblin = world.instantiate("goblin", 1)[0];
+    let hierarchy = world.borrow::<HierarchyView>().unwrap();
+    assert_eq!(hierarchy.children(goblin).count(), 0);
+}
+
+#[test]
+#[should_panic(expected = "it would create a cycle")]
+fn cycle() {
+    let mut world = World::new();
+
+    world.add_prefab("a", ());
+    world.add_prefab("b", ());
+    world.add_prefab_child("a", "b");
+    world.add_prefab_child("b", "a");
+}
+
+#[test]
+#[should_panic(expected = "does not exist")]
+fn missing_prefab() {
+    let mut world = World::new();
+
+    world.instantiate("goblin", 1);
+}
@@ -0,0 +1,115 @@
+use shipyard::advanced::StorageId;
+use shipyard::error;
+use shipyard::sparse_set::SparseSet;
+use shipyard::{AllStoragesViewMut, Component, Unique, UniqueView, View, World};
+
+#[derive(Component, Clone, Debug, Default, PartialEq, Eq)]
+struct Health(u32);
+
+#[derive(Component, Debug)]
+struct Name(&'static str);
+
+#[derive(Unique, Debug, Default, PartialEq, Eq)]
+struct Score(u32);
+
+#[test]
+fn lookup() {
+    let mut world = World::new();
+
+    world.register_component::<Health>("Health");
+    world.register_unique::<Score>("Score");
+
+    let all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    let registry = all_storages.registry();
+
+    assert_eq!(registry.len(), 2);
+
+    let health = registry.get_by_name("Health").unwrap();
+    assert_eq!(health.storage_id(), StorageId::of::<SparseSet<Health>>());
+    assert!(!health.is_unique());
+    assert!(std::ptr::eq(
+        health,
+        registry.get(StorageId::of::<SparseSet<Health>>()).unwrap()
+    ));
+
+    let score = registry.get_by_name("Score").unwrap();
+    assert!(score.is_unique());
+    assert!(registry.get_by_name("Name").is_none());
+}
+
+#[test]
+fn hooks() {
+    let mut world = World::new();
+
+    world
+        .register_component::<Health>("Health")
+        .clone()
+        .debug()
+        .default();
+    world.register_component::<Name>("Name").debug();
+    world.register_unique::<Score>("Score").debug().default();
+
+    let entity0 = world.add_entity(Name("Alice"));
+    let entity1 = world.add_entity(());
+
+    {
+        let all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+        let registry = all_storages.registry();
+
+        let health = registry.get_by_name("Health").unwrap();
+        health.add_default(&all_storages, entity0).unwrap();
+        health
+            .clone_component(&all_storages, entity0, entity1)
+            .unwrap();
+        assert_eq!(health.debug(&all_storages, entity1).unwrap(), "Health(0)");
+
+        let name = registry.get_by_name("Name").unwrap();
+        assert_eq!(
+            name.debug(&all_storages, entity0).unwrap(),
+            "Name(\"Alice\")"
+        );
+        assert!(matches!(
+            name.debug(&all_storages, entity1),
+            Err(error::Registry::MissingComponent(_))
+        ));
+        assert!(matches!(
+            name.add_default(&all_storages, entity1),
+            Err(error::Registry::MissingHook {
+                hook: "default",
+                ..
+            })
+        ));
+
+        let score = registry.get_by_name("Score").unwrap();
+        score.insert_storage(&all_storages).unwrap();
+        assert_eq!(score.debug(&all_storages, entity0).unwrap(), "Score(0)");
+    }
+
+    assert_eq!(world.borrow::<View<Health>>().unwrap().len(), 2);
+    assert_eq!(*world.borrow::<UniqueView<Score>>().unwrap(), Score(0));
+    assert_eq!(world.get::<&Name>(entity0).unwrap().0, "Alice");
+}
+
+#[test]
+fn rename() {
+    let mut world = World::new();
+
+    world.register_component::<Health>("Health");
+    world.register_component::<Health>("Hp");
+
+    let all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    let registry = all_storages.registry();
+
+    assert_eq!(registry.len(), 1);
+    assert!(registry.get_by_name("Health").is_none());
+    assert_eq!(registry.get_by_name("Hp").unwrap().name(), "Hp");
+}
+
+#[test]
+#[should_panic(expected = "the name is already used by another type")]
+fn name_collision() {
+    let mut world = World::new();
+
+    world.register_component::<Health>("Health");
+    world.register_component::<Name>("Health");
+}
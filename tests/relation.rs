@@ -0,0 +1,125 @@
+use shipyard::relation::{Relation, RelationStorage};
+use shipyard::*;
+
+#[derive(PartialEq, Eq, Debug)]
+struct Targets(u32);
+impl Relation for Targets {}
+
+#[derive(PartialEq, Eq, Debug)]
+struct ChildOf;
+impl Relation for ChildOf {}
+
+fn sorted(mut entities: Vec<EntityId>) -> Vec<EntityId> {
+    entities.sort_unstable();
+    entities
+}
+
+#[test]
+fn insert_remove() {
+    let mut relations = RelationStorage::<Targets>::new();
+
+    let [a, b, c] = [0, 1, 2].map(|index| EntityId::new_from_index_and_gen(index, 0));
+
+    assert_eq!(relations.insert(a, b, Targets(0)), None);
+    assert_eq!(relations.insert(a, c, Targets(1)), None);
+    assert_eq!(relations.insert(c, b, Targets(2)), None);
+    assert_eq!(relations.insert(a, b, Targets(3)), Some(Targets(0)));
+
+    assert_eq!(relations.len(), 3);
+    assert_eq!(relations.get(a, b), Some(&Targets(3)));
+    assert_eq!(relations.get(b, a), None);
+    assert_eq!(
+        sorted(relations.targets(a).map(|(target, _)| target).collect()),
+        [b, c]
+    );
+    assert_eq!(sorted(relations.sources(b).collect()), [a, c]);
+
+    relations.get_mut(c, b).unwrap().0 += 10;
+    assert_eq!(relations.get(c, b), Some(&Targets(12)));
+
+    assert_eq!(relations.remove(a, b), Some(Targets(3)));
+    assert_eq!(relations.remove(a, b), None);
+    assert_eq!(relations.sources(b).collect::<Vec<_>>(), [c]);
+    assert_eq!(relations.len(), 2);
+
+    relations.remove_target(b);
+    assert_eq!(relations.len(), 1);
+    assert!(relations.contains(a, c));
+
+    relations.remove_source(a);
+    assert!(relations.is_empty());
+    assert_eq!(relations.iter().count(), 0);
+}
+
+#[test]
+fn delete_entity() {
+    let mut world = World::new();
+
+    let a = world.add_entity(());
+    let b = world.add_entity(());
+    let c = world.add_entity(());
+
+    world.run(
+        |mut targets: RelationViewMut<Targets>, mut child_of: RelationViewMut<ChildOf>| {
+            targets.insert(a, b, Targets(0));
+            targets.insert(a, c, Targets(1));
+            targets.insert(b, a, Targets(2));
+            child_of.insert(c, b, ChildOf);
+        },
+    );
+
+    world.delete_entity(b);
+
+    world.run(
+        |targets: RelationView<Targets>, child_of: RelationView<ChildOf>| {
+            assert_eq!(targets.iter().collect::<Vec<_>>(), [(a, c, &Targets(1))]);
+            assert_eq!(targets.sources(c).collect::<Vec<_>>(), [a]);
+            assert!(child_of.is_empty());
+        },
+    );
+
+    world.strip(a);
+
+    assert!(world.borrow::<RelationView<Targets>>().unwrap().is_empty());
+}
+
+#[test]
+fn delete_unchecked() {
+    let mut world = World::new();
+
+    let a = world.add_entity(());
+    let b = world.add_entity(());
+    let c = world.add_entity(());
+
+    world.run(|mut targets: RelationViewMut<Targets>| {
+        targets.insert(a, b, Targets(0));
+        targets.insert(a, c, Targets(1));
+        targets.insert(b, c, Targets(2));
+    });
+
+    world.run(|mut entities: EntitiesViewMut| {
+        entities.delete_unchecked(b);
+    });
+
+    // Shared views skip the pairs until the storage is borrowed exclusively
+    world.run(|targets: RelationView<Targets>| {
+        assert_eq!(targets.len(), 1);
+        assert_eq!(targets.targets(a).collect::<Vec<_>>(), [(c, &Targets(1))]);
+        assert_eq!(targets.sources(c).collect::<Vec<_>>(), [a]);
+        assert!(!targets.contains(a, b));
+        assert!(targets.get(b, c).is_none());
+    });
+
+    world.run(|targets: RelationViewMut<Targets>| {
+        assert_eq!(targets.len(), 1);
+        assert_eq!(targets.sources(b).count(), 0);
+    });
+
+    // The reused index doesn't inherit any pair
+    let d = world.add_entity(());
+    assert_eq!(d.index(), b.index());
+    world.run(|targets: RelationView<Targets>| {
+        assert_eq!(targets.sources(d).count(), 0);
+        assert_eq!(targets.targets(d).count(), 0);
+    });
+}
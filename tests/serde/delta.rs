@@ -0,0 +1,114 @@
+use shipyard::views::serde::ser::delta::ViewDeltaSerializer;
+use shipyard::{track, Component, Get, Remove, View, ViewMut, World};
+
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+struct Health(u32);
+impl Component for Health {
+    type Tracking = track::All;
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+struct Name(String);
+impl Component for Name {
+    type Tracking = track::All;
+}
+
+fn serialize_delta(
+    world: &World,
+    since: shipyard::advanced::tracking::TrackingTimestamp,
+) -> (String, shipyard::advanced::tracking::TrackingTimestamp) {
+    let until = world.get_tracking_timestamp();
+
+    let mut serialized = Vec::new();
+    world
+        .serialize_delta::<_, (View<Health>, View<Name>)>(
+            &mut serde_json::ser::Serializer::new(&mut serialized),
+            since,
+            until,
+        )
+        .unwrap_or_else(|_| panic!());
+
+    (String::from_utf8(serialized).unwrap(), until)
+}
+
+fn apply_delta(world: &World, delta: &str) {
+    world
+        .deserialize_delta::<_, (ViewMut<Health>, ViewMut<Name>)>(
+            &mut serde_json::de::Deserializer::from_str(delta),
+        )
+        .unwrap_or_else(|_| panic!());
+}
+
+#[test]
+fn view_delta() {
+    let mut world = World::new();
+
+    let entity0 = world.add_entity(Health(0));
+    let entity1 = world.add_entity(Health(1));
+
+    let since = world.get_tracking_timestamp();
+
+    world.run(|mut healths: ViewMut<Health>| {
+        (&mut healths).get(entity1).unwrap().0 += 1;
+        healths.remove(entity0);
+    });
+
+    let until = world.get_tracking_timestamp();
+
+    let healths = world.borrow::<View<Health>>().unwrap();
+    let serialized =
+        serde_json::to_string(&ViewDeltaSerializer::new(&healths, since, until)).unwrap();
+
+    assert_eq!(
+        serialized,
+        r#"{"removed":[{"index":0,"gen":0}],"changed":[[{"index":1,"gen":0},2]]}"#
+    );
+}
+
+#[test]
+fn replicate() {
+    let mut server = World::new();
+    let mut client = World::new();
+
+    let since = server.get_tracking_timestamp();
+
+    let alice = server.add_entity((Health(10), Name("Alice".to_string())));
+    let bob = server.add_entity((Health(20), Name("Bob".to_string())));
+    let carol = server.add_entity((Health(30),));
+
+    let (delta, since) = serialize_delta(&server, since);
+    apply_delta(&client, &delta);
+
+    assert!(client.is_entity_alive(alice));
+    assert!(client.is_entity_alive(bob));
+    assert!(client.is_entity_alive(carol));
+    assert_eq!(client.borrow::<View<Health>>().unwrap().len(), 3);
+    assert_eq!(client.borrow::<View<Name>>().unwrap().len(), 2);
+
+    // Only what changed is part of the second delta
+    server.delete_entity(alice);
+    server.run(|mut healths: ViewMut<Health>, mut names: ViewMut<Name>| {
+        (&mut healths).get(bob).unwrap().0 = 15;
+        names.remove(bob);
+    });
+    let dave = server.add_entity((Name("Dave".to_string()),));
+
+    let (delta, _) = serialize_delta(&server, since);
+
+    assert_eq!(
+        delta,
+        r#"{"deleted_entities":[{"index":0,"gen":0}],"views":[{"removed":[{"index":0,"gen":0}],"changed":[[{"index":1,"gen":0},15]]},{"removed":[{"index":0,"gen":0},{"index":1,"gen":0}],"changed":[[{"index":0,"gen":1},"Dave"]]}]}"#
+    );
+
+    apply_delta(&client, &delta);
+
+    assert!(!client.is_entity_alive(alice));
+    assert!(client.is_entity_alive(dave));
+
+    let (healths, names) = client.borrow::<(View<Health>, View<Name>)>().unwrap();
+    assert_eq!(healths.len(), 2);
+    assert_eq!(healths[bob], Health(15));
+    assert_eq!(healths[carol], Health(30));
+    assert_eq!(names.len(), 1);
+    assert_eq!(names[dave], Name("Dave".to_string()));
+}
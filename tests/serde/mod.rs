@@ -1,6 +1,8 @@
+mod delta;
 mod entities;
 mod entities_mut;
 mod entity_id;
+mod registry;
 mod unique_or_default_views;
 mod unique_or_init_views;
 mod unique_views;
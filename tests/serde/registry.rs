@@ -0,0 +1,73 @@
+use shipyard::{Component, Get, Unique, UniqueView, View, World};
+
+#[derive(Component, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+struct Name(String);
+
+#[derive(Component, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+struct Health(u32);
+
+#[derive(Unique, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+struct Score(u32);
+
+fn register(world: &mut World) {
+    world.register_component::<Name>("Name").serde();
+    world.register_component::<Health>("Health").serde();
+    world.register_unique::<Score>("Score").serde();
+}
+
+#[test]
+fn round_trip() {
+    let mut world = World::new();
+    register(&mut world);
+
+    let alice = world.add_entity((Name("Alice".to_string()), Health(10)));
+    let bob = world.add_entity((Health(20),));
+    world.add_unique(Score(3));
+
+    let serialized = {
+        let mut serialized = Vec::new();
+        world
+            .serialize_registered(&mut serde_json::ser::Serializer::new(&mut serialized))
+            .unwrap();
+        String::from_utf8(serialized).unwrap()
+    };
+
+    assert_eq!(
+        serialized,
+        r#"{"Entities":[{"index":0,"gen":0},{"index":1,"gen":0}],"Health":[[{"index":0,"gen":0},10],[{"index":1,"gen":0},20]],"Name":[[{"index":0,"gen":0},"Alice"]],"Score":3}"#
+    );
+
+    let mut other = World::new();
+    register(&mut other);
+
+    other
+        .deserialize_registered(&mut serde_json::de::Deserializer::from_str(&serialized))
+        .unwrap();
+
+    assert!(other.is_entity_alive(alice));
+    assert!(other.is_entity_alive(bob));
+    assert_eq!(*other.borrow::<UniqueView<Score>>().unwrap(), Score(3));
+
+    let (names, healths) = other.borrow::<(View<Name>, View<Health>)>().unwrap();
+    assert_eq!(names.get(alice).unwrap(), &Name("Alice".to_string()));
+    assert_eq!(healths.get(bob).unwrap(), &Health(20));
+}
+
+#[test]
+fn unknown_name() {
+    let world = World::new();
+
+    assert!(world
+        .deserialize_registered(&mut serde_json::de::Deserializer::from_str(
+            r#"{"Name":[]}"#
+        ))
+        .is_err());
+}
+
+#[test]
+#[should_panic(expected = "the name is reserved for entities")]
+fn reserved_name() {
+    let mut world = World::new();
+
+    world.register_component::<Name>("Entities");
+}
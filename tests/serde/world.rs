@@ -128,3 +128,44 @@ fn world_deserialize() {
         assert_eq!(v_location[alice], location_alice);
     });
 }
+
+#[test]
+fn world_deserialize_scene() {
+    let mut world = World::new();
+    let existing = world.add_entity(Player {
+        name: "Carol".to_string(),
+        score: 0,
+    });
+
+    let serialized_players_locations = "{\
+        \"vm_player\":[\
+            [\
+                {\"index\":0,\"gen\":0},\
+                {\"name\":\"Alice\",\"score\":100}\
+            ]\
+        ],\
+        \"vm_location\":[\
+            [\
+                {\"index\":0,\"gen\":0},\
+                {\"x\":10,\"y\":20}\
+            ]\
+        ]\
+    }";
+
+    let entity_map = world
+        .deserialize_scene::<_, PlayerLocationView>(
+            &World::new(),
+            &mut serde_json::de::Deserializer::from_str(serialized_players_locations),
+        )
+        .unwrap_or_else(|_| panic!());
+
+    let alice = entity_map.map(EntityId::new_from_index_and_gen(0, 0));
+    assert_ne!(alice, existing);
+    assert!(world.is_entity_alive(alice));
+
+    world.run(|v_player: View<Player>, v_location: View<Location>| {
+        assert_eq!(v_player[existing].name, "Carol");
+        assert_eq!(v_player[alice].name, "Alice");
+        assert_eq!(v_location[alice], Location { x: 10, y: 20 });
+    });
+}
@@ -0,0 +1,167 @@
+use shipyard::{
+    sparse_set::SparseSet, track, Component, EntityId, IntoIter, Unique, UniqueStorage, UniqueView,
+    UniqueViewMut, View, ViewMut, World,
+};
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+struct U32(u32);
+impl Component for U32 {
+    type Tracking = track::Modification;
+}
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+struct Tick(u32);
+impl Unique for Tick {}
+
+fn simulate(world: &mut World, dead: EntityId) -> Vec<EntityId> {
+    world.delete_entity(dead);
+
+    world.run(|mut u32s: ViewMut<U32>, mut tick: UniqueViewMut<Tick>| {
+        for mut x in (&mut u32s).iter() {
+            x.0 += 1;
+        }
+        tick.0 += 1;
+    });
+
+    vec![world.add_entity(U32(10)), world.add_entity(U32(20))]
+}
+
+#[test]
+fn rollback() {
+    let mut world = World::new();
+
+    let entity0 = world.add_entity(U32(0));
+    let entity1 = world.add_entity(U32(1));
+    world.delete_entity(entity0);
+
+    world.add_unique(Tick(0));
+    world.register_clone::<(SparseSet<U32>, UniqueStorage<Tick>)>();
+
+    let snapshot = world.snapshot();
+
+    let spawned = simulate(&mut world, entity1);
+    assert_eq!(world.borrow::<UniqueView<Tick>>().unwrap().0, 1);
+
+    world.restore(&snapshot);
+
+    assert!(world.is_entity_alive(entity1));
+    assert!(spawned.iter().all(|entity| !world.is_entity_alive(*entity)));
+    assert_eq!(world.borrow::<UniqueView<Tick>>().unwrap().0, 0);
+    world.run(|u32s: View<U32>| {
+        assert_eq!(u32s.len(), 1);
+        assert_eq!(u32s[entity1], U32(1));
+    });
+
+    // Re-simulating produces the same entities
+    assert_eq!(simulate(&mut world, entity1), spawned);
+
+    // The same snapshot can be restored again
+    world.restore(&snapshot);
+    assert_eq!(world.borrow::<View<U32>>().unwrap()[entity1], U32(1));
+}
+
+#[test]
+fn tracking() {
+    let mut world = World::new();
+
+    let entity = world.add_entity(U32(0));
+    world.register_clone::<SparseSet<U32>>();
+
+    world.run(|mut u32s: ViewMut<U32>| u32s[entity].0 += 1);
+
+    let snapshot = world.snapshot();
+
+    world.run(|u32s: ViewMut<U32>| u32s.clear_all_modified());
+
+    world.restore(&snapshot);
+
+    world.run(|u32s: View<U32>| {
+        assert!(u32s.is_modified(entity));
+    });
+}
+
+#[test]
+fn unregistered_storage() {
+    #[derive(Debug, Clone, Copy)]
+    struct NotCloned;
+    impl Component for NotCloned {
+        type Tracking = track::Untracked;
+    }
+
+    let mut world = World::new();
+
+    world.add_entity(NotCloned);
+
+    let snapshot = world.snapshot();
+    assert_eq!(snapshot.len(), 1);
+
+    world.add_entity(NotCloned);
+    world.restore(&snapshot);
+
+    // Only the entity alive in the snapshot keeps its component
+    assert_eq!(world.borrow::<View<NotCloned>>().unwrap().len(), 1);
+}
+
+#[test]
+fn storage_created_after_snapshot() {
+    #[derive(Debug, Clone, Copy)]
+    struct Poison;
+    impl Component for Poison {
+        type Tracking = track::Untracked;
+    }
+
+    let mut world = World::new();
+
+    world.register_clone::<SparseSet<U32>>();
+
+    let snapshot = world.snapshot();
+
+    world.add_entity((U32(0), Poison));
+    world.restore(&snapshot);
+
+    let entity = world.add_entity(U32(1));
+
+    assert!(world.borrow::<View<Poison>>().unwrap().is_empty());
+    assert!(!world.borrow::<View<Poison>>().unwrap().contains(entity));
+}
+
+#[test]
+fn rollback_is_tracked() {
+    #[derive(PartialEq, Eq, Debug, Clone, Copy)]
+    struct Health(u32);
+    impl Component for Health {
+        type Tracking = track::All;
+    }
+
+    let mut world = World::new();
+
+    world.add_unique(Tick(0));
+    world.register_clone::<(SparseSet<Health>, UniqueStorage<Tick>)>();
+
+    let kept = world.add_entity(Health(0));
+    let revived = world.add_entity(Health(1));
+
+    world.run(|healths: ViewMut<Health>, tick: UniqueViewMut<Tick>| {
+        healths.clear_all_inserted_and_modified();
+        tick.clear_inserted_and_modified();
+    });
+
+    let snapshot = world.snapshot();
+
+    world.delete_entity(revived);
+    let spawned = world.add_entity(Health(2));
+
+    world.restore(&snapshot);
+
+    world.run(|healths: View<Health>, tick: UniqueView<Tick>| {
+        assert_eq!(healths.inserted().iter().collect::<Vec<_>>(), [&Health(1)]);
+        assert!(healths.is_inserted(revived));
+        assert_eq!(healths.modified().iter().collect::<Vec<_>>(), [&Health(0)]);
+        assert!(healths.is_modified(kept));
+        assert_eq!(
+            healths.deleted().collect::<Vec<_>>(),
+            [(spawned, &Health(2))]
+        );
+        assert!(tick.is_modified());
+    });
+}
@@ -0,0 +1,127 @@
+use shipyard::{track, Component, IntoIter, View, ViewMut, World};
+
+#[derive(Component, Debug, PartialEq, Eq, Clone, Copy)]
+struct Depth(u32, u32);
+
+#[derive(Component, Debug, PartialEq, Eq, Clone, Copy)]
+#[track(Modification)]
+struct Sprite(u32);
+
+#[test]
+fn stable_multi_key() {
+    let mut world = World::new();
+
+    world.add_entity((Depth(1, 0),));
+    world.add_entity((Depth(0, 1),));
+    world.add_entity((Depth(1, 1),));
+    world.add_entity((Depth(0, 0),));
+    world.add_entity((Depth(0, 1),));
+
+    world.run(|mut depths: ViewMut<Depth>| {
+        let order = |depths: &ViewMut<Depth>| {
+            (0..depths.len())
+                .map(|index| {
+                    let entity = depths.id_at(index).unwrap();
+                    (depths[entity], entity)
+                })
+                .collect::<Vec<_>>()
+        };
+
+        depths.sort_by_key(|depth| depth.1);
+        depths.sort_by_key(|depth| depth.0);
+
+        let sorted = order(&depths);
+        assert_eq!(
+            sorted.iter().map(|(depth, _)| *depth).collect::<Vec<_>>(),
+            [
+                Depth(0, 0),
+                Depth(0, 1),
+                Depth(0, 1),
+                Depth(1, 0),
+                Depth(1, 1)
+            ]
+        );
+        // equal elements kept their insertion order
+        assert!(sorted[1].1 < sorted[2].1);
+
+        depths.sort_by_key(|depth| core::cmp::Reverse(*depth));
+        depths.sort_by(|a, b| a.cmp_key().cmp(&b.cmp_key()));
+        assert_eq!(order(&depths), sorted);
+    });
+}
+
+impl Depth {
+    fn cmp_key(&self) -> (u32, u32) {
+        (self.0, self.1)
+    }
+}
+
+impl PartialOrd for Depth {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Depth {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.cmp_key().cmp(&other.cmp_key())
+    }
+}
+
+#[test]
+fn respect() {
+    let mut world = World::new();
+
+    let entity0 = world.add_entity((Sprite(0),));
+    let entity1 = world.add_entity((Depth(3, 0), Sprite(1)));
+    let entity2 = world.add_entity((Depth(1, 0),));
+    let entity3 = world.add_entity((Depth(2, 0), Sprite(3)));
+    let entity4 = world.add_entity((Sprite(4),));
+    let entity5 = world.add_entity((Depth(0, 0), Sprite(5)));
+
+    world.run(|mut sprites: ViewMut<Sprite, track::Modification>| {
+        sprites[entity4].0 = 40;
+    });
+
+    world.run(
+        |mut depths: ViewMut<Depth>, mut sprites: ViewMut<Sprite, track::Modification>| {
+            depths.sort_by_key(|depth| depth.0);
+            sprites.respect(&depths);
+
+            assert_eq!(
+                (0..sprites.len())
+                    .map(|index| sprites.id_at(index).unwrap())
+                    .collect::<Vec<_>>(),
+                [entity5, entity3, entity1, entity0, entity4]
+            );
+
+            assert_eq!(
+                (&depths, &sprites)
+                    .iter()
+                    .with_id()
+                    .map(|(id, _)| id)
+                    .collect::<Vec<_>>(),
+                [entity5, entity3, entity1]
+            );
+            assert_eq!(
+                (0..depths.len())
+                    .map(|index| depths.id_at(index).unwrap())
+                    .collect::<Vec<_>>(),
+                [entity5, entity2, entity3, entity1]
+            );
+        },
+    );
+
+    // tracking information follows the components
+    world.run(|sprites: View<Sprite, track::Modification>| {
+        assert_eq!(
+            sprites
+                .modified()
+                .iter()
+                .with_id()
+                .map(|(id, sprite)| (id, *sprite))
+                .collect::<Vec<_>>(),
+            [(entity4, Sprite(40))]
+        );
+    });
+}
@@ -0,0 +1,83 @@
+use shipyard::scheduler::{info::WorkloadInfo, SystemModificator};
+use shipyard::{Component, View, ViewMut, Workload};
+
+#[derive(Component)]
+struct Wrapper<T: Send + Sync + 'static>(T);
+
+#[derive(Component)]
+struct Health;
+
+fn write<T: Send + Sync + 'static>(_: ViewMut<Wrapper<T>>) {}
+fn read<T: Send + Sync + 'static>(_: View<Wrapper<T>>) {}
+fn heal(_: ViewMut<Health>) {}
+
+fn info() -> WorkloadInfo {
+    Workload::new("Main \"loop\"")
+        .with_system(write::<[u8; 4]>)
+        .with_system(read::<[u8; 4]>)
+        .with_system(heal.after_all(read::<[u8; 4]>))
+        .build()
+        .unwrap()
+        .1
+}
+
+#[test]
+fn to_dot() {
+    let expected = r##"digraph "Main \"loop\"" {
+    node [shape=box];
+    subgraph cluster_0 {
+        label="Batch 0";
+        system_0 [label="System(lib::workload::info::write<[u8; 4]>)\n&mut shipyard::sparse_set::SparseSet<lib::workload::info::Wrapper<[u8; 4]>>"];
+    }
+    subgraph cluster_1 {
+        label="Batch 1";
+        system_1 [label="System(lib::workload::info::read<[u8; 4]>)\n&shipyard::sparse_set::SparseSet<lib::workload::info::Wrapper<[u8; 4]>>"];
+    }
+    subgraph cluster_2 {
+        label="Batch 2";
+        system_2 [label="System(lib::workload::info::heal)\n&mut shipyard::sparse_set::SparseSet<lib::workload::info::Health>"];
+    }
+    system_0 -> system_1 [style=dashed, color=red, label="&mut shipyard::sparse_set::SparseSet<lib::workload::info::Wrapper<[u8; 4]>> / &shipyard::sparse_set::SparseSet<lib::workload::info::Wrapper<[u8; 4]>>"];
+    system_1 -> system_2 [label="after_all System(lib::workload::info::read<[u8; 4]>)"];
+}
+"##;
+
+    assert_eq!(info().to_dot(), expected);
+}
+
+#[test]
+fn to_mermaid() {
+    let expected = r##"flowchart TB
+    %% Main "loop"
+    subgraph batch_0["Batch 0"]
+        system_0["System(lib::workload::info::write#lt;[u8; 4]#gt;)<br/>#amp;mut shipyard::sparse_set::SparseSet#lt;lib::workload::info::Wrapper#lt;[u8; 4]#gt;#gt;"]
+    end
+    subgraph batch_1["Batch 1"]
+        system_1["System(lib::workload::info::read#lt;[u8; 4]#gt;)<br/>#amp;shipyard::sparse_set::SparseSet#lt;lib::workload::info::Wrapper#lt;[u8; 4]#gt;#gt;"]
+    end
+    subgraph batch_2["Batch 2"]
+        system_2["System(lib::workload::info::heal)<br/>#amp;mut shipyard::sparse_set::SparseSet#lt;lib::workload::info::Health#gt;"]
+    end
+    system_0 -. "#amp;mut shipyard::sparse_set::SparseSet#lt;lib::workload::info::Wrapper#lt;[u8; 4]#gt;#gt; / #amp;shipyard::sparse_set::SparseSet#lt;lib::workload::info::Wrapper#lt;[u8; 4]#gt;#gt;" .-> system_1
+    system_1 -- "after_all System(lib::workload::info::read#lt;[u8; 4]#gt;)" --> system_2
+"##;
+
+    assert_eq!(info().to_mermaid(), expected);
+}
+
+#[test]
+fn batch_to_dot() {
+    let info = info();
+
+    let expected = r##"digraph "batch" {
+    node [shape=box];
+    subgraph cluster_0 {
+        label="Batch 0";
+        system_1 [label="System(lib::workload::info::read<[u8; 4]>)\n&shipyard::sparse_set::SparseSet<lib::workload::info::Wrapper<[u8; 4]>>"];
+    }
+}
+"##;
+
+    // edges to systems outside of the batch are omitted
+    assert_eq!(info.batches_info[1].to_dot(), expected);
+}
@@ -1,7 +1,8 @@
+mod info;
 #[cfg(feature = "thread_local")]
 mod non_send_sync;
 
-use shipyard::scheduler::{SystemModificator, WorkloadModificator};
+use shipyard::scheduler::{SystemModificator, SystemPipe, WorkloadModificator};
 use shipyard::{borrow::BorrowInfo, scheduler::AsLabel, *};
 
 struct U32(u32);
@@ -245,3 +246,684 @@ fn skip_first() {
 
     world.run_default_workload().unwrap();
 }
+
+#[test]
+fn profiling() {
+    use core::sync::atomic::{AtomicU64, Ordering};
+    use core::time::Duration;
+    use shipyard::scheduler::IntoWorkloadTrySystem;
+
+    static TICKS: AtomicU64 = AtomicU64::new(0);
+
+    fn clock() -> Duration {
+        Duration::from_nanos(TICKS.fetch_add(5, Ordering::Relaxed))
+    }
+
+    fn sys1(_: AllStoragesViewMut) {}
+    fn sys2(_: AllStoragesViewMut) {}
+    fn sys3(_: AllStoragesViewMut) -> Result<(), &'static str> {
+        Err("error")
+    }
+
+    let mut world = World::new();
+
+    Workload::new("Profiled")
+        .with_system(sys1)
+        .with_system(sys2.run_if(|| false))
+        .with_try_system(sys3.into_workload_try_system().unwrap())
+        .add_to_world(&world)
+        .unwrap();
+
+    world.run_workload("Profiled").unwrap_err();
+
+    let stats = world.workload_stats("Profiled").unwrap();
+    assert!(stats.systems.iter().all(|system| system.calls == 0));
+
+    world.enable_profiling_with_clock(clock);
+
+    for _ in 0..3 {
+        world.run_workload("Profiled").unwrap_err();
+    }
+
+    let stats = world.workload_stats("Profiled").unwrap();
+    assert_eq!(stats.name, "Profiled");
+    assert_eq!(stats.systems.len(), 3);
+
+    assert_eq!(stats.systems[0].name, format!("{:?}", sys1.as_label()));
+    assert_eq!(stats.systems[0].calls, 3);
+    assert_eq!(stats.systems[0].errors, 0);
+    assert_eq!(stats.systems[0].min, Duration::from_nanos(5));
+    assert_eq!(stats.systems[0].max, Duration::from_nanos(5));
+    assert_eq!(stats.systems[0].mean, Duration::from_nanos(5));
+    assert_eq!(stats.systems[0].total, Duration::from_nanos(15));
+
+    assert_eq!(stats.systems[1].calls, 0);
+    assert_eq!(stats.systems[1].skipped, 3);
+    assert_eq!(stats.systems[1].min, Duration::ZERO);
+
+    assert_eq!(stats.systems[2].calls, 3);
+    assert_eq!(stats.systems[2].errors, 3);
+
+    world.reset_workload_stats();
+    world.disable_profiling();
+    world.run_workload("Profiled").unwrap_err();
+
+    let stats = world.workload_stats("Profiled").unwrap();
+    assert!(stats
+        .systems
+        .iter()
+        .all(|system| system.calls == 0 && system.skipped == 0));
+    assert!(world.workload_stats("Missing").is_none());
+}
+
+#[test]
+fn ambiguities() {
+    fn sys_a(_: ViewMut<U32>) {}
+    fn sys_b(_: View<U32>) {}
+    fn sys_c(_: View<U32>) {}
+    fn sys_d(_: View<USIZE>) {}
+
+    let (_, info) = Workload::new("")
+        .with_system(sys_a)
+        .with_system(sys_b)
+        .with_system(sys_c.after_all(sys_a))
+        .with_system(sys_d)
+        .build()
+        .unwrap();
+
+    assert_eq!(info.ambiguities.len(), 1);
+    let ambiguity = &info.ambiguities[0];
+    let system_name = |unique_id| {
+        info.batches_info
+            .iter()
+            .flat_map(|batch| batch.systems())
+            .find(|system| system.unique_id == unique_id)
+            .unwrap()
+            .name
+            .clone()
+    };
+    assert_eq!(
+        system_name(ambiguity.system),
+        format!("{:?}", sys_a.as_label())
+    );
+    assert_eq!(
+        system_name(ambiguity.other_system),
+        format!("{:?}", sys_b.as_label())
+    );
+
+    let (_, info) = Workload::new("")
+        .with_system(sys_a)
+        .with_barrier()
+        .with_system(sys_b)
+        .build()
+        .unwrap();
+    assert!(info.ambiguities.is_empty());
+
+    let (_, info) = Workload::new("")
+        .with_system(sys_a)
+        .with_system(sys_b.tag("readers"))
+        .with_system(sys_c.tag("readers"))
+        .allow_ambiguity("readers", sys_a)
+        .build()
+        .unwrap();
+    assert!(info.ambiguities.is_empty());
+
+    let err = Workload::new("")
+        .with_system(sys_a)
+        .with_system(sys_b)
+        .deny_ambiguities()
+        .build()
+        .err();
+    assert!(matches!(
+        err,
+        Some(error::AddWorkload::Ambiguity { system, other_system, type_info })
+            if system == sys_a.as_label()
+                && other_system == sys_b.as_label()
+                && type_info.mutability == borrow::Mutability::Exclusive
+    ));
+}
+
+#[test]
+fn merged_deny_ambiguities() {
+    fn sys_a(_: ViewMut<U32>) {}
+    fn sys_b(_: View<U32>) {}
+
+    let nested = Workload::new("Nested")
+        .with_system(sys_a)
+        .with_system(sys_b)
+        .deny_ambiguities();
+
+    let err = Workload::new("").with_workload(nested).build().err();
+    assert!(matches!(err, Some(error::AddWorkload::Ambiguity { .. })));
+}
+
+#[test]
+fn deterministic() {
+    use std::sync::Mutex;
+    use std::thread::ThreadId;
+
+    static ORDER: Mutex<Vec<(u32, ThreadId)>> = Mutex::new(Vec::new());
+
+    fn sys0() {
+        ORDER.lock().unwrap().push((0, std::thread::current().id()));
+    }
+    fn sys1() {
+        ORDER.lock().unwrap().push((1, std::thread::current().id()));
+    }
+    fn sys2() {
+        ORDER.lock().unwrap().push((2, std::thread::current().id()));
+    }
+    fn sys3() {
+        ORDER.lock().unwrap().push((3, std::thread::current().id()));
+    }
+
+    let mut world = World::new();
+    world.set_deterministic(true);
+    assert!(world.is_deterministic());
+
+    Workload::new("")
+        .with_system(sys0)
+        .with_system(sys1)
+        .with_system(sys2)
+        .with_system(sys3)
+        .add_to_world(&world)
+        .unwrap();
+
+    let thread_id = std::thread::current().id();
+    for _ in 0..10 {
+        world.run_default_workload().unwrap();
+
+        assert_eq!(
+            core::mem::take(&mut *ORDER.lock().unwrap()),
+            [
+                (0, thread_id),
+                (1, thread_id),
+                (2, thread_id),
+                (3, thread_id)
+            ]
+        );
+    }
+}
+
+#[test]
+fn modify_registered_workload() {
+    fn increment(mut u32: UniqueViewMut<U32>) {
+        u32.0 += 1;
+    }
+    fn double(mut u32: UniqueViewMut<U32>) {
+        u32.0 *= 2;
+    }
+    fn triple(mut u32: UniqueViewMut<U32>) {
+        u32.0 *= 3;
+    }
+    fn check(_: UniqueView<U32>) {}
+
+    let world = World::new();
+    world.add_unique(U32(0));
+
+    Workload::new("Update")
+        .with_system(increment)
+        .add_to_world(&world)
+        .unwrap();
+
+    let run = || {
+        world.run_workload("Update").unwrap();
+        world.borrow::<UniqueView<U32>>().unwrap().0
+    };
+
+    assert_eq!(run(), 1);
+
+    world.add_system_to_workload("Update", double).unwrap();
+    assert_eq!(run(), 4);
+
+    world
+        .disable_system_in_workload("Update", increment)
+        .unwrap();
+    assert_eq!(run(), 8);
+
+    world
+        .enable_system_in_workload("Update", increment)
+        .unwrap();
+    assert_eq!(run(), 18);
+
+    world
+        .replace_system_in_workload("Update", double, triple)
+        .unwrap();
+    assert_eq!(run(), 57);
+    assert_eq!(world.workloads_info().0["Update"].batches_info.len(), 2);
+
+    world
+        .remove_system_from_workload("Update", increment)
+        .unwrap();
+    assert_eq!(run(), 171);
+
+    assert_eq!(
+        world.remove_system_from_workload("Update", increment),
+        Err(error::ModifyWorkload::MissingSystem(increment.as_label()))
+    );
+    assert_eq!(
+        world.add_system_to_workload("Missing", double),
+        Err(error::ModifyWorkload::MissingWorkload)
+    );
+    assert!(matches!(
+        world.add_system_to_workload("Update", check.require_before(increment)),
+        Err(error::ModifyWorkload::Invalid(
+            error::AddWorkload::MissingBefore(..)
+        ))
+    ));
+    // the invalid modification was not applied
+    assert_eq!(run(), 513);
+}
+
+#[test]
+fn error_policy() {
+    use shipyard::scheduler::{ErrorPolicy, IntoWorkloadTrySystem};
+
+    fn ai(_: UniqueView<U32>) -> Result<(), &'static str> {
+        Err("ai")
+    }
+    fn physics(mut u32: UniqueViewMut<U32>) {
+        u32.0 += 1;
+    }
+    fn render(_: UniqueView<U32>) -> Result<(), &'static str> {
+        Err("render")
+    }
+
+    let world = World::new();
+    world.add_unique(U32(0));
+
+    let workload = || {
+        Workload::new("")
+            .with_try_system(ai)
+            .with_system(physics)
+            .with_try_system(render)
+    };
+
+    workload().rename("Abort").add_to_world(&world).unwrap();
+    workload()
+        .rename("Continue")
+        .on_error(ErrorPolicy::Continue)
+        .add_to_world(&world)
+        .unwrap();
+    workload()
+        .rename("Collect")
+        .on_error(ErrorPolicy::Collect)
+        .add_to_world(&world)
+        .unwrap();
+    Workload::new("Override")
+        .with_system(
+            ai.into_workload_try_system()
+                .unwrap()
+                .on_error(ErrorPolicy::Collect),
+        )
+        .with_system(physics)
+        .with_try_system(render)
+        .add_to_world(&world)
+        .unwrap();
+
+    let physics_runs = || world.borrow::<UniqueView<U32>>().unwrap().0;
+
+    match world.run_workload("Abort") {
+        Err(error::RunWorkload::Run((system, _))) => {
+            assert_eq!(format!("{:?}", system), format!("{:?}", ai.as_label()))
+        }
+        _ => panic!(),
+    }
+    assert_eq!(physics_runs(), 0);
+
+    world.run_workload("Continue").unwrap();
+    assert_eq!(physics_runs(), 1);
+
+    match world.run_workload("Collect") {
+        Err(error::RunWorkload::Multiple(errors)) => {
+            assert_eq!(errors.len(), 2);
+            assert!(
+                matches!(&errors[0], error::RunWorkload::Run((system, _)) if format!("{:?}", system) == format!("{:?}", ai.as_label()))
+            );
+            assert!(
+                matches!(&errors[1], error::RunWorkload::Run((system, _)) if format!("{:?}", system) == format!("{:?}", render.as_label()))
+            );
+        }
+        _ => panic!(),
+    }
+    assert_eq!(physics_runs(), 2);
+
+    // render keeps the default policy and stops the workload after ai's error was collected
+    match world.run_workload("Override") {
+        Err(error::RunWorkload::Multiple(errors)) => {
+            assert_eq!(errors.len(), 2);
+            assert!(
+                matches!(&errors[0], error::RunWorkload::Run((system, _)) if format!("{:?}", system) == format!("{:?}", ai.as_label()))
+            );
+            assert!(
+                matches!(&errors[1], error::RunWorkload::Run((system, _)) if format!("{:?}", system) == format!("{:?}", render.as_label()))
+            );
+        }
+        _ => panic!(),
+    }
+    assert_eq!(physics_runs(), 3);
+}
+
+#[test]
+fn error_policy_collect_and_commands() {
+    use shipyard::scheduler::{ErrorPolicy, IntoWorkloadTrySystem};
+
+    fn ai(_: UniqueView<U32>) -> Result<(), &'static str> {
+        Err("ai")
+    }
+    fn spawn(mut commands: Commands) {
+        commands.add_entity((U32(0),));
+    }
+
+    let world = World::new();
+    world.add_unique(U32(0));
+
+    Workload::new("")
+        .with_system(
+            ai.into_workload_try_system()
+                .unwrap()
+                .on_error(ErrorPolicy::Collect),
+        )
+        .with_system(spawn)
+        .with_barrier()
+        .add_to_world(&world)
+        .unwrap();
+
+    let all_storages = world.all_storages().unwrap();
+
+    // The commands can't be applied, the error is returned alongside ai's
+    match world.run_workload("") {
+        Err(error::RunWorkload::Multiple(errors)) => {
+            assert_eq!(errors.len(), 2);
+            assert!(matches!(errors[0], error::RunWorkload::Run(_)));
+            assert!(matches!(
+                errors[1],
+                error::RunWorkload::AllStoragesBorrow(_)
+            ));
+        }
+        _ => panic!(),
+    }
+
+    drop(all_storages);
+}
+
+#[test]
+fn local() {
+    use shipyard::views::Local;
+
+    fn count(mut count: Local<u32>, mut u32: UniqueViewMut<U32>) {
+        *count += 1;
+        u32.0 = *count;
+    }
+    #[derive(Borrow, BorrowInfo)]
+    struct Counters<'v> {
+        count: Local<'v, u32>,
+        total: Local<'v, usize>,
+    }
+
+    fn other_count(mut counters: Counters, (mut runs, _): (Local<u64>, Option<Local<i8>>)) {
+        *counters.count += 10;
+        *counters.total += *counters.count as usize;
+        *runs += 1;
+
+        assert_eq!(*counters.total as u64, 10 * *runs * (*runs + 1) / 2);
+    }
+
+    let world = World::new();
+    world.add_unique(U32(0));
+
+    Workload::new("A")
+        .with_system(count)
+        .with_system(other_count)
+        .add_to_world(&world)
+        .unwrap();
+    Workload::new("B")
+        .with_system(count)
+        .add_to_world(&world)
+        .unwrap();
+
+    // Local views never conflict
+    assert_eq!(world.workloads_info().0["A"].batches_info.len(), 1);
+
+    world.run_workload("A").unwrap();
+    world.run_workload("A").unwrap();
+    assert_eq!(world.borrow::<UniqueView<U32>>().unwrap().0, 2);
+
+    // Each workload has its own state
+    world.run_workload("B").unwrap();
+    assert_eq!(world.borrow::<UniqueView<U32>>().unwrap().0, 1);
+
+    // The state is kept when the workload is modified
+    world.disable_system_in_workload("A", count).unwrap();
+    world.run_workload("A").unwrap();
+    world.enable_system_in_workload("A", count).unwrap();
+    world.run_workload("A").unwrap();
+    assert_eq!(world.borrow::<UniqueView<U32>>().unwrap().0, 3);
+
+    assert_eq!(
+        world.borrow::<Local<u32>>().err(),
+        Some(error::GetStorage::MissingLocal {
+            name: core::any::type_name::<Local<u32>>()
+        })
+    );
+}
+
+#[test]
+fn local_same_system_twice() {
+    use shipyard::views::Local;
+
+    fn count(mut count: Local<u32>, mut u32: UniqueViewMut<U32>) {
+        *count += 1;
+        u32.0 += *count;
+    }
+    fn count_by_two(mut count: Local<u32>, mut u32: UniqueViewMut<U32>) {
+        *count += 2;
+        u32.0 += *count;
+    }
+
+    let world = World::new();
+    world.add_unique(U32(0));
+
+    Workload::new("")
+        .with_system(count)
+        .with_system(count)
+        .add_to_world(&world)
+        .unwrap();
+
+    // Each instance has its own state
+    world.run_workload("").unwrap();
+    world.run_workload("").unwrap();
+    assert_eq!(world.borrow::<UniqueView<U32>>().unwrap().0, 1 + 1 + 2 + 2);
+
+    // The new system doesn't get the state of the one it replaced
+    world
+        .replace_system_in_workload("", count, count_by_two)
+        .unwrap();
+    world.borrow::<UniqueViewMut<U32>>().unwrap().0 = 0;
+    world.run_workload("").unwrap();
+    assert_eq!(world.borrow::<UniqueView<U32>>().unwrap().0, 2);
+}
+
+#[test]
+fn pipe() {
+    fn check(u32: UniqueView<U32>) -> Result<u32, error::MissingComponent> {
+        if u32.0 < 2 {
+            Ok(u32.0)
+        } else {
+            Err(error::MissingComponent {
+                id: EntityId::dead(),
+                name: "U32",
+            })
+        }
+    }
+    fn handle(
+        result: Result<u32, error::MissingComponent>,
+        mut u32: UniqueViewMut<U32>,
+        mut usize: UniqueViewMut<USIZE>,
+    ) {
+        match result {
+            Ok(_) => u32.0 += 1,
+            Err(_) => usize.0 += 1,
+        }
+    }
+    fn read_usize(_: UniqueView<USIZE>) {}
+    fn ten() -> usize {
+        10
+    }
+    fn add(n: usize, mut usize: UniqueViewMut<USIZE>) {
+        usize.0 += n;
+    }
+    fn reject(result: Result<u32, error::MissingComponent>) -> Result<(), error::MissingComponent> {
+        result.map(drop)
+    }
+
+    let world = World::new();
+    world.add_unique(U32(0));
+    world.add_unique(USIZE(0));
+
+    Workload::new("A")
+        .with_system(check.pipe(handle))
+        .with_system(read_usize)
+        .add_to_world(&world)
+        .unwrap();
+    Workload::new("B")
+        .with_system(ten.pipe(add))
+        .add_to_world(&world)
+        .unwrap();
+
+    // The piped system borrows the storages of both systems
+    let info = &world.workloads_info().0["A"];
+    assert_eq!(info.batches_info.len(), 2);
+    assert_eq!(info.batches_info[0].systems.1[0].borrow.len(), 3);
+
+    for _ in 0..4 {
+        world.run_workload("A").unwrap();
+    }
+    assert_eq!(world.borrow::<UniqueView<U32>>().unwrap().0, 2);
+    assert_eq!(world.borrow::<UniqueView<USIZE>>().unwrap().0, 2);
+
+    world.run_workload("B").unwrap();
+    assert_eq!(world.borrow::<UniqueView<USIZE>>().unwrap().0, 12);
+
+    // The error returned by the second system reaches the workload
+    Workload::new("C")
+        .with_system(check.try_pipe(reject))
+        .add_to_world(&world)
+        .unwrap();
+
+    assert!(matches!(
+        world.run_workload("C"),
+        Err(error::RunWorkload::Run(_))
+    ));
+}
+
+#[test]
+fn pipe_locals() {
+    use shipyard::views::Local;
+
+    fn first(mut count: Local<u32>) -> u32 {
+        *count += 1;
+        *count
+    }
+    fn second(n: u32, mut count: Local<u32>, mut u32: UniqueViewMut<U32>) {
+        *count += 10;
+        u32.0 = n + *count;
+    }
+
+    let world = World::new();
+    world.add_unique(U32(0));
+
+    Workload::new("")
+        .with_system(first.pipe(second))
+        .add_to_world(&world)
+        .unwrap();
+
+    // Both systems have their own state
+    world.run_workload("").unwrap();
+    world.run_workload("").unwrap();
+    assert_eq!(world.borrow::<UniqueView<U32>>().unwrap().0, 2 + 20);
+}
+
+#[test]
+fn fixed_timestep() {
+    use core::time::Duration;
+    use shipyard::scheduler::{FixedTimestep, FixedTimestepAlpha};
+
+    struct Spawned;
+    impl Component for Spawned {
+        type Tracking = track::Insertion;
+    }
+
+    fn spawn(mut entities: EntitiesViewMut, mut spawned: ViewMut<Spawned>) {
+        entities.add_entity(&mut spawned, Spawned);
+    }
+    fn count(spawned: View<Spawned, track::Insertion>, mut u32: UniqueViewMut<U32>) {
+        // each sub-step only sees the entity added since the previous one
+        assert_eq!(spawned.inserted().iter().count(), 1);
+
+        u32.0 += 1;
+    }
+
+    let world = World::new();
+    world.add_unique(U32(0));
+
+    Workload::new("Fixed")
+        .with_system(spawn)
+        .with_system(count)
+        .add_to_world(&world)
+        .unwrap();
+
+    let mut fixed = FixedTimestep::new("Fixed", Duration::from_millis(10)).with_max_steps(3);
+
+    assert_eq!(fixed.run(&world, Duration::from_millis(5)).unwrap(), 0);
+    assert_eq!(
+        world
+            .borrow::<UniqueView<FixedTimestepAlpha>>()
+            .unwrap()
+            .get("Fixed"),
+        Some(0.5)
+    );
+
+    assert_eq!(fixed.run(&world, Duration::from_millis(20)).unwrap(), 2);
+    assert_eq!(world.borrow::<UniqueView<U32>>().unwrap().0, 2);
+    assert_eq!(fixed.accumulator(), Duration::from_millis(5));
+
+    // Only 3 steps are caught up, the rest is dropped
+    assert_eq!(fixed.run(&world, Duration::from_millis(100)).unwrap(), 3);
+    assert_eq!(world.borrow::<UniqueView<U32>>().unwrap().0, 5);
+    assert_eq!(fixed.accumulator(), Duration::from_millis(5));
+    assert_eq!(
+        world
+            .borrow::<UniqueView<FixedTimestepAlpha>>()
+            .unwrap()
+            .get("Fixed"),
+        Some(0.5)
+    );
+
+    // Each runner has its own alpha
+    Workload::new("Other")
+        .with_system(|| {})
+        .add_to_world(&world)
+        .unwrap();
+    let mut other = FixedTimestep::new("Other", Duration::from_millis(20));
+    assert_eq!(other.run(&world, Duration::from_millis(5)).unwrap(), 0);
+    let alpha = world.borrow::<UniqueView<FixedTimestepAlpha>>().unwrap();
+    assert_eq!(alpha.get("Fixed"), Some(0.5));
+    assert_eq!(alpha.get("Other"), Some(0.25));
+    drop(alpha);
+
+    let mut missing = FixedTimestep::new("Missing", Duration::from_millis(10));
+    assert!(matches!(
+        missing.run(&world, Duration::from_millis(10)),
+        Err(error::RunWorkload::MissingWorkload)
+    ));
+    assert_eq!(missing.accumulator(), Duration::from_millis(10));
+    // The alpha is updated even if a sub-step failed
+    assert_eq!(
+        world
+            .borrow::<UniqueView<FixedTimestepAlpha>>()
+            .unwrap()
+            .get("Missing"),
+        Some(1.0)
+    );
+}